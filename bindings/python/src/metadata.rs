@@ -179,7 +179,7 @@ impl EntryMode {
         match self.0 {
             ocore::EntryMode::FILE => "EntryMode.FILE",
             ocore::EntryMode::DIR => "EntryMode.DIR",
-            ocore::EntryMode::Unknown => "EntryMode.UNKNOWN",
+            ocore::EntryMode::Symlink | ocore::EntryMode::Unknown => "EntryMode.UNKNOWN",
         }
     }
 }
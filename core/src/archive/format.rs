@@ -0,0 +1,70 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+/// Formats of archives.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Format {
+    /// Zip archive, files are compressed with deflate.
+    Zip,
+    /// Uncompressed tar archive.
+    #[default]
+    Tar,
+    /// Tar archive compressed with gzip.
+    TarGz,
+    /// Tar archive compressed with zstd.
+    TarZstd,
+}
+
+impl Format {
+    /// Parse format from its name like `tar.gz`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "zip" => Some(Self::Zip),
+            "tar" => Some(Self::Tar),
+            "tar.gz" | "tgz" => Some(Self::TarGz),
+            "tar.zst" | "tzst" => Some(Self::TarZstd),
+            _ => None,
+        }
+    }
+
+    /// Detect format from the extension of `path`.
+    pub fn from_path(path: &str) -> Option<Self> {
+        let path = path.to_lowercase();
+        [
+            (".zip", Self::Zip),
+            (".tar", Self::Tar),
+            (".tar.gz", Self::TarGz),
+            (".tgz", Self::TarGz),
+            (".tar.zst", Self::TarZstd),
+            (".tzst", Self::TarZstd),
+        ]
+        .into_iter()
+        .find(|(ext, _)| path.ends_with(ext))
+        .map(|(_, format)| format)
+    }
+
+    /// The content type of archives in this format.
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Zip => "application/zip",
+            Self::Tar => "application/x-tar",
+            Self::TarGz => "application/gzip",
+            Self::TarZstd => "application/zstd",
+        }
+    }
+}
@@ -0,0 +1,330 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::mem;
+
+use async_compression::futures::write::DeflateEncoder;
+use async_compression::futures::write::GzipEncoder;
+use async_compression::futures::write::ZstdEncoder;
+use bytes::Bytes;
+use futures::AsyncWriteExt;
+use futures::TryStreamExt;
+
+use super::tar;
+use super::zip::end_of_central_directory;
+use super::zip::ZipEntry;
+use super::Format;
+use crate::options::WriteOptions;
+use crate::raw::*;
+use crate::*;
+
+/// Compressed bytes are written into destination once they reach this size.
+const FLUSH_SIZE: usize = 256 * 1024;
+
+/// Options for [`pack_options`].
+#[derive(Debug, Clone, Default)]
+pub struct PackOptions {
+    /// The format of archive.
+    ///
+    /// Default to [`Format::Tar`].
+    pub format: Format,
+    /// Whether to store user metadata of objects into the archive.
+    ///
+    /// User metadata is stored as pax records named `OPENDAL.meta.<key>` of tar
+    /// archives, it's ignored by zip archives. Every object will be stated to fetch
+    /// its user metadata.
+    ///
+    /// Default to `false`.
+    pub user_metadata: bool,
+    /// Options to write the archive, like `chunk` and `concurrent` for multipart uploads.
+    ///
+    /// `content_type` is set by the format if it's not specified.
+    pub write: WriteOptions,
+}
+
+/// Pack all objects under `prefix` of `src` into an archive at `dst_path` of `dst`.
+///
+/// Refer to [`pack_options`] for more details.
+///
+/// # Examples
+///
+/// ```
+/// # use opendal::Result;
+/// # use opendal::Operator;
+/// use opendal::archive::Format;
+///
+/// # async fn test(src: Operator, dst: Operator) -> Result<()> {
+/// opendal::archive::pack(&src, "tenant-1/", &dst, "export/tenant-1.tar.zst", Format::TarZstd)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn pack(
+    src: &Operator,
+    prefix: &str,
+    dst: &Operator,
+    dst_path: &str,
+    format: Format,
+) -> Result<Metadata> {
+    let opts = PackOptions {
+        format,
+        ..Default::default()
+    };
+    pack_options(src, prefix, dst, dst_path, opts).await
+}
+
+/// Pack all objects under `prefix` of `src` into an archive at `dst_path` of `dst`
+/// with extra options.
+///
+/// Entries are listed recursively and streamed into the archive one by one, so
+/// neither objects nor the archive are buffered in memory or on local disk.
+/// Paths of entries are relative to `prefix`, which must be a dir like `abc/`.
+/// `last_modified` of objects is kept in the archive.
+///
+/// Returns the metadata of written archive.
+///
+/// # Notes
+///
+/// - `dst_path` must not be under `prefix` of the same storage, or the archive may
+///   include itself.
+/// - An error will be returned if an object is changed while packing.
+pub async fn pack_options(
+    src: &Operator,
+    prefix: &str,
+    dst: &Operator,
+    dst_path: &str,
+    mut opts: PackOptions,
+) -> Result<Metadata> {
+    let prefix = normalize_path(prefix);
+    if !prefix.ends_with('/') {
+        return Err(
+            Error::new(ErrorKind::NotADirectory, "prefix to pack must be a dir")
+                .with_operation("archive::pack")
+                .with_context("prefix", prefix),
+        );
+    }
+
+    if opts.write.content_type.is_none() {
+        opts.write.content_type = Some(opts.format.content_type().to_string());
+    }
+    let (format, user_metadata) = (opts.format, opts.user_metadata);
+    let writer = dst.writer_options(dst_path, opts.write).await?;
+    let mut out = Output::new(writer, format);
+
+    let res = async {
+        let mut lister = src.lister_with(&prefix).recursive(true).await?;
+        let mut zip_entries = Vec::new();
+        while let Some(entry) = lister.try_next().await? {
+            let (path, mut meta) = entry.into_parts();
+            let name = if prefix == "/" {
+                path.as_str()
+            } else {
+                path.strip_prefix(prefix.as_str()).unwrap_or_default()
+            };
+            if name.is_empty() {
+                continue;
+            }
+
+            let is_dir = meta.is_dir();
+            let missing_metadata = meta.last_modified().is_none() || meta.content_length() == 0;
+            if !is_dir && (user_metadata || missing_metadata) {
+                meta = src.stat(&path).await?;
+            }
+            let user_meta = if user_metadata {
+                meta.user_metadata()
+            } else {
+                None
+            };
+
+            if format == Format::Zip {
+                let size = if is_dir { 0 } else { meta.content_length() };
+                let entry = ZipEntry::new(
+                    name.to_string(),
+                    is_dir,
+                    size,
+                    meta.last_modified(),
+                    out.offset,
+                );
+                zip_entries.push(out.write_zip_entry(src, &path, entry, size).await?);
+            } else {
+                let size = if is_dir { 0 } else { meta.content_length() };
+                let headers =
+                    tar::build_headers(name, is_dir, size, meta.last_modified(), user_meta);
+                out.write(Bytes::from(headers)).await?;
+                if !is_dir {
+                    out.write_object(src, &path, size).await?;
+                    let padding = tar::padding(size);
+                    if padding > 0 {
+                        out.write(Bytes::from(vec![0; padding])).await?;
+                    }
+                }
+            }
+        }
+
+        if format == Format::Zip {
+            let offset = out.offset;
+            for entry in &zip_entries {
+                out.write(Bytes::from(entry.central_header())).await?;
+            }
+            let eocd =
+                end_of_central_directory(zip_entries.len() as u64, offset, out.offset - offset);
+            out.write(Bytes::from(eocd)).await?;
+        } else {
+            out.write(Bytes::from(tar::end_blocks())).await?;
+        }
+        out.close().await
+    }
+    .await;
+    if res.is_err() {
+        // Keep the original error, abort is best effort here.
+        let _ = out.writer.abort().await;
+    }
+    res
+}
+
+/// Output compresses the archive if needed and writes it into destination.
+struct Output {
+    writer: Writer,
+    encoder: Encoder,
+    /// Size of the archive written so far, before it's compressed.
+    offset: u64,
+}
+
+enum Encoder {
+    None,
+    Gzip(GzipEncoder<Vec<u8>>),
+    Zstd(ZstdEncoder<Vec<u8>>),
+}
+
+impl Output {
+    fn new(writer: Writer, format: Format) -> Self {
+        let encoder = match format {
+            Format::TarGz => Encoder::Gzip(GzipEncoder::new(Vec::new())),
+            Format::TarZstd => Encoder::Zstd(ZstdEncoder::new(Vec::new())),
+            _ => Encoder::None,
+        };
+        Self {
+            writer,
+            encoder,
+            offset: 0,
+        }
+    }
+
+    async fn write(&mut self, bs: Bytes) -> Result<()> {
+        self.offset += bs.len() as u64;
+        match &mut self.encoder {
+            Encoder::None => return self.writer.write(bs).await,
+            Encoder::Gzip(e) => e.write_all(&bs).await.map_err(new_std_io_error)?,
+            Encoder::Zstd(e) => e.write_all(&bs).await.map_err(new_std_io_error)?,
+        }
+        self.flush(false).await
+    }
+
+    /// Write compressed bytes into destination, all of them are written if `force` is true.
+    async fn flush(&mut self, force: bool) -> Result<()> {
+        let buf = match &mut self.encoder {
+            Encoder::None => return Ok(()),
+            Encoder::Gzip(e) => e.get_mut(),
+            Encoder::Zstd(e) => e.get_mut(),
+        };
+        if buf.len() >= FLUSH_SIZE || (force && !buf.is_empty()) {
+            let bs = mem::take(buf);
+            self.writer.write(bs).await?;
+        }
+        Ok(())
+    }
+
+    /// Write the whole content of object at `path`, whose size must be `size`.
+    async fn write_object(&mut self, src: &Operator, path: &str, size: u64) -> Result<()> {
+        let mut stream = src.reader(path).await?.into_stream(..).await?;
+        let mut written = 0;
+        while let Some(buf) = stream.try_next().await? {
+            written += buf.len() as u64;
+            if written > size {
+                break;
+            }
+            for bs in buf {
+                self.write(bs).await?;
+            }
+        }
+        if written != size {
+            return Err(object_changed(path));
+        }
+        Ok(())
+    }
+
+    /// Write a zip entry whose data of `size` is compressed with deflate, returns
+    /// the entry filled with crc and sizes.
+    async fn write_zip_entry(
+        &mut self,
+        src: &Operator,
+        path: &str,
+        mut entry: ZipEntry,
+        size: u64,
+    ) -> Result<ZipEntry> {
+        self.write(Bytes::from(entry.local_header())).await?;
+
+        if !entry.is_dir {
+            let mut hasher = crc32fast::Hasher::new();
+            let mut encoder = DeflateEncoder::new(Vec::new());
+            let mut stream = src.reader(path).await?.into_stream(..).await?;
+            while let Some(buf) = stream.try_next().await? {
+                for bs in buf {
+                    hasher.update(&bs);
+                    entry.size += bs.len() as u64;
+                    encoder.write_all(&bs).await.map_err(new_std_io_error)?;
+                    if encoder.get_ref().len() >= FLUSH_SIZE {
+                        let bs = mem::take(encoder.get_mut());
+                        entry.compressed_size += bs.len() as u64;
+                        self.write(Bytes::from(bs)).await?;
+                    }
+                }
+            }
+            encoder.close().await.map_err(new_std_io_error)?;
+            let bs = encoder.into_inner();
+            entry.compressed_size += bs.len() as u64;
+            self.write(Bytes::from(bs)).await?;
+            entry.crc = hasher.finalize();
+
+            if entry.size != size || !entry.is_valid() {
+                return Err(object_changed(path));
+            }
+        }
+
+        self.write(Bytes::from(entry.data_descriptor())).await?;
+        Ok(entry)
+    }
+
+    async fn close(&mut self) -> Result<Metadata> {
+        match &mut self.encoder {
+            Encoder::None => {}
+            Encoder::Gzip(e) => e.close().await.map_err(new_std_io_error)?,
+            Encoder::Zstd(e) => e.close().await.map_err(new_std_io_error)?,
+        }
+        self.flush(true).await?;
+        self.writer.close().await
+    }
+}
+
+fn object_changed(path: &str) -> Error {
+    Error::new(
+        ErrorKind::ConditionNotMatch,
+        "object is changed while packing",
+    )
+    .with_operation("archive::pack")
+    .with_context("path", path)
+}
@@ -0,0 +1,144 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use chrono::DateTime;
+use chrono::Utc;
+
+pub const BLOCK_SIZE: usize = 512;
+
+/// User metadata is stored as pax records with this prefix followed by the key.
+pub const PAX_USER_METADATA_PREFIX: &str = "OPENDAL.meta.";
+
+/// The largest value of numeric fields, which are 11 octal digits.
+const MAX_NUMERIC: u64 = 0o77777777777;
+
+/// Build headers of an entry, which is a ustar header and an optional pax header
+/// before it for the fields that can't be stored in ustar header.
+pub fn build_headers(
+    name: &str,
+    is_dir: bool,
+    size: u64,
+    last_modified: Option<DateTime<Utc>>,
+    user_metadata: Option<&HashMap<String, String>>,
+) -> Vec<u8> {
+    let mtime = last_modified.map_or(0, |v| v.timestamp().clamp(0, MAX_NUMERIC as i64) as u64);
+
+    let mut records = Vec::new();
+    if name.len() > 100 || !name.is_ascii() {
+        records.push(("path".to_string(), name.to_string()));
+    }
+    if size > MAX_NUMERIC {
+        records.push(("size".to_string(), size.to_string()));
+    }
+    if let Some(v) = last_modified.filter(|v| v.timestamp_subsec_nanos() != 0) {
+        let nanos = format!("{:09}", v.timestamp_subsec_nanos());
+        records.push((
+            "mtime".to_string(),
+            format!("{mtime}.{}", nanos.trim_end_matches('0')),
+        ));
+    }
+    if let Some(meta) = user_metadata {
+        let mut meta: Vec<_> = meta.iter().collect();
+        meta.sort();
+        for (k, v) in meta {
+            records.push((format!("{PAX_USER_METADATA_PREFIX}{k}"), v.clone()));
+        }
+    }
+
+    let mut buf = Vec::with_capacity(BLOCK_SIZE * 3);
+    if !records.is_empty() {
+        let data = build_pax_records(&records);
+        let pax_name = format!("PaxHeaders/{}", truncate(name, 89));
+        buf.extend(build_header(&pax_name, b'x', data.len() as u64, mtime));
+        buf.extend_from_slice(&data);
+        buf.resize(buf.len() + padding(data.len() as u64), 0);
+    }
+    let typeflag = if is_dir { b'5' } else { b'0' };
+    buf.extend(build_header(
+        truncate(name, 100),
+        typeflag,
+        if is_dir { 0 } else { size.min(MAX_NUMERIC) },
+        mtime,
+    ));
+    buf
+}
+
+/// The zero blocks at the end of tar archive.
+pub fn end_blocks() -> Vec<u8> {
+    vec![0; BLOCK_SIZE * 2]
+}
+
+/// Size of zeros to pad data of `size` to the whole blocks.
+pub fn padding(size: u64) -> usize {
+    (size.next_multiple_of(BLOCK_SIZE as u64) - size) as usize
+}
+
+fn build_header(name: &str, typeflag: u8, size: u64, mtime: u64) -> [u8; BLOCK_SIZE] {
+    let mut header = [0; BLOCK_SIZE];
+    header[..name.len()].copy_from_slice(name.as_bytes());
+    let mode = if typeflag == b'5' { 0o755 } else { 0o644 };
+    write_numeric(&mut header[100..108], mode);
+    write_numeric(&mut header[108..116], 0);
+    write_numeric(&mut header[116..124], 0);
+    write_numeric(&mut header[124..136], size);
+    write_numeric(&mut header[136..148], mtime);
+    header[156] = typeflag;
+    header[257..263].copy_from_slice(b"ustar\0");
+    header[263..265].copy_from_slice(b"00");
+
+    // The checksum is calculated with the checksum field taken as spaces.
+    header[148..156].fill(b' ');
+    let checksum: u32 = header.iter().map(|b| *b as u32).sum();
+    header[148..155].copy_from_slice(format!("{checksum:06o}\0").as_bytes());
+    header
+}
+
+/// Write `v` as octal digits that fill the field except the trailing nul.
+fn write_numeric(field: &mut [u8], v: u64) {
+    let n = field.len() - 1;
+    field[..n].copy_from_slice(format!("{v:0n$o}").as_bytes());
+    field[n] = 0;
+}
+
+/// Build records like `<len> <key>=<value>\n`, the length includes itself.
+fn build_pax_records(records: &[(String, String)]) -> Vec<u8> {
+    let mut data = String::new();
+    for (k, v) in records {
+        let rest = k.len() + v.len() + 3;
+        let mut len = rest + 1;
+        while len != rest + len.to_string().len() {
+            len = rest + len.to_string().len();
+        }
+        let _ = writeln!(data, "{len} {k}={v}");
+    }
+    data.into_bytes()
+}
+
+/// Truncate `s` to at most `n` bytes at a char boundary.
+fn truncate(s: &str, n: usize) -> &str {
+    if s.len() <= n {
+        return s;
+    }
+    let mut end = n;
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    &s[..end]
+}
@@ -0,0 +1,269 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use chrono::DateTime;
+use chrono::Datelike;
+use chrono::Timelike;
+use chrono::Utc;
+
+pub const METHOD_STORED: u16 = 0;
+pub const METHOD_DEFLATED: u16 = 8;
+
+const LOCAL_HEADER_SIGNATURE: u32 = 0x04034b50;
+const DATA_DESCRIPTOR_SIGNATURE: u32 = 0x08074b50;
+const CENTRAL_HEADER_SIGNATURE: u32 = 0x02014b50;
+const ZIP64_EOCD_SIGNATURE: u32 = 0x06064b50;
+const ZIP64_LOCATOR_SIGNATURE: u32 = 0x07064b50;
+const EOCD_SIGNATURE: u32 = 0x06054b50;
+
+const EXTRA_ZIP64: u16 = 0x0001;
+const EXTRA_TIMESTAMP: u16 = 0x5455;
+
+/// Sizes and crc are written in the data descriptor after data.
+const FLAG_DATA_DESCRIPTOR: u16 = 0x0008;
+/// Names are encoded in UTF-8.
+const FLAG_UTF8: u16 = 0x0800;
+
+const VERSION: u16 = 20;
+const VERSION_ZIP64: u16 = 45;
+/// Archives are made on unix, so that external attributes are unix modes.
+const MADE_BY_UNIX: u16 = 3 << 8;
+
+/// Values equal to or larger than this must be stored in zip64 fields.
+const ZIP64_LIMIT: u64 = u32::MAX as u64;
+
+/// ZipEntry is a member written into zip archive.
+pub struct ZipEntry {
+    pub name: String,
+    pub is_dir: bool,
+    pub method: u16,
+    pub last_modified: Option<DateTime<Utc>>,
+    pub header_offset: u64,
+    /// Whether sizes are stored in zip64 fields, which must be decided before
+    /// the data is written.
+    pub zip64: bool,
+    pub crc: u32,
+    pub compressed_size: u64,
+    pub size: u64,
+}
+
+impl ZipEntry {
+    /// Create an entry at `header_offset` for data of `size`.
+    pub fn new(
+        name: String,
+        is_dir: bool,
+        size: u64,
+        last_modified: Option<DateTime<Utc>>,
+        header_offset: u64,
+    ) -> Self {
+        // Deflate adds a few bytes for every stored block of 64 KiB at most.
+        let zip64 = size + size / 8192 + 1024 >= ZIP64_LIMIT;
+        Self {
+            name,
+            is_dir,
+            method: if is_dir {
+                METHOD_STORED
+            } else {
+                METHOD_DEFLATED
+            },
+            last_modified,
+            header_offset,
+            zip64,
+            crc: 0,
+            compressed_size: 0,
+            size: 0,
+        }
+    }
+
+    /// Whether sizes written after data are valid for the header.
+    pub fn is_valid(&self) -> bool {
+        self.zip64 || (self.size < ZIP64_LIMIT && self.compressed_size < ZIP64_LIMIT)
+    }
+
+    /// Build the local file header, whose crc and sizes are left as zeros.
+    pub fn local_header(&self) -> Vec<u8> {
+        let mut extra = Vec::new();
+        if self.zip64 {
+            put_u16(&mut extra, EXTRA_ZIP64);
+            put_u16(&mut extra, 16);
+            put_u64(&mut extra, 0);
+            put_u64(&mut extra, 0);
+        }
+        self.put_timestamp(&mut extra);
+
+        let (time, date) = dos_datetime(self.last_modified);
+        let sizes = if self.zip64 { u32::MAX } else { 0 };
+        let mut buf = Vec::with_capacity(30 + self.name.len() + extra.len());
+        put_u32(&mut buf, LOCAL_HEADER_SIGNATURE);
+        put_u16(&mut buf, self.version());
+        put_u16(&mut buf, FLAG_DATA_DESCRIPTOR | FLAG_UTF8);
+        put_u16(&mut buf, self.method);
+        put_u16(&mut buf, time);
+        put_u16(&mut buf, date);
+        put_u32(&mut buf, 0);
+        put_u32(&mut buf, sizes);
+        put_u32(&mut buf, sizes);
+        put_u16(&mut buf, self.name.len() as u16);
+        put_u16(&mut buf, extra.len() as u16);
+        buf.extend_from_slice(self.name.as_bytes());
+        buf.extend_from_slice(&extra);
+        buf
+    }
+
+    /// Build the data descriptor, sizes are 8 bytes for zip64 entries.
+    pub fn data_descriptor(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(24);
+        put_u32(&mut buf, DATA_DESCRIPTOR_SIGNATURE);
+        put_u32(&mut buf, self.crc);
+        if self.zip64 {
+            put_u64(&mut buf, self.compressed_size);
+            put_u64(&mut buf, self.size);
+        } else {
+            put_u32(&mut buf, self.compressed_size as u32);
+            put_u32(&mut buf, self.size as u32);
+        }
+        buf
+    }
+
+    /// Build the file header in central directory.
+    pub fn central_header(&self) -> Vec<u8> {
+        // Zip64 fields only exist for the values that overflowed, in this order.
+        let mut zip64 = Vec::new();
+        for v in [self.size, self.compressed_size, self.header_offset] {
+            if v >= ZIP64_LIMIT {
+                put_u64(&mut zip64, v);
+            }
+        }
+        let mut extra = Vec::new();
+        if !zip64.is_empty() {
+            put_u16(&mut extra, EXTRA_ZIP64);
+            put_u16(&mut extra, zip64.len() as u16);
+            extra.extend_from_slice(&zip64);
+        }
+        self.put_timestamp(&mut extra);
+
+        let (time, date) = dos_datetime(self.last_modified);
+        let mode: u32 = if self.is_dir { 0o40755 } else { 0o100644 };
+        // The low byte is for MS-DOS attributes, 0x10 means directory.
+        let attrs = (mode << 16) | if self.is_dir { 0x10 } else { 0 };
+        let mut buf = Vec::with_capacity(46 + self.name.len() + extra.len());
+        put_u32(&mut buf, CENTRAL_HEADER_SIGNATURE);
+        put_u16(&mut buf, MADE_BY_UNIX | VERSION_ZIP64);
+        put_u16(&mut buf, self.version());
+        put_u16(&mut buf, FLAG_DATA_DESCRIPTOR | FLAG_UTF8);
+        put_u16(&mut buf, self.method);
+        put_u16(&mut buf, time);
+        put_u16(&mut buf, date);
+        put_u32(&mut buf, self.crc);
+        put_u32(&mut buf, self.compressed_size.min(ZIP64_LIMIT) as u32);
+        put_u32(&mut buf, self.size.min(ZIP64_LIMIT) as u32);
+        put_u16(&mut buf, self.name.len() as u16);
+        put_u16(&mut buf, extra.len() as u16);
+        // Comment length, disk number and internal attributes.
+        put_u16(&mut buf, 0);
+        put_u16(&mut buf, 0);
+        put_u16(&mut buf, 0);
+        put_u32(&mut buf, attrs);
+        put_u32(&mut buf, self.header_offset.min(ZIP64_LIMIT) as u32);
+        buf.extend_from_slice(self.name.as_bytes());
+        buf.extend_from_slice(&extra);
+        buf
+    }
+
+    fn version(&self) -> u16 {
+        if self.zip64 {
+            VERSION_ZIP64
+        } else {
+            VERSION
+        }
+    }
+
+    /// Extended timestamp keeps mtime in seconds, which is more precise than MS-DOS time.
+    fn put_timestamp(&self, extra: &mut Vec<u8>) {
+        let Some(v) = self.last_modified else {
+            return;
+        };
+        let Ok(mtime) = i32::try_from(v.timestamp()) else {
+            return;
+        };
+        put_u16(extra, EXTRA_TIMESTAMP);
+        put_u16(extra, 5);
+        extra.push(1);
+        put_u32(extra, mtime as u32);
+    }
+}
+
+/// Build the end of central directory, zip64 records are added if needed.
+///
+/// `offset` is where the central directory of `size` starts.
+pub fn end_of_central_directory(count: u64, offset: u64, size: u64) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(98);
+    let zip64 = count >= u16::MAX as u64 || offset >= ZIP64_LIMIT || size >= ZIP64_LIMIT;
+    if zip64 {
+        let eocd_offset = offset + size;
+        put_u32(&mut buf, ZIP64_EOCD_SIGNATURE);
+        // Size of the remaining record.
+        put_u64(&mut buf, 44);
+        put_u16(&mut buf, MADE_BY_UNIX | VERSION_ZIP64);
+        put_u16(&mut buf, VERSION_ZIP64);
+        put_u32(&mut buf, 0);
+        put_u32(&mut buf, 0);
+        put_u64(&mut buf, count);
+        put_u64(&mut buf, count);
+        put_u64(&mut buf, size);
+        put_u64(&mut buf, offset);
+
+        put_u32(&mut buf, ZIP64_LOCATOR_SIGNATURE);
+        put_u32(&mut buf, 0);
+        put_u64(&mut buf, eocd_offset);
+        put_u32(&mut buf, 1);
+    }
+
+    let count = count.min(u16::MAX as u64) as u16;
+    put_u32(&mut buf, EOCD_SIGNATURE);
+    put_u16(&mut buf, 0);
+    put_u16(&mut buf, 0);
+    put_u16(&mut buf, count);
+    put_u16(&mut buf, count);
+    put_u32(&mut buf, size.min(ZIP64_LIMIT) as u32);
+    put_u32(&mut buf, offset.min(ZIP64_LIMIT) as u32);
+    put_u16(&mut buf, 0);
+    buf
+}
+
+/// Convert datetime into MS-DOS time and date, which only covers 1980 to 2107.
+fn dos_datetime(v: Option<DateTime<Utc>>) -> (u16, u16) {
+    let Some(v) = v.filter(|v| (1980..=2107).contains(&v.year())) else {
+        // 1980-01-01 00:00:00
+        return (0, 0x21);
+    };
+    let time = ((v.hour() << 11) | (v.minute() << 5) | (v.second() / 2)) as u16;
+    let date = (((v.year() as u32 - 1980) << 9) | (v.month() << 5) | v.day()) as u16;
+    (time, date)
+}
+
+fn put_u16(buf: &mut Vec<u8>, v: u16) {
+    buf.extend_from_slice(&v.to_le_bytes());
+}
+
+fn put_u32(buf: &mut Vec<u8>, v: u32) {
+    buf.extend_from_slice(&v.to_le_bytes());
+}
+
+fn put_u64(buf: &mut Vec<u8>, v: u64) {
+    buf.extend_from_slice(&v.to_le_bytes());
+}
@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use chrono::DateTime;
+use chrono::Utc;
 use tokio::runtime::Handle;
 
 use crate::Operator as AsyncOperator;
@@ -477,6 +479,188 @@ impl Operator {
         self.handle.block_on(self.op.rename(from, to))
     }
 
+    /// Create a symlink at `path` pointing to `target`.
+    ///
+    /// Refer to [`AsyncOperator::create_symlink`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// use opendal::blocking;
+    /// # use opendal::blocking::Operator;
+    ///
+    /// # fn test(op: blocking::Operator) -> Result<()> {
+    /// op.create_symlink("file", "path/to/link")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_symlink(&self, target: &str, path: &str) -> Result<()> {
+        self.handle.block_on(self.op.create_symlink(target, path))
+    }
+
+    /// Read the target of the symlink at `path`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// use opendal::blocking;
+    /// # use opendal::blocking::Operator;
+    ///
+    /// # fn test(op: blocking::Operator) -> Result<()> {
+    /// let target = op.read_link("path/to/link")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_link(&self, path: &str) -> Result<String> {
+        self.handle.block_on(self.op.read_link(path))
+    }
+
+    /// Set the unix permission bits of `path` to `mode`.
+    ///
+    /// Refer to [`AsyncOperator::set_permissions`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// use opendal::blocking;
+    /// # use opendal::blocking::Operator;
+    ///
+    /// # fn test(op: blocking::Operator) -> Result<()> {
+    /// op.set_permissions("path/to/file", 0o600)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_permissions(&self, path: &str, mode: u32) -> Result<()> {
+        self.handle.block_on(self.op.set_permissions(path, mode))
+    }
+
+    /// Set the access time and modification time of `path`.
+    ///
+    /// Refer to [`AsyncOperator::set_times`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// use opendal::blocking;
+    /// # use opendal::blocking::Operator;
+    /// use chrono::Utc;
+    ///
+    /// # fn test(op: blocking::Operator) -> Result<()> {
+    /// op.set_times("path/to/file", None, Some(Utc::now()))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_times(
+        &self,
+        path: &str,
+        accessed: Option<DateTime<Utc>>,
+        modified: Option<DateTime<Utc>>,
+    ) -> Result<()> {
+        self.handle
+            .block_on(self.op.set_times(path, accessed, modified))
+    }
+
+    /// Concatenate `sources` into a new file at `path`.
+    ///
+    /// Refer to [`AsyncOperator::concat`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// use opendal::blocking;
+    /// # use opendal::blocking::Operator;
+    ///
+    /// # fn test(op: blocking::Operator) -> Result<()> {
+    /// op.concat("path/to/file", &["path/to/part-1", "path/to/part-2"])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn concat(&self, path: &str, sources: &[&str]) -> Result<()> {
+        self.handle.block_on(self.op.concat(path, sources))
+    }
+
+    /// Concatenate `sources` into a new file at `path` with additional options.
+    ///
+    /// Refer to [`AsyncOperator::concat_options`] for more details.
+    pub fn concat_options(
+        &self,
+        path: &str,
+        sources: &[&str],
+        opts: options::ConcatOptions,
+    ) -> Result<()> {
+        self.handle
+            .block_on(self.op.concat_options(path, sources, opts))
+    }
+
+    /// Update metadata of an existing object without re-uploading it.
+    ///
+    /// Refer to [`AsyncOperator::update_metadata`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// use opendal::blocking;
+    /// # use opendal::blocking::Operator;
+    /// use opendal::MetadataUpdate;
+    ///
+    /// # fn test(op: blocking::Operator) -> Result<()> {
+    /// let meta = op.update_metadata(
+    ///     "path/to/file",
+    ///     MetadataUpdate::new().with_content_type("application/json"),
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update_metadata(&self, path: &str, update: MetadataUpdate) -> Result<Metadata> {
+        self.handle.block_on(self.op.update_metadata(path, update))
+    }
+
+    /// Update the content of `path` with `f` by optimistic concurrency control.
+    ///
+    /// Refer to [`AsyncOperator::update`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// use opendal::blocking;
+    /// # use opendal::blocking::Operator;
+    ///
+    /// # fn test(op: blocking::Operator) -> Result<()> {
+    /// op.update("path/to/file", |current| {
+    ///     Ok(current.or_else(|| Some("init".into())))
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update<F>(&self, path: &str, f: F) -> Result<Option<Buffer>>
+    where
+        F: FnMut(Option<Buffer>) -> Result<Option<Buffer>>,
+    {
+        self.handle.block_on(self.op.update(path, f))
+    }
+
+    /// Update the content of `path` with `f` and additional options.
+    ///
+    /// Refer to [`AsyncOperator::update_options`] for more details.
+    pub fn update_options<F>(
+        &self,
+        path: &str,
+        opts: options::UpdateOptions,
+        f: F,
+    ) -> Result<Option<Buffer>>
+    where
+        F: FnMut(Option<Buffer>) -> Result<Option<Buffer>>,
+    {
+        self.handle.block_on(self.op.update_options(path, opts, f))
+    }
+
     /// Delete given path.
     ///
     /// # Notes
@@ -604,7 +788,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir {}", entry.path())
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -663,7 +847,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir like start a new list via meta.path()")
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
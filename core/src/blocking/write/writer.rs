@@ -73,6 +73,28 @@ impl Writer {
         self.handle.block_on(inner.write(bs))
     }
 
+    /// Write [`Buffer`] into writer at the given offset.
+    ///
+    /// Refer to [`crate::Writer::write_at`] for more details.
+    pub fn write_at(&mut self, offset: u64, bs: impl Into<Buffer>) -> Result<()> {
+        let Some(inner) = self.inner.as_mut() else {
+            return Err(Error::new(ErrorKind::Unexpected, "writer has been dropped"));
+        };
+
+        self.handle.block_on(inner.write_at(offset, bs))
+    }
+
+    /// Truncate or extend the file to given length.
+    ///
+    /// Refer to [`crate::Writer::set_len`] for more details.
+    pub fn set_len(&mut self, len: u64) -> Result<()> {
+        let Some(inner) = self.inner.as_mut() else {
+            return Err(Error::new(ErrorKind::Unexpected, "writer has been dropped"));
+        };
+
+        self.handle.block_on(inner.set_len(len))
+    }
+
     /// Close the writer and make sure all data have been committed.
     ///
     /// ## Notes
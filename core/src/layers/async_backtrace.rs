@@ -144,6 +144,16 @@ impl<R: oio::Write> oio::Write for AsyncBacktraceWrapper<R> {
         self.inner.write(bs).await
     }
 
+    #[async_backtrace::framed]
+    async fn write_at(&mut self, offset: u64, bs: Buffer) -> Result<()> {
+        self.inner.write_at(offset, bs).await
+    }
+
+    #[async_backtrace::framed]
+    async fn set_len(&mut self, len: u64) -> Result<()> {
+        self.inner.set_len(len).await
+    }
+
     #[async_backtrace::framed]
     async fn close(&mut self) -> Result<Metadata> {
         self.inner.close().await
@@ -160,6 +170,11 @@ impl<R: oio::List> oio::List for AsyncBacktraceWrapper<R> {
     async fn next(&mut self) -> Result<Option<oio::Entry>> {
         self.inner.next().await
     }
+
+    #[async_backtrace::framed]
+    async fn next_page(&mut self) -> Result<(Vec<oio::Entry>, Option<String>)> {
+        self.inner.next_page().await
+    }
 }
 
 impl<R: oio::Delete> oio::Delete for AsyncBacktraceWrapper<R> {
@@ -166,6 +166,22 @@ impl<R: oio::Write> oio::Write for AwaitTreeWrapper<R> {
             .instrument_await(format!("opendal::{}", Operation::Write.into_static()))
     }
 
+    fn write_at(
+        &mut self,
+        offset: u64,
+        bs: Buffer,
+    ) -> impl Future<Output = Result<()>> + MaybeSend {
+        self.inner
+            .write_at(offset, bs)
+            .instrument_await(format!("opendal::{}", Operation::Write.into_static()))
+    }
+
+    fn set_len(&mut self, len: u64) -> impl Future<Output = Result<()>> + MaybeSend {
+        self.inner
+            .set_len(len)
+            .instrument_await(format!("opendal::{}", Operation::Write.into_static()))
+    }
+
     fn abort(&mut self) -> impl Future<Output = Result<()>> + MaybeSend {
         self.inner
             .abort()
@@ -186,6 +202,13 @@ impl<R: oio::List> oio::List for AwaitTreeWrapper<R> {
             .instrument_await(format!("opendal::{}", Operation::List))
             .await
     }
+
+    async fn next_page(&mut self) -> Result<(Vec<oio::Entry>, Option<String>)> {
+        self.inner
+            .next_page()
+            .instrument_await(format!("opendal::{}", Operation::List))
+            .await
+    }
 }
 
 impl<R: oio::Delete> oio::Delete for AwaitTreeWrapper<R> {
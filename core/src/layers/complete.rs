@@ -20,6 +20,7 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
 
+use crate::layers::TypeEraseLayer;
 use crate::raw::oio::FlatLister;
 use crate::raw::oio::PrefixLister;
 use crate::raw::*;
@@ -52,6 +53,18 @@ use crate::*;
 ///
 /// - If support `list_with_recursive`, return directly.
 /// - if not, wrap with [`FlatLister`].
+///
+/// ## Concat Completion
+///
+/// Services that can't concat natively, or sources that don't fit the limits
+/// of native concat, will be concatenated by streaming every source into the
+/// target via read and write.
+///
+/// ## Lock Completion
+///
+/// Services that support conditional writes can lock without native lock
+/// primitives. The [`LockRecord`] is stored as the content of the lock file
+/// and updated by writes with `if_match` or `if_not_exists`.
 pub struct CompleteLayer;
 
 impl<A: Access> Layer<A> for CompleteLayer {
@@ -63,6 +76,12 @@ impl<A: Access> Layer<A> for CompleteLayer {
             if cap.list && cap.write_can_empty {
                 cap.create_dir = true;
             }
+            if cap.read && cap.write {
+                cap.concat = true;
+            }
+            if cap.read_with_if_match && cap.write_with_if_not_exists && cap.write_with_if_match {
+                cap.lock = true;
+            }
             cap
         });
 
@@ -143,6 +162,175 @@ impl<A: Access> CompleteAccessor<A> {
         self.inner.stat(path, args).await
     }
 
+    async fn complete_concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        let capability = self.info.native_capability();
+        if capability.concat && self.fit_native_concat(&args).await? {
+            return self.inner.concat(path, args).await;
+        }
+
+        if capability.read && capability.write {
+            return self.stream_concat(path, args).await;
+        }
+
+        self.inner.concat(path, args).await
+    }
+
+    /// Check if sources fit the limits of native concat.
+    async fn fit_native_concat(&self, args: &OpConcat) -> Result<bool> {
+        let capability = self.info.native_capability();
+
+        if let Some(max) = capability.concat_max_sources {
+            if args.sources().len() > max {
+                return Ok(false);
+            }
+        }
+
+        let min = capability.concat_min_source_size;
+        let max = capability.concat_max_source_size;
+        if min.is_none() && max.is_none() {
+            return Ok(true);
+        }
+
+        let sources = args.sources();
+        for (idx, source) in sources.iter().enumerate() {
+            let size = self
+                .inner
+                .stat(source, OpStat::new())
+                .await?
+                .into_metadata()
+                .content_length();
+            // The last source is allowed to be smaller than min size.
+            if let Some(min) = min.filter(|_| idx + 1 < sources.len()) {
+                if size < min as u64 {
+                    return Ok(false);
+                }
+            }
+            if let Some(max) = max {
+                if size > max as u64 {
+                    return Ok(false);
+                }
+            }
+        }
+
+        Ok(true)
+    }
+
+    async fn complete_lock(&self, path: &str, args: OpLock) -> Result<RpLock> {
+        let capability = self.info.native_capability();
+        if capability.lock {
+            return self.inner.lock(path, args).await;
+        }
+
+        if capability.read_with_if_match
+            && capability.write_with_if_not_exists
+            && capability.write_with_if_match
+        {
+            let (current, etag) = self.read_lock_record(path).await?;
+            let record = LockRecord::lock(current.as_ref(), &args)?;
+            self.write_lock_record(path, &record, etag).await?;
+            return Ok(RpLock::new(record.token()));
+        }
+
+        self.inner.lock(path, args).await
+    }
+
+    async fn complete_unlock(&self, path: &str, args: OpUnlock) -> Result<RpUnlock> {
+        let capability = self.info.native_capability();
+        if capability.lock {
+            return self.inner.unlock(path, args).await;
+        }
+
+        if capability.read_with_if_match
+            && capability.write_with_if_not_exists
+            && capability.write_with_if_match
+        {
+            let (current, etag) = self.read_lock_record(path).await?;
+            let record = LockRecord::unlock(current.as_ref(), &args)?;
+            self.write_lock_record(path, &record, etag).await?;
+            return Ok(RpUnlock::default());
+        }
+
+        self.inner.unlock(path, args).await
+    }
+
+    /// Read the lock record at `path` along with its etag.
+    async fn read_lock_record(&self, path: &str) -> Result<(Option<LockRecord>, Option<String>)> {
+        let meta = match self.inner.stat(path, OpStat::new()).await {
+            Ok(rp) => rp.into_metadata(),
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok((None, None)),
+            Err(err) => return Err(err),
+        };
+        let Some(etag) = meta.etag() else {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "lock file doesn't have etag to update it conditionally",
+            ));
+        };
+
+        // Make sure the content we read is the one we stat.
+        let (_, mut r) = self
+            .inner
+            .read(path, OpRead::new().with_if_match(etag))
+            .await?;
+        let bs = oio::Read::read_all(&mut r).await?;
+        let record = LockRecord::parse(&bs.to_bytes())?;
+        Ok((Some(record), Some(etag.to_string())))
+    }
+
+    /// Write the lock record to `path` only if it's still at `etag`.
+    async fn write_lock_record(
+        &self,
+        path: &str,
+        record: &LockRecord,
+        etag: Option<String>,
+    ) -> Result<()> {
+        let op = match etag {
+            Some(etag) => OpWrite::new().with_if_match(&etag),
+            None => OpWrite::new().with_if_not_exists(true),
+        };
+        let (_, mut w) = self.inner.write(path, op).await?;
+        oio::Write::write(&mut w, Buffer::from(record.to_bytes())).await?;
+        oio::Write::close(&mut w).await?;
+        Ok(())
+    }
+
+    async fn stream_concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        let mut op = OpWrite::new().with_concurrent(args.concurrent().max(1));
+        let mut opts = OpWriter::new();
+        if let Some(v) = args.content_type() {
+            op = op.with_content_type(v);
+        }
+        if let Some(v) = args.chunk() {
+            op = op.with_chunk(v);
+            opts = opts.with_chunk(v);
+        }
+
+        let acc = Arc::new(TypeEraseLayer.layer(self.inner.clone())) as Accessor;
+        let mut w = Writer::new(WriteContext::new(acc, path.to_string(), op, opts)).await?;
+
+        for source in args.sources() {
+            if let Err(err) = self.stream_copy_into(source, &mut w).await {
+                // Keep the original error, abort is best effort here.
+                let _ = w.abort().await;
+                return Err(err.with_context("source", source));
+            }
+        }
+
+        w.close().await?;
+        Ok(RpConcat::default())
+    }
+
+    async fn stream_copy_into(&self, source: &str, w: &mut Writer) -> Result<()> {
+        let (_, mut r) = self.inner.read(source, OpRead::new()).await?;
+        loop {
+            let bs = oio::Read::read(&mut r).await?;
+            if bs.is_empty() {
+                return Ok(());
+            }
+            w.write(bs).await?;
+        }
+    }
+
     async fn complete_list(
         &self,
         path: &str,
@@ -234,6 +422,18 @@ impl<A: Access> LayeredAccess for CompleteAccessor<A> {
         self.complete_list(path, args).await
     }
 
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        self.complete_concat(path, args).await
+    }
+
+    async fn lock(&self, path: &str, args: OpLock) -> Result<RpLock> {
+        self.complete_lock(path, args).await
+    }
+
+    async fn unlock(&self, path: &str, args: OpUnlock) -> Result<RpUnlock> {
+        self.complete_unlock(path, args).await
+    }
+
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         self.inner.presign(path, args).await
     }
@@ -296,6 +496,9 @@ pub struct CompleteWriter<W> {
     inner: Option<W>,
     append: bool,
     size: u64,
+    /// Set once `write_at` or `set_len` has been called, `size` is no
+    /// longer the length of the file after that.
+    positional: bool,
 }
 
 impl<W> CompleteWriter<W> {
@@ -304,11 +507,12 @@ impl<W> CompleteWriter<W> {
             inner: Some(inner),
             append,
             size: 0,
+            positional: false,
         }
     }
 
     fn check(&self, content_length: u64) -> Result<()> {
-        if self.append || content_length == 0 {
+        if self.append || self.positional || content_length == 0 {
             return Ok(());
         }
 
@@ -355,6 +559,28 @@ where
         Ok(())
     }
 
+    async fn write_at(&mut self, offset: u64, bs: Buffer) -> Result<()> {
+        let w = self.inner.as_mut().ok_or_else(|| {
+            Error::new(ErrorKind::Unexpected, "writer has been closed or aborted")
+        })?;
+
+        w.write_at(offset, bs).await?;
+        self.positional = true;
+
+        Ok(())
+    }
+
+    async fn set_len(&mut self, len: u64) -> Result<()> {
+        let w = self.inner.as_mut().ok_or_else(|| {
+            Error::new(ErrorKind::Unexpected, "writer has been closed or aborted")
+        })?;
+
+        w.set_len(len).await?;
+        self.positional = true;
+
+        Ok(())
+    }
+
     async fn close(&mut self) -> Result<Metadata> {
         let w = self.inner.as_mut().ok_or_else(|| {
             Error::new(ErrorKind::Unexpected, "writer has been closed or aborted")
@@ -364,7 +590,7 @@ where
         // we won't be able to retry `close` in `RetryLayer`.
         let mut ret = w.close().await?;
         self.check(ret.content_length())?;
-        if ret.content_length() == 0 {
+        if ret.content_length() == 0 && !self.positional {
             ret = ret.with_content_length(self.size);
         }
         self.inner = None;
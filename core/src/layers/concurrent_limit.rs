@@ -296,6 +296,14 @@ impl<R: oio::Write> oio::Write for ConcurrentLimitWrapper<R> {
         self.inner.write(bs).await
     }
 
+    async fn write_at(&mut self, offset: u64, bs: Buffer) -> Result<()> {
+        self.inner.write_at(offset, bs).await
+    }
+
+    async fn set_len(&mut self, len: u64) -> Result<()> {
+        self.inner.set_len(len).await
+    }
+
     async fn close(&mut self) -> Result<Metadata> {
         self.inner.close().await
     }
@@ -309,6 +317,10 @@ impl<R: oio::List> oio::List for ConcurrentLimitWrapper<R> {
     async fn next(&mut self) -> Result<Option<oio::Entry>> {
         self.inner.next().await
     }
+
+    async fn next_page(&mut self) -> Result<(Vec<oio::Entry>, Option<String>)> {
+        self.inner.next_page().await
+    }
 }
 
 impl<R: oio::Delete> oio::Delete for ConcurrentLimitWrapper<R> {
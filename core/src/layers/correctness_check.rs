@@ -147,6 +147,16 @@ impl<A: Access> LayeredAccess for CorrectnessAccessor<A> {
                 "if_not_exists",
             ));
         }
+        if args.random_access() && !capability.write_can_random_access {
+            return Err(new_unsupported_error(
+                &self.info,
+                Operation::Write,
+                "random_access",
+            ));
+        }
+        if args.ttl().is_some() && !capability.write_with_ttl {
+            return Err(new_unsupported_error(&self.info, Operation::Write, "ttl"));
+        }
         if let Some(if_none_match) = args.if_none_match() {
             if !capability.write_with_if_none_match {
                 let mut err =
@@ -162,8 +172,52 @@ impl<A: Access> LayeredAccess for CorrectnessAccessor<A> {
         self.inner.write(path, args).await
     }
 
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        let capability = self.info.full_capability();
+        if !capability.copy_with_if_match && args.if_match().is_some() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::Copy,
+                "if_match",
+            ));
+        }
+        if !capability.copy_with_if_none_match && args.if_none_match().is_some() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::Copy,
+                "if_none_match",
+            ));
+        }
+
+        self.inner.copy(from, to, args).await
+    }
+
+    async fn update_metadata(
+        &self,
+        path: &str,
+        args: OpUpdateMetadata,
+    ) -> Result<RpUpdateMetadata> {
+        let capability = self.info.full_capability();
+        if !capability.update_metadata_with_if_match && args.update().if_match().is_some() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::UpdateMetadata,
+                "if_match",
+            ));
+        }
+
+        self.inner.update_metadata(path, args).await
+    }
+
     async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         let capability = self.info.full_capability();
+        if !capability.stat_with_follow_symlinks && !args.follow_symlinks() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::Stat,
+                "follow_symlinks",
+            ));
+        }
         if !capability.stat_with_version && args.version().is_some() {
             return Err(new_unsupported_error(
                 self.info.as_ref(),
@@ -211,6 +265,15 @@ impl<A: Access> LayeredAccess for CorrectnessAccessor<A> {
     }
 
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        let capability = self.info.full_capability();
+        if !capability.list_with_continuation_token && args.continuation_token().is_some() {
+            return Err(new_unsupported_error(
+                self.info.as_ref(),
+                Operation::List,
+                "continuation_token",
+            ));
+        }
+
         self.inner.list(path, args).await
     }
 }
@@ -251,6 +314,8 @@ impl<T: oio::Delete> oio::Delete for CheckWrapper<T> {
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use super::*;
     use crate::raw::oio;
     use crate::Capability;
@@ -408,6 +473,13 @@ mod tests {
             .await;
         assert!(res.is_ok());
 
+        let res = op
+            .write_with("path", "".as_bytes())
+            .ttl(Duration::from_secs(60))
+            .await;
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+
         let op = new_test_operator(Capability {
             write: true,
             write_can_append: true,
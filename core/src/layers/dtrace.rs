@@ -264,6 +264,24 @@ impl<R: oio::Write> oio::Write for DtraceLayerWrapper<R> {
             })
     }
 
+    async fn write_at(&mut self, offset: u64, bs: Buffer) -> Result<()> {
+        let c_path = CString::new(self.path.clone()).unwrap();
+        probe_lazy!(opendal, writer_write_start, c_path.as_ptr());
+        self.inner
+            .write_at(offset, bs)
+            .await
+            .map(|_| {
+                probe_lazy!(opendal, writer_write_ok, c_path.as_ptr());
+            })
+            .inspect_err(|_| {
+                probe_lazy!(opendal, writer_write_error, c_path.as_ptr());
+            })
+    }
+
+    async fn set_len(&mut self, len: u64) -> Result<()> {
+        self.inner.set_len(len).await
+    }
+
     async fn abort(&mut self) -> Result<()> {
         let c_path = CString::new(self.path.clone()).unwrap();
         probe_lazy!(opendal, writer_poll_abort_start, c_path.as_ptr());
@@ -185,6 +185,145 @@ impl<A: Access> LayeredAccess for ErrorContextAccessor<A> {
                 .with_context("path", path)
         })
     }
+
+    async fn list_uploads(&self, path: &str, args: OpListUploads) -> Result<RpListUploads> {
+        self.inner.list_uploads(path, args).await.map_err(|err| {
+            err.with_operation(Operation::ListUploads)
+                .with_context("service", self.info.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn abort_upload(&self, path: &str, args: OpAbortUpload) -> Result<RpAbortUpload> {
+        let upload_id = args.upload_id().to_string();
+        self.inner.abort_upload(path, args).await.map_err(|err| {
+            err.with_operation(Operation::AbortUpload)
+                .with_context("service", self.info.scheme())
+                .with_context("path", path)
+                .with_context("upload_id", upload_id)
+        })
+    }
+
+    async fn initiate_upload(
+        &self,
+        path: &str,
+        args: OpInitiateUpload,
+    ) -> Result<RpInitiateUpload> {
+        self.inner.initiate_upload(path, args).await.map_err(|err| {
+            err.with_operation(Operation::InitiateUpload)
+                .with_context("service", self.info.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        self.inner.presign_post(path, args).await.map_err(|err| {
+            err.with_operation(Operation::PresignPost)
+                .with_context("service", self.info.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn complete_upload(
+        &self,
+        path: &str,
+        args: OpCompleteUpload,
+    ) -> Result<RpCompleteUpload> {
+        let upload_id = args.upload_id().to_string();
+        self.inner.complete_upload(path, args).await.map_err(|err| {
+            err.with_operation(Operation::CompleteUpload)
+                .with_context("service", self.info.scheme())
+                .with_context("path", path)
+                .with_context("upload_id", upload_id)
+        })
+    }
+
+    async fn create_symlink(&self, path: &str, args: OpCreateSymlink) -> Result<RpCreateSymlink> {
+        self.inner.create_symlink(path, args).await.map_err(|err| {
+            err.with_operation(Operation::CreateSymlink)
+                .with_context("service", self.info.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn read_link(&self, path: &str, args: OpReadLink) -> Result<RpReadLink> {
+        self.inner.read_link(path, args).await.map_err(|err| {
+            err.with_operation(Operation::ReadLink)
+                .with_context("service", self.info.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn set_permissions(
+        &self,
+        path: &str,
+        args: OpSetPermissions,
+    ) -> Result<RpSetPermissions> {
+        self.inner.set_permissions(path, args).await.map_err(|err| {
+            err.with_operation(Operation::SetPermissions)
+                .with_context("service", self.info.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn set_times(&self, path: &str, args: OpSetTimes) -> Result<RpSetTimes> {
+        self.inner.set_times(path, args).await.map_err(|err| {
+            err.with_operation(Operation::SetTimes)
+                .with_context("service", self.info.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn update_metadata(
+        &self,
+        path: &str,
+        args: OpUpdateMetadata,
+    ) -> Result<RpUpdateMetadata> {
+        self.inner.update_metadata(path, args).await.map_err(|err| {
+            err.with_operation(Operation::UpdateMetadata)
+                .with_context("service", self.info.scheme())
+                .with_context("path", path)
+        })
+    }
+
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        let sources = args.sources().len();
+        self.inner.concat(path, args).await.map_err(|err| {
+            err.with_operation(Operation::Concat)
+                .with_context("service", self.info.scheme())
+                .with_context("path", path)
+                .with_context("sources", sources.to_string())
+        })
+    }
+
+    async fn lock(&self, path: &str, args: OpLock) -> Result<RpLock> {
+        let owner = args.owner().to_string();
+        self.inner.lock(path, args).await.map_err(|err| {
+            err.with_operation(Operation::Lock)
+                .with_context("service", self.info.scheme())
+                .with_context("path", path)
+                .with_context("owner", owner)
+        })
+    }
+
+    async fn unlock(&self, path: &str, args: OpUnlock) -> Result<RpUnlock> {
+        let token = args.token();
+        self.inner.unlock(path, args).await.map_err(|err| {
+            err.with_operation(Operation::Unlock)
+                .with_context("service", self.info.scheme())
+                .with_context("path", path)
+                .with_context("token", token.to_string())
+        })
+    }
+
+    async fn transaction(&self, args: OpTransaction) -> Result<RpTransaction> {
+        let mutations = args.mutations().len();
+        self.inner.transaction(args).await.map_err(|err| {
+            err.with_operation(Operation::Transaction)
+                .with_context("service", self.info.scheme())
+                .with_context("mutations", mutations.to_string())
+        })
+    }
 }
 
 pub struct ErrorContextWrapper<T> {
@@ -248,6 +387,33 @@ impl<T: oio::Write> oio::Write for ErrorContextWrapper<T> {
             })
     }
 
+    async fn write_at(&mut self, offset: u64, bs: Buffer) -> Result<()> {
+        let size = bs.len();
+        self.inner
+            .write_at(offset, bs)
+            .await
+            .map(|_| {
+                self.processed += size as u64;
+            })
+            .map_err(|err| {
+                err.with_operation(Operation::Write)
+                    .with_context("service", self.scheme)
+                    .with_context("path", &self.path)
+                    .with_context("offset", offset.to_string())
+                    .with_context("size", size.to_string())
+                    .with_context("written", self.processed.to_string())
+            })
+    }
+
+    async fn set_len(&mut self, len: u64) -> Result<()> {
+        self.inner.set_len(len).await.map_err(|err| {
+            err.with_operation(Operation::Write)
+                .with_context("service", self.scheme)
+                .with_context("path", &self.path)
+                .with_context("len", len.to_string())
+        })
+    }
+
     async fn close(&mut self) -> Result<Metadata> {
         self.inner.close().await.map_err(|err| {
             err.with_operation(Operation::Write)
@@ -282,6 +448,21 @@ impl<T: oio::List> oio::List for ErrorContextWrapper<T> {
                     .with_context("listed", self.processed.to_string())
             })
     }
+
+    async fn next_page(&mut self) -> Result<(Vec<oio::Entry>, Option<String>)> {
+        self.inner
+            .next_page()
+            .await
+            .inspect(|(entries, _)| {
+                self.processed += entries.len() as u64;
+            })
+            .map_err(|err| {
+                err.with_operation(Operation::List)
+                    .with_context("service", self.scheme)
+                    .with_context("path", &self.path)
+                    .with_context("listed", self.processed.to_string())
+            })
+    }
 }
 
 impl<T: oio::Delete> oio::Delete for ErrorContextWrapper<T> {
@@ -237,6 +237,22 @@ impl<R: oio::Write> oio::Write for FastraceWrapper<R> {
         self.inner.write(bs)
     }
 
+    fn write_at(
+        &mut self,
+        offset: u64,
+        bs: Buffer,
+    ) -> impl Future<Output = Result<()>> + MaybeSend {
+        let _g = self.span.set_local_parent();
+        let _span = LocalSpan::enter_with_local_parent(Operation::Write.into_static());
+        self.inner.write_at(offset, bs)
+    }
+
+    fn set_len(&mut self, len: u64) -> impl Future<Output = Result<()>> + MaybeSend {
+        let _g = self.span.set_local_parent();
+        let _span = LocalSpan::enter_with_local_parent(Operation::Write.into_static());
+        self.inner.set_len(len)
+    }
+
     fn abort(&mut self) -> impl Future<Output = Result<()>> + MaybeSend {
         let _g = self.span.set_local_parent();
         let _span = LocalSpan::enter_with_local_parent(Operation::Write.into_static());
@@ -255,6 +271,11 @@ impl<R: oio::List> oio::List for FastraceWrapper<R> {
     async fn next(&mut self) -> Result<Option<oio::Entry>> {
         self.inner.next().await
     }
+
+    #[trace(enter_on_poll = true)]
+    async fn next_page(&mut self) -> Result<(Vec<oio::Entry>, Option<String>)> {
+        self.inner.next_page().await
+    }
 }
 
 impl<R: oio::Delete> oio::Delete for FastraceWrapper<R> {
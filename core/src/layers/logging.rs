@@ -646,6 +646,48 @@ impl<W: oio::Write, I: LoggingInterceptor> oio::Write for LoggingWriter<W, I> {
         }
     }
 
+    async fn write_at(&mut self, offset: u64, bs: Buffer) -> Result<()> {
+        let size = bs.len();
+
+        match self.inner.write_at(offset, bs).await {
+            Ok(_) => {
+                self.written += size as u64;
+                Ok(())
+            }
+            Err(err) => {
+                self.logger.log(
+                    &self.info,
+                    Operation::Write,
+                    &[
+                        ("path", &self.path),
+                        ("written", &self.written.to_string()),
+                        ("offset", &offset.to_string()),
+                        ("size", &size.to_string()),
+                    ],
+                    "write_at failed",
+                    Some(&err),
+                );
+                Err(err)
+            }
+        }
+    }
+
+    async fn set_len(&mut self, len: u64) -> Result<()> {
+        match self.inner.set_len(len).await {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                self.logger.log(
+                    &self.info,
+                    Operation::Write,
+                    &[("path", &self.path), ("len", &len.to_string())],
+                    "set_len failed",
+                    Some(&err),
+                );
+                Err(err)
+            }
+        }
+    }
+
     async fn abort(&mut self) -> Result<()> {
         match self.inner.abort().await {
             Ok(_) => {
@@ -749,6 +791,36 @@ impl<P: oio::List, I: LoggingInterceptor> oio::List for LoggingLister<P, I> {
 
         res
     }
+
+    async fn next_page(&mut self) -> Result<(Vec<oio::Entry>, Option<String>)> {
+        let res = self.inner.next_page().await;
+
+        match &res {
+            Ok((entries, token)) => {
+                self.listed += entries.len();
+                if token.is_none() {
+                    self.logger.log(
+                        &self.info,
+                        Operation::List,
+                        &[("path", &self.path), ("listed", &self.listed.to_string())],
+                        "finished",
+                        None,
+                    );
+                }
+            }
+            Err(err) => {
+                self.logger.log(
+                    &self.info,
+                    Operation::List,
+                    &[("path", &self.path), ("listed", &self.listed.to_string())],
+                    "failed",
+                    Some(err),
+                );
+            }
+        };
+
+        res
+    }
 }
 
 pub struct LoggingDeleter<D, I: LoggingInterceptor> {
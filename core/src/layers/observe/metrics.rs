@@ -874,6 +874,32 @@ impl<R: oio::Write, I: MetricsIntercept> oio::Write for MetricsWrapper<R, I> {
             })
     }
 
+    async fn write_at(&mut self, offset: u64, bs: Buffer) -> Result<()> {
+        let size = bs.len();
+
+        self.inner
+            .write_at(offset, bs)
+            .await
+            .inspect(|_| {
+                self.size += size as u64;
+            })
+            .inspect_err(|err| {
+                self.interceptor.observe(
+                    self.labels.clone().with_error(err.kind()),
+                    MetricValue::OperationErrorsTotal,
+                );
+            })
+    }
+
+    async fn set_len(&mut self, len: u64) -> Result<()> {
+        self.inner.set_len(len).await.inspect_err(|err| {
+            self.interceptor.observe(
+                self.labels.clone().with_error(err.kind()),
+                MetricValue::OperationErrorsTotal,
+            );
+        })
+    }
+
     async fn close(&mut self) -> Result<Metadata> {
         self.inner.close().await.inspect_err(|err| {
             self.interceptor.observe(
@@ -908,6 +934,21 @@ impl<R: oio::List, I: MetricsIntercept> oio::List for MetricsWrapper<R, I> {
                 );
             })
     }
+
+    async fn next_page(&mut self) -> Result<(Vec<oio::Entry>, Option<String>)> {
+        self.inner
+            .next_page()
+            .await
+            .inspect(|(entries, _)| {
+                self.size += entries.len() as u64;
+            })
+            .inspect_err(|err| {
+                self.interceptor.observe(
+                    self.labels.clone().with_error(err.kind()),
+                    MetricValue::OperationErrorsTotal,
+                );
+            })
+    }
 }
 
 impl<R: oio::Delete, I: MetricsIntercept> oio::Delete for MetricsWrapper<R, I> {
@@ -187,6 +187,18 @@ impl<R: oio::Write> oio::Write for OtelTraceWrapper<R> {
         self.inner.write(bs)
     }
 
+    fn write_at(
+        &mut self,
+        offset: u64,
+        bs: Buffer,
+    ) -> impl Future<Output = Result<()>> + MaybeSend {
+        self.inner.write_at(offset, bs)
+    }
+
+    fn set_len(&mut self, len: u64) -> impl Future<Output = Result<()>> + MaybeSend {
+        self.inner.set_len(len)
+    }
+
     fn abort(&mut self) -> impl Future<Output = Result<()>> + MaybeSend {
         self.inner.abort()
     }
@@ -200,4 +212,8 @@ impl<R: oio::List> oio::List for OtelTraceWrapper<R> {
     async fn next(&mut self) -> Result<Option<oio::Entry>> {
         self.inner.next().await
     }
+
+    async fn next_page(&mut self) -> Result<(Vec<oio::Entry>, Option<String>)> {
+        self.inner.next_page().await
+    }
 }
@@ -194,6 +194,32 @@ impl<R: oio::Write> oio::Write for ThrottleWrapper<R> {
         }
     }
 
+    async fn write_at(&mut self, offset: u64, bs: Buffer) -> Result<()> {
+        // Empty buffers don't consume any bandwidth.
+        let Some(buf_length) = NonZeroU32::new(bs.len() as u32) else {
+            return self.inner.write_at(offset, bs).await;
+        };
+
+        loop {
+            match self.limiter.check_n(buf_length) {
+                Ok(res) => match res {
+                    Ok(_) => return self.inner.write_at(offset, bs).await,
+                    Err(not_until) => {
+                        let _ = not_until.wait_time_from(DefaultClock::default().now());
+                    }
+                },
+                Err(_) => return Err(Error::new(
+                    ErrorKind::RateLimited,
+                    "InsufficientCapacity due to burst size being smaller than the request size",
+                )),
+            }
+        }
+    }
+
+    async fn set_len(&mut self, len: u64) -> Result<()> {
+        self.inner.set_len(len).await
+    }
+
     async fn abort(&mut self) -> Result<()> {
         self.inner.abort().await
     }
@@ -202,3 +228,49 @@ impl<R: oio::Write> oio::Write for ThrottleWrapper<R> {
         self.inner.close().await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct MockWriter {
+        written: Vec<(u64, usize)>,
+    }
+
+    impl oio::Write for MockWriter {
+        async fn write(&mut self, bs: Buffer) -> Result<()> {
+            self.written.push((0, bs.len()));
+            Ok(())
+        }
+
+        async fn write_at(&mut self, offset: u64, bs: Buffer) -> Result<()> {
+            self.written.push((offset, bs.len()));
+            Ok(())
+        }
+
+        async fn close(&mut self) -> Result<Metadata> {
+            Ok(Metadata::new(EntryMode::FILE))
+        }
+
+        async fn abort(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_write_at_empty_buffer() {
+        let limiter = Arc::new(RateLimiter::direct(Quota::per_second(
+            NonZeroU32::new(1024).unwrap(),
+        )));
+        let mut w = ThrottleWrapper::new(MockWriter::default(), limiter);
+
+        oio::Write::write_at(&mut w, 4, Buffer::new())
+            .await
+            .unwrap();
+        oio::Write::write_at(&mut w, 0, Buffer::from("hello"))
+            .await
+            .unwrap();
+        assert_eq!(w.inner.written, [(4, 0), (0, 5)]);
+    }
+}
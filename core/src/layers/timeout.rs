@@ -37,6 +37,9 @@ use crate::*;
 /// - IO Operation like `read`, `Reader::read` and `Writer::write`, they operate on data directly, we
 ///   control them by setting `io_timeout`.
 ///
+/// `concat` is not controlled by `timeout` since its duration grows with the number and
+/// size of sources.
+///
 /// # Default
 ///
 /// - timeout: 60 seconds
@@ -276,6 +279,102 @@ impl<A: Access> LayeredAccess for TimeoutAccessor<A> {
         self.timeout(Operation::Presign, self.inner.presign(path, args))
             .await
     }
+
+    async fn list_uploads(&self, path: &str, args: OpListUploads) -> Result<RpListUploads> {
+        self.timeout(Operation::ListUploads, self.inner.list_uploads(path, args))
+            .await
+    }
+
+    async fn abort_upload(&self, path: &str, args: OpAbortUpload) -> Result<RpAbortUpload> {
+        self.timeout(Operation::AbortUpload, self.inner.abort_upload(path, args))
+            .await
+    }
+
+    async fn initiate_upload(
+        &self,
+        path: &str,
+        args: OpInitiateUpload,
+    ) -> Result<RpInitiateUpload> {
+        self.timeout(
+            Operation::InitiateUpload,
+            self.inner.initiate_upload(path, args),
+        )
+        .await
+    }
+
+    async fn complete_upload(
+        &self,
+        path: &str,
+        args: OpCompleteUpload,
+    ) -> Result<RpCompleteUpload> {
+        self.timeout(
+            Operation::CompleteUpload,
+            self.inner.complete_upload(path, args),
+        )
+        .await
+    }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        self.timeout(Operation::PresignPost, self.inner.presign_post(path, args))
+            .await
+    }
+
+    async fn create_symlink(&self, path: &str, args: OpCreateSymlink) -> Result<RpCreateSymlink> {
+        self.timeout(
+            Operation::CreateSymlink,
+            self.inner.create_symlink(path, args),
+        )
+        .await
+    }
+
+    async fn read_link(&self, path: &str, args: OpReadLink) -> Result<RpReadLink> {
+        self.timeout(Operation::ReadLink, self.inner.read_link(path, args))
+            .await
+    }
+
+    async fn set_permissions(
+        &self,
+        path: &str,
+        args: OpSetPermissions,
+    ) -> Result<RpSetPermissions> {
+        self.timeout(
+            Operation::SetPermissions,
+            self.inner.set_permissions(path, args),
+        )
+        .await
+    }
+
+    async fn set_times(&self, path: &str, args: OpSetTimes) -> Result<RpSetTimes> {
+        self.timeout(Operation::SetTimes, self.inner.set_times(path, args))
+            .await
+    }
+
+    async fn update_metadata(
+        &self,
+        path: &str,
+        args: OpUpdateMetadata,
+    ) -> Result<RpUpdateMetadata> {
+        self.timeout(
+            Operation::UpdateMetadata,
+            self.inner.update_metadata(path, args),
+        )
+        .await
+    }
+
+    async fn lock(&self, path: &str, args: OpLock) -> Result<RpLock> {
+        self.timeout(Operation::Lock, self.inner.lock(path, args))
+            .await
+    }
+
+    async fn unlock(&self, path: &str, args: OpUnlock) -> Result<RpUnlock> {
+        self.timeout(Operation::Unlock, self.inner.unlock(path, args))
+            .await
+    }
+
+    async fn transaction(&self, args: OpTransaction) -> Result<RpTransaction> {
+        self.timeout(Operation::Transaction, self.inner.transaction(args))
+            .await
+    }
 }
 
 pub struct TimeoutExecutor {
@@ -338,6 +437,16 @@ impl<R: oio::Write> oio::Write for TimeoutWrapper<R> {
         Self::io_timeout(self.timeout, Operation::Write.into_static(), fut).await
     }
 
+    async fn write_at(&mut self, offset: u64, bs: Buffer) -> Result<()> {
+        let fut = self.inner.write_at(offset, bs);
+        Self::io_timeout(self.timeout, Operation::Write.into_static(), fut).await
+    }
+
+    async fn set_len(&mut self, len: u64) -> Result<()> {
+        let fut = self.inner.set_len(len);
+        Self::io_timeout(self.timeout, Operation::Write.into_static(), fut).await
+    }
+
     async fn close(&mut self) -> Result<Metadata> {
         let fut = self.inner.close();
         Self::io_timeout(self.timeout, Operation::Write.into_static(), fut).await
@@ -354,6 +463,11 @@ impl<R: oio::List> oio::List for TimeoutWrapper<R> {
         let fut = self.inner.next();
         Self::io_timeout(self.timeout, Operation::List.into_static(), fut).await
     }
+
+    async fn next_page(&mut self) -> Result<(Vec<oio::Entry>, Option<String>)> {
+        let fut = self.inner.next_page();
+        Self::io_timeout(self.timeout, Operation::List.into_static(), fut).await
+    }
 }
 
 impl<R: oio::Delete> oio::Delete for TimeoutWrapper<R> {
@@ -313,6 +313,18 @@ impl<R: oio::Write> oio::Write for TracingWrapper<R> {
         self.inner.write(bs).await
     }
 
+    async fn write_at(&mut self, offset: u64, bs: Buffer) -> Result<()> {
+        let _enter = self.span.enter();
+
+        self.inner.write_at(offset, bs).await
+    }
+
+    async fn set_len(&mut self, len: u64) -> Result<()> {
+        let _enter = self.span.enter();
+
+        self.inner.set_len(len).await
+    }
+
     async fn abort(&mut self) -> Result<()> {
         let _enter = self.span.enter();
 
@@ -332,6 +344,12 @@ impl<R: oio::List> oio::List for TracingWrapper<R> {
 
         self.inner.next().await
     }
+
+    async fn next_page(&mut self) -> Result<(Vec<oio::Entry>, Option<String>)> {
+        let _enter = self.span.enter();
+
+        self.inner.next_page().await
+    }
 }
 
 impl<R: oio::Delete> oio::Delete for TracingWrapper<R> {
@@ -160,6 +160,8 @@ mod types;
 pub use types::*;
 
 // Public modules, they will be accessed like `opendal::layers::Xxxx`
+#[cfg(feature = "archive")]
+pub mod archive;
 #[cfg(feature = "blocking")]
 pub mod blocking;
 #[cfg(docsrs)]
@@ -181,8 +183,8 @@ mod tests {
     #[test]
     fn assert_size() {
         assert_eq!(16, size_of::<Operator>());
-        assert_eq!(320, size_of::<Entry>());
-        assert_eq!(296, size_of::<Metadata>());
+        assert_eq!(408, size_of::<Entry>());
+        assert_eq!(384, size_of::<Metadata>());
         assert_eq!(1, size_of::<EntryMode>());
         assert_eq!(24, size_of::<Scheme>());
     }
@@ -257,6 +257,305 @@ pub trait Access: Send + Sync + Debug + Unpin + 'static {
             "operation is not supported",
         )))
     }
+
+    /// Invoke the `list_uploads` operation on the specified path.
+    ///
+    /// Require [`Capability::list_uploads`]
+    ///
+    /// # Behavior
+    ///
+    /// - Input path is used as prefix, all in-progress uploads under it SHOULD be returned.
+    /// - Returned upload paths MUST be relative to the root.
+    fn list_uploads(
+        &self,
+        path: &str,
+        args: OpListUploads,
+    ) -> impl Future<Output = Result<RpListUploads>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `abort_upload` operation on the specified path.
+    ///
+    /// Require [`Capability::abort_upload`]
+    ///
+    /// # Behavior
+    ///
+    /// - Input path MUST be file path, DON'T NEED to check mode.
+    /// - Abort on a finished or not existing upload SHOULD return `NotFound`.
+    fn abort_upload(
+        &self,
+        path: &str,
+        args: OpAbortUpload,
+    ) -> impl Future<Output = Result<RpAbortUpload>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `initiate_upload` operation on the specified path.
+    ///
+    /// Require [`Capability::presign_multipart`]
+    ///
+    /// # Behavior
+    ///
+    /// - Input path MUST be file path, DON'T NEED to check mode.
+    /// - The returned upload id MUST be usable by `presign` with [`PresignOperation::UploadPart`],
+    ///   `complete_upload` and `abort_upload`.
+    fn initiate_upload(
+        &self,
+        path: &str,
+        args: OpInitiateUpload,
+    ) -> impl Future<Output = Result<RpInitiateUpload>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `presign_post` operation on the specified path or prefix.
+    ///
+    /// Require [`Capability::presign_post`]
+    ///
+    /// # Behavior
+    ///
+    /// - Input path ends with `/` SHOULD be used as key prefix, otherwise as the exact key.
+    /// - This API is only for creating presigned POST forms and MUST NOT send any request.
+    fn presign_post(
+        &self,
+        path: &str,
+        args: OpPresignPost,
+    ) -> impl Future<Output = Result<RpPresignPost>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `complete_upload` operation on the specified path.
+    ///
+    /// Require [`Capability::presign_multipart`]
+    ///
+    /// # Behavior
+    ///
+    /// - Input path MUST be file path, DON'T NEED to check mode.
+    /// - Parts are sorted by part number before calling this operation.
+    fn complete_upload(
+        &self,
+        path: &str,
+        args: OpCompleteUpload,
+    ) -> impl Future<Output = Result<RpCompleteUpload>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `create_symlink` operation on the specified path.
+    ///
+    /// Require [`Capability::create_symlink`]
+    ///
+    /// # Behavior
+    ///
+    /// - Input path MUST be file path, DON'T NEED to check mode.
+    /// - The target MUST be stored as is, services SHOULD NOT resolve or validate it.
+    /// - Services SHOULD return `AlreadyExists` if path already exists.
+    fn create_symlink(
+        &self,
+        path: &str,
+        args: OpCreateSymlink,
+    ) -> impl Future<Output = Result<RpCreateSymlink>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `read_link` operation on the specified path.
+    ///
+    /// Require [`Capability::read_link`]
+    ///
+    /// # Behavior
+    ///
+    /// - Input path MUST be file path, DON'T NEED to check mode.
+    /// - Services SHOULD return `NotFound` if path doesn't exist.
+    fn read_link(
+        &self,
+        path: &str,
+        args: OpReadLink,
+    ) -> impl Future<Output = Result<RpReadLink>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `set_permissions` operation on the specified path.
+    ///
+    /// Require [`Capability::set_permissions`]
+    ///
+    /// # Behavior
+    ///
+    /// - Only the permission bits of `args.mode()` should be applied.
+    fn set_permissions(
+        &self,
+        path: &str,
+        args: OpSetPermissions,
+    ) -> impl Future<Output = Result<RpSetPermissions>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `set_times` operation on the specified path.
+    ///
+    /// Require [`Capability::set_times`]
+    ///
+    /// # Behavior
+    ///
+    /// - Times that are `None` in `args` should be left unchanged.
+    fn set_times(
+        &self,
+        path: &str,
+        args: OpSetTimes,
+    ) -> impl Future<Output = Result<RpSetTimes>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `update_metadata` operation on the specified path.
+    ///
+    /// Require [`Capability::update_metadata`]
+    ///
+    /// # Behavior
+    ///
+    /// - Input path MUST be file path, DON'T NEED to check mode.
+    /// - Fields that are not set in the update MUST be left unchanged.
+    /// - Services SHOULD return the metadata of the object after update, including the new etag.
+    fn update_metadata(
+        &self,
+        path: &str,
+        args: OpUpdateMetadata,
+    ) -> impl Future<Output = Result<RpUpdateMetadata>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `concat` operation on the specified path.
+    ///
+    /// Require [`Capability::concat`]
+    ///
+    /// # Behavior
+    ///
+    /// - Input path and sources MUST be file paths, DON'T NEED to check mode.
+    /// - Sources MUST be left unchanged, the path will be overwritten if it exists.
+    /// - Services MAY assume that sources satisfy [`Capability::concat_min_source_size`],
+    ///   [`Capability::concat_max_source_size`] and [`Capability::concat_max_sources`].
+    fn concat(
+        &self,
+        path: &str,
+        args: OpConcat,
+    ) -> impl Future<Output = Result<RpConcat>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `lock` operation on the specified path.
+    ///
+    /// Require [`Capability::lock`]
+    ///
+    /// # Behavior
+    ///
+    /// - Input path MUST be file path, DON'T NEED to check mode.
+    /// - Services MUST try to acquire the lock only once, and return an error with
+    ///   [`ErrorKind::ConditionNotMatch`] if the lock is held by another owner.
+    /// - If [`OpLock::token`] is set, services MUST renew the lease of the lock held
+    ///   with this token instead, and return [`ErrorKind::ConditionNotMatch`] if the
+    ///   lease has been lost.
+    /// - The returned token MUST be greater than the tokens of all previous holders.
+    fn lock(&self, path: &str, args: OpLock) -> impl Future<Output = Result<RpLock>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `unlock` operation on the specified path.
+    ///
+    /// Require [`Capability::lock`]
+    ///
+    /// # Behavior
+    ///
+    /// - Services MUST only release the lock if it's still held with [`OpUnlock::token`],
+    ///   and return [`ErrorKind::ConditionNotMatch`] otherwise.
+    fn unlock(
+        &self,
+        path: &str,
+        args: OpUnlock,
+    ) -> impl Future<Output = Result<RpUnlock>> + MaybeSend {
+        let (_, _) = (path, args);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
+
+    /// Invoke the `transaction` operation.
+    ///
+    /// Require [`Capability::transaction`]
+    ///
+    /// # Behavior
+    ///
+    /// - Services MUST apply all mutations atomically, either all of them are
+    ///   visible or none of them.
+    /// - Services MUST return an error with [`ErrorKind::ConditionNotMatch`] without
+    ///   applying any mutation if any of the checks doesn't hold at commit.
+    fn transaction(
+        &self,
+        args: OpTransaction,
+    ) -> impl Future<Output = Result<RpTransaction>> + MaybeSend {
+        let _ = args;
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "operation is not supported",
+        )))
+    }
 }
 
 /// `AccessDyn` is the dyn version of [`Access`] make it possible to use as
@@ -312,6 +611,76 @@ pub trait AccessDyn: Send + Sync + Debug + Unpin {
         path: &'a str,
         args: OpPresign,
     ) -> BoxedFuture<'a, Result<RpPresign>>;
+    /// Dyn version of [`Accessor::list_uploads`]
+    fn list_uploads_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpListUploads,
+    ) -> BoxedFuture<'a, Result<RpListUploads>>;
+    /// Dyn version of [`Accessor::abort_upload`]
+    fn abort_upload_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpAbortUpload,
+    ) -> BoxedFuture<'a, Result<RpAbortUpload>>;
+    /// Dyn version of [`Accessor::initiate_upload`]
+    fn initiate_upload_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpInitiateUpload,
+    ) -> BoxedFuture<'a, Result<RpInitiateUpload>>;
+    /// Dyn version of [`Accessor::presign_post`]
+    fn presign_post_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpPresignPost,
+    ) -> BoxedFuture<'a, Result<RpPresignPost>>;
+    /// Dyn version of [`Accessor::complete_upload`]
+    fn complete_upload_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpCompleteUpload,
+    ) -> BoxedFuture<'a, Result<RpCompleteUpload>>;
+    /// Dyn version of [`Accessor::create_symlink`]
+    fn create_symlink_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpCreateSymlink,
+    ) -> BoxedFuture<'a, Result<RpCreateSymlink>>;
+    /// Dyn version of [`Accessor::read_link`]
+    fn read_link_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpReadLink,
+    ) -> BoxedFuture<'a, Result<RpReadLink>>;
+    /// Dyn version of [`Accessor::set_permissions`]
+    fn set_permissions_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpSetPermissions,
+    ) -> BoxedFuture<'a, Result<RpSetPermissions>>;
+    /// Dyn version of [`Accessor::set_times`]
+    fn set_times_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpSetTimes,
+    ) -> BoxedFuture<'a, Result<RpSetTimes>>;
+    /// Dyn version of [`Accessor::update_metadata`]
+    fn update_metadata_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpUpdateMetadata,
+    ) -> BoxedFuture<'a, Result<RpUpdateMetadata>>;
+    /// Dyn version of [`Accessor::concat`]
+    fn concat_dyn<'a>(&'a self, path: &'a str, args: OpConcat)
+        -> BoxedFuture<'a, Result<RpConcat>>;
+    /// Dyn version of [`Accessor::lock`]
+    fn lock_dyn<'a>(&'a self, path: &'a str, args: OpLock) -> BoxedFuture<'a, Result<RpLock>>;
+    /// Dyn version of [`Accessor::unlock`]
+    fn unlock_dyn<'a>(&'a self, path: &'a str, args: OpUnlock)
+        -> BoxedFuture<'a, Result<RpUnlock>>;
+    /// Dyn version of [`Accessor::transaction`]
+    fn transaction_dyn(&self, args: OpTransaction) -> BoxedFuture<'_, Result<RpTransaction>>;
 }
 
 impl<A: ?Sized> AccessDyn for A
@@ -392,6 +761,110 @@ where
     ) -> BoxedFuture<'a, Result<RpPresign>> {
         Box::pin(self.presign(path, args))
     }
+
+    fn list_uploads_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpListUploads,
+    ) -> BoxedFuture<'a, Result<RpListUploads>> {
+        Box::pin(self.list_uploads(path, args))
+    }
+
+    fn abort_upload_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpAbortUpload,
+    ) -> BoxedFuture<'a, Result<RpAbortUpload>> {
+        Box::pin(self.abort_upload(path, args))
+    }
+
+    fn initiate_upload_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpInitiateUpload,
+    ) -> BoxedFuture<'a, Result<RpInitiateUpload>> {
+        Box::pin(self.initiate_upload(path, args))
+    }
+
+    fn complete_upload_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpCompleteUpload,
+    ) -> BoxedFuture<'a, Result<RpCompleteUpload>> {
+        Box::pin(self.complete_upload(path, args))
+    }
+
+    fn presign_post_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpPresignPost,
+    ) -> BoxedFuture<'a, Result<RpPresignPost>> {
+        Box::pin(self.presign_post(path, args))
+    }
+
+    fn create_symlink_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpCreateSymlink,
+    ) -> BoxedFuture<'a, Result<RpCreateSymlink>> {
+        Box::pin(self.create_symlink(path, args))
+    }
+
+    fn read_link_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpReadLink,
+    ) -> BoxedFuture<'a, Result<RpReadLink>> {
+        Box::pin(self.read_link(path, args))
+    }
+
+    fn set_permissions_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpSetPermissions,
+    ) -> BoxedFuture<'a, Result<RpSetPermissions>> {
+        Box::pin(self.set_permissions(path, args))
+    }
+
+    fn set_times_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpSetTimes,
+    ) -> BoxedFuture<'a, Result<RpSetTimes>> {
+        Box::pin(self.set_times(path, args))
+    }
+
+    fn update_metadata_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpUpdateMetadata,
+    ) -> BoxedFuture<'a, Result<RpUpdateMetadata>> {
+        Box::pin(self.update_metadata(path, args))
+    }
+
+    fn concat_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpConcat,
+    ) -> BoxedFuture<'a, Result<RpConcat>> {
+        Box::pin(self.concat(path, args))
+    }
+
+    fn lock_dyn<'a>(&'a self, path: &'a str, args: OpLock) -> BoxedFuture<'a, Result<RpLock>> {
+        Box::pin(self.lock(path, args))
+    }
+
+    fn unlock_dyn<'a>(
+        &'a self,
+        path: &'a str,
+        args: OpUnlock,
+    ) -> BoxedFuture<'a, Result<RpUnlock>> {
+        Box::pin(self.unlock(path, args))
+    }
+
+    fn transaction_dyn(&self, args: OpTransaction) -> BoxedFuture<'_, Result<RpTransaction>> {
+        Box::pin(self.transaction(args))
+    }
 }
 
 impl Access for dyn AccessDyn {
@@ -439,6 +912,78 @@ impl Access for dyn AccessDyn {
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         self.presign_dyn(path, args).await
     }
+
+    async fn list_uploads(&self, path: &str, args: OpListUploads) -> Result<RpListUploads> {
+        self.list_uploads_dyn(path, args).await
+    }
+
+    async fn abort_upload(&self, path: &str, args: OpAbortUpload) -> Result<RpAbortUpload> {
+        self.abort_upload_dyn(path, args).await
+    }
+
+    async fn initiate_upload(
+        &self,
+        path: &str,
+        args: OpInitiateUpload,
+    ) -> Result<RpInitiateUpload> {
+        self.initiate_upload_dyn(path, args).await
+    }
+
+    async fn complete_upload(
+        &self,
+        path: &str,
+        args: OpCompleteUpload,
+    ) -> Result<RpCompleteUpload> {
+        self.complete_upload_dyn(path, args).await
+    }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        self.presign_post_dyn(path, args).await
+    }
+
+    async fn create_symlink(&self, path: &str, args: OpCreateSymlink) -> Result<RpCreateSymlink> {
+        self.create_symlink_dyn(path, args).await
+    }
+
+    async fn read_link(&self, path: &str, args: OpReadLink) -> Result<RpReadLink> {
+        self.read_link_dyn(path, args).await
+    }
+
+    async fn set_permissions(
+        &self,
+        path: &str,
+        args: OpSetPermissions,
+    ) -> Result<RpSetPermissions> {
+        self.set_permissions_dyn(path, args).await
+    }
+
+    async fn set_times(&self, path: &str, args: OpSetTimes) -> Result<RpSetTimes> {
+        self.set_times_dyn(path, args).await
+    }
+
+    async fn update_metadata(
+        &self,
+        path: &str,
+        args: OpUpdateMetadata,
+    ) -> Result<RpUpdateMetadata> {
+        self.update_metadata_dyn(path, args).await
+    }
+
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        self.concat_dyn(path, args).await
+    }
+
+    async fn lock(&self, path: &str, args: OpLock) -> Result<RpLock> {
+        self.lock_dyn(path, args).await
+    }
+
+    async fn unlock(&self, path: &str, args: OpUnlock) -> Result<RpUnlock> {
+        self.unlock_dyn(path, args).await
+    }
+
+    async fn transaction(&self, args: OpTransaction) -> Result<RpTransaction> {
+        self.transaction_dyn(args).await
+    }
 }
 
 /// Dummy implementation of accessor.
@@ -538,6 +1083,113 @@ impl<T: Access + ?Sized> Access for Arc<T> {
     ) -> impl Future<Output = Result<RpPresign>> + MaybeSend {
         async move { self.as_ref().presign(path, args).await }
     }
+
+    fn list_uploads(
+        &self,
+        path: &str,
+        args: OpListUploads,
+    ) -> impl Future<Output = Result<RpListUploads>> + MaybeSend {
+        async move { self.as_ref().list_uploads(path, args).await }
+    }
+
+    fn abort_upload(
+        &self,
+        path: &str,
+        args: OpAbortUpload,
+    ) -> impl Future<Output = Result<RpAbortUpload>> + MaybeSend {
+        async move { self.as_ref().abort_upload(path, args).await }
+    }
+
+    fn initiate_upload(
+        &self,
+        path: &str,
+        args: OpInitiateUpload,
+    ) -> impl Future<Output = Result<RpInitiateUpload>> + MaybeSend {
+        async move { self.as_ref().initiate_upload(path, args).await }
+    }
+
+    fn complete_upload(
+        &self,
+        path: &str,
+        args: OpCompleteUpload,
+    ) -> impl Future<Output = Result<RpCompleteUpload>> + MaybeSend {
+        async move { self.as_ref().complete_upload(path, args).await }
+    }
+
+    fn presign_post(
+        &self,
+        path: &str,
+        args: OpPresignPost,
+    ) -> impl Future<Output = Result<RpPresignPost>> + MaybeSend {
+        async move { self.as_ref().presign_post(path, args).await }
+    }
+
+    fn create_symlink(
+        &self,
+        path: &str,
+        args: OpCreateSymlink,
+    ) -> impl Future<Output = Result<RpCreateSymlink>> + MaybeSend {
+        async move { self.as_ref().create_symlink(path, args).await }
+    }
+
+    fn read_link(
+        &self,
+        path: &str,
+        args: OpReadLink,
+    ) -> impl Future<Output = Result<RpReadLink>> + MaybeSend {
+        async move { self.as_ref().read_link(path, args).await }
+    }
+
+    fn set_permissions(
+        &self,
+        path: &str,
+        args: OpSetPermissions,
+    ) -> impl Future<Output = Result<RpSetPermissions>> + MaybeSend {
+        async move { self.as_ref().set_permissions(path, args).await }
+    }
+
+    fn set_times(
+        &self,
+        path: &str,
+        args: OpSetTimes,
+    ) -> impl Future<Output = Result<RpSetTimes>> + MaybeSend {
+        async move { self.as_ref().set_times(path, args).await }
+    }
+
+    fn update_metadata(
+        &self,
+        path: &str,
+        args: OpUpdateMetadata,
+    ) -> impl Future<Output = Result<RpUpdateMetadata>> + MaybeSend {
+        async move { self.as_ref().update_metadata(path, args).await }
+    }
+
+    fn concat(
+        &self,
+        path: &str,
+        args: OpConcat,
+    ) -> impl Future<Output = Result<RpConcat>> + MaybeSend {
+        async move { self.as_ref().concat(path, args).await }
+    }
+
+    fn lock(&self, path: &str, args: OpLock) -> impl Future<Output = Result<RpLock>> + MaybeSend {
+        async move { self.as_ref().lock(path, args).await }
+    }
+
+    fn unlock(
+        &self,
+        path: &str,
+        args: OpUnlock,
+    ) -> impl Future<Output = Result<RpUnlock>> + MaybeSend {
+        async move { self.as_ref().unlock(path, args).await }
+    }
+
+    fn transaction(
+        &self,
+        args: OpTransaction,
+    ) -> impl Future<Output = Result<RpTransaction>> + MaybeSend {
+        async move { self.as_ref().transaction(args).await }
+    }
 }
 
 /// Accessor is the type erased accessor with `Arc<dyn Accessor>`.
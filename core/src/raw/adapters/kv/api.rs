@@ -15,9 +15,11 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::VecDeque;
 use std::fmt::Debug;
 use std::future::ready;
 use std::ops::DerefMut;
+use std::time::Duration;
 
 use futures::Future;
 
@@ -44,11 +46,7 @@ impl Scan for () {
 /// A Scan implementation for all trivial non-async iterators
 pub struct ScanStdIter<I>(I);
 
-#[cfg(any(
-    feature = "services-cloudflare-kv",
-    feature = "services-rocksdb",
-    feature = "services-sled"
-))]
+#[cfg(feature = "services-cloudflare-kv")]
 impl<I> ScanStdIter<I>
 where
     I: Iterator<Item = Result<String>> + Unpin + Send + Sync,
@@ -87,6 +85,92 @@ impl<T: ScanDyn + ?Sized> Scan for Box<T> {
     }
 }
 
+/// ScanOptions is pushed down to [`Adapter::scan`] from list operations.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScanOptions {
+    /// The max count of keys to fetch from the service per request.
+    ///
+    /// Scanners still return all keys, this only controls how many keys are
+    /// fetched at once.
+    pub limit: Option<usize>,
+    /// Only keys greater than this key should be returned.
+    pub start_after: Option<String>,
+}
+
+/// The default count of keys fetched per request by [`PagedScanner`].
+const DEFAULT_SCAN_PAGE_SIZE: usize = 1000;
+
+/// ScanPage fetches keys of a scan page by page, used by [`PagedScanner`].
+pub trait ScanPage: Send + Sync + Unpin + 'static {
+    /// Fetch at most `limit` keys that start with `prefix` and are greater than
+    /// `start_after`, in ascending order.
+    ///
+    /// - `start_after` is never less than `prefix`, so that services can scan
+    ///   from it directly.
+    /// - Returning less than `limit` keys means there are no more keys.
+    fn next_page(
+        &self,
+        prefix: &str,
+        start_after: Option<&str>,
+        limit: usize,
+    ) -> impl Future<Output = Result<Vec<String>>> + MaybeSend;
+}
+
+/// PagedScanner is a [`Scan`] for services that can fetch ordered keys in a range.
+///
+/// Keys are fetched page by page starting after the last key of previous page,
+/// so that only the keys being consumed are fetched from the service.
+pub struct PagedScanner<P> {
+    inner: P,
+    prefix: String,
+    start_after: Option<String>,
+    limit: usize,
+
+    keys: VecDeque<String>,
+    done: bool,
+}
+
+impl<P: ScanPage> PagedScanner<P> {
+    /// Create a new PagedScanner on keys that start with `prefix`.
+    pub fn new(inner: P, prefix: &str, opts: ScanOptions) -> Self {
+        Self {
+            inner,
+            prefix: prefix.to_string(),
+            start_after: opts.start_after.filter(|v| v.as_str() >= prefix),
+            limit: opts.limit.unwrap_or(DEFAULT_SCAN_PAGE_SIZE).max(1),
+
+            keys: VecDeque::new(),
+            done: false,
+        }
+    }
+}
+
+impl<P: ScanPage> Scan for PagedScanner<P> {
+    async fn next(&mut self) -> Result<Option<String>> {
+        loop {
+            if let Some(key) = self.keys.pop_front() {
+                return Ok(Some(key));
+            }
+            if self.done {
+                return Ok(None);
+            }
+
+            let keys = self
+                .inner
+                .next_page(&self.prefix, self.start_after.as_deref(), self.limit)
+                .await?;
+            self.done = keys.len() < self.limit;
+            if let Some(key) = keys.last() {
+                self.start_after = Some(key.clone());
+            }
+            // Services might match prefix loosely, like `LIKE` in sql.
+            let prefix = &self.prefix;
+            self.keys
+                .extend(keys.into_iter().filter(|k| k.starts_with(prefix.as_str())));
+        }
+    }
+}
+
 /// KvAdapter is the adapter to underlying kv services.
 ///
 /// By implement this trait, any kv service can work as an OpenDAL Service.
@@ -102,17 +186,59 @@ pub trait Adapter: Send + Sync + Debug + Unpin + 'static {
     /// - return `Ok(None)` if this key is not exist.
     fn get(&self, path: &str) -> impl Future<Output = Result<Option<Buffer>>> + MaybeSend;
 
+    /// Get multiple keys from service, values are returned in the same order as `paths`.
+    ///
+    /// Services that support read transactions should read all keys in one
+    /// transaction, so that keys written together are read consistently.
+    fn get_many(
+        &self,
+        paths: &[String],
+    ) -> impl Future<Output = Result<Vec<Option<Buffer>>>> + MaybeSend {
+        async move {
+            let mut values = Vec::with_capacity(paths.len());
+            for path in paths {
+                values.push(self.get(path).await?);
+            }
+            Ok(values)
+        }
+    }
+
     /// Set a key into service.
     fn set(&self, path: &str, value: Buffer) -> impl Future<Output = Result<()>> + MaybeSend;
 
+    /// Set a key into service, the key will be removed by service after `ttl`.
+    ///
+    /// - Adapters that implement this MUST set [`Capability::write_with_ttl`].
+    fn set_with_ttl(
+        &self,
+        path: &str,
+        value: Buffer,
+        ttl: Duration,
+    ) -> impl Future<Output = Result<()>> + MaybeSend {
+        let _ = (path, value, ttl);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "kv adapter doesn't support this operation",
+        )
+        .with_operation("kv::Adapter::set_with_ttl")))
+    }
+
     /// Delete a key from service.
     ///
     /// - return `Ok(())` even if this key is not exist.
     fn delete(&self, path: &str) -> impl Future<Output = Result<()>> + MaybeSend;
 
     /// Scan a key prefix to get all keys that start with this key.
-    fn scan(&self, path: &str) -> impl Future<Output = Result<Self::Scanner>> + MaybeSend {
-        let _ = path;
+    ///
+    /// Keys should be returned in ascending order, `opts` should be pushed down
+    /// to the service if possible.
+    fn scan(
+        &self,
+        path: &str,
+        opts: ScanOptions,
+    ) -> impl Future<Output = Result<Self::Scanner>> + MaybeSend {
+        let _ = (path, opts);
 
         ready(Err(Error::new(
             ErrorKind::Unsupported,
@@ -132,6 +258,46 @@ pub trait Adapter: Send + Sync + Debug + Unpin + 'static {
         )
         .with_operation("kv::Adapter::append")))
     }
+
+    /// Set a key into service only if its current value is `current`.
+    ///
+    /// - `current` is `None` means the key must not exist.
+    /// - return `Ok(false)` if the current value doesn't match.
+    /// - Adapters that implement this MUST set [`Capability::lock`].
+    fn compare_and_swap(
+        &self,
+        path: &str,
+        current: Option<Buffer>,
+        value: Buffer,
+    ) -> impl Future<Output = Result<bool>> + MaybeSend {
+        let _ = (path, current, value);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "kv adapter doesn't support this operation",
+        )
+        .with_operation("kv::Adapter::compare_and_swap")))
+    }
+
+    /// Apply `mutations` in one transaction only if all `checks` hold.
+    ///
+    /// - `checks` are the values keys must have, `None` means the key must not exist.
+    /// - `mutations` are the values to set, `None` means the key should be deleted.
+    /// - return `Ok(false)` without applying any mutation if any check doesn't hold.
+    /// - Adapters that implement this MUST set [`Capability::transaction`].
+    fn transaction(
+        &self,
+        checks: Vec<(String, Option<Buffer>)>,
+        mutations: Vec<(String, Option<Buffer>)>,
+    ) -> impl Future<Output = Result<bool>> + MaybeSend {
+        let _ = (checks, mutations);
+
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "kv adapter doesn't support this operation",
+        )
+        .with_operation("kv::Adapter::transaction")))
+    }
 }
 
 /// Info for this key value accessor.
@@ -16,9 +16,16 @@
 // under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
+use super::chunk::ChunkManifest;
+use super::chunk::CHUNK_DIR;
+use super::metadata::check_conditions;
+use super::metadata::check_write_conditions;
+use super::metadata::ValueMetadata;
 use super::Adapter;
 use super::Scan;
+use super::ScanOptions;
 use crate::raw::oio::HierarchyLister;
 use crate::raw::oio::QueueBuf;
 use crate::raw::*;
@@ -36,6 +43,8 @@ pub struct Backend<S: Adapter> {
     kv: Arc<S>,
     root: String,
     info: Arc<AccessorInfo>,
+    chunk_size: Option<usize>,
+    metadata: bool,
 }
 
 impl<S> Backend<S>
@@ -66,15 +75,67 @@ where
 
                 if cap.list {
                     cap.list_with_recursive = true;
+                    cap.list_with_start_after = true;
+                    cap.list_with_continuation_token = true;
                 }
 
                 am.set_native_capability(cap);
 
                 am.into()
             },
+            chunk_size: None,
+            metadata: false,
         }
     }
 
+    /// Split values larger than `chunk_size` into chunks.
+    ///
+    /// Writers store values chunk by chunk and ranged reads only fetch the chunks
+    /// they cover, so that values of any size can be stored on services with
+    /// per-value limits. The key of a split value stores a small manifest instead.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        let mut cap = self.info.native_capability();
+        cap.write_can_multi = true;
+        cap.write_total_max_size = None;
+        self.info.set_native_capability(cap);
+
+        self.chunk_size = Some(chunk_size.max(1));
+        self
+    }
+
+    /// Store metadata like etag, last modified and content type with values.
+    ///
+    /// Conditional reads are supported with etag and last modified, and conditional
+    /// writes are supported if the service can compare and swap or apply transactions.
+    /// Values written before are still readable, they just don't have metadata.
+    pub fn with_metadata(mut self) -> Self {
+        let mut cap = self.info.native_capability();
+        cap.write_with_content_type = true;
+        cap.write_with_content_disposition = true;
+        cap.write_with_content_encoding = true;
+        cap.write_with_cache_control = true;
+        cap.write_with_user_metadata = true;
+
+        cap.stat_with_if_match = true;
+        cap.stat_with_if_none_match = true;
+        cap.stat_with_if_modified_since = true;
+        cap.stat_with_if_unmodified_since = true;
+        cap.read_with_if_match = true;
+        cap.read_with_if_none_match = true;
+        cap.read_with_if_modified_since = true;
+        cap.read_with_if_unmodified_since = true;
+
+        if cap.lock || cap.transaction {
+            cap.write_with_if_match = true;
+            cap.write_with_if_none_match = true;
+            cap.write_with_if_not_exists = true;
+        }
+        self.info.set_native_capability(cap);
+
+        self.metadata = true;
+        self
+    }
+
     /// Configure root within this backend.
     pub fn with_root(self, root: &str) -> Self {
         self.with_normalized_root(normalize_root(root))
@@ -101,7 +162,7 @@ impl<S: Adapter> Access for Backend<S> {
         self.info.clone()
     }
 
-    async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         let p = build_abs_path(&self.root, path);
 
         if p == build_abs_path(&self.root, "") {
@@ -109,9 +170,26 @@ impl<S: Adapter> Access for Backend<S> {
         } else {
             let bs = self.kv.get(&p).await?;
             match bs {
-                Some(bs) => Ok(RpStat::new(
-                    Metadata::new(EntryMode::FILE).with_content_length(bs.len() as u64),
-                )),
+                Some(bs) => {
+                    let (value_meta, bs) = split_metadata(self.metadata, bs)?;
+                    check_conditions(
+                        value_meta.as_ref(),
+                        args.if_match(),
+                        args.if_none_match(),
+                        args.if_modified_since(),
+                        args.if_unmodified_since(),
+                    )?;
+
+                    let length = match parse_manifest(self.chunk_size.is_some(), &bs)? {
+                        Some(manifest) => manifest.content_length(),
+                        None => bs.len() as u64,
+                    };
+                    let meta = Metadata::new(EntryMode::FILE).with_content_length(length);
+                    Ok(RpStat::new(match value_meta {
+                        Some(value_meta) => value_meta.apply(meta),
+                        None => meta,
+                    }))
+                }
                 None => Err(Error::new(ErrorKind::NotFound, "kv doesn't have this path")),
             }
         }
@@ -123,61 +201,249 @@ impl<S: Adapter> Access for Backend<S> {
             Some(bs) => bs,
             None => return Err(Error::new(ErrorKind::NotFound, "kv doesn't have this path")),
         };
-        Ok((RpRead::new(), bs.slice(args.range().to_range_as_usize())))
+        let (value_meta, bs) = split_metadata(self.metadata, bs)?;
+        check_conditions(
+            value_meta.as_ref(),
+            args.if_match(),
+            args.if_none_match(),
+            args.if_modified_since(),
+            args.if_unmodified_since(),
+        )?;
+
+        let bs = read_value(
+            &*self.kv,
+            &self.root,
+            self.chunk_size.is_some(),
+            bs,
+            args.range(),
+        )
+        .await?;
+        Ok((RpRead::new(), bs))
     }
 
-    async fn write(&self, path: &str, _: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let p = build_abs_path(&self.root, path);
+        // Compare and swap and transactions can't set ttl.
+        if args.ttl().is_some()
+            && (args.if_match().is_some() || args.if_none_match().is_some() || args.if_not_exists())
+        {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "kv service doesn't support conditional writes with ttl",
+            ));
+        }
 
-        Ok((RpWrite::new(), KvWriter::new(self.kv.clone(), p)))
+        Ok((
+            RpWrite::new(),
+            KvWriter::new(
+                self.kv.clone(),
+                &self.root,
+                p,
+                args,
+                self.chunk_size,
+                self.metadata,
+            ),
+        ))
     }
 
     async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
         Ok((
             RpDelete::default(),
-            oio::OneShotDeleter::new(KvDeleter::new(self.kv.clone(), self.root.clone())),
+            oio::OneShotDeleter::new(KvDeleter::new(
+                self.kv.clone(),
+                self.root.clone(),
+                self.chunk_size.is_some(),
+                self.metadata,
+            )),
         ))
     }
 
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
         let p = build_abs_path(&self.root, path);
-        let res = self.kv.scan(&p).await?;
-        let lister = KvLister::new(&self.root, res);
+        // The continuation token is the last key listed, resume after it.
+        let start_after = args
+            .continuation_token()
+            .or(args.start_after())
+            .map(|v| build_abs_path(&self.root, v));
+        let opts = ScanOptions {
+            limit: args.limit(),
+            start_after: start_after.clone(),
+        };
+
+        let res = self.kv.scan(&p, opts).await?;
+        let lister = KvLister::new(&self.root, res, start_after).with_limit(args.limit());
         let lister = HierarchyLister::new(lister, path, args.recursive());
 
         Ok((RpList::default(), lister))
     }
+
+    async fn lock(&self, path: &str, args: OpLock) -> Result<RpLock> {
+        let p = build_abs_path(&self.root, path);
+        let record = self
+            .update_lock(&p, |current| LockRecord::lock(current, &args))
+            .await?;
+        Ok(RpLock::new(record.token()))
+    }
+
+    async fn unlock(&self, path: &str, args: OpUnlock) -> Result<RpUnlock> {
+        let p = build_abs_path(&self.root, path);
+        self.update_lock(&p, |current| LockRecord::unlock(current, &args))
+            .await?;
+        Ok(RpUnlock::default())
+    }
+
+    async fn transaction(&self, args: OpTransaction) -> Result<RpTransaction> {
+        let abs = |entries: &[(String, Option<Buffer>)]| {
+            entries
+                .iter()
+                .map(|(path, value)| (build_abs_path(&self.root, path), value.clone()))
+                .collect()
+        };
+        let conflict = || {
+            Error::new(
+                ErrorKind::ConditionNotMatch,
+                "transaction conflicts with changes by others",
+            )
+        };
+
+        let mut checks: Vec<(String, Option<Buffer>)> = abs(args.checks());
+        let mut mutations: Vec<(String, Option<Buffer>)> = abs(args.mutations());
+
+        // Values stored in chunks or with metadata are checked by the stored
+        // value instead, which changes on every write.
+        if self.chunk_size.is_some() || self.metadata {
+            for (path, expected) in checks.iter_mut() {
+                let Some(value) = expected else {
+                    continue;
+                };
+                let current = self.kv.get(path).await?.ok_or_else(conflict)?;
+                let (_, bs) = split_metadata(self.metadata, current.clone())?;
+                let content = read_value(
+                    &*self.kv,
+                    &self.root,
+                    self.chunk_size.is_some(),
+                    bs,
+                    BytesRange::default(),
+                )
+                .await?;
+                if content.to_bytes() != value.to_bytes() {
+                    return Err(conflict());
+                }
+                *expected = Some(current);
+            }
+        }
+        if self.metadata {
+            for (_, value) in mutations.iter_mut() {
+                if let Some(bs) = value.take() {
+                    *value = Some(ValueMetadata::new(&OpWrite::new()).join(bs)?);
+                }
+            }
+        }
+
+        if self.kv.transaction(checks, mutations).await? {
+            Ok(RpTransaction::default())
+        } else {
+            Err(conflict())
+        }
+    }
+}
+
+impl<S: Adapter> Backend<S> {
+    /// Replace the lock record at `path` with the one built by `f` via compare and swap.
+    async fn update_lock(
+        &self,
+        path: &str,
+        f: impl FnOnce(Option<&LockRecord>) -> Result<LockRecord>,
+    ) -> Result<LockRecord> {
+        let current = self.kv.get(path).await?;
+        let record = match &current {
+            Some(bs) => f(Some(&LockRecord::parse(&bs.to_bytes())?))?,
+            None => f(None)?,
+        };
+
+        if self
+            .kv
+            .compare_and_swap(path, current, Buffer::from(record.to_bytes()))
+            .await?
+        {
+            Ok(record)
+        } else {
+            Err(Error::new(
+                ErrorKind::ConditionNotMatch,
+                "lock has been changed by others",
+            ))
+        }
+    }
 }
 
+/// The default page size used by [`KvLister::next_page`] if no limit is specified.
+const DEFAULT_KV_PAGE_SIZE: usize = 1000;
+
+/// KvLister lists keys returned by [`Scan`].
+///
+/// The continuation token of kv services is the last key that has been fetched
+/// from scanner, listing resumes by scanning keys after it. This relies on the
+/// underlying scanner returning keys in ascending order.
 pub struct KvLister<Iter> {
     root: String,
     inner: Iter,
+
+    limit: usize,
+    /// Keys not greater than it are skipped, in case the scanner doesn't support
+    /// `start_after`.
+    start_after: Option<String>,
+    /// The last key that has been fetched from scanner.
+    last_key: Option<String>,
 }
 
 impl<Iter> KvLister<Iter>
 where
     Iter: Scan,
 {
-    fn new(root: &str, inner: Iter) -> Self {
+    fn new(root: &str, inner: Iter, start_after: Option<String>) -> Self {
         Self {
             root: root.to_string(),
             inner,
+
+            limit: DEFAULT_KV_PAGE_SIZE,
+            start_after,
+            last_key: None,
         }
     }
 
+    fn with_limit(mut self, limit: Option<usize>) -> Self {
+        if let Some(limit) = limit {
+            self.limit = limit.max(1);
+        }
+        self
+    }
+
     async fn inner_next(&mut self) -> Result<Option<oio::Entry>> {
-        Ok(self.inner.next().await?.map(|v| {
-            let mode = if v.ends_with('/') {
+        loop {
+            let Some(key) = self.inner.next().await? else {
+                return Ok(None);
+            };
+            if self.start_after.as_ref().is_some_and(|v| key <= *v) {
+                continue;
+            }
+
+            let mut path = build_rel_path(&self.root, &key);
+            let mode = if key.ends_with('/') {
                 EntryMode::DIR
             } else {
                 EntryMode::FILE
             };
-            let mut path = build_rel_path(&self.root, &v);
+            self.last_key = Some(key);
+
+            // Chunks of values are not visible to users.
+            if path.starts_with(CHUNK_DIR) {
+                continue;
+            }
             if path.is_empty() {
                 path = "/".to_string();
             }
-            oio::Entry::new(&path, Metadata::new(mode))
-        }))
+            return Ok(Some(oio::Entry::new(&path, Metadata::new(mode))));
+        }
     }
 }
 
@@ -188,20 +454,55 @@ where
     async fn next(&mut self) -> Result<Option<oio::Entry>> {
         self.inner_next().await
     }
+
+    async fn next_page(&mut self) -> Result<(Vec<oio::Entry>, Option<String>)> {
+        let mut entries = Vec::with_capacity(self.limit);
+        while entries.len() < self.limit {
+            match self.inner_next().await? {
+                Some(entry) => entries.push(entry),
+                None => return Ok((entries, None)),
+            }
+        }
+        let token = self
+            .last_key
+            .as_ref()
+            .map(|key| build_rel_path(&self.root, key));
+        Ok((entries, token))
+    }
 }
 
 pub struct KvWriter<S> {
     kv: Arc<S>,
+    root: String,
     path: String,
+    args: OpWrite,
     buffer: QueueBuf,
+
+    chunk_size: Option<usize>,
+    /// The manifest of chunks written so far.
+    manifest: Option<ChunkManifest>,
+    metadata: bool,
 }
 
 impl<S> KvWriter<S> {
-    fn new(kv: Arc<S>, path: String) -> Self {
+    fn new(
+        kv: Arc<S>,
+        root: &str,
+        path: String,
+        args: OpWrite,
+        chunk_size: Option<usize>,
+        metadata: bool,
+    ) -> Self {
         KvWriter {
             kv,
+            root: root.to_string(),
             path,
+            args,
             buffer: QueueBuf::new(),
+
+            chunk_size,
+            manifest: None,
+            metadata,
         }
     }
 }
@@ -211,23 +512,144 @@ impl<S> KvWriter<S> {
 /// We will only take `&mut Self` reference for KvWriter.
 unsafe impl<S: Adapter> Sync for KvWriter<S> {}
 
+impl<S: Adapter> KvWriter<S> {
+    /// Write the first `size` bytes in buffer as the next chunk.
+    async fn write_chunk(&mut self, chunk_size: usize, size: usize) -> Result<()> {
+        let chunk = self.buffer.clone().collect().slice(0..size);
+        self.buffer.advance(size);
+
+        let manifest = self
+            .manifest
+            .get_or_insert_with(|| ChunkManifest::new(chunk_size));
+        let key = manifest.chunk_key(&self.root, manifest.chunk_count());
+        // Chunks are written before the manifest, they must outlive it.
+        let ttl = self.args.ttl().map(|ttl| ttl + CHUNK_TTL_GRACE);
+        set_value(&*self.kv, &key, chunk, ttl).await?;
+        manifest.set_content_length(manifest.content_length() + size as u64);
+        Ok(())
+    }
+
+    fn is_conditional(&self) -> bool {
+        self.args.if_match().is_some()
+            || self.args.if_none_match().is_some()
+            || self.args.if_not_exists()
+    }
+
+    /// Store `value` only if the current value is still `current`.
+    async fn set_if(&self, current: Option<Buffer>, value: Buffer) -> Result<()> {
+        let updated = if self.kv.info().capabilities().lock {
+            self.kv.compare_and_swap(&self.path, current, value).await?
+        } else {
+            self.kv
+                .transaction(
+                    vec![(self.path.clone(), current)],
+                    vec![(self.path.clone(), Some(value))],
+                )
+                .await?
+        };
+
+        if updated {
+            Ok(())
+        } else {
+            Err(Error::new(
+                ErrorKind::ConditionNotMatch,
+                "value has been changed by others",
+            ))
+        }
+    }
+}
+
 impl<S: Adapter> oio::Write for KvWriter<S> {
     async fn write(&mut self, bs: Buffer) -> Result<()> {
         self.buffer.push(bs);
+
+        // Keep the last chunk in buffer, so that values fit in one chunk can
+        // be stored without manifest.
+        if let Some(chunk_size) = self.chunk_size {
+            while self.buffer.len() > chunk_size {
+                self.write_chunk(chunk_size, chunk_size).await?;
+            }
+        }
         Ok(())
     }
 
     async fn close(&mut self) -> Result<Metadata> {
+        let conditional = self.is_conditional();
+        let previous = if self.chunk_size.is_some() || conditional {
+            self.kv.get(&self.path).await?
+        } else {
+            None
+        };
+        let previous_value = match &previous {
+            Some(bs) => Some(split_metadata(self.metadata, bs.clone())?),
+            None => None,
+        };
+        if conditional {
+            if let Err(err) = check_write_conditions(
+                previous_value.as_ref().map(|(meta, _)| meta.as_ref()),
+                &self.args,
+            ) {
+                self.abort().await?;
+                return Err(err);
+            }
+        }
+
         let buf = self.buffer.clone().collect();
-        let length = buf.len() as u64;
-        self.kv.set(&self.path, buf).await?;
+        let (value, length) = match self.chunk_size {
+            Some(chunk_size) if self.manifest.is_some() || ChunkManifest::is_reserved(&buf) => {
+                if !buf.is_empty() {
+                    self.write_chunk(chunk_size, buf.len()).await?;
+                }
+                let manifest = self
+                    .manifest
+                    .get_or_insert_with(|| ChunkManifest::new(chunk_size));
+                (manifest.to_buffer(), manifest.content_length())
+            }
+            _ => {
+                let length = buf.len() as u64;
+                (buf, length)
+            }
+        };
+
+        let mut meta = Metadata::new(EntryMode::from_path(&self.path)).with_content_length(length);
+        let value = if self.metadata {
+            let value_meta = ValueMetadata::new(&self.args);
+            meta = value_meta.apply(meta);
+            value_meta.join(value)?
+        } else {
+            if let Some(ttl) = self.args.ttl() {
+                meta.set_expires_at(expire_time_from_ttl(ttl));
+            }
+            value
+        };
+
+        if conditional {
+            if let Err(err) = self.set_if(previous, value).await {
+                self.abort().await?;
+                return Err(err);
+            }
+        } else {
+            set_value(&*self.kv, &self.path, value, self.args.ttl()).await?;
+        }
+        // The value has been replaced, the chunks are not needed anymore.
+        self.manifest = None;
+
+        // Cleaning up is best effort since the value has been replaced, chunks
+        // left behind are only wasting space.
+        if let Some((_, bs)) = &previous_value {
+            if let Ok(Some(manifest)) = parse_manifest(self.chunk_size.is_some(), bs) {
+                let _ = delete_chunks(&*self.kv, &self.root, &manifest).await;
+            }
+        }
 
-        let meta = Metadata::new(EntryMode::from_path(&self.path)).with_content_length(length);
         Ok(meta)
     }
 
     async fn abort(&mut self) -> Result<()> {
         self.buffer.clear();
+        if let Some(manifest) = self.manifest.take() {
+            delete_chunks(&*self.kv, &self.root, &manifest).await?;
+        }
         Ok(())
     }
 }
@@ -235,11 +657,18 @@ impl<S: Adapter> oio::Write for KvWriter<S> {
 pub struct KvDeleter<S> {
     kv: Arc<S>,
     root: String,
+    chunked: bool,
+    metadata: bool,
 }
 
 impl<S> KvDeleter<S> {
-    fn new(kv: Arc<S>, root: String) -> Self {
-        KvDeleter { kv, root }
+    fn new(kv: Arc<S>, root: String, chunked: bool, metadata: bool) -> Self {
+        KvDeleter {
+            kv,
+            root,
+            chunked,
+            metadata,
+        }
     }
 }
 
@@ -247,7 +676,328 @@ impl<S: Adapter> oio::OneShotDelete for KvDeleter<S> {
     async fn delete_once(&self, path: String, _: OpDelete) -> Result<()> {
         let p = build_abs_path(&self.root, &path);
 
+        let previous = if self.chunked {
+            self.kv.get(&p).await?
+        } else {
+            None
+        };
         self.kv.delete(&p).await?;
+
+        // Cleaning up is best effort since the value has been replaced, chunks
+        // left behind are only wasting space.
+        if let Some(bs) = previous {
+            if let Ok((_, bs)) = split_metadata(self.metadata, bs) {
+                if let Ok(Some(manifest)) = parse_manifest(self.chunked, &bs) {
+                    let _ = delete_chunks(&*self.kv, &self.root, &manifest).await;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Chunks of a value written with ttl live longer than the manifest by this
+/// duration, since they are written before the manifest.
+const CHUNK_TTL_GRACE: Duration = Duration::from_secs(3600);
+
+/// Set `value` at `key` with `ttl` if it's given.
+async fn set_value<S: Adapter>(
+    kv: &S,
+    key: &str,
+    value: Buffer,
+    ttl: Option<Duration>,
+) -> Result<()> {
+    match ttl {
+        Some(ttl) => kv.set_with_ttl(key, value, ttl).await,
+        None => kv.set(key, value).await,
+    }
+}
+
+/// Split the metadata from a stored value if metadata is enabled.
+fn split_metadata(enabled: bool, value: Buffer) -> Result<(Option<ValueMetadata>, Buffer)> {
+    if enabled {
+        ValueMetadata::split(value)
+    } else {
+        Ok((None, value))
+    }
+}
+
+/// Parse the manifest from a stored value if chunking is enabled.
+///
+/// Values are always plain if chunking is disabled, even if they look like a manifest.
+fn parse_manifest(chunked: bool, value: &Buffer) -> Result<Option<ChunkManifest>> {
+    if chunked {
+        ChunkManifest::parse(value)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Read `range` of a stored value, which might be stored in chunks.
+async fn read_value<S: Adapter>(
+    kv: &S,
+    root: &str,
+    chunked: bool,
+    value: Buffer,
+    range: BytesRange,
+) -> Result<Buffer> {
+    match parse_manifest(chunked, &value)? {
+        Some(manifest) => read_chunks(kv, root, &manifest, range).await,
+        None => Ok(value.slice(range.to_range_as_usize())),
+    }
+}
+
+/// Read `range` of the value stored in chunks of `manifest`.
+async fn read_chunks<S: Adapter>(
+    kv: &S,
+    root: &str,
+    manifest: &ChunkManifest,
+    range: BytesRange,
+) -> Result<Buffer> {
+    let (indexes, slice) = manifest.chunks_for(range);
+
+    let keys: Vec<_> = indexes
+        .map(|index| manifest.chunk_key(root, index))
+        .collect();
+    let chunks = kv
+        .get_many(&keys)
+        .await?
+        .into_iter()
+        .map(|chunk| {
+            chunk.ok_or_else(|| {
+                Error::new(
+                    ErrorKind::NotFound,
+                    "kv chunk doesn't exist, the value might have been replaced",
+                )
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let bs: Buffer = chunks.into_iter().flatten().collect();
+    Ok(bs.slice(slice))
+}
+
+/// Delete all chunks of `manifest`.
+async fn delete_chunks<S: Adapter>(kv: &S, root: &str, manifest: &ChunkManifest) -> Result<()> {
+    for index in 0..manifest.chunk_count() {
+        kv.delete(&manifest.chunk_key(root, index)).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::raw::oio::Delete;
+    use crate::raw::oio::List;
+    use crate::raw::oio::Read;
+    use crate::raw::oio::Write;
+
+    #[derive(Debug, Default)]
+    struct MapAdapter {
+        data: Mutex<BTreeMap<String, Buffer>>,
+    }
+
+    struct KeysScan(std::vec::IntoIter<String>);
+
+    impl Scan for KeysScan {
+        async fn next(&mut self) -> Result<Option<String>> {
+            Ok(self.0.next())
+        }
+    }
+
+    impl Adapter for MapAdapter {
+        type Scanner = KeysScan;
+
+        fn info(&self) -> super::super::Info {
+            super::super::Info::new(
+                Scheme::Memory,
+                "map",
+                Capability {
+                    read: true,
+                    write: true,
+                    list: true,
+                    lock: true,
+                    ..Default::default()
+                },
+            )
+        }
+
+        async fn get(&self, path: &str) -> Result<Option<Buffer>> {
+            Ok(self.data.lock().unwrap().get(path).cloned())
+        }
+
+        async fn set(&self, path: &str, value: Buffer) -> Result<()> {
+            self.data.lock().unwrap().insert(path.to_string(), value);
+            Ok(())
+        }
+
+        async fn delete(&self, path: &str) -> Result<()> {
+            self.data.lock().unwrap().remove(path);
+            Ok(())
+        }
+
+        async fn compare_and_swap(
+            &self,
+            path: &str,
+            current: Option<Buffer>,
+            value: Buffer,
+        ) -> Result<bool> {
+            let mut data = self.data.lock().unwrap();
+            if data.get(path).map(|v| v.to_bytes()) != current.map(|v| v.to_bytes()) {
+                return Ok(false);
+            }
+            data.insert(path.to_string(), value);
+            Ok(true)
+        }
+
+        async fn scan(&self, path: &str, _: ScanOptions) -> Result<Self::Scanner> {
+            let keys: Vec<_> = self
+                .data
+                .lock()
+                .unwrap()
+                .keys()
+                .filter(|k| k.starts_with(path))
+                .cloned()
+                .collect();
+            Ok(KeysScan(keys.into_iter()))
+        }
+    }
+
+    impl Backend<MapAdapter> {
+        fn keys(&self) -> Vec<String> {
+            self.kv.data.lock().unwrap().keys().cloned().collect()
+        }
+    }
+
+    async fn write(backend: &Backend<MapAdapter>, path: &str, parts: &[&str]) -> Result<()> {
+        let (_, mut w) = backend.write(path, OpWrite::new()).await?;
+        for part in parts {
+            w.write(Buffer::from(part.to_string())).await?;
+        }
+        w.close().await?;
+        Ok(())
+    }
+
+    async fn read(backend: &Backend<MapAdapter>, path: &str, range: BytesRange) -> Result<String> {
+        let (_, mut r) = backend.read(path, OpRead::new().with_range(range)).await?;
+        Ok(String::from_utf8(r.read_all().await?.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_chunked_value() -> Result<()> {
+        let backend = Backend::new(MapAdapter::default()).with_chunk_size(4);
+
+        write(&backend, "small", &["abc"]).await?;
+        write(&backend, "large", &["hello", ", ", "world"]).await?;
+        // "small" is stored as is, "large" is stored as manifest with 3 chunks.
+        assert_eq!(backend.keys().len(), 5);
+
+        let meta = backend.stat("large", OpStat::new()).await?.into_metadata();
+        assert_eq!(meta.content_length(), 12);
+        assert_eq!(read(&backend, "small", BytesRange::default()).await?, "abc");
+        assert_eq!(
+            read(&backend, "large", BytesRange::default()).await?,
+            "hello, world"
+        );
+        assert_eq!(
+            read(&backend, "large", BytesRange::new(3, Some(6))).await?,
+            "lo, wo"
+        );
+
+        let (_, mut lister) = backend.list("", OpList::new().with_recursive(true)).await?;
+        let mut paths = vec![];
+        while let Some(entry) = lister.next().await? {
+            paths.push(entry.path().to_string());
+        }
+        paths.sort();
+        assert_eq!(paths, vec!["large", "small"]);
+
+        // Overwrite and delete should clean up chunks.
+        write(&backend, "large", &["hello"]).await?;
+        assert_eq!(backend.keys().len(), 4);
+        let (_, mut deleter) = backend.delete().await?;
+        deleter.delete("large", OpDelete::new())?;
+        deleter.flush().await?;
+        assert_eq!(backend.keys(), vec!["small"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_value_metadata() -> Result<()> {
+        let backend = Backend::new(MapAdapter::default())
+            .with_chunk_size(4)
+            .with_metadata();
+
+        let args = OpWrite::new()
+            .with_content_type("text/plain")
+            .with_user_metadata(HashMap::from([("k".to_string(), "v".to_string())]));
+        let (_, mut w) = backend.write("file", args).await?;
+        w.write(Buffer::from("hello, world")).await?;
+        let written = w.close().await?;
+
+        let meta = backend.stat("file", OpStat::new()).await?.into_metadata();
+        assert_eq!(meta.content_length(), 12);
+        assert_eq!(meta.content_type(), Some("text/plain"));
+        assert_eq!(meta.user_metadata().unwrap()["k"], "v");
+        assert!(meta.last_modified().is_some());
+        let etag = meta.etag().unwrap().to_string();
+        assert_eq!(written.etag(), Some(etag.as_str()));
+        assert_eq!(
+            read(&backend, "file", BytesRange::new(3, Some(6))).await?,
+            "lo, wo"
+        );
+
+        let res = backend
+            .read("file", OpRead::new().with_if_none_match(&etag))
+            .await;
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::ConditionNotMatch);
+        let res = backend
+            .stat("file", OpStat::new().with_if_match("\"other\""))
+            .await;
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::ConditionNotMatch);
+
+        // Conditional writes that fail must not leave chunks behind.
+        let keys = backend.keys();
+        for args in [
+            OpWrite::new().with_if_match("\"other\""),
+            OpWrite::new().with_if_not_exists(true),
+        ] {
+            let (_, mut w) = backend.write("file", args).await?;
+            w.write(Buffer::from("hello, world")).await?;
+            let res = w.close().await;
+            assert_eq!(res.unwrap_err().kind(), ErrorKind::ConditionNotMatch);
+        }
+        assert_eq!(backend.keys(), keys);
+
+        let (_, mut w) = backend
+            .write("file", OpWrite::new().with_if_match(&etag))
+            .await?;
+        w.write(Buffer::from("abc")).await?;
+        w.close().await?;
+        let meta = backend.stat("file", OpStat::new()).await?.into_metadata();
+        assert_ne!(meta.etag(), Some(etag.as_str()));
+        assert_eq!(meta.content_type(), None);
+        assert_eq!(read(&backend, "file", BytesRange::default()).await?, "abc");
+        // Chunks of the replaced value have been cleaned up.
+        assert_eq!(backend.keys(), vec!["file"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_reserved_value_without_chunks() -> Result<()> {
+        let backend = Backend::new(MapAdapter::default());
+
+        // Values look like a manifest are plain values if chunking is disabled.
+        let value = "opendal:kv:chunks:v1\nid\n100\n4";
+        write(&backend, "file", &[value]).await?;
+        let meta = backend.stat("file", OpStat::new()).await?.into_metadata();
+        assert_eq!(meta.content_length(), value.len() as u64);
+        assert_eq!(read(&backend, "file", BytesRange::default()).await?, value);
         Ok(())
     }
 }
@@ -0,0 +1,168 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::ops::Range;
+
+use bytes::Bytes;
+
+use crate::raw::*;
+use crate::*;
+
+/// The leading bytes of a manifest, values starting with it are always stored in chunks.
+const MANIFEST_MAGIC: &[u8] = b"opendal:kv:chunks:v1\n";
+
+/// Chunks are stored under this dir of root, which is hidden from listing.
+pub(super) const CHUNK_DIR: &str = ".opendal_chunks/";
+
+/// ChunkManifest is stored at the key of a value that has been split into chunks.
+///
+/// Chunks of the value are stored at `{root}/.opendal_chunks/{id}/{index}`. Every
+/// write uses a new id, so that the chunks of the value being replaced are kept
+/// until the new manifest is written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct ChunkManifest {
+    id: String,
+    content_length: u64,
+    chunk_size: u64,
+}
+
+impl ChunkManifest {
+    pub fn new(chunk_size: usize) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            content_length: 0,
+            chunk_size: chunk_size as u64,
+        }
+    }
+
+    /// Check if the value needs to be stored in chunks even if it fits in one chunk.
+    pub fn is_reserved(value: &Buffer) -> bool {
+        value.len() >= MANIFEST_MAGIC.len()
+            && value.slice(0..MANIFEST_MAGIC.len()).to_bytes() == MANIFEST_MAGIC
+    }
+
+    /// Parse the manifest from a value, returns `None` if it's a plain value.
+    pub fn parse(value: &Buffer) -> Result<Option<Self>> {
+        if !Self::is_reserved(value) {
+            return Ok(None);
+        }
+
+        let invalid = || Error::new(ErrorKind::Unexpected, "kv chunk manifest is invalid");
+        let bs = value.to_bytes();
+        let s = std::str::from_utf8(&bs[MANIFEST_MAGIC.len()..])
+            .map_err(|err| invalid().set_source(err))?;
+        let mut lines = s.lines();
+        let id = lines.next().ok_or_else(invalid)?.to_string();
+        let content_length = lines
+            .next()
+            .and_then(|v| v.parse().ok())
+            .ok_or_else(invalid)?;
+        let chunk_size: u64 = lines
+            .next()
+            .and_then(|v| v.parse().ok())
+            .filter(|v| *v > 0)
+            .ok_or_else(invalid)?;
+
+        Ok(Some(Self {
+            id,
+            content_length,
+            chunk_size,
+        }))
+    }
+
+    pub fn to_buffer(&self) -> Buffer {
+        let mut bs = MANIFEST_MAGIC.to_vec();
+        bs.extend_from_slice(
+            format!("{}\n{}\n{}", self.id, self.content_length, self.chunk_size).as_bytes(),
+        );
+        Buffer::from(Bytes::from(bs))
+    }
+
+    pub fn content_length(&self) -> u64 {
+        self.content_length
+    }
+
+    pub fn set_content_length(&mut self, v: u64) {
+        self.content_length = v;
+    }
+
+    /// The count of chunks written for `content_length` bytes.
+    pub fn chunk_count(&self) -> u64 {
+        self.content_length.div_ceil(self.chunk_size)
+    }
+
+    /// The key of the chunk at `index`.
+    pub fn chunk_key(&self, root: &str, index: u64) -> String {
+        build_abs_path(root, &format!("{CHUNK_DIR}{}/{index}", self.id))
+    }
+
+    /// The indexes of chunks that cover `range`, and the range to slice from
+    /// these chunks once concatenated.
+    pub fn chunks_for(&self, range: BytesRange) -> (Range<u64>, Range<usize>) {
+        let start = range.offset().min(self.content_length);
+        let end = match range.size() {
+            Some(size) => (start + size).min(self.content_length),
+            None => self.content_length,
+        };
+        if start == end {
+            return (0..0, 0..0);
+        }
+
+        let first = start / self.chunk_size;
+        let last = (end - 1) / self.chunk_size;
+        let base = first * self.chunk_size;
+        (
+            first..last + 1,
+            (start - base) as usize..(end - base) as usize,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manifest_round_trip() {
+        let mut manifest = ChunkManifest::new(4);
+        manifest.set_content_length(10);
+        assert_eq!(manifest.chunk_count(), 3);
+
+        let value = manifest.to_buffer();
+        assert!(ChunkManifest::is_reserved(&value));
+        assert_eq!(ChunkManifest::parse(&value).unwrap(), Some(manifest));
+        assert_eq!(ChunkManifest::parse(&Buffer::from("hello")).unwrap(), None);
+    }
+
+    #[test]
+    fn test_chunks_for() {
+        let mut manifest = ChunkManifest::new(4);
+        manifest.set_content_length(10);
+
+        let cases = vec![
+            (BytesRange::default(), (0..3, 0..10)),
+            (BytesRange::new(0, Some(4)), (0..1, 0..4)),
+            (BytesRange::new(3, Some(2)), (0..2, 3..5)),
+            (BytesRange::new(5, None), (1..3, 1..6)),
+            (BytesRange::new(8, Some(100)), (2..3, 0..2)),
+            (BytesRange::new(10, None), (0..0, 0..0)),
+        ];
+        for (range, expected) in cases {
+            assert_eq!(manifest.chunks_for(range), expected, "range: {range}");
+        }
+    }
+}
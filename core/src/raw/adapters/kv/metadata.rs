@@ -0,0 +1,297 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+
+use bytes::Bytes;
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::raw::*;
+use crate::*;
+
+/// The leading bytes of a value stored with metadata.
+const METADATA_MAGIC: &[u8] = b"opendal:kv:meta:v1\n";
+
+/// ValueMetadata is stored in front of the value when metadata is enabled.
+///
+/// The stored value is the magic, the metadata in json, a `\n` and then the
+/// value itself, which might be a chunk manifest. Values written without
+/// metadata are still readable, they just don't have any metadata.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct ValueMetadata {
+    etag: String,
+    /// The last modified time in rfc3339.
+    last_modified: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_disposition: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_encoding: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cache_control: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_metadata: Option<HashMap<String, String>>,
+    /// The expiration time in rfc3339 if written with ttl.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expires_at: Option<String>,
+}
+
+impl ValueMetadata {
+    /// Create metadata for a new value written with `args`.
+    ///
+    /// Every write gets a new etag, so that etags never come back even if the
+    /// same content is written again.
+    pub fn new(args: &OpWrite) -> Self {
+        Self {
+            etag: format!("\"{}\"", uuid::Uuid::new_v4()),
+            last_modified: Utc::now().to_rfc3339(),
+            content_type: args.content_type().map(String::from),
+            content_disposition: args.content_disposition().map(String::from),
+            content_encoding: args.content_encoding().map(String::from),
+            cache_control: args.cache_control().map(String::from),
+            user_metadata: args.user_metadata().cloned(),
+            expires_at: args.ttl().map(|v| expire_time_from_ttl(v).to_rfc3339()),
+        }
+    }
+
+    pub fn etag(&self) -> &str {
+        &self.etag
+    }
+
+    fn last_modified(&self) -> Option<DateTime<Utc>> {
+        parse_datetime_from_rfc3339(&self.last_modified).ok()
+    }
+
+    /// Split a stored value into its metadata and the value itself.
+    pub fn split(value: Buffer) -> Result<(Option<Self>, Buffer)> {
+        if value.len() < METADATA_MAGIC.len()
+            || value.slice(0..METADATA_MAGIC.len()).to_bytes() != METADATA_MAGIC
+        {
+            return Ok((None, value));
+        }
+
+        let bs = value.to_bytes();
+        let rest = &bs[METADATA_MAGIC.len()..];
+        let end = rest
+            .iter()
+            .position(|b| *b == b'\n')
+            .ok_or_else(|| Error::new(ErrorKind::Unexpected, "kv value metadata is invalid"))?;
+        let meta: Self = serde_json::from_slice(&rest[..end]).map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "kv value metadata is invalid").set_source(err)
+        })?;
+
+        let start = METADATA_MAGIC.len() + end + 1;
+        Ok((Some(meta), value.slice(start..value.len())))
+    }
+
+    /// Store `value` with this metadata.
+    pub fn join(&self, value: Buffer) -> Result<Buffer> {
+        let mut bs = METADATA_MAGIC.to_vec();
+        serde_json::to_writer(&mut bs, self).map_err(new_json_serialize_error)?;
+        bs.push(b'\n');
+
+        Ok(std::iter::once(Bytes::from(bs)).chain(value).collect())
+    }
+
+    /// Fill the metadata of a file with this metadata.
+    pub fn apply(&self, mut meta: Metadata) -> Metadata {
+        meta.set_etag(&self.etag);
+        if let Some(v) = self.last_modified() {
+            meta.set_last_modified(v);
+        }
+        if let Some(v) = &self.content_type {
+            meta.set_content_type(v);
+        }
+        if let Some(v) = &self.content_disposition {
+            meta.set_content_disposition(v);
+        }
+        if let Some(v) = &self.content_encoding {
+            meta.set_content_encoding(v);
+        }
+        if let Some(v) = &self.cache_control {
+            meta.set_cache_control(v);
+        }
+        if let Some(v) = &self.user_metadata {
+            meta = meta.with_user_metadata(v.clone());
+        }
+        if let Some(v) = self
+            .expires_at
+            .as_deref()
+            .and_then(|v| parse_datetime_from_rfc3339(v).ok())
+        {
+            meta.set_expires_at(v);
+        }
+        meta
+    }
+}
+
+/// Check the conditions of read and stat against the metadata of an existing value.
+///
+/// Values without metadata have no etag and are considered modified at any
+/// time, so that callers never take them as unchanged.
+pub(crate) fn check_conditions(
+    meta: Option<&ValueMetadata>,
+    if_match: Option<&str>,
+    if_none_match: Option<&str>,
+    if_modified_since: Option<DateTime<Utc>>,
+    if_unmodified_since: Option<DateTime<Utc>>,
+) -> Result<()> {
+    let etag = meta.map(|v| v.etag());
+    let last_modified = meta.and_then(|v| v.last_modified());
+
+    if let Some(if_match) = if_match {
+        if !etag_matches(if_match, etag) {
+            return Err(condition_not_match("etag doesn't match"));
+        }
+    }
+    if let Some(if_none_match) = if_none_match {
+        if etag_matches(if_none_match, etag) {
+            return Err(condition_not_match("etag matches"));
+        }
+    }
+    if let Some(since) = if_modified_since {
+        if last_modified.is_some_and(|v| v <= since) {
+            return Err(condition_not_match(
+                "value is not modified since given time",
+            ));
+        }
+    }
+    if let Some(since) = if_unmodified_since {
+        if !last_modified.is_some_and(|v| v <= since) {
+            return Err(condition_not_match("value is modified since given time"));
+        }
+    }
+    Ok(())
+}
+
+/// Check the conditions of write against the value being replaced.
+pub(crate) fn check_write_conditions(
+    current: Option<Option<&ValueMetadata>>,
+    args: &OpWrite,
+) -> Result<()> {
+    let etag = current.map(|v| v.map(|v| v.etag()));
+
+    if args.if_not_exists() && current.is_some() {
+        return Err(condition_not_match("value already exists"));
+    }
+    if let Some(if_match) = args.if_match() {
+        if !etag.is_some_and(|v| etag_matches(if_match, v)) {
+            return Err(condition_not_match("etag doesn't match"));
+        }
+    }
+    if let Some(if_none_match) = args.if_none_match() {
+        if etag.is_some_and(|v| etag_matches(if_none_match, v)) {
+            return Err(condition_not_match("etag matches"));
+        }
+    }
+    Ok(())
+}
+
+/// Check if `condition` of `If-Match` or `If-None-Match` matches the etag of an
+/// existing value, `*` matches any value.
+fn etag_matches(condition: &str, etag: Option<&str>) -> bool {
+    condition == "*" || etag == Some(condition)
+}
+
+fn condition_not_match(msg: &str) -> Error {
+    Error::new(ErrorKind::ConditionNotMatch, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_metadata_round_trip() {
+        let args = OpWrite::new()
+            .with_content_type("text/plain")
+            .with_user_metadata(HashMap::from([("k".to_string(), "v".to_string())]))
+            .with_ttl(Duration::from_secs(60));
+        let meta = ValueMetadata::new(&args);
+
+        let value = meta.join(Buffer::from("hello\nworld")).unwrap();
+        let (parsed, content) = ValueMetadata::split(value).unwrap();
+        assert_eq!(parsed.as_ref(), Some(&meta));
+        assert_eq!(content.to_bytes(), "hello\nworld");
+
+        let expires_at = meta
+            .apply(Metadata::new(EntryMode::FILE))
+            .expires_at()
+            .unwrap();
+        assert!(expires_at > Utc::now());
+
+        let (parsed, content) = ValueMetadata::split(Buffer::from("hello")).unwrap();
+        assert_eq!(parsed, None);
+        assert_eq!(content.to_bytes(), "hello");
+    }
+
+    #[test]
+    fn test_check_conditions() {
+        let meta = ValueMetadata::new(&OpWrite::new());
+        let etag = meta.etag().to_string();
+        let last_modified = meta.last_modified().unwrap();
+        let check = |if_match, if_none_match, since, unmodified_since| {
+            check_conditions(
+                Some(&meta),
+                if_match,
+                if_none_match,
+                since,
+                unmodified_since,
+            )
+            .is_ok()
+        };
+
+        assert!(check(Some(&etag), None, None, None));
+        assert!(check(Some("*"), None, None, None));
+        assert!(!check(Some("\"other\""), None, None, None));
+        assert!(check(None, Some("\"other\""), None, None));
+        assert!(!check(None, Some(&etag), None, None));
+        assert!(!check(None, None, Some(last_modified), None));
+        assert!(check(None, None, None, Some(last_modified)));
+
+        // Values without metadata only match `*`.
+        assert!(check_conditions(None, Some("*"), None, None, None).is_ok());
+        assert!(check_conditions(None, Some(&etag), None, None, None).is_err());
+        assert!(check_conditions(None, None, Some(&etag), None, None).is_ok());
+    }
+
+    #[test]
+    fn test_check_write_conditions() {
+        let meta = ValueMetadata::new(&OpWrite::new());
+        let etag = meta.etag();
+
+        let args = OpWrite::new().with_if_not_exists(true);
+        assert!(check_write_conditions(None, &args).is_ok());
+        assert!(check_write_conditions(Some(None), &args).is_err());
+
+        let args = OpWrite::new().with_if_match(etag);
+        assert!(check_write_conditions(Some(Some(&meta)), &args).is_ok());
+        assert!(check_write_conditions(Some(None), &args).is_err());
+        assert!(check_write_conditions(None, &args).is_err());
+
+        let args = OpWrite::new().with_if_none_match("*");
+        assert!(check_write_conditions(None, &args).is_ok());
+        assert!(check_write_conditions(Some(Some(&meta)), &args).is_err());
+    }
+}
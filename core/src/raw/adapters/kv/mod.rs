@@ -22,14 +22,23 @@
 mod api;
 pub use api::Adapter;
 pub use api::Info;
+pub use api::PagedScanner;
 pub use api::Scan;
-#[cfg(any(
-    feature = "services-cloudflare-kv",
-    feature = "services-rocksdb",
-    feature = "services-sled"
-))]
+pub use api::ScanOptions;
+pub use api::ScanPage;
+#[cfg(feature = "services-cloudflare-kv")]
 pub(crate) use api::ScanStdIter;
 pub use api::Scanner;
 
 mod backend;
 pub use backend::Backend;
+
+mod chunk;
+
+mod metadata;
+#[cfg(feature = "services-redis")]
+pub(crate) use metadata::check_conditions;
+#[cfg(feature = "services-redis")]
+pub(crate) use metadata::check_write_conditions;
+#[cfg(feature = "services-redis")]
+pub(crate) use metadata::ValueMetadata;
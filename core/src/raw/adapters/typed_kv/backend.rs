@@ -68,6 +68,7 @@ where
                 if kv_cap.scan {
                     cap.list = true;
                     cap.list_with_recursive = true;
+                    cap.list_with_continuation_token = true;
                 }
 
                 if kv_cap.shared {
@@ -142,26 +143,57 @@ impl<S: Adapter> Access for Backend<S> {
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
         let p = build_abs_path(&self.root, path);
         let res = self.kv.scan(&p).await?;
-        let lister = KvLister::new(&self.root, res);
+        // The continuation token is the last key listed, resume after it.
+        let start_after = args
+            .continuation_token()
+            .map(|v| build_abs_path(&self.root, v));
+        let lister = KvLister::new(&self.root, res, start_after).with_limit(args.limit());
         let lister = HierarchyLister::new(lister, path, args.recursive());
 
         Ok((RpList::default(), lister))
     }
 }
 
+/// The default page size used by [`KvLister::next_page`] if no limit is specified.
+const DEFAULT_KV_PAGE_SIZE: usize = 1000;
+
+/// KvLister lists keys returned by `scan`.
+///
+/// Keys are listed in ascending order, and the continuation token is the last
+/// key that has been returned, so that keys inserted or deleted between pages
+/// won't be skipped or duplicated.
 pub struct KvLister {
     root: String,
     inner: IntoIter<String>,
+
+    limit: usize,
+    /// The last key that has been returned.
+    last_key: Option<String>,
 }
 
 impl KvLister {
-    fn new(root: &str, inner: Vec<String>) -> Self {
+    fn new(root: &str, mut inner: Vec<String>, start_after: Option<String>) -> Self {
+        if let Some(start_after) = &start_after {
+            inner.retain(|v| v > start_after);
+        }
+        inner.sort_unstable();
+
         Self {
             root: root.to_string(),
             inner: inner.into_iter(),
+
+            limit: DEFAULT_KV_PAGE_SIZE,
+            last_key: None,
         }
     }
 
+    fn with_limit(mut self, limit: Option<usize>) -> Self {
+        if let Some(limit) = limit {
+            self.limit = limit.max(1);
+        }
+        self
+    }
+
     fn inner_next(&mut self) -> Option<oio::Entry> {
         self.inner.next().map(|v| {
             let mode = if v.ends_with('/') {
@@ -173,6 +205,7 @@ impl KvLister {
             if path.is_empty() {
                 path = "/".to_string();
             }
+            self.last_key = Some(v);
             oio::Entry::new(&path, Metadata::new(mode))
         })
     }
@@ -182,6 +215,25 @@ impl oio::List for KvLister {
     async fn next(&mut self) -> Result<Option<oio::Entry>> {
         Ok(self.inner_next())
     }
+
+    async fn next_page(&mut self) -> Result<(Vec<oio::Entry>, Option<String>)> {
+        let mut entries = Vec::with_capacity(self.limit);
+        while entries.len() < self.limit {
+            match self.inner_next() {
+                Some(entry) => entries.push(entry),
+                None => return Ok((entries, None)),
+            }
+        }
+        // All keys have been returned, there is no next page.
+        if self.inner.as_slice().is_empty() {
+            return Ok((entries, None));
+        }
+        let token = self
+            .last_key
+            .as_ref()
+            .map(|key| build_rel_path(&self.root, key));
+        Ok((entries, token))
+    }
 }
 
 pub struct KvWriter<S> {
@@ -87,6 +87,16 @@ pub fn parse_datetime_from_from_timestamp(s: i64) -> Result<DateTime<Utc>> {
     Ok(st.into())
 }
 
+/// Get the expiration time of an object written with `ttl` from now.
+///
+/// Returns the max datetime if it overflows.
+pub fn expire_time_from_ttl(ttl: Duration) -> DateTime<Utc> {
+    chrono::TimeDelta::from_std(ttl)
+        .ok()
+        .and_then(|v| Utc::now().checked_add_signed(v))
+        .unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
 /// format datetime into http date, this format is required by:
 /// https://httpwg.org/specs/rfc9110.html#field.if-modified-since
 pub fn format_datetime_into_http_date(s: DateTime<Utc>) -> String {
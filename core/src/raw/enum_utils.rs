@@ -68,6 +68,20 @@ impl<ONE: oio::Write, TWO: oio::Write> oio::Write for TwoWays<ONE, TWO> {
         }
     }
 
+    async fn write_at(&mut self, offset: u64, bs: Buffer) -> Result<()> {
+        match self {
+            Self::One(v) => v.write_at(offset, bs).await,
+            Self::Two(v) => v.write_at(offset, bs).await,
+        }
+    }
+
+    async fn set_len(&mut self, len: u64) -> Result<()> {
+        match self {
+            Self::One(v) => v.set_len(len).await,
+            Self::Two(v) => v.set_len(len).await,
+        }
+    }
+
     async fn close(&mut self) -> Result<Metadata> {
         match self {
             Self::One(v) => v.close().await,
@@ -90,6 +104,13 @@ impl<ONE: oio::List, TWO: oio::List> oio::List for TwoWays<ONE, TWO> {
             Self::Two(v) => v.next().await,
         }
     }
+
+    async fn next_page(&mut self) -> Result<(Vec<oio::Entry>, Option<String>)> {
+        match self {
+            Self::One(v) => v.next_page().await,
+            Self::Two(v) => v.next_page().await,
+        }
+    }
 }
 
 /// ThreeWays is used to implement traits that based on three ways.
@@ -150,6 +171,14 @@ impl<ONE: oio::List, TWO: oio::List, THREE: oio::List> oio::List for ThreeWays<O
             Self::Three(v) => v.next().await,
         }
     }
+
+    async fn next_page(&mut self) -> Result<(Vec<oio::Entry>, Option<String>)> {
+        match self {
+            Self::One(v) => v.next_page().await,
+            Self::Two(v) => v.next_page().await,
+            Self::Three(v) => v.next_page().await,
+        }
+    }
 }
 
 /// FourWays is used to implement traits that based on four ways.
@@ -198,4 +227,13 @@ where
             Self::Four(v) => v.next().await,
         }
     }
+
+    async fn next_page(&mut self) -> Result<(Vec<oio::Entry>, Option<String>)> {
+        match self {
+            Self::One(v) => v.next_page().await,
+            Self::Two(v) => v.next_page().await,
+            Self::Three(v) => v.next_page().await,
+            Self::Four(v) => v.next_page().await,
+        }
+    }
 }
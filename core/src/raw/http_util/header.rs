@@ -16,6 +16,7 @@
 // under the License.
 
 use std::collections::HashMap;
+use std::fmt::Write;
 
 use base64::engine::general_purpose;
 use base64::Engine;
@@ -223,6 +224,15 @@ pub fn format_content_md5(bs: &[u8]) -> String {
     general_purpose::STANDARD.encode(hasher.finalize())
 }
 
+/// format bytes into lowercase hex string, used by signatures.
+pub fn format_hex(bs: &[u8]) -> String {
+    bs.iter()
+        .fold(String::with_capacity(bs.len() * 2), |mut s, b| {
+            let _ = write!(s, "{b:02x}");
+            s
+        })
+}
+
 /// format authorization header by basic auth.
 ///
 /// # Errors
@@ -295,6 +305,12 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_format_hex() {
+        assert_eq!(format_hex(b""), "");
+        assert_eq!(format_hex(&[0x00, 0x0f, 0xa5, 0xff]), "000fa5ff");
+    }
+
     /// Test cases is borrowed from
     ///
     /// - RFC2617: https://datatracker.ietf.org/doc/html/rfc2617#section-2
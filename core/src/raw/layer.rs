@@ -177,6 +177,113 @@ pub trait LayeredAccess: Send + Sync + Debug + Unpin + 'static {
     ) -> impl Future<Output = Result<RpPresign>> + MaybeSend {
         self.inner().presign(path, args)
     }
+
+    fn list_uploads(
+        &self,
+        path: &str,
+        args: OpListUploads,
+    ) -> impl Future<Output = Result<RpListUploads>> + MaybeSend {
+        self.inner().list_uploads(path, args)
+    }
+
+    fn abort_upload(
+        &self,
+        path: &str,
+        args: OpAbortUpload,
+    ) -> impl Future<Output = Result<RpAbortUpload>> + MaybeSend {
+        self.inner().abort_upload(path, args)
+    }
+
+    fn initiate_upload(
+        &self,
+        path: &str,
+        args: OpInitiateUpload,
+    ) -> impl Future<Output = Result<RpInitiateUpload>> + MaybeSend {
+        self.inner().initiate_upload(path, args)
+    }
+
+    fn complete_upload(
+        &self,
+        path: &str,
+        args: OpCompleteUpload,
+    ) -> impl Future<Output = Result<RpCompleteUpload>> + MaybeSend {
+        self.inner().complete_upload(path, args)
+    }
+
+    fn presign_post(
+        &self,
+        path: &str,
+        args: OpPresignPost,
+    ) -> impl Future<Output = Result<RpPresignPost>> + MaybeSend {
+        self.inner().presign_post(path, args)
+    }
+
+    fn create_symlink(
+        &self,
+        path: &str,
+        args: OpCreateSymlink,
+    ) -> impl Future<Output = Result<RpCreateSymlink>> + MaybeSend {
+        self.inner().create_symlink(path, args)
+    }
+
+    fn read_link(
+        &self,
+        path: &str,
+        args: OpReadLink,
+    ) -> impl Future<Output = Result<RpReadLink>> + MaybeSend {
+        self.inner().read_link(path, args)
+    }
+
+    fn set_permissions(
+        &self,
+        path: &str,
+        args: OpSetPermissions,
+    ) -> impl Future<Output = Result<RpSetPermissions>> + MaybeSend {
+        self.inner().set_permissions(path, args)
+    }
+
+    fn set_times(
+        &self,
+        path: &str,
+        args: OpSetTimes,
+    ) -> impl Future<Output = Result<RpSetTimes>> + MaybeSend {
+        self.inner().set_times(path, args)
+    }
+
+    fn update_metadata(
+        &self,
+        path: &str,
+        args: OpUpdateMetadata,
+    ) -> impl Future<Output = Result<RpUpdateMetadata>> + MaybeSend {
+        self.inner().update_metadata(path, args)
+    }
+
+    fn concat(
+        &self,
+        path: &str,
+        args: OpConcat,
+    ) -> impl Future<Output = Result<RpConcat>> + MaybeSend {
+        self.inner().concat(path, args)
+    }
+
+    fn lock(&self, path: &str, args: OpLock) -> impl Future<Output = Result<RpLock>> + MaybeSend {
+        self.inner().lock(path, args)
+    }
+
+    fn unlock(
+        &self,
+        path: &str,
+        args: OpUnlock,
+    ) -> impl Future<Output = Result<RpUnlock>> + MaybeSend {
+        self.inner().unlock(path, args)
+    }
+
+    fn transaction(
+        &self,
+        args: OpTransaction,
+    ) -> impl Future<Output = Result<RpTransaction>> + MaybeSend {
+        self.inner().transaction(args)
+    }
 }
 
 impl<L: LayeredAccess> Access for L {
@@ -224,6 +331,78 @@ impl<L: LayeredAccess> Access for L {
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         LayeredAccess::presign(self, path, args).await
     }
+
+    async fn list_uploads(&self, path: &str, args: OpListUploads) -> Result<RpListUploads> {
+        LayeredAccess::list_uploads(self, path, args).await
+    }
+
+    async fn abort_upload(&self, path: &str, args: OpAbortUpload) -> Result<RpAbortUpload> {
+        LayeredAccess::abort_upload(self, path, args).await
+    }
+
+    async fn initiate_upload(
+        &self,
+        path: &str,
+        args: OpInitiateUpload,
+    ) -> Result<RpInitiateUpload> {
+        LayeredAccess::initiate_upload(self, path, args).await
+    }
+
+    async fn complete_upload(
+        &self,
+        path: &str,
+        args: OpCompleteUpload,
+    ) -> Result<RpCompleteUpload> {
+        LayeredAccess::complete_upload(self, path, args).await
+    }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        LayeredAccess::presign_post(self, path, args).await
+    }
+
+    async fn create_symlink(&self, path: &str, args: OpCreateSymlink) -> Result<RpCreateSymlink> {
+        LayeredAccess::create_symlink(self, path, args).await
+    }
+
+    async fn read_link(&self, path: &str, args: OpReadLink) -> Result<RpReadLink> {
+        LayeredAccess::read_link(self, path, args).await
+    }
+
+    async fn set_permissions(
+        &self,
+        path: &str,
+        args: OpSetPermissions,
+    ) -> Result<RpSetPermissions> {
+        LayeredAccess::set_permissions(self, path, args).await
+    }
+
+    async fn set_times(&self, path: &str, args: OpSetTimes) -> Result<RpSetTimes> {
+        LayeredAccess::set_times(self, path, args).await
+    }
+
+    async fn update_metadata(
+        &self,
+        path: &str,
+        args: OpUpdateMetadata,
+    ) -> Result<RpUpdateMetadata> {
+        LayeredAccess::update_metadata(self, path, args).await
+    }
+
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        LayeredAccess::concat(self, path, args).await
+    }
+
+    async fn lock(&self, path: &str, args: OpLock) -> Result<RpLock> {
+        LayeredAccess::lock(self, path, args).await
+    }
+
+    async fn unlock(&self, path: &str, args: OpUnlock) -> Result<RpUnlock> {
+        LayeredAccess::unlock(self, path, args).await
+    }
+
+    async fn transaction(&self, args: OpTransaction) -> Result<RpTransaction> {
+        LayeredAccess::transaction(self, args).await
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,175 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use bytes::Bytes;
+use chrono::Utc;
+
+use crate::raw::*;
+use crate::*;
+
+/// LockRecord is the state of a lock stored as the content of the lock file.
+///
+/// Services without native lock primitives store this record and update it with
+/// compare-and-swap semantics, for example conditional writes. A released lock keeps
+/// its record with zero expiry, so that tokens keep growing across holders.
+///
+/// The record is encoded as `{token}\n{expires_at}\n{owner}`, in which `expires_at`
+/// is the unix timestamp in milliseconds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockRecord {
+    token: u64,
+    expires_at: i64,
+    owner: String,
+}
+
+impl LockRecord {
+    /// Parse the record from the content of the lock file.
+    pub fn parse(bs: &[u8]) -> Result<Self> {
+        let invalid = || Error::new(ErrorKind::Unexpected, "lock record is invalid");
+
+        let s = std::str::from_utf8(bs).map_err(|err| invalid().set_source(err))?;
+        let mut parts = s.splitn(3, '\n');
+        let token = parts
+            .next()
+            .and_then(|v| v.parse().ok())
+            .ok_or_else(invalid)?;
+        let expires_at = parts
+            .next()
+            .and_then(|v| v.parse().ok())
+            .ok_or_else(invalid)?;
+        let owner = parts.next().ok_or_else(invalid)?.to_string();
+
+        Ok(Self {
+            token,
+            expires_at,
+            owner,
+        })
+    }
+
+    /// Encode the record as the content of the lock file.
+    pub fn to_bytes(&self) -> Bytes {
+        Bytes::from(format!(
+            "{}\n{}\n{}",
+            self.token, self.expires_at, self.owner
+        ))
+    }
+
+    /// Get the fencing token of this record.
+    pub fn token(&self) -> u64 {
+        self.token
+    }
+
+    /// Get the owner of this record.
+    pub fn owner(&self) -> &str {
+        &self.owner
+    }
+
+    /// Check if the lease of this record has expired or been released.
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Utc::now().timestamp_millis()
+    }
+
+    /// Build the record to store for `args` based on the `current` record.
+    ///
+    /// Returns an error with [`ErrorKind::ConditionNotMatch`] if the lock is held by
+    /// another owner, or the lease to renew has been lost.
+    pub fn lock(current: Option<&LockRecord>, args: &OpLock) -> Result<Self> {
+        let expires_at = Utc::now().timestamp_millis() + args.ttl().as_millis() as i64;
+
+        let token = match (current, args.token()) {
+            (Some(cur), Some(token)) if cur.token == token && cur.owner == args.owner() => token,
+            (_, Some(_)) => {
+                return Err(Error::new(
+                    ErrorKind::ConditionNotMatch,
+                    "lease of the lock has been lost",
+                ))
+            }
+            // Acquire the lock again for the same owner is allowed, so that retried
+            // requests won't fail.
+            (Some(cur), None) if !cur.is_expired() && cur.owner == args.owner() => cur.token,
+            (Some(cur), None) if !cur.is_expired() => {
+                return Err(Error::new(
+                    ErrorKind::ConditionNotMatch,
+                    "lock is held by another owner",
+                ))
+            }
+            (Some(cur), None) => cur.token + 1,
+            (None, None) => 1,
+        };
+
+        Ok(Self {
+            token,
+            expires_at,
+            owner: args.owner().to_string(),
+        })
+    }
+
+    /// Build the released record to store for `args` based on the `current` record.
+    ///
+    /// Returns an error with [`ErrorKind::ConditionNotMatch`] if the lock is not held
+    /// with the token anymore.
+    pub fn unlock(current: Option<&LockRecord>, args: &OpUnlock) -> Result<Self> {
+        match current {
+            Some(cur) if cur.token == args.token() && cur.owner == args.owner() => Ok(Self {
+                token: cur.token,
+                expires_at: 0,
+                owner: cur.owner.clone(),
+            }),
+            _ => Err(Error::new(
+                ErrorKind::ConditionNotMatch,
+                "lock is not held with this token",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_lock_record_round_trip() {
+        let record = LockRecord::lock(None, &OpLock::new(Duration::from_secs(10), "a\nb")).unwrap();
+        assert_eq!(record.token(), 1);
+        assert!(!record.is_expired());
+        assert_eq!(LockRecord::parse(&record.to_bytes()).unwrap(), record);
+    }
+
+    #[test]
+    fn test_lock_record_transitions() {
+        let ttl = Duration::from_secs(10);
+        let held = LockRecord::lock(None, &OpLock::new(ttl, "a")).unwrap();
+
+        let err = LockRecord::lock(Some(&held), &OpLock::new(ttl, "b")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
+
+        let renewed = LockRecord::lock(Some(&held), &OpLock::new(ttl, "a").with_token(1)).unwrap();
+        assert_eq!(renewed.token(), 1);
+        let err = LockRecord::lock(Some(&held), &OpLock::new(ttl, "a").with_token(2)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
+
+        let err = LockRecord::unlock(Some(&held), &OpUnlock::new("b", 1)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
+        let released = LockRecord::unlock(Some(&held), &OpUnlock::new("a", 1)).unwrap();
+        assert!(released.is_expired());
+
+        let next = LockRecord::lock(Some(&released), &OpLock::new(ttl, "b")).unwrap();
+        assert_eq!(next.token(), 2);
+    }
+}
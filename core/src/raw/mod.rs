@@ -94,6 +94,9 @@ pub use enum_utils::*;
 mod atomic_util;
 pub use atomic_util::*;
 
+mod lock_util;
+pub use lock_util::*;
+
 // Expose as a pub mod to avoid confusing.
 pub mod adapters;
 pub mod oio;
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::future::ready;
 use std::future::Future;
 use std::ops::DerefMut;
 
@@ -32,12 +33,32 @@ pub trait List: Unpin + Send + Sync {
     /// `Ok(None)` means all pages have been returned. Any following call
     /// to `next` will always get the same result.
     fn next(&mut self) -> impl Future<Output = Result<Option<Entry>>> + MaybeSend;
+
+    /// Fetch the next page of [`Entry`] returned by underlying storage, along with the
+    /// continuation token of the page after it.
+    ///
+    /// The token can be passed to [`OpList::with_continuation_token`] to resume listing
+    /// from the following page. `None` token means all pages have been returned.
+    ///
+    /// The returned page could be empty even if there are more pages to come.
+    fn next_page(
+        &mut self,
+    ) -> impl Future<Output = Result<(Vec<Entry>, Option<String>)>> + MaybeSend {
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "list with continuation token is not supported",
+        )))
+    }
 }
 
 impl List for () {
     async fn next(&mut self) -> Result<Option<Entry>> {
         Ok(None)
     }
+
+    async fn next_page(&mut self) -> Result<(Vec<Entry>, Option<String>)> {
+        Ok((vec![], None))
+    }
 }
 
 impl<P: List> List for Option<P> {
@@ -47,20 +68,37 @@ impl<P: List> List for Option<P> {
             None => Ok(None),
         }
     }
+
+    async fn next_page(&mut self) -> Result<(Vec<Entry>, Option<String>)> {
+        match self {
+            Some(p) => p.next_page().await,
+            None => Ok((vec![], None)),
+        }
+    }
 }
 
 pub trait ListDyn: Unpin + Send + Sync {
     fn next_dyn(&mut self) -> BoxedFuture<'_, Result<Option<Entry>>>;
+
+    fn next_page_dyn(&mut self) -> BoxedFuture<'_, Result<(Vec<Entry>, Option<String>)>>;
 }
 
 impl<T: List + ?Sized> ListDyn for T {
     fn next_dyn(&mut self) -> BoxedFuture<'_, Result<Option<Entry>>> {
         Box::pin(self.next())
     }
+
+    fn next_page_dyn(&mut self) -> BoxedFuture<'_, Result<(Vec<Entry>, Option<String>)>> {
+        Box::pin(self.next_page())
+    }
 }
 
 impl<T: ListDyn + ?Sized> List for Box<T> {
     async fn next(&mut self) -> Result<Option<Entry>> {
         self.deref_mut().next_dyn().await
     }
+
+    async fn next_page(&mut self) -> Result<(Vec<Entry>, Option<String>)> {
+        self.deref_mut().next_page_dyn().await
+    }
 }
@@ -132,4 +132,11 @@ impl<P: oio::List> oio::List for HierarchyLister<P> {
             }
         }
     }
+    async fn next_page(&mut self) -> Result<(Vec<oio::Entry>, Option<String>)> {
+        let (mut entries, token) = self.lister.next_page().await?;
+        if !self.recursive {
+            entries.retain_mut(|e| self.keep_entry(e));
+        }
+        Ok((entries, token))
+    }
 }
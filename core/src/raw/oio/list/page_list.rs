@@ -84,6 +84,17 @@ where
             },
         }
     }
+
+    /// Resume listing from the given continuation token.
+    ///
+    /// The token must be the one returned by a previous [`oio::List::next_page`] call,
+    /// it will be used as the initial `token` of [`PageContext`].
+    pub fn with_continuation_token(mut self, token: Option<&str>) -> Self {
+        if let Some(token) = token {
+            self.ctx.token = token.to_string();
+        }
+        self
+    }
 }
 
 impl<L> oio::List for PageLister<L>
@@ -102,4 +113,20 @@ where
             self.inner.next_page(&mut self.ctx).await?;
         }
     }
+
+    async fn next_page(&mut self) -> Result<(Vec<oio::Entry>, Option<String>)> {
+        // Drain the entries left by `next` first, they belong to the page identified
+        // by current token.
+        if self.ctx.entries.is_empty() && !self.ctx.done {
+            self.inner.next_page(&mut self.ctx).await?;
+        }
+
+        let entries = self.ctx.entries.drain(..).collect();
+        let token = if self.ctx.done {
+            None
+        } else {
+            Some(self.ctx.token.clone())
+        };
+        Ok((entries, token))
+    }
 }
@@ -61,4 +61,10 @@ where
             }
         }
     }
+
+    async fn next_page(&mut self) -> Result<(Vec<oio::Entry>, Option<String>)> {
+        let (mut entries, token) = self.lister.next_page().await?;
+        entries.retain(|e| e.path().starts_with(&self.prefix));
+        Ok((entries, token))
+    }
 }
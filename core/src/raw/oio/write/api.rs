@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::future::ready;
 use std::future::Future;
 use std::ops::DerefMut;
 
@@ -34,6 +35,33 @@ pub trait Write: Unpin + Send + Sync {
     /// - `Err(err)` means error happens and no bytes has been written.
     fn write(&mut self, bs: Buffer) -> impl Future<Output = Result<()>> + MaybeSend;
 
+    /// Write given bytes into writer at the given offset.
+    ///
+    /// # Behavior
+    ///
+    /// - `Ok(())` means all bytes has been written successfully.
+    /// - `Err(err)` means error happens and no bytes has been written.
+    ///
+    /// `write_at` doesn't change the position of following `write` calls.
+    fn write_at(
+        &mut self,
+        _offset: u64,
+        _bs: Buffer,
+    ) -> impl Future<Output = Result<()>> + MaybeSend {
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "writer doesn't support write_at",
+        )))
+    }
+
+    /// Truncate or extend the written file to given length.
+    fn set_len(&mut self, _len: u64) -> impl Future<Output = Result<()>> + MaybeSend {
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "writer doesn't support set_len",
+        )))
+    }
+
     /// Close the writer and make sure all data has been flushed.
     fn close(&mut self) -> impl Future<Output = Result<Metadata>> + MaybeSend;
 
@@ -64,6 +92,10 @@ impl Write for () {
 pub trait WriteDyn: Unpin + Send + Sync {
     fn write_dyn(&mut self, bs: Buffer) -> BoxedFuture<'_, Result<()>>;
 
+    fn write_at_dyn(&mut self, offset: u64, bs: Buffer) -> BoxedFuture<'_, Result<()>>;
+
+    fn set_len_dyn(&mut self, len: u64) -> BoxedFuture<'_, Result<()>>;
+
     fn close_dyn(&mut self) -> BoxedFuture<'_, Result<Metadata>>;
 
     fn abort_dyn(&mut self) -> BoxedFuture<'_, Result<()>>;
@@ -74,6 +106,14 @@ impl<T: Write + ?Sized> WriteDyn for T {
         Box::pin(self.write(bs))
     }
 
+    fn write_at_dyn(&mut self, offset: u64, bs: Buffer) -> BoxedFuture<'_, Result<()>> {
+        Box::pin(self.write_at(offset, bs))
+    }
+
+    fn set_len_dyn(&mut self, len: u64) -> BoxedFuture<'_, Result<()>> {
+        Box::pin(self.set_len(len))
+    }
+
     fn close_dyn(&mut self) -> BoxedFuture<'_, Result<Metadata>> {
         Box::pin(self.close())
     }
@@ -88,6 +128,14 @@ impl<T: WriteDyn + ?Sized> Write for Box<T> {
         self.deref_mut().write_dyn(bs).await
     }
 
+    async fn write_at(&mut self, offset: u64, bs: Buffer) -> Result<()> {
+        self.deref_mut().write_at_dyn(offset, bs).await
+    }
+
+    async fn set_len(&mut self, len: u64) -> Result<()> {
+        self.deref_mut().set_len_dyn(len).await
+    }
+
     async fn close(&mut self) -> Result<Metadata> {
         self.deref_mut().close_dyn().await
     }
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use futures::select;
@@ -109,6 +110,12 @@ pub struct BlockWriter<W: BlockWrite> {
     started: bool,
     block_ids: Vec<Uuid>,
     cache: Option<Buffer>,
+
+    /// The block size used to map offsets of `write_at` to blocks.
+    block_size: Option<usize>,
+    /// The id and size of blocks written by `write_at`, indexed by block number.
+    blocks: BTreeMap<usize, (Uuid, usize)>,
+
     tasks: ConcurrentTasks<WriteInput<W>, Uuid>,
 }
 
@@ -123,6 +130,8 @@ impl<W: BlockWrite> BlockWriter<W> {
             started: false,
             block_ids: Vec::new(),
             cache: None,
+            block_size: None,
+            blocks: BTreeMap::new(),
 
             tasks: ConcurrentTasks::new(executor, concurrent, 8192, |input| {
                 Box::pin(async move {
@@ -159,12 +168,41 @@ impl<W: BlockWrite> BlockWriter<W> {
         }
     }
 
+    /// Set the block size to allow `write_at` on this writer.
+    ///
+    /// Every offset of `write_at` must be aligned to the block size so that
+    /// it maps to exactly one block.
+    pub fn with_block_size(mut self, block_size: Option<usize>) -> Self {
+        self.block_size = block_size;
+        self
+    }
+
     fn fill_cache(&mut self, bs: Buffer) -> usize {
         let size = bs.len();
         assert!(self.cache.is_none());
         self.cache = Some(bs);
         size
     }
+
+    async fn close_blocks(&mut self) -> Result<Metadata> {
+        while self.tasks.next().await.transpose()?.is_some() {}
+
+        let block_size = self.block_size.unwrap_or_default();
+        let last = self.blocks.len() - 1;
+        for (idx, (block_number, (_, size))) in self.blocks.iter().enumerate() {
+            if *block_number != idx || (idx != last && *size != block_size) {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "blocks written by write_at are not contiguous",
+                )
+                .with_context("block_number", idx)
+                .with_context("block_size", block_size));
+            }
+        }
+
+        let block_ids = self.blocks.values().map(|(id, _)| *id).collect();
+        self.w.complete_block(block_ids).await
+    }
 }
 
 impl<W> oio::Write for BlockWriter<W>
@@ -172,6 +210,13 @@ where
     W: BlockWrite,
 {
     async fn write(&mut self, bs: Buffer) -> Result<()> {
+        if !self.blocks.is_empty() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "block writer can't mix write with write_at",
+            ));
+        }
+
         if !self.started && self.cache.is_none() {
             self.fill_cache(bs);
             return Ok(());
@@ -194,7 +239,53 @@ where
         Ok(())
     }
 
+    async fn write_at(&mut self, offset: u64, bs: Buffer) -> Result<()> {
+        let Some(block_size) = self.block_size else {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "block writer requires chunk to be set for write_at",
+            ));
+        };
+        if self.cache.is_some() || (self.started && self.blocks.is_empty()) {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "block writer can't mix write with write_at",
+            ));
+        }
+        if offset % block_size as u64 != 0 || bs.len() > block_size {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "write_at must be aligned to the block size",
+            )
+            .with_context("offset", offset)
+            .with_context("size", bs.len())
+            .with_context("block_size", block_size));
+        }
+
+        self.started = true;
+
+        // Every write uses a new block id, the previous block of the same
+        // number will be discarded while committing.
+        let block_id = Uuid::new_v4();
+        let size = bs.len();
+        self.tasks
+            .execute(WriteInput {
+                w: self.w.clone(),
+                executor: self.executor.clone(),
+                block_id,
+                bytes: bs,
+            })
+            .await?;
+        self.blocks
+            .insert((offset / block_size as u64) as usize, (block_id, size));
+        Ok(())
+    }
+
     async fn close(&mut self) -> Result<Metadata> {
+        if !self.blocks.is_empty() {
+            return self.close_blocks().await;
+        }
+
         if !self.started {
             let (size, body) = match self.cache.clone() {
                 Some(cache) => (cache.len(), cache),
@@ -236,7 +327,10 @@ where
 
         self.tasks.clear();
         self.cache = None;
-        self.w.abort_block(self.block_ids.clone()).await?;
+        let mut block_ids = self.block_ids.clone();
+        block_ids.extend(self.blocks.values().map(|(id, _)| *id));
+        self.blocks.clear();
+        self.w.abort_block(block_ids).await?;
         Ok(())
     }
 }
@@ -24,6 +24,10 @@ pub use multipart_write::MultipartPart;
 pub use multipart_write::MultipartWrite;
 pub use multipart_write::MultipartWriter;
 
+mod part_copy;
+pub use part_copy::CopyPlan;
+pub use part_copy::PartCopy;
+
 mod append_write;
 pub use append_write::AppendWrite;
 pub use append_write::AppendWriter;
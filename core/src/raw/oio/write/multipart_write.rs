@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use futures::select;
@@ -47,6 +48,10 @@ use crate::*;
 ///
 /// We will use `write_once` instead of starting a new multipart upload.
 ///
+/// `MultipartWriter` emulates `write_at` if a block size has been set by
+/// [`MultipartWriter::with_block_size`]: every offset must be aligned to the
+/// block size so that it maps to exactly one part.
+///
 /// # Requirements
 ///
 /// Services that implement `BlockWrite` must fulfill the following requirements:
@@ -139,6 +144,11 @@ pub struct MultipartWriter<W: MultipartWrite> {
     cache: Option<Buffer>,
     next_part_number: usize,
 
+    /// The block size used to map offsets of `write_at` to parts.
+    block_size: Option<usize>,
+    /// The size of parts written by `write_at`, indexed by part number.
+    blocks: BTreeMap<usize, usize>,
+
     tasks: ConcurrentTasks<WriteInput<W>, MultipartPart>,
 }
 
@@ -157,6 +167,8 @@ impl<W: MultipartWrite> MultipartWriter<W> {
             parts: Vec::new(),
             cache: None,
             next_part_number: 0,
+            block_size: None,
+            blocks: BTreeMap::new(),
 
             tasks: ConcurrentTasks::new(executor, concurrent, 8192, |input| {
                 Box::pin({
@@ -194,12 +206,48 @@ impl<W: MultipartWrite> MultipartWriter<W> {
         }
     }
 
+    /// Set the block size to allow `write_at` on this writer.
+    pub fn with_block_size(mut self, block_size: Option<usize>) -> Self {
+        self.block_size = block_size;
+        self
+    }
+
     fn fill_cache(&mut self, bs: Buffer) -> usize {
         let size = bs.len();
         assert!(self.cache.is_none());
         self.cache = Some(bs);
         size
     }
+
+    async fn close_blocks(&mut self, upload_id: Arc<String>) -> Result<Metadata> {
+        while let Some(part) = self.tasks.next().await.transpose()? {
+            self.parts.push(part);
+        }
+
+        // The later written part wins if the same block has been written more than once.
+        let parts: BTreeMap<usize, MultipartPart> = self
+            .parts
+            .iter()
+            .map(|part| (part.part_number, part.clone()))
+            .collect();
+
+        let block_size = self.block_size.unwrap_or_default();
+        let last = self.blocks.len() - 1;
+        for (idx, (part_number, size)) in self.blocks.iter().enumerate() {
+            if *part_number != idx || (idx != last && *size != block_size) {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "blocks written by write_at are not contiguous",
+                )
+                .with_context("part_number", idx)
+                .with_context("block_size", block_size)
+                .with_context("upload_id", upload_id.as_str()));
+            }
+        }
+
+        let parts: Vec<_> = parts.into_values().collect();
+        self.w.complete_part(&upload_id, &parts).await
+    }
 }
 
 impl<W> oio::Write for MultipartWriter<W>
@@ -207,6 +255,13 @@ where
     W: MultipartWrite,
 {
     async fn write(&mut self, bs: Buffer) -> Result<()> {
+        if !self.blocks.is_empty() {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "multipart writer can't mix write with write_at",
+            ));
+        }
+
         let upload_id = match self.upload_id.clone() {
             Some(v) => v,
             None => {
@@ -241,7 +296,67 @@ where
         Ok(())
     }
 
+    async fn write_at(&mut self, offset: u64, bs: Buffer) -> Result<()> {
+        let Some(block_size) = self.block_size else {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "multipart writer requires chunk to be set for write_at",
+            ));
+        };
+        if self.cache.is_some() || self.next_part_number != 0 {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "multipart writer can't mix write with write_at",
+            ));
+        }
+        if offset % block_size as u64 != 0 || bs.len() > block_size {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "write_at must be aligned to the block size",
+            )
+            .with_context("offset", offset)
+            .with_context("size", bs.len())
+            .with_context("block_size", block_size));
+        }
+
+        let upload_id = match self.upload_id.clone() {
+            Some(v) => v,
+            None => {
+                let upload_id = Arc::new(self.w.initiate_part().await?);
+                self.upload_id = Some(upload_id.clone());
+                upload_id
+            }
+        };
+
+        let part_number = (offset / block_size as u64) as usize;
+        // Wait for the previous upload of the same part, otherwise we can't
+        // tell which one is kept by the service.
+        if self.blocks.contains_key(&part_number) {
+            while let Some(part) = self.tasks.next().await.transpose()? {
+                self.parts.push(part);
+            }
+        }
+
+        let size = bs.len();
+        self.tasks
+            .execute(WriteInput {
+                w: self.w.clone(),
+                executor: self.executor.clone(),
+                upload_id,
+                part_number,
+                bytes: bs,
+            })
+            .await?;
+        self.blocks.insert(part_number, size);
+        Ok(())
+    }
+
     async fn close(&mut self) -> Result<Metadata> {
+        if !self.blocks.is_empty() {
+            let upload_id = self.upload_id.clone().expect("upload id must exist");
+            return self.close_blocks(upload_id).await;
+        }
+
         let upload_id = match self.upload_id.clone() {
             Some(v) => v,
             None => {
@@ -300,6 +415,7 @@ where
 
         self.tasks.clear();
         self.cache = None;
+        self.blocks.clear();
         self.w.abort_part(&upload_id).await?;
         Ok(())
     }
@@ -515,4 +631,36 @@ mod tests {
             assert_eq!(inner.content.clone().unwrap().to_bytes(), bs);
         }
     }
+
+    #[tokio::test]
+    async fn test_multipart_writer_write_at() {
+        let mut w =
+            MultipartWriter::new(Arc::default(), TestWrite::new(), 8).with_block_size(Some(16));
+
+        let res = w.write_at(8, Buffer::from(vec![0; 16])).await;
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+
+        // Blocks could be written in any order, the last one could be shorter.
+        for (offset, size) in [(32, 5), (0, 16), (16, 16)] {
+            while w
+                .write_at(offset, Buffer::from(vec![1; size]))
+                .await
+                .is_err()
+            {}
+        }
+
+        let res = w.write(Buffer::from(vec![1; 16])).await;
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+
+        let meta = loop {
+            if let Ok(meta) = w.close().await {
+                break meta;
+            }
+        };
+        assert_eq!(meta.content_length(), 37);
+
+        let mut part_numbers = w.w.lock().await.part_numbers.clone();
+        part_numbers.sort_unstable();
+        assert_eq!(part_numbers, vec![0, 1, 2]);
+    }
 }
@@ -0,0 +1,190 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::raw::*;
+use crate::*;
+
+/// The max count of parts in a multipart upload.
+const MAX_PART_COUNT: u64 = 10000;
+
+/// PartCopy is a part copied from `source` by `UploadPartCopy` like APIs.
+#[derive(Debug, Clone)]
+pub struct PartCopy {
+    /// The path of the source.
+    pub source: String,
+    /// The range of the source to copy, copy the whole source if not set.
+    pub range: Option<BytesRange>,
+    /// Copy only if the etag of the source matches.
+    pub if_match: Option<String>,
+}
+
+/// CopyPlan decides how to copy a source for services that support both
+/// `CopyObject` and `UploadPartCopy`, like s3.
+///
+/// Services are expected to copy in one request first unless `chunk` is set,
+/// so that most copies don't stat the source. Sources over the limit of one
+/// copy are rejected with `400 Bad Request`, services stat them with
+/// [`CopyPlan::stat_args`] and plan the copy by [`CopyPlan::new`] then.
+#[derive(Debug)]
+pub enum CopyPlan {
+    /// The source fits in one copy request.
+    Single,
+    /// The source must be copied in `parts` of a multipart upload initiated
+    /// with `args`.
+    Multipart {
+        /// The args to initiate the multipart upload with.
+        args: OpWrite,
+        /// The parts to copy in order.
+        parts: Vec<PartCopy>,
+    },
+}
+
+impl CopyPlan {
+    /// The args to stat the source with, so that conditions of copy are
+    /// checked by stat.
+    pub fn stat_args(args: &OpCopy) -> OpStat {
+        let mut stat = OpStat::new();
+        if let Some(v) = args.if_match() {
+            stat = stat.with_if_match(v);
+        }
+        if let Some(v) = args.if_none_match() {
+            stat = stat.with_if_none_match(v);
+        }
+        stat
+    }
+
+    /// Plan the copy of `source` with its metadata returned by stat.
+    ///
+    /// - `max_single_size`: the max size of source copied in one request.
+    /// - `min_part_size`: the min size of every part except the last one.
+    pub fn new(
+        source: &str,
+        meta: &Metadata,
+        args: &OpCopy,
+        max_single_size: u64,
+        min_part_size: u64,
+    ) -> Self {
+        let size = meta.content_length();
+        let chunk = args
+            .chunk()
+            .map_or(max_single_size, |v| v as u64)
+            .min(max_single_size);
+        if size <= chunk {
+            return CopyPlan::Single;
+        }
+
+        let part_size = chunk.max(size.div_ceil(MAX_PART_COUNT)).max(min_part_size);
+        // Make sure every part is copied from the source we stat.
+        let if_match = meta.etag().map(|v| v.to_string());
+        let mut parts = Vec::with_capacity(size.div_ceil(part_size) as usize);
+        let mut offset = 0;
+        while offset < size {
+            parts.push(PartCopy {
+                source: source.to_string(),
+                range: Some(BytesRange::new(offset, Some(part_size.min(size - offset)))),
+                if_match: if_match.clone(),
+            });
+            offset += part_size;
+        }
+
+        // CopyObject keeps the metadata of source, so do we.
+        let mut op = OpWrite::new();
+        if let Some(v) = meta.content_type() {
+            op = op.with_content_type(v);
+        }
+        if let Some(v) = meta.content_disposition() {
+            op = op.with_content_disposition(v);
+        }
+        if let Some(v) = meta.cache_control() {
+            op = op.with_cache_control(v);
+        }
+        if let Some(v) = meta.content_encoding() {
+            op = op.with_content_encoding(v);
+        }
+        if let Some(v) = meta.user_metadata() {
+            op = op.with_user_metadata(v.clone());
+        }
+
+        CopyPlan::Multipart { args: op, parts }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MIB: u64 = 1024 * 1024;
+
+    #[test]
+    fn test_copy_plan_single() {
+        let meta = Metadata::new(EntryMode::FILE).with_content_length(5 * MIB);
+        let plan = CopyPlan::new("src", &meta, &OpCopy::new(), 5 * MIB, MIB);
+        assert!(matches!(plan, CopyPlan::Single));
+    }
+
+    #[test]
+    fn test_copy_plan_multipart() {
+        let meta = Metadata::new(EntryMode::FILE)
+            .with_content_length(5 * MIB + 1)
+            .with_etag("\"etag\"".to_string())
+            .with_content_type("text/plain".to_string());
+        let CopyPlan::Multipart { args, parts } = CopyPlan::new(
+            "src",
+            &meta,
+            &OpCopy::new().with_chunk(2 * MIB as usize),
+            5 * MIB,
+            MIB,
+        ) else {
+            panic!("source over chunk must be copied in parts");
+        };
+
+        assert_eq!(args.content_type(), Some("text/plain"));
+        let ranges: Vec<_> = parts.iter().map(|v| v.range.unwrap().to_header()).collect();
+        assert_eq!(
+            ranges,
+            [
+                format!("bytes=0-{}", 2 * MIB - 1),
+                format!("bytes={}-{}", 2 * MIB, 4 * MIB - 1),
+                format!("bytes={}-{}", 4 * MIB, 5 * MIB),
+            ]
+        );
+        assert!(parts
+            .iter()
+            .all(|v| v.source == "src" && v.if_match.as_deref() == Some("\"etag\"")));
+    }
+
+    #[test]
+    fn test_copy_plan_part_size() {
+        // Parts are never smaller than the min size.
+        let meta = Metadata::new(EntryMode::FILE).with_content_length(3 * MIB);
+        let CopyPlan::Multipart { parts, .. } =
+            CopyPlan::new("src", &meta, &OpCopy::new().with_chunk(1024), 5 * MIB, MIB)
+        else {
+            panic!("source over chunk must be copied in parts");
+        };
+        assert_eq!(parts.len(), 3);
+
+        // There are at most 10000 parts.
+        let meta = Metadata::new(EntryMode::FILE).with_content_length(100000 * MIB);
+        let CopyPlan::Multipart { parts, .. } =
+            CopyPlan::new("src", &meta, &OpCopy::new(), 5 * MIB, MIB)
+        else {
+            panic!("source over max single size must be copied in parts");
+        };
+        assert_eq!(parts.len(), 10000);
+    }
+}
@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::future::ready;
 use std::sync::Arc;
 
 use futures::select;
@@ -51,6 +52,14 @@ pub trait PositionWrite: Send + Sync + Unpin + 'static {
         buf: Buffer,
     ) -> impl Future<Output = Result<()>> + MaybeSend;
 
+    /// set_len is used to truncate or extend the underlying file.
+    fn set_len(&self, _len: u64) -> impl Future<Output = Result<()>> + MaybeSend {
+        ready(Err(Error::new(
+            ErrorKind::Unsupported,
+            "writer doesn't support set_len",
+        )))
+    }
+
     /// close is used to close the underlying file.
     fn close(&self) -> impl Future<Output = Result<Metadata>> + MaybeSend;
 
@@ -122,6 +131,20 @@ impl<W: PositionWrite> PositionWriter<W> {
         self.cache = Some(bs);
         size
     }
+
+    /// Wait for all running tasks and write the cached data, so that
+    /// positional writes can't be reordered with them.
+    async fn flush(&mut self) -> Result<()> {
+        while self.tasks.next().await.transpose()?.is_some() {}
+
+        if let Some(buffer) = self.cache.clone() {
+            let length = buffer.len() as u64;
+            self.w.write_all_at(self.next_offset, buffer).await?;
+            self.cache = None;
+            self.next_offset += length;
+        }
+        Ok(())
+    }
 }
 
 impl<W: PositionWrite> oio::Write for PositionWriter<W> {
@@ -149,6 +172,16 @@ impl<W: PositionWrite> oio::Write for PositionWriter<W> {
         Ok(())
     }
 
+    async fn write_at(&mut self, offset: u64, bs: Buffer) -> Result<()> {
+        self.flush().await?;
+        self.w.write_all_at(offset, bs).await
+    }
+
+    async fn set_len(&mut self, len: u64) -> Result<()> {
+        self.flush().await?;
+        self.w.set_len(len).await
+    }
+
     async fn close(&mut self) -> Result<Metadata> {
         // Make sure all tasks are finished.
         while self.tasks.next().await.transpose()?.is_some() {}
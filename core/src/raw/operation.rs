@@ -49,6 +49,34 @@ pub enum Operation {
     List,
     /// Operation to generate a presigned URL.
     Presign,
+    /// Operation to list in-progress multipart uploads.
+    ListUploads,
+    /// Operation to abort an in-progress multipart upload.
+    AbortUpload,
+    /// Operation to initiate a multipart upload.
+    InitiateUpload,
+    /// Operation to complete a multipart upload.
+    CompleteUpload,
+    /// Operation to generate a presigned POST form.
+    PresignPost,
+    /// Operation to create a symlink.
+    CreateSymlink,
+    /// Operation to read the target of a symlink.
+    ReadLink,
+    /// Operation to change unix permission bits.
+    SetPermissions,
+    /// Operation to change access and modification times.
+    SetTimes,
+    /// Operation to update metadata of an existing object.
+    UpdateMetadata,
+    /// Operation to concatenate objects into a new object.
+    Concat,
+    /// Operation to acquire or renew a lock.
+    Lock,
+    /// Operation to release a lock.
+    Unlock,
+    /// Operation to commit a transaction.
+    Transaction,
 }
 
 impl Operation {
@@ -77,6 +105,20 @@ impl From<Operation> for &'static str {
             Operation::Delete => "delete",
             Operation::List => "list",
             Operation::Presign => "presign",
+            Operation::ListUploads => "list_uploads",
+            Operation::AbortUpload => "abort_upload",
+            Operation::InitiateUpload => "initiate_upload",
+            Operation::CompleteUpload => "complete_upload",
+            Operation::PresignPost => "presign_post",
+            Operation::CreateSymlink => "create_symlink",
+            Operation::ReadLink => "read_link",
+            Operation::SetPermissions => "set_permissions",
+            Operation::SetTimes => "set_times",
+            Operation::UpdateMetadata => "update_metadata",
+            Operation::Concat => "concat",
+            Operation::Lock => "lock",
+            Operation::Unlock => "unlock",
+            Operation::Transaction => "transaction",
         }
     }
 }
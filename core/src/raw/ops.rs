@@ -27,6 +27,10 @@ use chrono::Utc;
 
 use crate::options;
 use crate::raw::*;
+use crate::Buffer;
+use crate::MetadataUpdate;
+use crate::PostPolicy;
+use crate::UploadPart;
 
 /// Args for `create` operation.
 ///
@@ -101,6 +105,8 @@ pub struct OpList {
     /// The start_after passes to underlying service to specify the specified key
     /// to start listing from.
     start_after: Option<String>,
+    /// The continuation token returned by a previous page to resume listing from.
+    continuation_token: Option<String>,
     /// The recursive is used to control whether the list operation is recursive.
     ///
     /// - If `false`, list operation will only list the entries under the given path.
@@ -154,6 +160,17 @@ impl OpList {
         self.start_after.as_deref()
     }
 
+    /// Change the continuation_token of this list operation.
+    pub fn with_continuation_token(mut self, token: &str) -> Self {
+        self.continuation_token = Some(token.into());
+        self
+    }
+
+    /// Get the continuation_token of list operation.
+    pub fn continuation_token(&self) -> Option<&str> {
+        self.continuation_token.as_deref()
+    }
+
     /// The recursive is used to control whether the list operation is recursive.
     ///
     /// - If `false`, list operation will only list the entries under the given path.
@@ -226,6 +243,7 @@ impl From<options::ListOptions> for OpList {
         Self {
             limit: value.limit,
             start_after: value.start_after,
+            continuation_token: value.continuation_token.map(|v| v.into_string()),
             recursive: value.recursive,
             versions: value.versions,
             deleted: value.deleted,
@@ -280,6 +298,8 @@ pub enum PresignOperation {
     Write(OpWrite),
     /// Presign a delete operation.
     Delete(OpDelete),
+    /// Presign an upload part operation of a multipart upload.
+    UploadPart(OpUploadPart),
 }
 
 impl From<OpStat> for PresignOperation {
@@ -306,6 +326,12 @@ impl From<OpDelete> for PresignOperation {
     }
 }
 
+impl From<OpUploadPart> for PresignOperation {
+    fn from(v: OpUploadPart) -> Self {
+        Self::UploadPart(v)
+    }
+}
+
 /// Args for `read` operation.
 #[derive(Debug, Clone, Default)]
 pub struct OpRead {
@@ -558,7 +584,7 @@ impl From<options::ReaderOptions> for (OpRead, OpReader) {
 }
 
 /// Args for `stat` operation.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct OpStat {
     if_match: Option<String>,
     if_none_match: Option<String>,
@@ -568,6 +594,23 @@ pub struct OpStat {
     override_cache_control: Option<String>,
     override_content_disposition: Option<String>,
     version: Option<String>,
+    follow_symlinks: bool,
+}
+
+impl Default for OpStat {
+    fn default() -> Self {
+        Self {
+            if_match: None,
+            if_none_match: None,
+            if_modified_since: None,
+            if_unmodified_since: None,
+            override_content_type: None,
+            override_cache_control: None,
+            override_content_disposition: None,
+            version: None,
+            follow_symlinks: true,
+        }
+    }
 }
 
 impl OpStat {
@@ -664,6 +707,17 @@ impl OpStat {
     pub fn version(&self) -> Option<&str> {
         self.version.as_deref()
     }
+
+    /// Set whether to follow symlinks of the option
+    pub fn with_follow_symlinks(mut self, follow_symlinks: bool) -> Self {
+        self.follow_symlinks = follow_symlinks;
+        self
+    }
+
+    /// Get whether to follow symlinks from option
+    pub fn follow_symlinks(&self) -> bool {
+        self.follow_symlinks
+    }
 }
 
 impl From<options::StatOptions> for OpStat {
@@ -677,6 +731,7 @@ impl From<options::StatOptions> for OpStat {
             override_cache_control: value.override_cache_control,
             override_content_disposition: value.override_content_disposition,
             version: value.version,
+            follow_symlinks: value.follow_symlinks,
         }
     }
 }
@@ -694,6 +749,9 @@ pub struct OpWrite {
     if_none_match: Option<String>,
     if_not_exists: bool,
     user_metadata: Option<HashMap<String, String>>,
+    ttl: Option<Duration>,
+    random_access: bool,
+    chunk: Option<usize>,
 }
 
 impl OpWrite {
@@ -821,6 +879,45 @@ impl OpWrite {
     pub fn user_metadata(&self) -> Option<&HashMap<String, String>> {
         self.user_metadata.as_ref()
     }
+
+    /// Set the ttl of the op.
+    ///
+    /// The written object will be removed by the service after the ttl.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Get the ttl from the op.
+    pub fn ttl(&self) -> Option<Duration> {
+        self.ttl
+    }
+
+    /// Set the random access mode of op.
+    ///
+    /// If the random access mode is set, the writer will accept `write_at` and `set_len`.
+    pub fn with_random_access(mut self, random_access: bool) -> Self {
+        self.random_access = random_access;
+        self
+    }
+
+    /// Get the random access mode from op.
+    pub fn random_access(&self) -> bool {
+        self.random_access
+    }
+
+    /// Set the chunk size requested by user.
+    ///
+    /// Services that emulate random access on top of blocks use it as the block size.
+    pub fn with_chunk(mut self, chunk: usize) -> Self {
+        self.chunk = Some(chunk);
+        self
+    }
+
+    /// Get the chunk size requested by user.
+    pub fn chunk(&self) -> Option<usize> {
+        self.chunk
+    }
 }
 
 /// Args for `writer` operation.
@@ -872,6 +969,9 @@ impl From<options::WriteOptions> for (OpWrite, OpWriter) {
                 if_none_match: value.if_none_match,
                 if_not_exists: value.if_not_exists,
                 user_metadata: value.user_metadata,
+                ttl: value.ttl,
+                random_access: value.random_access,
+                chunk: value.chunk,
             },
             OpWriter { chunk: value.chunk },
         )
@@ -882,6 +982,10 @@ impl From<options::WriteOptions> for (OpWrite, OpWriter) {
 #[derive(Debug, Clone, Default)]
 pub struct OpCopy {
     if_not_exists: bool,
+    if_match: Option<String>,
+    if_none_match: Option<String>,
+    concurrent: usize,
+    chunk: Option<usize>,
 }
 
 impl OpCopy {
@@ -903,6 +1007,228 @@ impl OpCopy {
     pub fn if_not_exists(&self) -> bool {
         self.if_not_exists
     }
+
+    /// Set the If-Match condition on the source.
+    pub fn with_if_match(mut self, if_match: &str) -> Self {
+        self.if_match = Some(if_match.to_string());
+        self
+    }
+
+    /// Get the If-Match condition on the source.
+    pub fn if_match(&self) -> Option<&str> {
+        self.if_match.as_deref()
+    }
+
+    /// Set the If-None-Match condition on the source.
+    pub fn with_if_none_match(mut self, if_none_match: &str) -> Self {
+        self.if_none_match = Some(if_none_match.to_string());
+        self
+    }
+
+    /// Get the If-None-Match condition on the source.
+    pub fn if_none_match(&self) -> Option<&str> {
+        self.if_none_match.as_deref()
+    }
+
+    /// Set the concurrent requests used while copying in parts.
+    pub fn with_concurrent(mut self, concurrent: usize) -> Self {
+        self.concurrent = concurrent;
+        self
+    }
+
+    /// Get the concurrent requests used while copying in parts.
+    pub fn concurrent(&self) -> usize {
+        self.concurrent
+    }
+
+    /// Set the part size used while copying in parts.
+    pub fn with_chunk(mut self, chunk: usize) -> Self {
+        self.chunk = Some(chunk);
+        self
+    }
+
+    /// Get the part size used while copying in parts.
+    pub fn chunk(&self) -> Option<usize> {
+        self.chunk
+    }
+}
+
+impl From<options::CopyOptions> for OpCopy {
+    fn from(value: options::CopyOptions) -> Self {
+        Self {
+            if_not_exists: value.if_not_exists,
+            if_match: value.if_match,
+            if_none_match: value.if_none_match,
+            // Ensure concurrent is at least 1
+            concurrent: value.concurrent.max(1),
+            chunk: value.chunk,
+        }
+    }
+}
+
+/// Args for `concat` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpConcat {
+    sources: Vec<String>,
+    content_type: Option<String>,
+    chunk: Option<usize>,
+    concurrent: usize,
+}
+
+impl OpConcat {
+    /// Create a new `OpConcat` with the given sources.
+    pub fn new(sources: Vec<String>) -> Self {
+        Self {
+            sources,
+            ..Default::default()
+        }
+    }
+
+    /// Get the sources to concatenate, in order.
+    pub fn sources(&self) -> &[String] {
+        &self.sources
+    }
+
+    /// Set the content type of the concatenated object.
+    pub fn with_content_type(mut self, content_type: &str) -> Self {
+        self.content_type = Some(content_type.to_string());
+        self
+    }
+
+    /// Get the content type of the concatenated object.
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// Set the chunk size used while concatenating by streaming.
+    pub fn with_chunk(mut self, chunk: usize) -> Self {
+        self.chunk = Some(chunk);
+        self
+    }
+
+    /// Get the chunk size used while concatenating by streaming.
+    pub fn chunk(&self) -> Option<usize> {
+        self.chunk
+    }
+
+    /// Set the concurrent requests used while concatenating by streaming.
+    pub fn with_concurrent(mut self, concurrent: usize) -> Self {
+        self.concurrent = concurrent;
+        self
+    }
+
+    /// Get the concurrent requests used while concatenating by streaming.
+    pub fn concurrent(&self) -> usize {
+        self.concurrent
+    }
+}
+
+impl From<(Vec<String>, options::ConcatOptions)> for OpConcat {
+    fn from((sources, value): (Vec<String>, options::ConcatOptions)) -> Self {
+        Self {
+            sources,
+            content_type: value.content_type,
+            chunk: value.chunk,
+            concurrent: value.concurrent,
+        }
+    }
+}
+
+/// Args for `lock` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpLock {
+    ttl: Duration,
+    owner: String,
+    token: Option<u64>,
+}
+
+impl OpLock {
+    /// Create a new `OpLock` to acquire the lock for `owner` with lease of `ttl`.
+    pub fn new(ttl: Duration, owner: &str) -> Self {
+        Self {
+            ttl,
+            owner: owner.to_string(),
+            token: None,
+        }
+    }
+
+    /// Get the time to live of the lease.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Get the owner of the lock.
+    pub fn owner(&self) -> &str {
+        &self.owner
+    }
+
+    /// Renew the lease of the lock held with the given token instead of acquiring it.
+    pub fn with_token(mut self, token: u64) -> Self {
+        self.token = Some(token);
+        self
+    }
+
+    /// Get the token of the lock to renew.
+    pub fn token(&self) -> Option<u64> {
+        self.token
+    }
+}
+
+/// Args for `unlock` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpUnlock {
+    owner: String,
+    token: u64,
+}
+
+impl OpUnlock {
+    /// Create a new `OpUnlock` to release the lock held by `owner` with `token`.
+    pub fn new(owner: &str, token: u64) -> Self {
+        Self {
+            owner: owner.to_string(),
+            token,
+        }
+    }
+
+    /// Get the owner of the lock.
+    pub fn owner(&self) -> &str {
+        &self.owner
+    }
+
+    /// Get the token of the lock.
+    pub fn token(&self) -> u64 {
+        self.token
+    }
+}
+
+/// Args for `transaction` operation.
+///
+/// Both checks and mutations are pairs of path and value. A `None` value means
+/// the path must not exist for checks, and the path should be deleted for mutations.
+#[derive(Debug, Clone, Default)]
+pub struct OpTransaction {
+    checks: Vec<(String, Option<Buffer>)>,
+    mutations: Vec<(String, Option<Buffer>)>,
+}
+
+impl OpTransaction {
+    /// Create a new `OpTransaction` that applies `mutations` if all `checks` hold.
+    pub fn new(
+        checks: Vec<(String, Option<Buffer>)>,
+        mutations: Vec<(String, Option<Buffer>)>,
+    ) -> Self {
+        Self { checks, mutations }
+    }
+
+    /// Get the values that paths must still have at commit.
+    pub fn checks(&self) -> &[(String, Option<Buffer>)] {
+        &self.checks
+    }
+
+    /// Get the values to set to paths at commit.
+    pub fn mutations(&self) -> &[(String, Option<Buffer>)] {
+        &self.mutations
+    }
 }
 
 /// Args for `rename` operation.
@@ -915,3 +1241,283 @@ impl OpRename {
         Self::default()
     }
 }
+
+/// Args for `list_uploads` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpListUploads {}
+
+impl OpListUploads {
+    /// Create a new `OpListUploads`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Args for `abort_upload` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpAbortUpload {
+    upload_id: String,
+}
+
+impl OpAbortUpload {
+    /// Create a new `OpAbortUpload` for given upload id.
+    pub fn new(upload_id: &str) -> Self {
+        Self {
+            upload_id: upload_id.to_string(),
+        }
+    }
+
+    /// Get the upload id of this abort operation.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+}
+
+/// Args for `initiate_upload` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpInitiateUpload {
+    write: OpWrite,
+}
+
+impl OpInitiateUpload {
+    /// Create a new `OpInitiateUpload`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the write args used while creating the upload, like content type and user metadata.
+    pub fn with_write(mut self, write: OpWrite) -> Self {
+        self.write = write;
+        self
+    }
+
+    /// Get the write args of this upload.
+    pub fn write(&self) -> &OpWrite {
+        &self.write
+    }
+}
+
+/// Args for presigning an `upload_part` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpUploadPart {
+    upload_id: String,
+    part_number: usize,
+}
+
+impl OpUploadPart {
+    /// Create a new `OpUploadPart` for given upload id and part number.
+    ///
+    /// Part number starts from 1.
+    pub fn new(upload_id: &str, part_number: usize) -> Self {
+        Self {
+            upload_id: upload_id.to_string(),
+            part_number,
+        }
+    }
+
+    /// Get the upload id of this operation.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
+    /// Get the part number of this operation.
+    pub fn part_number(&self) -> usize {
+        self.part_number
+    }
+}
+
+/// Args for `complete_upload` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpCompleteUpload {
+    upload_id: String,
+    parts: Vec<UploadPart>,
+}
+
+impl OpCompleteUpload {
+    /// Create a new `OpCompleteUpload` for given upload id and uploaded parts.
+    pub fn new(upload_id: &str, parts: Vec<UploadPart>) -> Self {
+        Self {
+            upload_id: upload_id.to_string(),
+            parts,
+        }
+    }
+
+    /// Get the upload id of this operation.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
+    /// Get the uploaded parts of this operation.
+    pub fn parts(&self) -> &[UploadPart] {
+        &self.parts
+    }
+}
+
+/// Args for `presign_post` operation.
+///
+/// The path must be normalized. A path ends with `/` is used as key prefix, clients
+/// can upload to any key under it.
+#[derive(Debug, Clone)]
+pub struct OpPresignPost {
+    expire: Duration,
+    content_length_range: Option<(u64, u64)>,
+    content_type_prefix: Option<String>,
+}
+
+impl OpPresignPost {
+    /// Create a new `OpPresignPost`.
+    pub fn new(expire: Duration) -> Self {
+        Self {
+            expire,
+            content_length_range: None,
+            content_type_prefix: None,
+        }
+    }
+
+    /// Get expire from op.
+    pub fn expire(&self) -> Duration {
+        self.expire
+    }
+
+    /// Set the content length range of this operation.
+    pub fn with_content_length_range(mut self, min: u64, max: u64) -> Self {
+        self.content_length_range = Some((min, max));
+        self
+    }
+
+    /// Get the content length range of this operation.
+    pub fn content_length_range(&self) -> Option<(u64, u64)> {
+        self.content_length_range
+    }
+
+    /// Set the content type prefix of this operation.
+    pub fn with_content_type_prefix(mut self, prefix: &str) -> Self {
+        self.content_type_prefix = Some(prefix.to_string());
+        self
+    }
+
+    /// Get the content type prefix of this operation.
+    pub fn content_type_prefix(&self) -> Option<&str> {
+        self.content_type_prefix.as_deref()
+    }
+}
+
+impl From<PostPolicy> for OpPresignPost {
+    fn from(v: PostPolicy) -> Self {
+        Self {
+            expire: v.expire(),
+            content_length_range: v.content_length_range(),
+            content_type_prefix: v.content_type_prefix().map(|v| v.to_string()),
+        }
+    }
+}
+
+/// Args for `create_symlink` operation.
+///
+/// The target is stored as is, services MUST NOT normalize it or join it with root.
+#[derive(Debug, Clone)]
+pub struct OpCreateSymlink {
+    target: String,
+}
+
+impl OpCreateSymlink {
+    /// Create a new `OpCreateSymlink`.
+    pub fn new(target: &str) -> Self {
+        Self {
+            target: target.to_string(),
+        }
+    }
+
+    /// Get the target of this symlink.
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+}
+
+/// Args for `read_link` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpReadLink {}
+
+impl OpReadLink {
+    /// Create a new `OpReadLink`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Args for `set_permissions` operation.
+#[derive(Debug, Clone)]
+pub struct OpSetPermissions {
+    mode: u32,
+}
+
+impl OpSetPermissions {
+    /// Create a new `OpSetPermissions`.
+    pub fn new(mode: u32) -> Self {
+        Self { mode }
+    }
+
+    /// Get the unix permission bits to set.
+    pub fn mode(&self) -> u32 {
+        self.mode
+    }
+}
+
+/// Args for `set_times` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpSetTimes {
+    accessed: Option<DateTime<Utc>>,
+    modified: Option<DateTime<Utc>>,
+}
+
+impl OpSetTimes {
+    /// Create a new `OpSetTimes`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the access time to set.
+    pub fn with_accessed(mut self, v: DateTime<Utc>) -> Self {
+        self.accessed = Some(v);
+        self
+    }
+
+    /// Get the access time to set.
+    pub fn accessed(&self) -> Option<DateTime<Utc>> {
+        self.accessed
+    }
+
+    /// Set the modification time to set.
+    pub fn with_modified(mut self, v: DateTime<Utc>) -> Self {
+        self.modified = Some(v);
+        self
+    }
+
+    /// Get the modification time to set.
+    pub fn modified(&self) -> Option<DateTime<Utc>> {
+        self.modified
+    }
+}
+
+/// Args for `update_metadata` operation.
+#[derive(Debug, Clone, Default)]
+pub struct OpUpdateMetadata {
+    update: MetadataUpdate,
+}
+
+impl OpUpdateMetadata {
+    /// Create a new `OpUpdateMetadata`.
+    pub fn new(update: MetadataUpdate) -> Self {
+        Self { update }
+    }
+
+    /// Get the metadata update.
+    pub fn update(&self) -> &MetadataUpdate {
+        &self.update
+    }
+
+    /// Consume op to get the metadata update.
+    pub fn into_update(self) -> MetadataUpdate {
+        self.update
+    }
+}
@@ -84,6 +84,33 @@ impl PresignedRequest {
     }
 }
 
+/// PresignedPost is a presigned POST form returned by `presign_post`.
+///
+/// Clients should send a `multipart/form-data` POST request to the uri with all fields,
+/// and the file content as the last field named `file`.
+#[derive(Debug, Clone)]
+pub struct PresignedPost {
+    uri: http::Uri,
+    fields: Vec<(String, String)>,
+}
+
+impl PresignedPost {
+    /// Create a new PresignedPost
+    pub fn new(uri: http::Uri, fields: Vec<(String, String)>) -> Self {
+        Self { uri, fields }
+    }
+
+    /// Return the uri that form should be posted to.
+    pub fn uri(&self) -> &http::Uri {
+        &self.uri
+    }
+
+    /// Return the form fields that must be sent along with the file.
+    pub fn fields(&self) -> &[(String, String)] {
+        &self.fields
+    }
+}
+
 impl<T: Default> From<PresignedRequest> for Request<T> {
     fn from(v: PresignedRequest) -> Self {
         let mut builder = Request::builder().method(v.method).uri(v.uri);
@@ -210,6 +237,221 @@ impl RpRename {
     }
 }
 
+/// Reply for `list_uploads` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpListUploads {
+    uploads: Vec<Upload>,
+}
+
+impl RpListUploads {
+    /// Create a new reply for `list_uploads`.
+    pub fn new(uploads: Vec<Upload>) -> Self {
+        Self { uploads }
+    }
+
+    /// Consume reply to get the in-progress uploads.
+    pub fn into_uploads(self) -> Vec<Upload> {
+        self.uploads
+    }
+}
+
+/// Reply for `abort_upload` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpAbortUpload {}
+
+impl RpAbortUpload {
+    /// Create a new reply for `abort_upload`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `initiate_upload` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpInitiateUpload {
+    upload_id: String,
+}
+
+impl RpInitiateUpload {
+    /// Create a new reply for `initiate_upload`.
+    pub fn new(upload_id: &str) -> Self {
+        Self {
+            upload_id: upload_id.to_string(),
+        }
+    }
+
+    /// Get the upload id assigned by the service.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
+    /// Consume reply to get the upload id.
+    pub fn into_upload_id(self) -> String {
+        self.upload_id
+    }
+}
+
+/// Reply for `complete_upload` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpCompleteUpload {}
+
+impl RpCompleteUpload {
+    /// Create a new reply for `complete_upload`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `presign_post` operation.
+#[derive(Debug, Clone)]
+pub struct RpPresignPost {
+    post: PresignedPost,
+}
+
+impl RpPresignPost {
+    /// Create a new reply for `presign_post`.
+    pub fn new(post: PresignedPost) -> Self {
+        Self { post }
+    }
+
+    /// Consume reply to build a presigned post.
+    pub fn into_presigned_post(self) -> PresignedPost {
+        self.post
+    }
+}
+
+/// Reply for `create_symlink` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpCreateSymlink {}
+
+impl RpCreateSymlink {
+    /// Create a new reply for `create_symlink`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `read_link` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpReadLink {
+    target: String,
+}
+
+impl RpReadLink {
+    /// Create a new reply for `read_link`.
+    pub fn new(target: &str) -> Self {
+        Self {
+            target: target.to_string(),
+        }
+    }
+
+    /// Get the target of the symlink.
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    /// Consume reply to get the target of the symlink.
+    pub fn into_target(self) -> String {
+        self.target
+    }
+}
+
+/// Reply for `set_permissions` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpSetPermissions {}
+
+impl RpSetPermissions {
+    /// Create a new reply for `set_permissions`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `set_times` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpSetTimes {}
+
+impl RpSetTimes {
+    /// Create a new reply for `set_times`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `concat` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpConcat {}
+
+impl RpConcat {
+    /// Create a new reply for `concat`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `lock` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpLock {
+    token: u64,
+}
+
+impl RpLock {
+    /// Create a new reply for `lock` with the fencing token of the lock.
+    pub fn new(token: u64) -> Self {
+        Self { token }
+    }
+
+    /// Get the fencing token of the lock.
+    pub fn token(&self) -> u64 {
+        self.token
+    }
+}
+
+/// Reply for `unlock` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpUnlock {}
+
+impl RpUnlock {
+    /// Create a new reply for `unlock`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `transaction` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpTransaction {}
+
+impl RpTransaction {
+    /// Create a new reply for `transaction`.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Reply for `update_metadata` operation.
+#[derive(Debug, Clone, Default)]
+pub struct RpUpdateMetadata {
+    metadata: Metadata,
+}
+
+impl RpUpdateMetadata {
+    /// Create a new reply for `update_metadata`.
+    pub fn new(metadata: Metadata) -> Self {
+        Self { metadata }
+    }
+
+    /// Get the metadata of the object after update.
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    /// Consume reply to get the metadata of the object after update.
+    pub fn into_metadata(self) -> Metadata {
+        self.metadata
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use anyhow::Result;
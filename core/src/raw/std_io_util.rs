@@ -63,3 +63,126 @@ pub(crate) fn format_std_io_error(err: Error) -> io::Error {
 
     io::Error::new(kind, err)
 }
+
+/// Copy the file at `from` to `to` without reading contents into user space if possible.
+///
+/// - On linux, `to` becomes a reflink of `from` with `FICLONE` on file systems that
+///   support it, like btrfs and xfs, so that no data is copied at all.
+/// - Otherwise, it falls back to [`std::fs::copy`], which uses `copy_file_range` or
+///   `sendfile` on linux and clones files on macOS.
+///
+/// Permissions of `from` are copied to `to` like [`std::fs::copy`] does.
+///
+/// This function is blocking.
+#[cfg(any(
+    feature = "services-fs",
+    feature = "services-compfs",
+    feature = "services-monoiofs"
+))]
+pub(crate) fn std_copy_file(from: &std::path::Path, to: &std::path::Path) -> io::Result<()> {
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        std_copy_file_with_clone(from, to, |dst, src| rustix::fs::ioctl_ficlone(dst, src))
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    {
+        std::fs::copy(from, to).map(|_| ())
+    }
+}
+
+/// Copy the file at `from` to `to` by `clone`, and fall back to [`std::fs::copy`]
+/// if it fails.
+#[cfg(all(
+    any(
+        feature = "services-fs",
+        feature = "services-compfs",
+        feature = "services-monoiofs"
+    ),
+    any(target_os = "linux", target_os = "android")
+))]
+fn std_copy_file_with_clone(
+    from: &std::path::Path,
+    to: &std::path::Path,
+    clone: impl FnOnce(&std::fs::File, &std::fs::File) -> rustix::io::Result<()>,
+) -> io::Result<()> {
+    let src = std::fs::File::open(from)?;
+    let dst = std::fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(to)?;
+    // Reflinks are not supported across file systems or by most file systems,
+    // just fall back in all cases.
+    if clone(&dst, &src).is_ok() {
+        return dst.set_permissions(src.metadata()?.permissions());
+    }
+
+    std::fs::copy(from, to).map(|_| ())
+}
+
+#[cfg(all(
+    test,
+    any(
+        feature = "services-fs",
+        feature = "services-compfs",
+        feature = "services-monoiofs"
+    )
+))]
+mod tests {
+    use std::path::PathBuf;
+
+    use rand::RngCore;
+
+    use super::*;
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new() -> Self {
+            let path = std::env::temp_dir().join(format!("opendal-copy-{}", uuid::Uuid::new_v4()));
+            std::fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// Write a file larger than a few blocks with an unaligned size.
+    fn write_source(dir: &TempDir) -> (PathBuf, Vec<u8>) {
+        let mut content = vec![0; 3 * 1024 * 1024 + 17];
+        rand::thread_rng().fill_bytes(&mut content);
+        let path = dir.0.join("from");
+        std::fs::write(&path, &content).unwrap();
+        (path, content)
+    }
+
+    #[test]
+    fn test_std_copy_file() {
+        let dir = TempDir::new();
+        let (from, content) = write_source(&dir);
+        let to = dir.0.join("to");
+
+        std_copy_file(&from, &to).unwrap();
+        assert_eq!(std::fs::read(&to).unwrap(), content);
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[test]
+    fn test_std_copy_file_fallback() {
+        let dir = TempDir::new();
+        let (from, content) = write_source(&dir);
+
+        for errno in [rustix::io::Errno::OPNOTSUPP, rustix::io::Errno::XDEV] {
+            let to = dir.0.join(format!("to-{}", errno.raw_os_error()));
+            // Existing content longer than the source must be truncated.
+            std::fs::write(&to, vec![1; content.len() + 4096]).unwrap();
+
+            std_copy_file_with_clone(&from, &to, |_, _| Err(errno)).unwrap();
+            assert_eq!(std::fs::read(&to).unwrap(), content);
+        }
+    }
+}
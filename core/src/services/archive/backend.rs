@@ -0,0 +1,572 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use super::core::*;
+use super::lister::ArchiveLister;
+use super::reader::ArchiveReader;
+use super::DEFAULT_SCHEME;
+use crate::archive::Format;
+use crate::raw::*;
+use crate::services::ArchiveConfig;
+use crate::*;
+
+impl Configurator for ArchiveConfig {
+    type Builder = ArchiveBuilder;
+    fn into_builder(self) -> Self::Builder {
+        ArchiveBuilder {
+            config: self,
+            operator: None,
+        }
+    }
+}
+
+/// Options of the underlying operator in config are prefixed with it.
+const OPERATOR_OPTION_PREFIX: &str = "operator.";
+
+/// Read zip and tar archives stored in another service.
+#[doc = include_str!("docs.md")]
+#[derive(Default, Debug)]
+pub struct ArchiveBuilder {
+    config: ArchiveConfig,
+    operator: Option<Operator>,
+}
+
+impl ArchiveBuilder {
+    /// Set the root dir inside the archive.
+    pub fn root(mut self, root: &str) -> Self {
+        self.config.root = if root.is_empty() {
+            None
+        } else {
+            Some(root.to_string())
+        };
+
+        self
+    }
+
+    /// Set the path of archive in the underlying operator.
+    pub fn path(mut self, path: &str) -> Self {
+        if !path.is_empty() {
+            self.config.path = Some(path.to_string());
+        }
+
+        self
+    }
+
+    /// Set the format of archive, one of `zip`, `tar`, `tar.gz` and `tar.zst`.
+    ///
+    /// The format is detected from the extension of path if not set.
+    pub fn format(mut self, format: &str) -> Self {
+        if !format.is_empty() {
+            self.config.format = Some(format.to_string());
+        }
+
+        self
+    }
+
+    /// Set the operator where the archive is stored.
+    ///
+    /// `operator_scheme` and `operator.*` options of config are ignored if it's set.
+    pub fn operator(mut self, op: Operator) -> Self {
+        self.operator = Some(op);
+        self
+    }
+}
+
+impl Builder for ArchiveBuilder {
+    type Config = ArchiveConfig;
+
+    fn build(self) -> Result<impl Access> {
+        let op = match self.operator {
+            Some(op) => op,
+            None => {
+                let scheme = self.config.operator_scheme.ok_or_else(|| {
+                    Error::new(ErrorKind::ConfigInvalid, "operator is not specified")
+                        .with_operation("Builder::build")
+                        .with_context("service", Scheme::Archive)
+                })?;
+                let options = self
+                    .config
+                    .operator_options
+                    .into_iter()
+                    .filter_map(|(k, v)| {
+                        k.strip_prefix(OPERATOR_OPTION_PREFIX)
+                            .map(|k| (k.to_string(), v))
+                    });
+                Operator::via_iter(scheme.parse()?, options)?
+            }
+        };
+        let path = self.config.path.ok_or_else(|| {
+            Error::new(ErrorKind::ConfigInvalid, "path is not specified")
+                .with_operation("Builder::build")
+                .with_context("service", Scheme::Archive)
+        })?;
+        let format = match &self.config.format {
+            Some(v) => Format::from_name(v).ok_or_else(|| {
+                Error::new(ErrorKind::ConfigInvalid, "format is not supported")
+                    .with_operation("Builder::build")
+                    .with_context("service", Scheme::Archive)
+                    .with_context("format", v)
+            })?,
+            None => Format::from_path(&path).ok_or_else(|| {
+                Error::new(
+                    ErrorKind::ConfigInvalid,
+                    "format is not specified and can't be detected from path",
+                )
+                .with_operation("Builder::build")
+                .with_context("service", Scheme::Archive)
+                .with_context("path", &path)
+            })?,
+        };
+
+        let root = normalize_root(self.config.root.as_deref().unwrap_or("/"));
+
+        let info = AccessorInfo::default();
+        info.set_scheme(DEFAULT_SCHEME)
+            .set_root(&root)
+            .set_name(&path)
+            .set_native_capability(Capability {
+                stat: true,
+                read: true,
+                list: true,
+                list_with_recursive: true,
+                list_with_start_after: true,
+                ..Default::default()
+            });
+
+        Ok(ArchiveBackend {
+            core: Arc::new(ArchiveCore::new(info.into(), op, path, format)),
+            root,
+        })
+    }
+}
+
+/// Backend is used to serve `Accessor` support for archives.
+#[derive(Debug, Clone)]
+pub struct ArchiveBackend {
+    core: Arc<ArchiveCore>,
+    root: String,
+}
+
+impl Access for ArchiveBackend {
+    type Reader = ArchiveReader;
+    type Writer = ();
+    type Lister = oio::HierarchyLister<ArchiveLister>;
+    type Deleter = ();
+
+    fn info(&self) -> Arc<AccessorInfo> {
+        self.core.info.clone()
+    }
+
+    async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+        let p = build_abs_path(&self.root, path);
+        let meta = self.core.stat(&p).await?;
+        Ok(RpStat::new(meta))
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let p = build_abs_path(&self.root, path);
+        let r = self.core.read(&p, args.range()).await?;
+        Ok((RpRead::new(), r))
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        let p = build_abs_path(&self.root, path);
+        let start_after = args.start_after().map(|v| build_abs_path(&self.root, v));
+        let entries = self.core.list(&p, start_after.as_deref()).await?;
+
+        let lister = ArchiveLister::new(&self.root, entries);
+        let lister = oio::HierarchyLister::new(lister, path, args.recursive());
+        Ok((RpList::default(), lister))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_compression::futures::write::DeflateEncoder;
+    use async_compression::futures::write::GzipEncoder;
+    use async_compression::futures::write::ZstdEncoder;
+    use futures::AsyncWriteExt;
+    use futures::TryStreamExt;
+
+    use super::*;
+    use crate::services::Memory;
+
+    async fn compress<W: futures::AsyncWrite + Unpin>(mut w: W, data: &[u8]) -> W {
+        w.write_all(data).await.unwrap();
+        w.close().await.unwrap();
+        w
+    }
+
+    fn tar_header(name: &str, typeflag: u8, size: usize) -> Vec<u8> {
+        let mut h = vec![0; 512];
+        h[..name.len()].copy_from_slice(name.as_bytes());
+        h[100..107].copy_from_slice(b"0000644");
+        h[124..135].copy_from_slice(format!("{size:011o}").as_bytes());
+        h[136..147].copy_from_slice(format!("{:011o}", 1700000000).as_bytes());
+        h[156] = typeflag;
+        h[257..263].copy_from_slice(b"ustar\0");
+        h[263..265].copy_from_slice(b"00");
+        h[148..156].fill(b' ');
+        let sum: u32 = h.iter().map(|b| *b as u32).sum();
+        h[148..155].copy_from_slice(format!("{sum:06o}\0").as_bytes());
+        h
+    }
+
+    fn tar_entry(buf: &mut Vec<u8>, name: &str, typeflag: u8, data: &[u8]) {
+        buf.extend(tar_header(name, typeflag, data.len()));
+        buf.extend(data);
+        buf.resize(buf.len().div_ceil(512) * 512, 0);
+    }
+
+    /// Build zip with entries of `(name, data, deflate)`.
+    async fn build_zip(entries: &[(&str, &[u8], bool)]) -> Vec<u8> {
+        let (mut buf, mut cd) = (Vec::new(), Vec::new());
+        for (name, data, deflate) in entries {
+            let (method, content) = if *deflate {
+                let w = compress(DeflateEncoder::new(Vec::new()), data).await;
+                (8u16, w.into_inner())
+            } else {
+                (0u16, data.to_vec())
+            };
+
+            let offset = buf.len() as u32;
+            let mut fields = Vec::new();
+            fields.extend(20u16.to_le_bytes());
+            fields.extend(0u16.to_le_bytes());
+            fields.extend(method.to_le_bytes());
+            // 2024-01-02 03:04:06
+            fields.extend(((3u16 << 11) | (4 << 5) | 3).to_le_bytes());
+            fields.extend((((2024u16 - 1980) << 9) | (1 << 5) | 2).to_le_bytes());
+            fields.extend(0u32.to_le_bytes());
+            fields.extend((content.len() as u32).to_le_bytes());
+            fields.extend((data.len() as u32).to_le_bytes());
+            fields.extend((name.len() as u16).to_le_bytes());
+
+            buf.extend(0x04034b50u32.to_le_bytes());
+            buf.extend(&fields);
+            // Local extra fields are allowed to be different from central directory.
+            buf.extend(4u16.to_le_bytes());
+            buf.extend(name.as_bytes());
+            buf.extend([0xff, 0xff, 0, 0]);
+            buf.extend(&content);
+
+            cd.extend(0x02014b50u32.to_le_bytes());
+            cd.extend(20u16.to_le_bytes());
+            cd.extend(&fields);
+            cd.extend([0; 12]);
+            cd.extend(offset.to_le_bytes());
+            cd.extend(name.as_bytes());
+        }
+
+        let cd_offset = buf.len() as u32;
+        buf.extend(&cd);
+        buf.extend(0x06054b50u32.to_le_bytes());
+        buf.extend([0; 4]);
+        buf.extend((entries.len() as u16).to_le_bytes());
+        buf.extend((entries.len() as u16).to_le_bytes());
+        buf.extend((cd.len() as u32).to_le_bytes());
+        buf.extend(cd_offset.to_le_bytes());
+        buf.extend(7u16.to_le_bytes());
+        buf.extend(b"comment");
+        buf
+    }
+
+    async fn new_archive(path: &str, data: Vec<u8>) -> Operator {
+        let memory = Operator::new(Memory::default()).unwrap().finish();
+        memory.write(path, data).await.unwrap();
+        let builder = ArchiveBuilder::default().operator(memory).path(path);
+        Operator::new(builder).unwrap().finish()
+    }
+
+    async fn list_paths(op: &Operator, path: &str, recursive: bool) -> Vec<String> {
+        let entries: Vec<_> = op
+            .lister_with(path)
+            .recursive(recursive)
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        let mut paths: Vec<_> = entries.into_iter().map(|e| e.path().to_string()).collect();
+        paths.sort();
+        paths
+    }
+
+    #[tokio::test]
+    async fn test_zip() {
+        let large: Vec<u8> = (0..100_000u32).flat_map(|v| v.to_le_bytes()).collect();
+        let zip = build_zip(&[
+            ("a.txt", b"hello", false),
+            ("dir/b.bin", &large, true),
+            ("empty/", b"", false),
+        ])
+        .await;
+        let op = new_archive("data.zip", zip).await;
+
+        let meta = op.stat("a.txt").await.unwrap();
+        assert_eq!(meta.content_length(), 5);
+        assert_eq!(
+            meta.last_modified().unwrap().to_string(),
+            "2024-01-02 03:04:06 UTC"
+        );
+        assert_eq!(op.read("a.txt").await.unwrap().to_vec(), b"hello");
+        assert_eq!(
+            op.read_with("a.txt").range(1..3).await.unwrap().to_vec(),
+            b"el"
+        );
+
+        assert_eq!(op.read("dir/b.bin").await.unwrap().to_vec(), large);
+        assert_eq!(
+            op.read_with("dir/b.bin")
+                .range(1000..300_000)
+                .await
+                .unwrap()
+                .to_vec(),
+            large[1000..300_000]
+        );
+
+        assert!(op.stat("dir/").await.unwrap().is_dir());
+        assert!(op.stat("empty/").await.unwrap().is_dir());
+        assert_eq!(
+            op.stat("not_exist").await.unwrap_err().kind(),
+            ErrorKind::NotFound
+        );
+
+        assert_eq!(
+            list_paths(&op, "/", false).await,
+            ["a.txt", "dir/", "empty/"]
+        );
+        assert_eq!(
+            list_paths(&op, "/", true).await,
+            ["a.txt", "dir/b.bin", "empty/"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tar() {
+        let large: Vec<u8> = (0..100_000u32).flat_map(|v| v.to_le_bytes()).collect();
+        let long_name = format!("{}/long.txt", "d".repeat(120));
+
+        let mut tar = Vec::new();
+        tar_entry(&mut tar, "./dir/", b'5', b"");
+        tar_entry(&mut tar, "./dir/a.txt", b'0', b"hello");
+        tar_entry(&mut tar, "link", b'2', b"");
+        let record = format!("path={long_name}\n");
+        let pax = format!("{} {record}", record.len() + 4);
+        tar_entry(&mut tar, "pax", b'x', pax.as_bytes());
+        tar_entry(&mut tar, "ignored", b'0', b"world");
+        tar_entry(&mut tar, "large.bin", b'0', &large);
+        tar.extend([0; 1024]);
+
+        let gz = compress(GzipEncoder::new(Vec::new()), &tar)
+            .await
+            .into_inner();
+        let zst = compress(ZstdEncoder::new(Vec::new()), &tar)
+            .await
+            .into_inner();
+
+        for (path, data) in [("data.tar", tar), ("data.tgz", gz), ("data.tar.zst", zst)] {
+            let op = new_archive(path, data).await;
+
+            assert_eq!(op.read("dir/a.txt").await.unwrap().to_vec(), b"hello");
+            assert_eq!(op.read(&long_name).await.unwrap().to_vec(), b"world");
+            assert_eq!(
+                op.read_with("large.bin")
+                    .range(4..400_000)
+                    .await
+                    .unwrap()
+                    .to_vec(),
+                large[4..]
+            );
+
+            let meta = op.stat("dir/a.txt").await.unwrap();
+            assert_eq!(meta.last_modified().unwrap().timestamp(), 1700000000);
+            assert_eq!(
+                op.stat("link").await.unwrap_err().kind(),
+                ErrorKind::NotFound
+            );
+            assert_eq!(
+                list_paths(&op, "/", true).await,
+                [&long_name, "dir/", "dir/a.txt", "large.bin"]
+            );
+            assert_eq!(
+                list_paths(&op, "/", false).await,
+                [
+                    format!("{}/", "d".repeat(120)).as_str(),
+                    "dir/",
+                    "large.bin"
+                ]
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pack() {
+        let large: Vec<u8> = (0..100_000u32).flat_map(|v| v.to_le_bytes()).collect();
+        let long_name = format!("{}/长.txt", "d".repeat(120));
+
+        let src = Operator::new(Memory::default()).unwrap().finish();
+        src.write("tenant/a.txt", "hello").await.unwrap();
+        src.write("tenant/dir/large.bin", large.clone())
+            .await
+            .unwrap();
+        src.write("tenant/empty", "").await.unwrap();
+        src.write(&format!("tenant/{long_name}"), "world")
+            .await
+            .unwrap();
+        src.write("other.txt", "other").await.unwrap();
+
+        let dst = Operator::new(Memory::default()).unwrap().finish();
+        for (path, format) in [
+            ("out.zip", Format::Zip),
+            ("out.tar", Format::Tar),
+            ("out.tar.gz", Format::TarGz),
+            ("out.tar.zst", Format::TarZstd),
+        ] {
+            crate::archive::pack(&src, "tenant/", &dst, path, format)
+                .await
+                .unwrap();
+            let builder = ArchiveBuilder::default().operator(dst.clone()).path(path);
+            let op = Operator::new(builder).unwrap().finish();
+
+            assert_eq!(op.read("a.txt").await.unwrap().to_vec(), b"hello");
+            assert_eq!(op.read("dir/large.bin").await.unwrap().to_vec(), large);
+            assert_eq!(op.read(&long_name).await.unwrap().to_vec(), b"world");
+            assert_eq!(op.stat("empty").await.unwrap().content_length(), 0);
+            assert_eq!(
+                list_paths(&op, "/", true).await,
+                ["a.txt", &long_name, "dir/large.bin", "empty"]
+            );
+        }
+
+        let err = crate::archive::pack(&src, "tenant", &dst, "out.tar", Format::Tar)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotADirectory);
+    }
+
+    /// ChangeOnReadLayer rewrites objects right before they are read, like a
+    /// concurrent writer.
+    struct ChangeOnReadLayer;
+
+    impl<A: Access> Layer<A> for ChangeOnReadLayer {
+        type LayeredAccess = ChangeOnReadAccessor<A>;
+
+        fn layer(&self, inner: A) -> Self::LayeredAccess {
+            ChangeOnReadAccessor(inner)
+        }
+    }
+
+    #[derive(Debug)]
+    struct ChangeOnReadAccessor<A: Access>(A);
+
+    impl<A: Access> LayeredAccess for ChangeOnReadAccessor<A> {
+        type Inner = A;
+        type Reader = A::Reader;
+        type Writer = A::Writer;
+        type Lister = A::Lister;
+        type Deleter = A::Deleter;
+
+        fn inner(&self) -> &Self::Inner {
+            &self.0
+        }
+
+        async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+            let (_, mut w) = self.0.write(path, OpWrite::new()).await?;
+            oio::Write::write(&mut w, Buffer::from("changed while packing")).await?;
+            oio::Write::close(&mut w).await?;
+            self.0.read(path, args).await
+        }
+
+        async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+            self.0.write(path, args).await
+        }
+
+        async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+            self.0.list(path, args).await
+        }
+
+        async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
+            self.0.delete().await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pack_changed_object() {
+        let src = Operator::new(Memory::default()).unwrap().finish();
+        src.write("tenant/a.txt", "hello").await.unwrap();
+        let src = src.layer(ChangeOnReadLayer);
+
+        let dst = Operator::new(Memory::default()).unwrap().finish();
+        for (path, format) in [("out.zip", Format::Zip), ("out.tar", Format::Tar)] {
+            let err = crate::archive::pack(&src, "tenant/", &dst, path, format)
+                .await
+                .unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
+            assert!(!dst.exists(path).await.unwrap());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_build_from_config() {
+        let op = Operator::via_iter(
+            Scheme::Archive,
+            [
+                ("path".to_string(), "data.zip".to_string()),
+                ("operator_scheme".to_string(), "memory".to_string()),
+                ("operator.root".to_string(), "/archives".to_string()),
+            ],
+        )
+        .unwrap();
+        assert_eq!(op.info().scheme(), Scheme::Archive);
+        // The archive doesn't exist in the new memory service.
+        let err = op.stat("a.txt").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+
+        let err = Operator::via_iter(
+            Scheme::Archive,
+            [("path".to_string(), "data.zip".to_string())],
+        )
+        .err()
+        .expect("build without operator must fail");
+        assert_eq!(err.kind(), ErrorKind::ConfigInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_tar_metadata() {
+        use std::collections::HashMap;
+
+        use crate::archive::tar::build_headers;
+        use crate::archive::tar::end_blocks;
+
+        let last_modified = chrono::DateTime::from_timestamp(1700000000, 120_000_000).unwrap();
+        let user_metadata = HashMap::from([("owner".to_string(), "tenant-1".to_string())]);
+        let mut tar = build_headers("a.txt", false, 5, Some(last_modified), Some(&user_metadata));
+        tar.extend(b"hello");
+        tar.resize(tar.len().div_ceil(512) * 512, 0);
+        tar.extend(end_blocks());
+
+        let op = new_archive("data.tar", tar).await;
+        let meta = op.stat("a.txt").await.unwrap();
+        assert_eq!(meta.last_modified(), Some(last_modified));
+        assert_eq!(meta.user_metadata(), Some(&user_metadata));
+        assert_eq!(op.read("a.txt").await.unwrap().to_vec(), b"hello");
+    }
+}
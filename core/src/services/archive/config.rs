@@ -0,0 +1,60 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Config for archive service.
+#[derive(Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct ArchiveConfig {
+    /// root of the backend, it's a dir inside the archive.
+    pub root: Option<String>,
+    /// path of the archive in the underlying operator.
+    pub path: Option<String>,
+    /// format of the archive, one of `zip`, `tar`, `tar.gz` and `tar.zst`.
+    ///
+    /// It's detected from the extension of path if not set.
+    pub format: Option<String>,
+    /// scheme of the service where the archive is stored, like `s3` and `fs`.
+    ///
+    /// It's only used if the operator is not set by `ArchiveBuilder::operator`.
+    pub operator_scheme: Option<String>,
+    /// config of the service where the archive is stored, keys are prefixed with
+    /// `operator.`, like `operator.bucket`.
+    ///
+    /// Other unknown keys are collected here as well and ignored while building.
+    #[serde(flatten)]
+    pub operator_options: HashMap<String, String>,
+}
+
+impl Debug for ArchiveConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Options of the operator might contain credentials.
+        f.debug_struct("ArchiveConfig")
+            .field("root", &self.root)
+            .field("path", &self.path)
+            .field("format", &self.format)
+            .field("operator_scheme", &self.operator_scheme)
+            .finish_non_exhaustive()
+    }
+}
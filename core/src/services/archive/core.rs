@@ -0,0 +1,293 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use tokio::sync::OnceCell;
+
+use super::reader::*;
+use super::tar;
+use super::tar::TarSource;
+use super::zip;
+use crate::archive::Format;
+use crate::raw::*;
+use crate::*;
+
+/// Normalize the name of an entry in archive into its absolute path without leading `/`.
+///
+/// Returns `None` for the root dir.
+pub fn normalize_entry_path(name: &str) -> Option<String> {
+    // Some zip tools on windows use `\` as the separator.
+    let mut name = name.replace('\\', "/");
+    loop {
+        if let Some(v) = name.strip_prefix("./") {
+            name = v.to_string();
+        } else if let Some(v) = name.strip_prefix('/') {
+            name = v.to_string();
+        } else {
+            break;
+        }
+    }
+    (!name.is_empty() && name != ".").then_some(name)
+}
+
+/// Where the data of an entry is stored in the archive.
+#[derive(Debug, Clone, Copy)]
+pub enum Location {
+    /// Zip member, its data follows the local header at `header_offset`.
+    Zip {
+        header_offset: u64,
+        compressed_size: u64,
+        method: u16,
+        encrypted: bool,
+    },
+    /// Tar member, its data starts at `offset` of the decompressed tar stream.
+    Tar { offset: u64 },
+}
+
+/// ArchiveEntry is a file or dir in the archive, dirs don't have location.
+#[derive(Debug, Clone)]
+pub struct ArchiveEntry {
+    pub metadata: Metadata,
+    pub location: Option<Location>,
+}
+
+/// Index of all entries, keyed by their absolute paths without leading `/`.
+struct Index {
+    /// The etag of archive while building this index, reads are pinned to it
+    /// if the underlying service supports `read_with_if_match`.
+    etag: Option<String>,
+    size: u64,
+    entries: BTreeMap<String, ArchiveEntry>,
+}
+
+pub struct ArchiveCore {
+    pub info: Arc<AccessorInfo>,
+    pub op: Operator,
+    pub path: String,
+    pub format: Format,
+    index: OnceCell<Index>,
+}
+
+impl Debug for ArchiveCore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArchiveCore")
+            .field("op", &self.op)
+            .field("path", &self.path)
+            .field("format", &self.format)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ArchiveCore {
+    pub fn new(info: Arc<AccessorInfo>, op: Operator, path: String, format: Format) -> Self {
+        Self {
+            info,
+            op,
+            path,
+            format,
+            index: OnceCell::new(),
+        }
+    }
+
+    /// Load the index of archive at the first time it's used.
+    ///
+    /// Archives are expected to be immutable, the index is never reloaded.
+    async fn index(&self) -> Result<&Index> {
+        self.index.get_or_try_init(|| self.load_index()).await
+    }
+
+    async fn load_index(&self) -> Result<Index> {
+        let meta = self.op.stat(&self.path).await?;
+        let etag = meta
+            .etag()
+            .filter(|_| self.op.info().full_capability().read_with_if_match)
+            .map(String::from);
+        let size = meta.content_length();
+
+        let reader = self.reader(etag.as_deref()).await?;
+        let entries = match self.format {
+            Format::Zip => zip::read_entries(&reader, size).await?,
+            Format::Tar => tar::read_entries(TarSource::ranged(reader, size)).await?,
+            Format::TarGz | Format::TarZstd => {
+                let decoder = self.decoder(reader, 0, size).await?;
+                tar::read_entries(TarSource::stream(decoder)).await?
+            }
+        };
+
+        Ok(Index {
+            etag,
+            size,
+            entries: entries.into_iter().collect(),
+        })
+    }
+
+    async fn reader(&self, etag: Option<&str>) -> Result<Reader> {
+        let mut fut = self.op.reader_with(&self.path);
+        if let Some(etag) = etag {
+            fut = fut.if_match(etag);
+        }
+        fut.await
+    }
+
+    /// Build a decoder of the tar stream from archive data in `[offset, offset + size)`.
+    async fn decoder(&self, reader: Reader, offset: u64, size: u64) -> Result<Decoder> {
+        let r = reader
+            .into_futures_async_read(offset..offset + size)
+            .await?;
+        Ok(match self.format {
+            Format::TarGz => Decoder::gzip(r),
+            Format::TarZstd => Decoder::zstd(r),
+            _ => Decoder::Plain(r),
+        })
+    }
+
+    pub async fn stat(&self, path: &str) -> Result<Metadata> {
+        if path.is_empty() {
+            return Ok(Metadata::new(EntryMode::DIR));
+        }
+
+        let index = self.index().await?;
+        if let Some(entry) = index.entries.get(path) {
+            return Ok(entry.metadata.clone());
+        }
+        // Dirs might only exist as parents of other entries.
+        if path.ends_with('/')
+            && index
+                .entries
+                .range(path.to_string()..)
+                .next()
+                .is_some_and(|(k, _)| k.starts_with(path))
+        {
+            return Ok(Metadata::new(EntryMode::DIR));
+        }
+
+        Err(
+            Error::new(ErrorKind::NotFound, "entry not found in archive")
+                .with_context("path", path),
+        )
+    }
+
+    /// List all entries that start with `path` in ascending order.
+    pub async fn list(
+        &self,
+        path: &str,
+        start_after: Option<&str>,
+    ) -> Result<Vec<(String, Metadata)>> {
+        let index = self.index().await?;
+        Ok(index
+            .entries
+            .range(path.to_string()..)
+            .take_while(|(k, _)| k.starts_with(path))
+            .filter(|(k, _)| start_after.is_none_or(|v| k.as_str() > v))
+            .map(|(k, v)| (k.clone(), v.metadata.clone()))
+            .collect())
+    }
+
+    pub async fn read(&self, path: &str, range: BytesRange) -> Result<ArchiveReader> {
+        let index = self.index().await?;
+        let Some(entry) = index.entries.get(path) else {
+            return Err(
+                Error::new(ErrorKind::NotFound, "entry not found in archive")
+                    .with_context("path", path),
+            );
+        };
+        let Some(location) = entry.location else {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "entry is a dir").with_context("path", path)
+            );
+        };
+
+        let size = entry.metadata.content_length();
+        let offset = range.offset().min(size);
+        let len = range.size().unwrap_or(u64::MAX).min(size - offset);
+        if len == 0 {
+            return Ok(ArchiveReader::One(Buffer::new()));
+        }
+
+        let reader = self.reader(index.etag.as_deref()).await?;
+        match (self.format, location) {
+            (
+                _,
+                Location::Zip {
+                    header_offset,
+                    compressed_size,
+                    method,
+                    encrypted,
+                },
+            ) => {
+                if encrypted {
+                    return Err(Error::new(
+                        ErrorKind::Unsupported,
+                        "encrypted zip entry is not supported",
+                    )
+                    .with_context("path", path));
+                }
+                let data_offset = zip::data_offset(&reader, header_offset).await?;
+                match method {
+                    zip::METHOD_STORED => {
+                        let start = data_offset + offset;
+                        let s = reader.into_bytes_stream(start..start + len).await?;
+                        Ok(ArchiveReader::Two(RangeReader::new(s)))
+                    }
+                    zip::METHOD_DEFLATED => {
+                        let r = reader
+                            .into_futures_async_read(data_offset..data_offset + compressed_size)
+                            .await?;
+                        let decoder = Decoder::deflate(r);
+                        Ok(ArchiveReader::Three(DecodeReader::new(
+                            decoder, offset, len,
+                        )))
+                    }
+                    _ => Err(Error::new(
+                        ErrorKind::Unsupported,
+                        "compression method of zip entry is not supported",
+                    )
+                    .with_context("path", path)
+                    .with_context("method", method)),
+                }
+            }
+            (
+                Format::Tar,
+                Location::Tar {
+                    offset: data_offset,
+                },
+            ) => {
+                let start = data_offset + offset;
+                let s = reader.into_bytes_stream(start..start + len).await?;
+                Ok(ArchiveReader::Two(RangeReader::new(s)))
+            }
+            (
+                _,
+                Location::Tar {
+                    offset: data_offset,
+                },
+            ) => {
+                // Compressed tar can only be decompressed from the beginning.
+                let decoder = self.decoder(reader, 0, index.size).await?;
+                Ok(ArchiveReader::Three(DecodeReader::new(
+                    decoder,
+                    data_offset + offset,
+                    len,
+                )))
+            }
+        }
+    }
+}
@@ -0,0 +1,166 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::io;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+use async_compression::futures::bufread::DeflateDecoder;
+use async_compression::futures::bufread::GzipDecoder;
+use async_compression::futures::bufread::ZstdDecoder;
+use futures::AsyncRead;
+use futures::AsyncReadExt;
+use futures::TryStreamExt;
+
+use crate::raw::*;
+use crate::*;
+
+/// The max size of buffers returned by [`DecodeReader`].
+const BUFFER_SIZE: u64 = 256 * 1024;
+
+pub type ArchiveReader = ThreeWays<Buffer, RangeReader, DecodeReader>;
+
+/// Decoder decompresses data read from the archive.
+pub enum Decoder {
+    Plain(FuturesAsyncReader),
+    Deflate(DeflateDecoder<FuturesAsyncReader>),
+    Gzip(GzipDecoder<FuturesAsyncReader>),
+    Zstd(ZstdDecoder<FuturesAsyncReader>),
+}
+
+/// # Safety
+///
+/// We will only take `&mut Self` reference for Decoder.
+unsafe impl Sync for Decoder {}
+
+impl Decoder {
+    pub fn deflate(r: FuturesAsyncReader) -> Self {
+        Self::Deflate(DeflateDecoder::new(r))
+    }
+
+    pub fn gzip(r: FuturesAsyncReader) -> Self {
+        let mut d = GzipDecoder::new(r);
+        d.multiple_members(true);
+        Self::Gzip(d)
+    }
+
+    pub fn zstd(r: FuturesAsyncReader) -> Self {
+        let mut d = ZstdDecoder::new(r);
+        d.multiple_members(true);
+        Self::Zstd(d)
+    }
+
+    /// Skip `n` bytes, returns error if the data ends before that.
+    pub async fn skip(&mut self, n: u64) -> Result<()> {
+        let skipped = futures::io::copy(self.take(n), &mut futures::io::sink())
+            .await
+            .map_err(parse_io_error)?;
+        if skipped < n {
+            return Err(unexpected_eof());
+        }
+        Ok(())
+    }
+}
+
+impl AsyncRead for Decoder {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        match self.get_mut() {
+            Self::Plain(r) => Pin::new(r).poll_read(cx, buf),
+            Self::Deflate(r) => Pin::new(r).poll_read(cx, buf),
+            Self::Gzip(r) => Pin::new(r).poll_read(cx, buf),
+            Self::Zstd(r) => Pin::new(r).poll_read(cx, buf),
+        }
+    }
+}
+
+/// RangeReader reads entries stored without compression by range.
+pub struct RangeReader {
+    stream: FuturesBytesStream,
+}
+
+impl RangeReader {
+    pub fn new(stream: FuturesBytesStream) -> Self {
+        Self { stream }
+    }
+}
+
+impl oio::Read for RangeReader {
+    async fn read(&mut self) -> Result<Buffer> {
+        match self.stream.try_next().await.map_err(parse_io_error)? {
+            Some(bs) => Ok(Buffer::from(bs)),
+            None => Ok(Buffer::new()),
+        }
+    }
+}
+
+/// DecodeReader reads `size` bytes after skipping `offset` bytes of decompressed data.
+pub struct DecodeReader {
+    decoder: Decoder,
+    skip: u64,
+    remaining: u64,
+}
+
+impl DecodeReader {
+    pub fn new(decoder: Decoder, offset: u64, size: u64) -> Self {
+        Self {
+            decoder,
+            skip: offset,
+            remaining: size,
+        }
+    }
+}
+
+impl oio::Read for DecodeReader {
+    async fn read(&mut self) -> Result<Buffer> {
+        if self.skip > 0 {
+            self.decoder.skip(self.skip).await?;
+            self.skip = 0;
+        }
+        if self.remaining == 0 {
+            return Ok(Buffer::new());
+        }
+
+        let mut buf = vec![0; self.remaining.min(BUFFER_SIZE) as usize];
+        let n = self.decoder.read(&mut buf).await.map_err(parse_io_error)?;
+        if n == 0 {
+            return Err(unexpected_eof());
+        }
+        buf.truncate(n);
+        self.remaining -= n as u64;
+        Ok(Buffer::from(buf))
+    }
+}
+
+/// Take back the error of underlying service if it's wrapped in `err`.
+pub fn parse_io_error(err: io::Error) -> Error {
+    if err.get_ref().is_some_and(|e| e.is::<Error>()) {
+        let inner = err.into_inner().expect("inner error must exist");
+        return *inner
+            .downcast::<Error>()
+            .expect("inner error must be Error");
+    }
+    new_std_io_error(err)
+}
+
+pub fn unexpected_eof() -> Error {
+    Error::new(ErrorKind::Unexpected, "archive ends unexpectedly")
+}
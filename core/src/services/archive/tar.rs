@@ -0,0 +1,323 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+
+use bytes::Bytes;
+use chrono::DateTime;
+use futures::AsyncReadExt;
+
+use super::core::*;
+use super::reader::*;
+use crate::archive::tar::BLOCK_SIZE;
+use crate::archive::tar::PAX_USER_METADATA_PREFIX;
+use crate::*;
+
+/// The initial size of windows read by [`TarSource::Ranged`].
+const MIN_WINDOW_SIZE: u64 = 64 * 1024;
+/// The max size of windows read by [`TarSource::Ranged`].
+const MAX_WINDOW_SIZE: u64 = 8 * 1024 * 1024;
+
+/// TarSource provides data of the tar stream for parsing headers, offsets must
+/// be read in increasing order.
+pub enum TarSource {
+    /// Plain tar read by range.
+    ///
+    /// Headers are read in windows, so that entries next to each other are
+    /// fetched together. The window grows while windows are read one after
+    /// another, and shrinks once a large entry is skipped.
+    Ranged {
+        reader: Reader,
+        size: u64,
+        window: Bytes,
+        window_offset: u64,
+        window_size: u64,
+    },
+    /// Decompressed tar stream, which can only be read forward.
+    Stream { decoder: Box<Decoder>, pos: u64 },
+}
+
+impl TarSource {
+    pub fn ranged(reader: Reader, size: u64) -> Self {
+        Self::Ranged {
+            reader,
+            size,
+            window: Bytes::new(),
+            window_offset: 0,
+            window_size: MIN_WINDOW_SIZE,
+        }
+    }
+
+    pub fn stream(decoder: Decoder) -> Self {
+        Self::Stream {
+            decoder: Box::new(decoder),
+            pos: 0,
+        }
+    }
+
+    /// Read `len` bytes at `offset`, less bytes are returned at the end of archive.
+    async fn read_at(&mut self, offset: u64, len: usize) -> Result<Bytes> {
+        match self {
+            Self::Ranged {
+                reader,
+                size,
+                window,
+                window_offset,
+                window_size,
+            } => {
+                let end = (offset + len as u64).min(*size);
+                if offset >= end {
+                    return Ok(Bytes::new());
+                }
+
+                let window_end = *window_offset + window.len() as u64;
+                if offset < *window_offset || end > window_end {
+                    *window_size = if offset == window_end {
+                        (*window_size * 2).min(MAX_WINDOW_SIZE)
+                    } else {
+                        MIN_WINDOW_SIZE
+                    };
+                    let fetch_end = (offset + (len as u64).max(*window_size)).min(*size);
+                    *window = reader.read(offset..fetch_end).await?.to_bytes();
+                    *window_offset = offset;
+                }
+
+                let start = (offset - *window_offset) as usize;
+                let end = (start + (end - offset) as usize).min(window.len());
+                Ok(window.slice(start..end))
+            }
+            Self::Stream { decoder, pos } => {
+                if offset > *pos {
+                    decoder.skip(offset - *pos).await?;
+                    *pos = offset;
+                }
+
+                let mut buf = Vec::with_capacity(len);
+                decoder
+                    .take(len as u64)
+                    .read_to_end(&mut buf)
+                    .await
+                    .map_err(parse_io_error)?;
+                *pos += buf.len() as u64;
+                Ok(buf.into())
+            }
+        }
+    }
+}
+
+/// Read all entries by walking through headers of tar archive.
+///
+/// Only regular files and dirs are returned, links and special files are skipped.
+pub async fn read_entries(mut src: TarSource) -> Result<Vec<(String, ArchiveEntry)>> {
+    let mut entries = Vec::new();
+    let mut offset = 0;
+    // Extended headers and GNU long names apply to the next entry.
+    let mut pax = HashMap::new();
+    let mut long_name = None;
+
+    loop {
+        let header = src.read_at(offset, BLOCK_SIZE).await?;
+        // Archives end with zero blocks, but some writers omit them.
+        if header.is_empty() || header.iter().all(|b| *b == 0) {
+            break;
+        }
+        if header.len() < BLOCK_SIZE {
+            return Err(invalid("tar header is truncated"));
+        }
+        if !verify_checksum(&header)? {
+            return Err(invalid("invalid checksum of tar header"));
+        }
+
+        let typeflag = header[156];
+        let mut size = parse_numeric(&header[124..136])?;
+        let data_offset = offset + BLOCK_SIZE as u64;
+        match typeflag {
+            b'x' => pax = parse_pax(&src.read_at(data_offset, size as usize).await?),
+            b'L' => {
+                let data = src.read_at(data_offset, size as usize).await?;
+                long_name = Some(parse_str(&data));
+            }
+            b'g' | b'K' => {}
+            _ => {
+                if let Some(v) = pax.get("size") {
+                    size = v
+                        .parse()
+                        .map_err(|_| invalid("invalid size in pax header"))?;
+                }
+                if matches!(typeflag, b'0' | b'\0' | b'7' | b'5') {
+                    let name = pax
+                        .remove("path")
+                        .or_else(|| long_name.take())
+                        .unwrap_or_else(|| header_name(&header));
+                    if let Some(path) = normalize_entry_path(&name) {
+                        let last_modified = match pax.get("mtime") {
+                            Some(v) => parse_pax_time(v),
+                            None => DateTime::from_timestamp(
+                                parse_numeric(&header[136..148])? as i64,
+                                0,
+                            ),
+                        };
+                        let (path, mut entry) =
+                            build_entry(path, typeflag == b'5', size, data_offset, last_modified);
+                        let user_metadata: HashMap<_, _> = pax
+                            .iter()
+                            .filter_map(|(k, v)| {
+                                let k = k.strip_prefix(PAX_USER_METADATA_PREFIX)?;
+                                Some((k.to_string(), v.clone()))
+                            })
+                            .collect();
+                        if !user_metadata.is_empty() {
+                            entry.metadata = entry.metadata.with_user_metadata(user_metadata);
+                        }
+                        entries.push((path, entry));
+                    }
+                }
+                pax.clear();
+                long_name = None;
+            }
+        }
+
+        offset = data_offset + size.div_ceil(BLOCK_SIZE as u64) * BLOCK_SIZE as u64;
+    }
+
+    Ok(entries)
+}
+
+fn build_entry(
+    mut path: String,
+    is_dir: bool,
+    size: u64,
+    data_offset: u64,
+    last_modified: Option<DateTime<chrono::Utc>>,
+) -> (String, ArchiveEntry) {
+    let mut entry = if is_dir || path.ends_with('/') {
+        if !path.ends_with('/') {
+            path.push('/');
+        }
+        ArchiveEntry {
+            metadata: Metadata::new(EntryMode::DIR),
+            location: None,
+        }
+    } else {
+        ArchiveEntry {
+            metadata: Metadata::new(EntryMode::FILE).with_content_length(size),
+            location: Some(Location::Tar {
+                offset: data_offset,
+            }),
+        }
+    };
+    if let Some(v) = last_modified {
+        entry.metadata.set_last_modified(v);
+    }
+    (path, entry)
+}
+
+/// The name of entry, which is joined with prefix for ustar archives.
+fn header_name(header: &[u8]) -> String {
+    let name = parse_str(&header[0..100]);
+    // GNU tar uses `ustar  \0` as magic and doesn't have prefix.
+    if &header[257..263] != b"ustar\0" {
+        return name;
+    }
+    let prefix = parse_str(&header[345..500]);
+    if prefix.is_empty() {
+        name
+    } else {
+        format!("{prefix}/{name}")
+    }
+}
+
+/// The checksum is the sum of all header bytes, with the checksum field taken as spaces.
+///
+/// Some old writers sum bytes as signed chars, both are accepted.
+fn verify_checksum(header: &[u8]) -> Result<bool> {
+    let expected = parse_numeric(&header[148..156])?;
+    let (mut unsigned, mut signed) = (0u64, 0i64);
+    for (i, b) in header[..BLOCK_SIZE].iter().enumerate() {
+        let b = if (148..156).contains(&i) { b' ' } else { *b };
+        unsigned += b as u64;
+        signed += b as i8 as i64;
+    }
+    Ok(unsigned == expected || signed == expected as i64)
+}
+
+/// Parse numeric fields, which are octal strings or big-endian base-256
+/// numbers with the highest bit set.
+fn parse_numeric(field: &[u8]) -> Result<u64> {
+    if field[0] & 0x80 != 0 {
+        let v = field[1..]
+            .iter()
+            .fold(0u64, |acc, b| (acc << 8) | *b as u64);
+        return Ok(v);
+    }
+
+    let s = parse_str(field);
+    let s = s.trim_matches(|c: char| c == ' ' || c == '\0');
+    if s.is_empty() {
+        return Ok(0);
+    }
+    u64::from_str_radix(s, 8).map_err(|_| invalid("invalid numeric field in tar header"))
+}
+
+/// Parse a nul-terminated string.
+fn parse_str(field: &[u8]) -> String {
+    let end = field.iter().position(|b| *b == 0).unwrap_or(field.len());
+    String::from_utf8_lossy(&field[..end]).to_string()
+}
+
+/// Parse records like `<len> <key>=<value>\n` of pax extended header.
+fn parse_pax(mut data: &[u8]) -> HashMap<String, String> {
+    let mut records = HashMap::new();
+    while let Some(sp) = data.iter().position(|b| *b == b' ') {
+        let Some(len) = std::str::from_utf8(&data[..sp])
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+        else {
+            break;
+        };
+        if len <= sp || len > data.len() {
+            break;
+        }
+
+        let record = &data[sp + 1..len];
+        let record = record.strip_suffix(b"\n").unwrap_or(record);
+        if let Some(eq) = record.iter().position(|b| *b == b'=') {
+            records.insert(
+                String::from_utf8_lossy(&record[..eq]).to_string(),
+                String::from_utf8_lossy(&record[eq + 1..]).to_string(),
+            );
+        }
+        data = &data[len..];
+    }
+    records
+}
+
+/// Parse time like `1700000000.123456789` of pax extended header.
+fn parse_pax_time(v: &str) -> Option<DateTime<chrono::Utc>> {
+    let (secs, frac) = v.split_once('.').unwrap_or((v, ""));
+    let secs = secs.parse().ok()?;
+    let nanos = if frac.is_empty() {
+        0
+    } else {
+        format!("{:0<9}", &frac[..frac.len().min(9)]).parse().ok()?
+    };
+    DateTime::from_timestamp(secs, nanos)
+}
+
+fn invalid(msg: &'static str) -> Error {
+    Error::new(ErrorKind::Unexpected, msg).with_context("format", "tar")
+}
@@ -0,0 +1,258 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use chrono::DateTime;
+use chrono::NaiveDate;
+use chrono::Utc;
+
+use super::core::*;
+use crate::*;
+
+pub const METHOD_STORED: u16 = 0;
+pub const METHOD_DEFLATED: u16 = 8;
+
+const EOCD_SIGNATURE: u32 = 0x06054b50;
+const EOCD_SIZE: u64 = 22;
+const MAX_COMMENT_SIZE: u64 = 65535;
+const ZIP64_LOCATOR_SIGNATURE: u32 = 0x07064b50;
+const ZIP64_LOCATOR_SIZE: u64 = 20;
+const ZIP64_EOCD_SIGNATURE: u32 = 0x06064b50;
+const ZIP64_EOCD_SIZE: u64 = 56;
+const CENTRAL_HEADER_SIGNATURE: u32 = 0x02014b50;
+const CENTRAL_HEADER_SIZE: usize = 46;
+const LOCAL_HEADER_SIGNATURE: u32 = 0x04034b50;
+const LOCAL_HEADER_SIZE: u64 = 30;
+
+const EXTRA_ZIP64: u16 = 0x0001;
+const EXTRA_TIMESTAMP: u16 = 0x5455;
+
+const FLAG_ENCRYPTED: u16 = 0x0001;
+
+/// Read all entries from the central directory of zip archive.
+///
+/// Only the end of central directory and the central directory are read.
+pub async fn read_entries(reader: &Reader, size: u64) -> Result<Vec<(String, ArchiveEntry)>> {
+    let (cd_offset, cd_size, count) = read_end_of_central_directory(reader, size).await?;
+    if cd_offset.checked_add(cd_size).is_none_or(|end| end > size) {
+        return Err(invalid("central directory is out of range"));
+    }
+    let cd = reader
+        .read(cd_offset..cd_offset + cd_size)
+        .await?
+        .to_bytes();
+
+    let mut entries = Vec::with_capacity(count.min(u16::MAX as u64) as usize);
+    let mut pos = 0;
+    while pos < cd.len() {
+        let b = &cd[pos..];
+        if b.len() < CENTRAL_HEADER_SIZE || le_u32(b, 0) != CENTRAL_HEADER_SIGNATURE {
+            return Err(invalid("invalid central directory file header"));
+        }
+        let flags = le_u16(b, 8);
+        let method = le_u16(b, 10);
+        let mut last_modified = dos_datetime(le_u16(b, 14), le_u16(b, 12));
+        let mut compressed_size = le_u32(b, 20) as u64;
+        let mut size = le_u32(b, 24) as u64;
+        let name_len = le_u16(b, 28) as usize;
+        let extra_len = le_u16(b, 30) as usize;
+        let comment_len = le_u16(b, 32) as usize;
+        let mut header_offset = le_u32(b, 42) as u64;
+
+        let name_end = CENTRAL_HEADER_SIZE + name_len;
+        let extra_end = name_end + extra_len;
+        if b.len() < extra_end + comment_len {
+            return Err(invalid("central directory file header is truncated"));
+        }
+        let name = String::from_utf8_lossy(&b[CENTRAL_HEADER_SIZE..name_end]);
+
+        for (id, data) in extra_fields(&b[name_end..extra_end]) {
+            match id {
+                // Zip64 fields only exist for the values that overflowed, in this order.
+                EXTRA_ZIP64 => {
+                    let mut fields = data.chunks_exact(8).map(|v| le_u64(v, 0));
+                    for v in [&mut size, &mut compressed_size, &mut header_offset] {
+                        if *v == u32::MAX as u64 {
+                            *v = fields
+                                .next()
+                                .ok_or_else(|| invalid("zip64 extra field is truncated"))?;
+                        }
+                    }
+                }
+                // Extended timestamp, the first bit of flags means mtime is present.
+                EXTRA_TIMESTAMP if data.len() >= 5 && data[0] & 1 != 0 => {
+                    last_modified = DateTime::from_timestamp(le_u32(data, 1) as i32 as i64, 0);
+                }
+                _ => {}
+            }
+        }
+        pos += extra_end + comment_len;
+
+        let Some(path) = normalize_entry_path(&name) else {
+            continue;
+        };
+        let entry = if path.ends_with('/') {
+            let mut metadata = Metadata::new(EntryMode::DIR);
+            if let Some(v) = last_modified {
+                metadata.set_last_modified(v);
+            }
+            ArchiveEntry {
+                metadata,
+                location: None,
+            }
+        } else {
+            let mut metadata = Metadata::new(EntryMode::FILE).with_content_length(size);
+            if let Some(v) = last_modified {
+                metadata.set_last_modified(v);
+            }
+            ArchiveEntry {
+                metadata,
+                location: Some(Location::Zip {
+                    header_offset,
+                    compressed_size,
+                    method,
+                    encrypted: flags & FLAG_ENCRYPTED != 0,
+                }),
+            }
+        };
+        entries.push((path, entry));
+    }
+
+    Ok(entries)
+}
+
+/// Returns the offset, size and entry count of central directory.
+async fn read_end_of_central_directory(reader: &Reader, size: u64) -> Result<(u64, u64, u64)> {
+    if size < EOCD_SIZE {
+        return Err(invalid("file is too small to be a zip archive"));
+    }
+
+    // The end of central directory is followed by a comment of at most 64 KiB.
+    let tail_size = size.min(EOCD_SIZE + MAX_COMMENT_SIZE);
+    let tail_offset = size - tail_size;
+    let tail = reader.read(tail_offset..size).await?.to_bytes();
+    if (tail.len() as u64) < EOCD_SIZE {
+        return Err(invalid("file is too small to be a zip archive"));
+    }
+
+    let candidates = || {
+        (0..=tail.len() - EOCD_SIZE as usize)
+            .rev()
+            .filter(|&i| le_u32(&tail, i) == EOCD_SIGNATURE)
+    };
+    // Prefer the record whose comment ends exactly at the end of file.
+    let pos = candidates()
+        .find(|&i| i + EOCD_SIZE as usize + le_u16(&tail, i + 20) as usize == tail.len())
+        .or_else(|| candidates().next())
+        .ok_or_else(|| invalid("end of central directory record not found"))?;
+
+    let count = le_u16(&tail, pos + 10) as u64;
+    let cd_size = le_u32(&tail, pos + 12) as u64;
+    let cd_offset = le_u32(&tail, pos + 16) as u64;
+    if count != u16::MAX as u64 && cd_size != u32::MAX as u64 && cd_offset != u32::MAX as u64 {
+        return Ok((cd_offset, cd_size, count));
+    }
+
+    // Values overflowed, they are stored in the zip64 end of central directory.
+    let eocd_offset = tail_offset + pos as u64;
+    if eocd_offset < ZIP64_LOCATOR_SIZE {
+        return Err(invalid("zip64 end of central directory locator not found"));
+    }
+    let locator = reader
+        .read(eocd_offset - ZIP64_LOCATOR_SIZE..eocd_offset)
+        .await?
+        .to_bytes();
+    if locator.len() < ZIP64_LOCATOR_SIZE as usize || le_u32(&locator, 0) != ZIP64_LOCATOR_SIGNATURE
+    {
+        return Err(invalid("zip64 end of central directory locator not found"));
+    }
+
+    let offset = le_u64(&locator, 8);
+    if offset
+        .checked_add(ZIP64_EOCD_SIZE)
+        .is_none_or(|end| end > size)
+    {
+        return Err(invalid("zip64 end of central directory is out of range"));
+    }
+    let eocd = reader
+        .read(offset..offset + ZIP64_EOCD_SIZE)
+        .await?
+        .to_bytes();
+    if eocd.len() < ZIP64_EOCD_SIZE as usize || le_u32(&eocd, 0) != ZIP64_EOCD_SIGNATURE {
+        return Err(invalid("invalid zip64 end of central directory record"));
+    }
+    Ok((le_u64(&eocd, 48), le_u64(&eocd, 40), le_u64(&eocd, 32)))
+}
+
+/// Returns the offset of data of the entry whose local file header is at `header_offset`.
+///
+/// The lengths of name and extra field in local header can be different from
+/// the central directory, so the local header must be read.
+pub async fn data_offset(reader: &Reader, header_offset: u64) -> Result<u64> {
+    let b = reader
+        .read(header_offset..header_offset + LOCAL_HEADER_SIZE)
+        .await?
+        .to_bytes();
+    if b.len() < LOCAL_HEADER_SIZE as usize || le_u32(&b, 0) != LOCAL_HEADER_SIGNATURE {
+        return Err(invalid("invalid local file header"));
+    }
+    Ok(header_offset + LOCAL_HEADER_SIZE + le_u16(&b, 26) as u64 + le_u16(&b, 28) as u64)
+}
+
+/// Iterate `(id, data)` of extra fields, truncated fields are ignored.
+fn extra_fields(mut b: &[u8]) -> impl Iterator<Item = (u16, &[u8])> {
+    std::iter::from_fn(move || {
+        if b.len() < 4 {
+            return None;
+        }
+        let id = le_u16(b, 0);
+        let len = le_u16(b, 2) as usize;
+        let data = b.get(4..4 + len)?;
+        b = &b[4 + len..];
+        Some((id, data))
+    })
+}
+
+/// Convert MS-DOS date and time into datetime, which has no timezone and is taken as UTC.
+fn dos_datetime(date: u16, time: u16) -> Option<DateTime<Utc>> {
+    NaiveDate::from_ymd_opt(
+        1980 + (date >> 9) as i32,
+        ((date >> 5) & 0xf) as u32,
+        (date & 0x1f) as u32,
+    )?
+    .and_hms_opt(
+        (time >> 11) as u32,
+        ((time >> 5) & 0x3f) as u32,
+        ((time & 0x1f) * 2) as u32,
+    )
+    .map(|v| v.and_utc())
+}
+
+fn le_u16(b: &[u8], i: usize) -> u16 {
+    u16::from_le_bytes([b[i], b[i + 1]])
+}
+
+fn le_u32(b: &[u8], i: usize) -> u32 {
+    u32::from_le_bytes(b[i..i + 4].try_into().expect("slice must have 4 bytes"))
+}
+
+fn le_u64(b: &[u8], i: usize) -> u64 {
+    u64::from_le_bytes(b[i..i + 8].try_into().expect("slice must have 8 bytes"))
+}
+
+fn invalid(msg: &'static str) -> Error {
+    Error::new(ErrorKind::Unexpected, msg).with_context("format", "zip")
+}
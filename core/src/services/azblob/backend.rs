@@ -29,6 +29,7 @@ use reqsign::AzureStorageLoader;
 use reqsign::AzureStorageSigner;
 use sha2::Digest;
 use sha2::Sha256;
+use uuid::Uuid;
 
 use super::core::constants::X_MS_META_PREFIX;
 use super::core::constants::X_MS_VERSION_ID;
@@ -414,6 +415,7 @@ impl Builder for AzblobBuilder {
                             write_can_append: true,
                             write_can_empty: true,
                             write_can_multi: true,
+                            write_can_random_access: true,
                             write_with_cache_control: true,
                             write_with_content_type: true,
                             write_with_if_not_exists: true,
@@ -425,9 +427,18 @@ impl Builder for AzblobBuilder {
 
                             copy: true,
                             copy_with_if_not_exists: true,
+                            concat: true,
+                            // A block blob can include at most 50000 blocks.
+                            //
+                            // ref: <https://learn.microsoft.com/en-us/rest/api/storageservices/put-block-list>
+                            concat_max_sources: Some(50000),
+
+                            update_metadata: true,
+                            update_metadata_with_if_match: true,
 
                             list: true,
                             list_with_recursive: true,
+                            list_with_continuation_token: true,
 
                             presign: self.config.sas_token.is_some(),
                             presign_stat: self.config.sas_token.is_some(),
@@ -467,6 +478,14 @@ pub struct AzblobBackend {
     core: Arc<AzblobCore>,
 }
 
+#[derive(Clone)]
+struct PutBlockFromUrlInput {
+    core: Arc<AzblobCore>,
+    path: Arc<String>,
+    block_id: Uuid,
+    source: String,
+}
+
 impl Access for AzblobBackend {
     type Reader = HttpBody;
     type Writer = AzblobWriters;
@@ -501,6 +520,61 @@ impl Access for AzblobBackend {
         }
     }
 
+    async fn update_metadata(
+        &self,
+        path: &str,
+        args: OpUpdateMetadata,
+    ) -> Result<RpUpdateMetadata> {
+        let update = args.update();
+
+        let mut op = OpStat::new();
+        if let Some(v) = update.if_match() {
+            op = op.with_if_match(v);
+        }
+        let mut meta = self.stat(path, op).await?.into_metadata();
+        update.apply(&mut meta);
+
+        // Every request is conditional on the etag returned by the previous
+        // one, so that we never mix our update with a concurrent write.
+        let mut etag = meta.etag().map(|v| v.to_string());
+
+        if update.content_type().is_some()
+            || update.cache_control().is_some()
+            || update.content_disposition().is_some()
+        {
+            let resp = self
+                .core
+                .azblob_set_blob_properties(path, &meta, etag.as_deref())
+                .await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp));
+            }
+            etag = parse_etag(resp.headers())?.map(|v| v.to_string());
+            if let Some(v) = parse_last_modified(resp.headers())? {
+                meta.set_last_modified(v);
+            }
+        }
+
+        if let Some(user_metadata) = update.user_metadata() {
+            let resp = self
+                .core
+                .azblob_set_blob_metadata(path, user_metadata, etag.as_deref())
+                .await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp));
+            }
+            etag = parse_etag(resp.headers())?.map(|v| v.to_string());
+            if let Some(v) = parse_last_modified(resp.headers())? {
+                meta.set_last_modified(v);
+            }
+        }
+
+        if let Some(v) = etag {
+            meta.set_etag(&v);
+        }
+        Ok(RpUpdateMetadata::new(meta))
+    }
+
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
         let resp = self.core.azblob_get_blob(path, args.range(), &args).await?;
 
@@ -520,11 +594,10 @@ impl Access for AzblobBackend {
         let w = if args.append() {
             AzblobWriters::Two(oio::AppendWriter::new(w))
         } else {
-            AzblobWriters::One(oio::BlockWriter::new(
-                self.core.info.clone(),
-                w,
-                args.concurrent(),
-            ))
+            AzblobWriters::One(
+                oio::BlockWriter::new(self.core.info.clone(), w, args.concurrent())
+                    .with_block_size(args.chunk()),
+            )
         };
 
         Ok((RpWrite::default(), w))
@@ -545,7 +618,10 @@ impl Access for AzblobBackend {
             args.limit(),
         );
 
-        Ok((RpList::default(), oio::PageLister::new(l)))
+        Ok((
+            RpList::default(),
+            oio::PageLister::new(l).with_continuation_token(args.continuation_token()),
+        ))
     }
 
     async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
@@ -559,6 +635,63 @@ impl Access for AzblobBackend {
         }
     }
 
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        let mut tasks = ConcurrentTasks::new(
+            self.core.info.executor(),
+            args.concurrent().max(1),
+            args.concurrent().max(1),
+            |input: PutBlockFromUrlInput| {
+                Box::pin(async move {
+                    let fut = input.core.azblob_put_block_from_url(
+                        &input.path,
+                        input.block_id,
+                        &input.source,
+                    );
+                    match fut.await {
+                        Ok(resp) if resp.status() == StatusCode::CREATED => {
+                            let block_id = input.block_id;
+                            (input, Ok(block_id))
+                        }
+                        Ok(resp) => (input, Err(parse_error(resp))),
+                        Err(err) => (input, Err(err)),
+                    }
+                })
+            },
+        );
+
+        let path = Arc::new(path.to_string());
+        for source in args.sources() {
+            tasks
+                .execute(PutBlockFromUrlInput {
+                    core: self.core.clone(),
+                    path: path.clone(),
+                    block_id: Uuid::new_v4(),
+                    source: source.clone(),
+                })
+                .await?;
+        }
+
+        let mut block_ids = Vec::with_capacity(args.sources().len());
+        while let Some(block_id) = tasks.next().await {
+            block_ids.push(block_id?);
+        }
+
+        let mut op = OpWrite::new();
+        if let Some(v) = args.content_type() {
+            op = op.with_content_type(v);
+        }
+        // Uncommitted blocks will be garbage collected by Azure, no need to abort.
+        let resp = self
+            .core
+            .azblob_complete_put_block_list(&path, block_ids, &op)
+            .await?;
+
+        match resp.status() {
+            StatusCode::CREATED | StatusCode::OK => Ok(RpConcat::default()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         let req = match args.operation() {
             PresignOperation::Stat(v) => self.core.azblob_head_blob_request(path, v),
@@ -570,7 +703,7 @@ impl Access for AzblobBackend {
                 self.core
                     .azblob_put_blob_request(path, None, &OpWrite::default(), Buffer::new())
             }
-            PresignOperation::Delete(_) => Err(Error::new(
+            PresignOperation::Delete(_) | PresignOperation::UploadPart(_) => Err(Error::new(
                 ErrorKind::Unsupported,
                 "operation is not supported",
             )),
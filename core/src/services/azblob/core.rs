@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Debug;
 use std::fmt::Formatter;
@@ -51,7 +52,12 @@ pub mod constants {
 
     pub const X_MS_BLOB_TYPE: &str = "x-ms-blob-type";
     pub const X_MS_COPY_SOURCE: &str = "x-ms-copy-source";
+    pub const X_MS_COPY_SOURCE_AUTHORIZATION: &str = "x-ms-copy-source-authorization";
     pub const X_MS_BLOB_CACHE_CONTROL: &str = "x-ms-blob-cache-control";
+    pub const X_MS_BLOB_CONTENT_TYPE: &str = "x-ms-blob-content-type";
+    pub const X_MS_BLOB_CONTENT_DISPOSITION: &str = "x-ms-blob-content-disposition";
+    pub const X_MS_BLOB_CONTENT_ENCODING: &str = "x-ms-blob-content-encoding";
+    pub const X_MS_BLOB_CONTENT_MD5: &str = "x-ms-blob-content-md5";
     pub const X_MS_BLOB_CONDITION_APPENDPOS: &str = "x-ms-blob-condition-appendpos";
     pub const X_MS_META_PREFIX: &str = "x-ms-meta-";
 
@@ -477,6 +483,55 @@ impl AzblobCore {
         self.send(req).await
     }
 
+    /// Put a block by copying the whole `source` blob.
+    ///
+    /// Azure reads the source on behalf of us, so it must be authorized by a
+    /// SAS token or a bearer token instead of shared key.
+    ///
+    /// ref: <https://learn.microsoft.com/en-us/rest/api/storageservices/put-block-from-url>
+    pub async fn azblob_put_block_from_url(
+        &self,
+        path: &str,
+        block_id: Uuid,
+        source: &str,
+    ) -> Result<Response<Buffer>> {
+        let url = QueryPairsWriter::new(&self.build_path_url(path))
+            .push("comp", "block")
+            .push(
+                "blockid",
+                &percent_encode_path(&BASE64_STANDARD.encode(block_id.as_bytes())),
+            )
+            .finish();
+
+        let mut req = Request::put(&url).header(CONTENT_LENGTH, 0);
+        // Set SSE headers.
+        req = self.insert_sse_headers(req);
+
+        let cred = self.load_credential().await?;
+        if let AzureStorageCredential::BearerToken(token, _) = &cred {
+            req = req
+                .header(constants::X_MS_COPY_SOURCE, self.build_path_url(source))
+                .header(
+                    constants::X_MS_COPY_SOURCE_AUTHORIZATION,
+                    format!("Bearer {token}"),
+                );
+        } else {
+            let mut source_req = Request::get(self.build_path_url(source))
+                .body(())
+                .map_err(new_request_build_error)?;
+            self.sign_query(&mut source_req).await?;
+            req = req.header(constants::X_MS_COPY_SOURCE, source_req.uri().to_string());
+        }
+
+        let mut req = req
+            .extension(Operation::Concat)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     fn azblob_complete_put_block_list_request(
         &self,
         path: &str,
@@ -492,6 +547,9 @@ impl AzblobCore {
         if let Some(cache_control) = args.cache_control() {
             req = req.header(constants::X_MS_BLOB_CACHE_CONTROL, cache_control);
         }
+        if let Some(ty) = args.content_type() {
+            req = req.header(constants::X_MS_BLOB_CONTENT_TYPE, ty);
+        }
 
         let content = quick_xml::se::to_string(&PutBlockListRequest {
             latest: block_ids
@@ -602,6 +660,78 @@ impl AzblobCore {
         self.send(req).await
     }
 
+    /// Set the system properties of a blob.
+    ///
+    /// Azblob clears the properties that are not specified, so `meta` MUST
+    /// contain all properties that should be kept.
+    pub async fn azblob_set_blob_properties(
+        &self,
+        path: &str,
+        meta: &Metadata,
+        if_match: Option<&str>,
+    ) -> Result<Response<Buffer>> {
+        let url = format!("{}?comp=properties", self.build_path_url(path));
+
+        let mut req = Request::put(&url).header(CONTENT_LENGTH, 0);
+
+        if let Some(v) = meta.content_type() {
+            req = req.header(constants::X_MS_BLOB_CONTENT_TYPE, v);
+        }
+        if let Some(v) = meta.cache_control() {
+            req = req.header(constants::X_MS_BLOB_CACHE_CONTROL, v);
+        }
+        if let Some(v) = meta.content_disposition() {
+            req = req.header(constants::X_MS_BLOB_CONTENT_DISPOSITION, v);
+        }
+        if let Some(v) = meta.content_encoding() {
+            req = req.header(constants::X_MS_BLOB_CONTENT_ENCODING, v);
+        }
+        if let Some(v) = meta.content_md5() {
+            req = req.header(constants::X_MS_BLOB_CONTENT_MD5, v);
+        }
+        if let Some(v) = if_match {
+            req = req.header(IF_MATCH, v);
+        }
+
+        let mut req = req
+            .extension(Operation::UpdateMetadata)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    /// Replace all user metadata of a blob.
+    pub async fn azblob_set_blob_metadata(
+        &self,
+        path: &str,
+        user_metadata: &HashMap<String, String>,
+        if_match: Option<&str>,
+    ) -> Result<Response<Buffer>> {
+        let url = format!("{}?comp=metadata", self.build_path_url(path));
+
+        let mut req = Request::put(&url).header(CONTENT_LENGTH, 0);
+
+        // Set SSE headers.
+        req = self.insert_sse_headers(req);
+
+        for (key, value) in user_metadata {
+            req = req.header(format!("{X_MS_META_PREFIX}{key}"), value)
+        }
+        if let Some(v) = if_match {
+            req = req.header(IF_MATCH, v);
+        }
+
+        let mut req = req
+            .extension(Operation::UpdateMetadata)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     pub async fn azblob_list_blobs(
         &self,
         path: &str,
@@ -19,6 +19,7 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
 
+use bytes::Buf;
 use http::Request;
 use http::Response;
 use http::StatusCode;
@@ -29,6 +30,7 @@ use super::core::constants;
 use super::core::parse_file_info;
 use super::core::B2Core;
 use super::core::B2Signer;
+use super::core::ListUnfinishedLargeFilesResponse;
 use super::delete::B2Deleter;
 use super::error::parse_error;
 use super::lister::B2Lister;
@@ -223,6 +225,9 @@ impl Builder for B2Builder {
                             list_with_start_after: true,
                             list_with_recursive: true,
 
+                            list_uploads: true,
+                            abort_upload: true,
+
                             presign: true,
                             presign_read: true,
                             presign_write: true,
@@ -349,6 +354,50 @@ impl Access for B2Backend {
         }
     }
 
+    async fn list_uploads(&self, path: &str, _: OpListUploads) -> Result<RpListUploads> {
+        let mut uploads = Vec::new();
+        let mut start_file_id: Option<String> = None;
+
+        loop {
+            let resp = self
+                .core
+                .list_unfinished_large_files(path, start_file_id.as_deref())
+                .await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp));
+            }
+
+            let output: ListUnfinishedLargeFilesResponse =
+                serde_json::from_reader(resp.into_body().reader())
+                    .map_err(new_json_deserialize_error)?;
+
+            for file in output.files {
+                let path = build_rel_path(&self.core.root, &file.file_name);
+                let mut u = Upload::new(&path, &file.file_id);
+                if let Some(ts) = file.upload_timestamp {
+                    u = u.with_initiated(parse_datetime_from_from_timestamp_millis(ts)?);
+                }
+                uploads.push(u);
+            }
+
+            match output.next_file_id {
+                Some(next) if !next.is_empty() => start_file_id = Some(next),
+                _ => break,
+            }
+        }
+
+        Ok(RpListUploads::new(uploads))
+    }
+
+    async fn abort_upload(&self, _: &str, args: OpAbortUpload) -> Result<RpAbortUpload> {
+        let resp = self.core.cancel_large_file(args.upload_id()).await?;
+
+        match resp.status() {
+            StatusCode::OK => Ok(RpAbortUpload::default()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         match args.operation() {
             PresignOperation::Stat(_) => {
@@ -425,7 +474,7 @@ impl Access for B2Backend {
                     parts.headers,
                 )))
             }
-            PresignOperation::Delete(_) => Err(Error::new(
+            PresignOperation::Delete(_) | PresignOperation::UploadPart(_) => Err(Error::new(
                 ErrorKind::Unsupported,
                 "operation is not supported",
             )),
@@ -423,6 +423,42 @@ impl B2Core {
         self.send(req).await
     }
 
+    pub async fn list_unfinished_large_files(
+        &self,
+        prefix: &str,
+        start_file_id: Option<&str>,
+    ) -> Result<Response<Buffer>> {
+        let auth_info = self.get_auth_info().await?;
+
+        let url = format!(
+            "{}/b2api/v2/b2_list_unfinished_large_files",
+            auth_info.api_url
+        );
+
+        let mut url = QueryPairsWriter::new(&url);
+        url = url.push("bucketId", &self.bucket_id);
+
+        let prefix = build_abs_path(&self.root, prefix);
+        if !prefix.is_empty() {
+            url = url.push("namePrefix", &percent_encode_path(&prefix));
+        }
+
+        if let Some(start_file_id) = start_file_id {
+            url = url.push("startFileId", start_file_id);
+        }
+
+        let mut req = Request::get(url.finish());
+
+        req = req.header(header::AUTHORIZATION, auth_info.authorization_token);
+
+        req = req.extension(Operation::ListUploads);
+
+        // Set body
+        let req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+
+        self.send(req).await
+    }
+
     pub async fn get_file_info(&self, path: &str, delimiter: Option<&str>) -> Result<File> {
         let resp = self
             .list_file_names_raw(Some(path), delimiter, None, None, Operation::Stat)
@@ -674,6 +710,22 @@ pub struct ListFileNamesResponse {
     pub next_file_name: Option<String>,
 }
 
+/// Response of [b2_list_unfinished_large_files](https://www.backblaze.com/apidocs/b2-list-unfinished-large-files).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListUnfinishedLargeFilesResponse {
+    pub files: Vec<UnfinishedLargeFile>,
+    pub next_file_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnfinishedLargeFile {
+    pub file_id: String,
+    pub file_name: String,
+    pub upload_timestamp: Option<i64>,
+}
+
 /// Response of [b2-finish-large-file](https://www.backblaze.com/apidocs/b2-finish-large-file).
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -17,6 +17,7 @@
 
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::time::Duration;
 
 use bytes::Buf;
 use http::header;
@@ -93,6 +94,14 @@ impl CloudflareKvBuilder {
 
         self
     }
+
+    /// Split values larger than `chunk_size` into chunks.
+    ///
+    /// Cloudflare KV rejects values larger than 25MiB, set this to store larger values.
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.config.chunk_size = Some(chunk_size);
+        self
+    }
 }
 
 impl Builder for CloudflareKvBuilder {
@@ -139,19 +148,27 @@ impl Builder for CloudflareKvBuilder {
             r"https://api.cloudflare.com/client/v4/accounts/{account_id}/storage/kv/namespaces/{namespace_id}"
         );
 
-        Ok(CloudflareKvBackend::new(Adapter {
+        let backend = CloudflareKvBackend::new(Adapter {
             authorization,
             account_id,
             namespace_id,
             client,
             url_prefix,
         })
-        .with_normalized_root(root))
+        .with_normalized_root(root);
+
+        Ok(match self.config.chunk_size {
+            Some(chunk_size) => backend.with_chunk_size(chunk_size),
+            None => backend,
+        })
     }
 }
 
 pub type CloudflareKvBackend = kv::Backend<Adapter>;
 
+/// The minimum `expiration_ttl` in seconds accepted by cloudflare kv.
+const MIN_EXPIRATION_TTL: u64 = 60;
+
 #[derive(Clone)]
 pub struct Adapter {
     authorization: String,
@@ -176,6 +193,28 @@ impl Adapter {
             .insert(header::AUTHORIZATION, self.authorization.parse().unwrap());
         Ok(req)
     }
+
+    /// Write the value of `path`, cloudflare kv requires the ttl to be at least 60 seconds.
+    async fn put(&self, path: &str, value: Buffer, ttl: Option<Duration>) -> Result<()> {
+        let mut url = format!("{}/values/{}", self.url_prefix, path);
+        if let Some(ttl) = ttl {
+            let secs = (ttl.as_secs() + u64::from(ttl.subsec_nanos() > 0)).max(MIN_EXPIRATION_TTL);
+            url = format!("{url}?expiration_ttl={secs}");
+        }
+        let req = Request::put(&url);
+        let multipart = Multipart::new();
+        let multipart = multipart
+            .part(FormDataPart::new("metadata").content(serde_json::Value::Null.to_string()))
+            .part(FormDataPart::new("value").content(value.to_vec()));
+        let mut req = multipart.apply(req)?;
+        req = self.sign(req)?;
+        let resp = self.client.send(req).await?;
+        let status = resp.status();
+        match status {
+            StatusCode::OK => Ok(()),
+            _ => Err(parse_error(resp)),
+        }
+    }
 }
 
 impl kv::Adapter for Adapter {
@@ -188,6 +227,7 @@ impl kv::Adapter for Adapter {
             Capability {
                 read: true,
                 write: true,
+                write_with_ttl: true,
                 list: true,
                 shared: true,
 
@@ -211,20 +251,11 @@ impl kv::Adapter for Adapter {
     }
 
     async fn set(&self, path: &str, value: Buffer) -> Result<()> {
-        let url = format!("{}/values/{}", self.url_prefix, path);
-        let req = Request::put(&url);
-        let multipart = Multipart::new();
-        let multipart = multipart
-            .part(FormDataPart::new("metadata").content(serde_json::Value::Null.to_string()))
-            .part(FormDataPart::new("value").content(value.to_vec()));
-        let mut req = multipart.apply(req)?;
-        req = self.sign(req)?;
-        let resp = self.client.send(req).await?;
-        let status = resp.status();
-        match status {
-            StatusCode::OK => Ok(()),
-            _ => Err(parse_error(resp)),
-        }
+        self.put(path, value, None).await
+    }
+
+    async fn set_with_ttl(&self, path: &str, value: Buffer, ttl: Duration) -> Result<()> {
+        self.put(path, value, Some(ttl)).await
     }
 
     async fn delete(&self, path: &str) -> Result<()> {
@@ -241,7 +272,7 @@ impl kv::Adapter for Adapter {
         }
     }
 
-    async fn scan(&self, path: &str) -> Result<Self::Scanner> {
+    async fn scan(&self, path: &str, _: kv::ScanOptions) -> Result<Self::Scanner> {
         let mut url = format!("{}/keys", self.url_prefix);
         if !path.is_empty() {
             url = format!("{url}?prefix={path}");
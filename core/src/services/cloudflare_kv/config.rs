@@ -33,6 +33,10 @@ pub struct CloudflareKvConfig {
 
     /// Root within this backend.
     pub root: Option<String>,
+    /// Split values larger than this size into chunks.
+    ///
+    /// Cloudflare KV rejects values larger than 25MiB, set this to store larger values.
+    pub chunk_size: Option<usize>,
 }
 
 impl Debug for CloudflareKvConfig {
@@ -42,6 +46,7 @@ impl Debug for CloudflareKvConfig {
         ds.field("root", &self.root);
         ds.field("account_id", &self.account_id);
         ds.field("namespace_id", &self.namespace_id);
+        ds.field("chunk_size", &self.chunk_size);
 
         if self.token.is_some() {
             ds.field("token", &"<redacted>");
@@ -19,7 +19,6 @@ use std::io::Cursor;
 use std::sync::Arc;
 
 use compio::dispatcher::Dispatcher;
-use compio::fs::OpenOptions;
 
 use super::core::CompfsCore;
 use super::delete::CompfsDeleter;
@@ -100,6 +99,7 @@ impl Builder for CompfsBuilder {
                         write: true,
                         write_can_empty: true,
                         write_can_multi: true,
+                        write_can_random_access: true,
                         create_dir: true,
                         delete: true,
 
@@ -185,24 +185,14 @@ impl Access for CompfsBackend {
         let to = self.core.prepare_path(to);
 
         self.core
-            .exec(move || async move {
-                let from = OpenOptions::new().read(true).open(from).await?;
+            .exec_blocking(move || {
                 if let Some(parent) = to.parent() {
-                    compio::fs::create_dir_all(parent).await?;
+                    std::fs::create_dir_all(parent)?;
                 }
-                let to = OpenOptions::new()
-                    .write(true)
-                    .create(true)
-                    .truncate(true)
-                    .open(to)
-                    .await?;
-
-                let (mut from, mut to) = (Cursor::new(from), Cursor::new(to));
-                compio::io::copy(&mut from, &mut to).await?;
-
-                Ok(())
+                std_copy_file(&from, &to)
             })
-            .await?;
+            .await?
+            .map_err(new_std_io_error)?;
 
         Ok(RpCopy::default())
     }
@@ -237,7 +227,7 @@ impl Access for CompfsBackend {
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let path = self.core.prepare_path(path);
-        let append = args.append();
+        let append = args.append() && !args.random_access();
         let file = self
             .core
             .exec(move || async move {
@@ -20,6 +20,7 @@ use std::sync::Arc;
 
 use compio::buf::buf_try;
 use compio::fs::File;
+use compio::io::AsyncWriteAtExt;
 use compio::io::AsyncWriteExt;
 
 use super::core::CompfsCore;
@@ -66,6 +67,25 @@ impl oio::Write for CompfsWriter {
         Ok(())
     }
 
+    async fn write_at(&mut self, offset: u64, bs: Buffer) -> Result<()> {
+        let Some(file) = self.file.clone() else {
+            return Err(Error::new(ErrorKind::Unexpected, "file has closed"));
+        };
+
+        self.core
+            .exec(move || async move {
+                let mut file = file.into_inner();
+                let mut pos = offset;
+                for b in bs {
+                    let len = b.len() as u64;
+                    buf_try!(@try file.write_all_at(b, pos).await);
+                    pos += len;
+                }
+                Ok(())
+            })
+            .await
+    }
+
     async fn close(&mut self) -> Result<Metadata> {
         let Some(f) = self.file.take() else {
             return Err(Error::new(ErrorKind::Unexpected, "file has closed"));
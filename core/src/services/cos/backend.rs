@@ -18,6 +18,7 @@
 use std::fmt::Debug;
 use std::sync::Arc;
 
+use bytes::Buf;
 use http::Response;
 use http::StatusCode;
 use http::Uri;
@@ -242,6 +243,7 @@ impl Builder for CosBuilder {
                             write_can_empty: true,
                             write_can_append: true,
                             write_can_multi: true,
+                            write_can_random_access: true,
                             write_with_content_type: true,
                             write_with_cache_control: true,
                             write_with_content_disposition: true,
@@ -264,16 +266,39 @@ impl Builder for CosBuilder {
                             delete: true,
                             delete_with_version: self.config.enable_versioning,
                             copy: true,
+                            copy_with_if_match: true,
+                            copy_with_if_none_match: true,
+                            concat: true,
+                            // Every part except the last one of multipart upload must
+                            // be at least 1 MiB and at most 5 GiB, and there are at
+                            // most 10000 parts.
+                            //
+                            // ref: <https://www.tencentcloud.com/document/product/436/8287>
+                            concat_min_source_size: Some(1024 * 1024),
+                            concat_max_source_size: if cfg!(target_pointer_width = "64") {
+                                Some(5 * 1024 * 1024 * 1024)
+                            } else {
+                                Some(usize::MAX)
+                            },
+                            concat_max_sources: Some(10000),
+
+                            update_metadata: true,
+                            update_metadata_with_if_match: true,
 
                             list: true,
                             list_with_recursive: true,
                             list_with_versions: self.config.enable_versioning,
                             list_with_deleted: self.config.enable_versioning,
 
+                            list_uploads: true,
+
+                            abort_upload: true,
+
                             presign: true,
                             presign_stat: true,
                             presign_read: true,
                             presign_write: true,
+                            presign_post: true,
 
                             shared: true,
 
@@ -304,6 +329,122 @@ pub struct CosBackend {
     core: Arc<CosCore>,
 }
 
+#[derive(Clone)]
+struct UploadPartCopyInput {
+    core: Arc<CosCore>,
+    path: Arc<String>,
+    upload_id: Arc<String>,
+    part_number: usize,
+    part: oio::PartCopy,
+    op: Operation,
+}
+
+impl CosBackend {
+    /// Build the object at `path` by copying `parts` in a multipart upload.
+    async fn copy_parts(
+        &self,
+        path: &str,
+        args: &OpWrite,
+        parts: Vec<oio::PartCopy>,
+        concurrent: usize,
+        op: Operation,
+    ) -> Result<()> {
+        let resp = self.core.cos_initiate_multipart_upload(path, args).await?;
+        let upload_id = match resp.status() {
+            StatusCode::OK => {
+                let result: InitiateMultipartUploadResult =
+                    quick_xml::de::from_reader(resp.into_body().reader())
+                        .map_err(new_xml_deserialize_error)?;
+                result.upload_id
+            }
+            _ => return Err(parse_error(resp)),
+        };
+
+        let res = self
+            .copy_parts_inner(path, &upload_id, parts, concurrent, op)
+            .await;
+        if res.is_err() {
+            // Abort is best effort, the upload will be cleaned by lifecycle rules otherwise.
+            let _ = self.core.cos_abort_multipart_upload(path, &upload_id).await;
+        }
+        res
+    }
+
+    async fn copy_parts_inner(
+        &self,
+        path: &str,
+        upload_id: &str,
+        parts: Vec<oio::PartCopy>,
+        concurrent: usize,
+        op: Operation,
+    ) -> Result<()> {
+        let mut tasks = ConcurrentTasks::new(
+            self.core.info.executor(),
+            concurrent.max(1),
+            concurrent.max(1),
+            |input: UploadPartCopyInput| {
+                Box::pin(async move {
+                    let fut = input.core.cos_upload_part_copy(
+                        &input.path,
+                        &input.upload_id,
+                        input.part_number,
+                        &input.part.source,
+                        input.part.range,
+                        input.part.if_match.as_deref(),
+                        input.op,
+                    );
+                    match fut.await {
+                        Ok(resp) if resp.status() == StatusCode::OK => {
+                            let result: Result<CopyPartResult> =
+                                quick_xml::de::from_reader(resp.into_body().reader())
+                                    .map_err(new_xml_deserialize_error);
+                            let part = result.map(|v| CompleteMultipartUploadRequestPart {
+                                part_number: input.part_number,
+                                etag: v.etag,
+                            });
+                            (input, part)
+                        }
+                        Ok(resp) => (input, Err(parse_error(resp))),
+                        Err(err) => (input, Err(err)),
+                    }
+                })
+            },
+        );
+
+        let path = Arc::new(path.to_string());
+        let upload_id = Arc::new(upload_id.to_string());
+        let total = parts.len();
+        for (idx, part) in parts.into_iter().enumerate() {
+            tasks
+                .execute(UploadPartCopyInput {
+                    core: self.core.clone(),
+                    path: path.clone(),
+                    upload_id: upload_id.clone(),
+                    // Part number starts from 1.
+                    part_number: idx + 1,
+                    part,
+                    op,
+                })
+                .await?;
+        }
+
+        let mut parts = Vec::with_capacity(total);
+        while let Some(part) = tasks.next().await {
+            parts.push(part?);
+        }
+
+        let resp = self
+            .core
+            .cos_complete_multipart_upload(&path, &upload_id, parts)
+            .await?;
+
+        match resp.status() {
+            StatusCode::OK => Ok(()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+}
+
 impl Access for CosBackend {
     type Reader = HttpBody;
     type Writer = CosWriters;
@@ -320,23 +461,10 @@ impl Access for CosBackend {
         let status = resp.status();
 
         match status {
-            StatusCode::OK => {
-                let headers = resp.headers();
-                let mut meta = parse_into_metadata(path, headers)?;
-
-                let user_meta = parse_prefixed_headers(headers, "x-cos-meta-");
-                if !user_meta.is_empty() {
-                    meta = meta.with_user_metadata(user_meta);
-                }
-
-                if let Some(v) = parse_header_to_str(headers, constants::X_COS_VERSION_ID)? {
-                    if v != "null" {
-                        meta.set_version(v);
-                    }
-                }
-
-                Ok(RpStat::new(meta))
-            }
+            StatusCode::OK => Ok(RpStat::new(parse_head_object_metadata(
+                path,
+                resp.headers(),
+            )?)),
             _ => Err(parse_error(resp)),
         }
     }
@@ -364,11 +492,10 @@ impl Access for CosBackend {
         let w = if args.append() {
             CosWriters::Two(oio::AppendWriter::new(writer))
         } else {
-            CosWriters::One(oio::MultipartWriter::new(
-                self.core.info.clone(),
-                writer,
-                args.concurrent(),
-            ))
+            CosWriters::One(
+                oio::MultipartWriter::new(self.core.info.clone(), writer, args.concurrent())
+                    .with_block_size(args.chunk()),
+            )
         };
 
         Ok((RpWrite::default(), w))
@@ -400,17 +527,173 @@ impl Access for CosBackend {
         Ok((RpList::default(), l))
     }
 
-    async fn copy(&self, from: &str, to: &str, _args: OpCopy) -> Result<RpCopy> {
-        let resp = self.core.cos_copy_object(from, to).await?;
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        // CopyObject can copy at most 5 GiB, larger sources must be copied in parts.
+        //
+        // ref: <https://www.tencentcloud.com/document/product/436/10881>
+        let max_single_size = 5 * 1024 * 1024 * 1024;
+
+        // Sources over the limit are rejected with `400 Bad Request`.
+        let single_err = if args.chunk().is_none() {
+            let resp = self.core.cos_copy_object(from, to, &args).await?;
+            match resp.status() {
+                StatusCode::OK => return Ok(RpCopy::default()),
+                StatusCode::BAD_REQUEST => Some(parse_error(resp)),
+                _ => return Err(parse_error(resp)),
+            }
+        } else {
+            None
+        };
 
-        let status = resp.status();
+        let stat = oio::CopyPlan::stat_args(&args);
+        let resp = self.core.cos_head_object(from, &stat).await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp));
+        }
+        let meta = parse_head_object_metadata(from, resp.headers())?;
 
-        match status {
-            StatusCode::OK => Ok(RpCopy::default()),
+        // Parts of multipart upload must be at least 1 MiB.
+        match oio::CopyPlan::new(from, &meta, &args, max_single_size, 1024 * 1024) {
+            oio::CopyPlan::Single => {
+                if let Some(err) = single_err {
+                    return Err(err);
+                }
+                let resp = self.core.cos_copy_object(from, to, &args).await?;
+                match resp.status() {
+                    StatusCode::OK => Ok(RpCopy::default()),
+                    _ => Err(parse_error(resp)),
+                }
+            }
+            oio::CopyPlan::Multipart { args: op, parts } => {
+                self.copy_parts(to, &op, parts, args.concurrent(), Operation::Copy)
+                    .await?;
+                Ok(RpCopy::default())
+            }
+        }
+    }
+
+    async fn update_metadata(
+        &self,
+        path: &str,
+        args: OpUpdateMetadata,
+    ) -> Result<RpUpdateMetadata> {
+        let resp = self.core.cos_head_object(path, &OpStat::new()).await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp));
+        }
+        let mut meta = parse_head_object_metadata(path, resp.headers())?;
+        let storage_class = parse_header_to_str(resp.headers(), constants::X_COS_STORAGE_CLASS)?;
+
+        let update = args.update();
+        if let (Some(expected), Some(actual)) = (update.if_match(), meta.etag()) {
+            if expected != actual {
+                return Err(Error::new(
+                    ErrorKind::ConditionNotMatch,
+                    "etag of the object doesn't match",
+                ));
+            }
+        }
+        update.apply(&mut meta);
+
+        // Copy only if the object is still the one we read, otherwise we may
+        // overwrite a concurrent write with stale content.
+        let if_match = meta.etag().map(|v| v.to_string());
+        let resp = self
+            .core
+            .cos_replace_object_metadata(path, &meta, storage_class, if_match.as_deref())
+            .await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let result: CopyObjectResult =
+                    quick_xml::de::from_reader(resp.into_body().reader())
+                        .map_err(new_xml_deserialize_error)?;
+                meta.set_etag(&result.etag);
+                meta.set_last_modified(parse_datetime_from_rfc3339(&result.last_modified)?);
+                Ok(RpUpdateMetadata::new(meta))
+            }
             _ => Err(parse_error(resp)),
         }
     }
 
+    async fn list_uploads(&self, path: &str, _: OpListUploads) -> Result<RpListUploads> {
+        let mut uploads = Vec::new();
+        let mut key_marker = String::new();
+        let mut upload_id_marker = String::new();
+
+        loop {
+            let resp = self
+                .core
+                .cos_list_multipart_uploads(path, &key_marker, &upload_id_marker)
+                .await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp));
+            }
+
+            let output: ListMultipartUploadsOutput =
+                quick_xml::de::from_reader(resp.into_body().reader())
+                    .map_err(new_xml_deserialize_error)?;
+
+            for upload in output.upload {
+                let path = build_rel_path(&self.core.root, &upload.key);
+                let mut u = Upload::new(&path, &upload.upload_id);
+                if let Some(initiated) = &upload.initiated {
+                    u = u.with_initiated(parse_datetime_from_rfc3339(initiated)?);
+                }
+                uploads.push(u);
+            }
+
+            if !output.is_truncated.unwrap_or_default() {
+                break;
+            }
+            key_marker = output.next_key_marker.unwrap_or_default();
+            upload_id_marker = output.next_upload_id_marker.unwrap_or_default();
+            if key_marker.is_empty() && upload_id_marker.is_empty() {
+                break;
+            }
+        }
+
+        Ok(RpListUploads::new(uploads))
+    }
+
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        let mut op = OpWrite::new();
+        if let Some(v) = args.content_type() {
+            op = op.with_content_type(v);
+        }
+
+        let parts = args
+            .sources()
+            .iter()
+            .map(|source| oio::PartCopy {
+                source: source.clone(),
+                range: None,
+                if_match: None,
+            })
+            .collect();
+        self.copy_parts(path, &op, parts, args.concurrent(), Operation::Concat)
+            .await?;
+
+        Ok(RpConcat::default())
+    }
+
+    async fn abort_upload(&self, path: &str, args: OpAbortUpload) -> Result<RpAbortUpload> {
+        let resp = self
+            .core
+            .cos_abort_multipart_upload(path, args.upload_id())
+            .await?;
+
+        match resp.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(RpAbortUpload::default()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        let post = self.core.cos_presign_post(path, &args).await?;
+        Ok(RpPresignPost::new(post))
+    }
+
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         let req = match args.operation() {
             PresignOperation::Stat(v) => self.core.cos_head_object_request(path, v),
@@ -422,7 +705,7 @@ impl Access for CosBackend {
                 self.core
                     .cos_put_object_request(path, None, v, Buffer::new())
             }
-            PresignOperation::Delete(_) => Err(Error::new(
+            PresignOperation::Delete(_) | PresignOperation::UploadPart(_) => Err(Error::new(
                 ErrorKind::Unsupported,
                 "operation is not supported",
             )),
@@ -440,3 +723,21 @@ impl Access for CosBackend {
         )))
     }
 }
+
+/// Parse metadata from the response headers of `HeadObject`.
+fn parse_head_object_metadata(path: &str, headers: &http::HeaderMap) -> Result<Metadata> {
+    let mut meta = parse_into_metadata(path, headers)?;
+
+    let user_meta = parse_prefixed_headers(headers, "x-cos-meta-");
+    if !user_meta.is_empty() {
+        meta = meta.with_user_metadata(user_meta);
+    }
+
+    if let Some(v) = parse_header_to_str(headers, constants::X_COS_VERSION_ID)? {
+        if v != "null" {
+            meta.set_version(v);
+        }
+    }
+
+    Ok(meta)
+}
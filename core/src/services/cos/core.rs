@@ -20,9 +20,16 @@ use std::fmt::Formatter;
 use std::sync::Arc;
 use std::time::Duration;
 
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
 use bytes::Bytes;
+use chrono::SecondsFormat;
+use chrono::Utc;
+use hmac::Hmac;
+use hmac::Mac;
 use http::header::CACHE_CONTROL;
 use http::header::CONTENT_DISPOSITION;
+use http::header::CONTENT_ENCODING;
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_TYPE;
 use http::header::IF_MATCH;
@@ -36,6 +43,9 @@ use reqsign::TencentCosCredentialLoader;
 use reqsign::TencentCosSigner;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::json;
+use sha1::Digest;
+use sha1::Sha1;
 
 use crate::raw::*;
 use crate::*;
@@ -44,6 +54,18 @@ pub mod constants {
     pub const COS_QUERY_VERSION_ID: &str = "versionId";
 
     pub const X_COS_VERSION_ID: &str = "x-cos-version-id";
+
+    pub const X_COS_COPY_SOURCE: &str = "x-cos-copy-source";
+
+    pub const X_COS_COPY_SOURCE_IF_MATCH: &str = "x-cos-copy-source-if-match";
+
+    pub const X_COS_COPY_SOURCE_IF_NONE_MATCH: &str = "x-cos-copy-source-if-none-match";
+
+    pub const X_COS_COPY_SOURCE_RANGE: &str = "x-cos-copy-source-range";
+
+    pub const X_COS_METADATA_DIRECTIVE: &str = "x-cos-metadata-directive";
+
+    pub const X_COS_STORAGE_CLASS: &str = "x-cos-storage-class";
 }
 
 pub struct CosCore {
@@ -343,7 +365,12 @@ impl CosCore {
         Ok(req)
     }
 
-    pub async fn cos_copy_object(&self, from: &str, to: &str) -> Result<Response<Buffer>> {
+    pub async fn cos_copy_object(
+        &self,
+        from: &str,
+        to: &str,
+        args: &OpCopy,
+    ) -> Result<Response<Buffer>> {
         let source = build_abs_path(&self.root, from);
         let target = build_abs_path(&self.root, to);
 
@@ -352,7 +379,67 @@ impl CosCore {
 
         let mut req = Request::put(&url)
             .extension(Operation::Copy)
-            .header("x-cos-copy-source", &source)
+            .header(constants::X_COS_COPY_SOURCE, &source);
+        if let Some(v) = args.if_match() {
+            req = req.header(constants::X_COS_COPY_SOURCE_IF_MATCH, v);
+        }
+        if let Some(v) = args.if_none_match() {
+            req = req.header(constants::X_COS_COPY_SOURCE_IF_NONE_MATCH, v);
+        }
+
+        let mut req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
+    /// Copy the object onto itself with `Replaced` metadata directive.
+    ///
+    /// COS replaces all metadata of the object in this case, so `meta` MUST
+    /// contain all metadata that should be kept.
+    pub async fn cos_replace_object_metadata(
+        &self,
+        path: &str,
+        meta: &Metadata,
+        storage_class: Option<&str>,
+        if_match: Option<&str>,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let source = format!("/{}/{}", self.bucket, percent_encode_path(&p));
+        let url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
+
+        let mut req = Request::put(&url);
+
+        if let Some(v) = meta.content_type() {
+            req = req.header(CONTENT_TYPE, v);
+        }
+        if let Some(v) = meta.content_disposition() {
+            req = req.header(CONTENT_DISPOSITION, v);
+        }
+        if let Some(v) = meta.content_encoding() {
+            req = req.header(CONTENT_ENCODING, v);
+        }
+        if let Some(v) = meta.cache_control() {
+            req = req.header(CACHE_CONTROL, v);
+        }
+        if let Some(user_metadata) = meta.user_metadata() {
+            for (key, value) in user_metadata {
+                req = req.header(format!("x-cos-meta-{key}"), value)
+            }
+        }
+        if let Some(v) = storage_class {
+            req = req.header(constants::X_COS_STORAGE_CLASS, v);
+        }
+        if let Some(v) = if_match {
+            req = req.header(constants::X_COS_COPY_SOURCE_IF_MATCH, v);
+        }
+
+        let mut req = req
+            .extension(Operation::UpdateMetadata)
+            .header(constants::X_COS_COPY_SOURCE, &source)
+            .header(constants::X_COS_METADATA_DIRECTIVE, "Replaced")
             .body(Buffer::new())
             .map_err(new_request_build_error)?;
 
@@ -418,6 +505,10 @@ impl CosCore {
             req = req.header(CACHE_CONTROL, cache_control)
         }
 
+        if let Some(encoding) = args.content_encoding() {
+            req = req.header(CONTENT_ENCODING, encoding);
+        }
+
         // Set user metadata headers.
         if let Some(user_metadata) = args.user_metadata() {
             for (key, value) in user_metadata {
@@ -465,6 +556,50 @@ impl CosCore {
         self.send(req).await
     }
 
+    /// Upload a part by copying the `range` of `source` object, or the whole
+    /// object if `range` is not set.
+    ///
+    /// ref: <https://www.tencentcloud.com/document/product/436/8287>
+    #[allow(clippy::too_many_arguments)]
+    pub async fn cos_upload_part_copy(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        source: &str,
+        range: Option<BytesRange>,
+        if_match: Option<&str>,
+        op: Operation,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let source = build_abs_path(&self.root, source);
+
+        let url = format!(
+            "{}/{}?partNumber={}&uploadId={}",
+            self.endpoint,
+            percent_encode_path(&p),
+            part_number,
+            percent_encode_path(upload_id)
+        );
+        let source = format!("/{}/{}", self.bucket, percent_encode_path(&source));
+
+        let mut req = Request::put(&url)
+            .extension(op)
+            .header(constants::X_COS_COPY_SOURCE, &source);
+        if let Some(range) = range {
+            req = req.header(constants::X_COS_COPY_SOURCE_RANGE, range.to_header());
+        }
+        if let Some(v) = if_match {
+            req = req.header(constants::X_COS_COPY_SOURCE_IF_MATCH, v);
+        }
+
+        let mut req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
     pub async fn cos_complete_multipart_upload(
         &self,
         path: &str,
@@ -500,6 +635,108 @@ impl CosCore {
         self.send(req).await
     }
 
+    /// Build a presigned PostObject form.
+    ///
+    /// ref: <https://cloud.tencent.com/document/product/436/14690>
+    pub async fn cos_presign_post(
+        &self,
+        path: &str,
+        args: &OpPresignPost,
+    ) -> Result<PresignedPost> {
+        let Some(cred) = self.load_credential().await? else {
+            return Err(Error::new(
+                ErrorKind::PermissionDenied,
+                "presign post requires credential but anonymous access is used",
+            ));
+        };
+
+        let p = build_abs_path(&self.root, path);
+        let now = Utc::now();
+        let expiration = now
+            + chrono::Duration::from_std(args.expire()).map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "expire is out of range").set_source(err)
+            })?;
+        let key_time = format!("{};{}", now.timestamp(), expiration.timestamp());
+
+        let mut fields = Vec::new();
+        let mut conditions = vec![json!({ "bucket": self.bucket })];
+        if p.is_empty() || p.ends_with('/') {
+            conditions.push(json!(["starts-with", "$key", p]));
+            fields.push(("key".to_string(), format!("{p}${{filename}}")));
+        } else {
+            conditions.push(json!({ "key": p }));
+            fields.push(("key".to_string(), p));
+        }
+        if let Some((min, max)) = args.content_length_range() {
+            conditions.push(json!(["content-length-range", min, max]));
+        }
+        if let Some(prefix) = args.content_type_prefix() {
+            conditions.push(json!(["starts-with", "$Content-Type", prefix]));
+        }
+
+        fields.push(("q-sign-algorithm".to_string(), "sha1".to_string()));
+        fields.push(("q-ak".to_string(), cred.secret_id.clone()));
+        fields.push(("q-key-time".to_string(), key_time.clone()));
+        conditions.push(json!({ "q-sign-algorithm": "sha1" }));
+        conditions.push(json!({ "q-ak": cred.secret_id }));
+        conditions.push(json!({ "q-sign-time": key_time }));
+        if let Some(token) = &cred.security_token {
+            conditions.push(json!({ "x-cos-security-token": token }));
+            fields.push(("x-cos-security-token".to_string(), token.clone()));
+        }
+
+        let policy = json!({
+            "expiration": expiration.to_rfc3339_opts(SecondsFormat::Millis, true),
+            "conditions": conditions,
+        })
+        .to_string();
+
+        let sign_key = hex_hmac_sha1(cred.secret_key.as_bytes(), key_time.as_bytes());
+        let string_to_sign = format_hex(&Sha1::digest(policy.as_bytes()));
+        let signature = hex_hmac_sha1(sign_key.as_bytes(), string_to_sign.as_bytes());
+
+        fields.push(("policy".to_string(), BASE64_STANDARD.encode(policy)));
+        fields.push(("q-signature".to_string(), signature));
+
+        let uri = format!("{}/", self.endpoint).parse().map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "endpoint is not a valid uri").set_source(err)
+        })?;
+        Ok(PresignedPost::new(uri, fields))
+    }
+
+    /// List in-progress multipart uploads.
+    ///
+    /// ref: <https://cloud.tencent.com/document/product/436/7736>
+    pub async fn cos_list_multipart_uploads(
+        &self,
+        prefix: &str,
+        key_marker: &str,
+        upload_id_marker: &str,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, prefix);
+
+        let mut url = QueryPairsWriter::new(&self.endpoint);
+        url = url.push("uploads", "");
+
+        if !p.is_empty() {
+            url = url.push("prefix", &percent_encode_path(&p));
+        }
+        if !key_marker.is_empty() {
+            url = url.push("key-marker", &percent_encode_path(key_marker));
+        }
+        if !upload_id_marker.is_empty() {
+            url = url.push("upload-id-marker", &percent_encode_path(upload_id_marker));
+        }
+
+        let mut req = Request::get(url.finish())
+            .extension(Operation::ListUploads)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     /// Abort an on-going multipart upload.
     pub async fn cos_abort_multipart_upload(
         &self,
@@ -563,6 +800,12 @@ impl CosCore {
     }
 }
 
+fn hex_hmac_sha1(key: &[u8], content: &[u8]) -> String {
+    let mut h = Hmac::<Sha1>::new_from_slice(key).expect("hmac accepts key of any size");
+    h.update(content);
+    format_hex(&h.finalize().into_bytes())
+}
+
 /// Result of CreateMultipartUpload
 #[derive(Default, Debug, Deserialize)]
 #[serde(default, rename_all = "PascalCase")]
@@ -614,6 +857,23 @@ pub struct CompleteMultipartUploadRequestPart {
     pub etag: String,
 }
 
+/// Output of `Upload Part - Copy` operation
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct CopyPartResult {
+    #[serde(rename = "ETag")]
+    pub etag: String,
+}
+
+/// Output of `CopyObject` operation
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct CopyObjectResult {
+    #[serde(rename = "ETag")]
+    pub etag: String,
+    pub last_modified: String,
+}
+
 /// Output of `CompleteMultipartUpload` operation
 #[derive(Debug, Default, Deserialize)]
 #[serde[default, rename_all = "PascalCase"]]
@@ -688,6 +948,24 @@ pub struct ListObjectVersionsOutputDeleteMarker {
     pub last_modified: String,
 }
 
+/// Output of ListMultipartUploads
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListMultipartUploadsOutput {
+    pub is_truncated: Option<bool>,
+    pub next_key_marker: Option<String>,
+    pub next_upload_id_marker: Option<String>,
+    pub upload: Vec<ListMultipartUploadsOutputUpload>,
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListMultipartUploadsOutputUpload {
+    pub key: String,
+    pub upload_id: String,
+    pub initiated: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use bytes::Buf;
@@ -255,8 +255,44 @@ impl Adapter {
     }
 }
 
+impl kv::ScanPage for Adapter {
+    async fn next_page(
+        &self,
+        prefix: &str,
+        start_after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<String>> {
+        let key_field = &self.key_field;
+        let query = format!(
+            "SELECT {key_field} FROM {} \
+                WHERE {key_field} LIKE ? AND (? IS NULL OR {key_field} > ?) \
+                ORDER BY {key_field} LIMIT ?",
+            self.table
+        );
+        let params = vec![
+            format!("{prefix}%").into(),
+            start_after.into(),
+            start_after.into(),
+            (limit as u64).into(),
+        ];
+        let req = self.create_d1_query_request(&query, params)?;
+
+        let resp = self.client.send(req).await?;
+        let status = resp.status();
+        match status {
+            StatusCode::OK | StatusCode::PARTIAL_CONTENT => {
+                let body = resp.into_body();
+                let bs = body.to_bytes();
+                let d1_response = D1Response::parse(&bs)?;
+                Ok(d1_response.get_keys(key_field))
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+}
+
 impl kv::Adapter for Adapter {
-    type Scanner = ();
+    type Scanner = kv::PagedScanner<Self>;
 
     fn info(&self) -> kv::Info {
         kv::Info::new(
@@ -265,6 +301,7 @@ impl kv::Adapter for Adapter {
             Capability {
                 read: true,
                 write: true,
+                list: true,
                 // Cloudflare D1 supports 1MB as max in write_total.
                 // refer to https://developers.cloudflare.com/d1/platform/limits/
                 write_total_max_size: Some(1000 * 1000),
@@ -327,4 +364,8 @@ impl kv::Adapter for Adapter {
             _ => Err(parse_error(resp)),
         }
     }
+
+    async fn scan(&self, path: &str, opts: kv::ScanOptions) -> Result<Self::Scanner> {
+        Ok(kv::PagedScanner::new(self.clone(), path, opts))
+    }
 }
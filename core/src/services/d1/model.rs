@@ -72,6 +72,21 @@ impl D1Response {
             _ => None,
         }
     }
+
+    /// Get the string values of `key` in all rows of the result.
+    pub fn get_keys(&self, key: &str) -> Vec<String> {
+        let Some(result) = self.result.first() else {
+            return vec![];
+        };
+        result
+            .results
+            .iter()
+            .filter_map(|row| match row.get(key) {
+                Some(Value::String(s)) => Some(s.clone()),
+                _ => None,
+            })
+            .collect()
+    }
 }
 
 #[derive(Deserialize, Debug)]
@@ -122,4 +137,25 @@ mod test {
         let response: D1Response = serde_json::from_str(data).unwrap();
         println!("{:?}", response.result[0].results[0]);
     }
+
+    #[test]
+    fn test_get_keys() {
+        let data = r#"
+        {
+            "result": [
+                {
+                    "results": [
+                        {"key": "dir/a"},
+                        {"key": "dir/b"}
+                    ],
+                    "success": true
+                }
+            ],
+            "success": true,
+            "errors": [],
+            "messages": []
+        }"#;
+        let response: D1Response = serde_json::from_str(data).unwrap();
+        assert_eq!(response.get_keys("key"), vec!["dir/a", "dir/b"]);
+    }
 }
@@ -112,6 +112,7 @@ impl DashmapAccessor {
             write_with_content_type: true,
             write_with_content_disposition: true,
             write_with_content_encoding: true,
+            write_with_ttl: true,
 
             delete: true,
             stat: true,
@@ -148,7 +149,7 @@ impl Access for DashmapAccessor {
             }
             None => {
                 if p.ends_with('/') {
-                    let has_children = self.core.cache.iter().any(|kv| kv.key().starts_with(&p));
+                    let has_children = self.core.keys().iter().any(|k| k.starts_with(&p));
                     if has_children {
                         return Ok(RpStat::new(Metadata::new(EntryMode::DIR)));
                     }
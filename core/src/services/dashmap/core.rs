@@ -18,6 +18,7 @@
 use std::fmt::Debug;
 use std::fmt::Formatter;
 
+use chrono::Utc;
 use dashmap::DashMap;
 
 use crate::*;
@@ -31,6 +32,13 @@ pub struct DashmapValue {
     pub content: Buffer,
 }
 
+impl DashmapValue {
+    /// Check if this value has expired, expired values are removed lazily.
+    pub fn is_expired(&self) -> bool {
+        self.metadata.expires_at().is_some_and(|v| v <= Utc::now())
+    }
+}
+
 #[derive(Clone)]
 pub struct DashmapCore {
     pub cache: DashMap<String, DashmapValue>,
@@ -46,6 +54,7 @@ impl Debug for DashmapCore {
 
 impl DashmapCore {
     pub fn get(&self, key: &str) -> Result<Option<DashmapValue>> {
+        self.cache.remove_if(key, |_, v| v.is_expired());
         Ok(self.cache.get(key).map(|v| v.value().clone()))
     }
 
@@ -58,4 +67,10 @@ impl DashmapCore {
         self.cache.remove(key);
         Ok(())
     }
+
+    /// Get all keys that have not expired, expired values are removed.
+    pub fn keys(&self) -> Vec<String> {
+        self.cache.retain(|_, v| !v.is_expired());
+        self.cache.iter().map(|item| item.key().clone()).collect()
+    }
 }
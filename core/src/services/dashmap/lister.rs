@@ -30,7 +30,7 @@ pub struct DashmapLister {
 
 impl DashmapLister {
     pub fn new(core: Arc<DashmapCore>, root: String, path: String) -> Self {
-        let entries: Vec<_> = core.cache.iter().map(|item| item.key().clone()).collect();
+        let entries = core.keys();
         let path = build_abs_path(&root, &path);
 
         Self {
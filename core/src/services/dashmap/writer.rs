@@ -20,7 +20,7 @@ use std::time::SystemTime;
 
 use super::core::DashmapCore;
 use super::core::DashmapValue;
-use crate::raw::{oio, OpWrite};
+use crate::raw::{expire_time_from_ttl, oio, OpWrite};
 use crate::*;
 
 pub struct DashmapWriter {
@@ -68,6 +68,9 @@ impl oio::Write for DashmapWriter {
         if let Some(v) = self.op.content_encoding() {
             meta.set_content_encoding(v);
         }
+        if let Some(ttl) = self.op.ttl() {
+            meta.set_expires_at(expire_time_from_ttl(ttl));
+        }
 
         self.core.set(
             &self.path,
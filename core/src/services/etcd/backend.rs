@@ -212,11 +212,14 @@ impl EtcdAccessor {
 
             write: true,
             write_can_empty: true,
+            write_with_ttl: true,
 
             delete: true,
             stat: true,
             list: true,
 
+            lock: true,
+
             shared: true,
 
             ..Default::default()
@@ -251,7 +254,7 @@ impl Access for EtcdAccessor {
         };
 
         // Store an empty buffer to represent the directory
-        self.core.set(&dir_path, Buffer::new()).await?;
+        self.core.set(&dir_path, Buffer::new(), None).await?;
 
         Ok(RpCreateDir::default())
     }
@@ -260,10 +263,13 @@ impl Access for EtcdAccessor {
         let abs_path = build_abs_path(&self.info.root(), path);
 
         // First check if it's a direct key
-        match self.core.get(&abs_path).await? {
-            Some(buffer) => {
+        match self.core.get_with_ttl(&abs_path).await? {
+            Some((buffer, ttl)) => {
                 let mut metadata = Metadata::new(EntryMode::from_path(&abs_path));
                 metadata.set_content_length(buffer.len() as u64);
+                if let Some(ttl) = ttl {
+                    metadata.set_expires_at(expire_time_from_ttl(ttl));
+                }
                 Ok(RpStat::new(metadata))
             }
             None => {
@@ -318,9 +324,9 @@ impl Access for EtcdAccessor {
         }
     }
 
-    async fn write(&self, path: &str, _op: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let abs_path = build_abs_path(&self.info.root(), path);
-        let writer = EtcdWriter::new(self.core.clone(), abs_path);
+        let writer = EtcdWriter::new(self.core.clone(), abs_path, args);
         Ok((RpWrite::new(), writer))
     }
 
@@ -342,4 +348,21 @@ impl Access for EtcdAccessor {
         let lister = oio::HierarchyLister::new(lister, path, args.recursive());
         Ok((RpList::default(), lister))
     }
+
+    async fn lock(&self, path: &str, args: OpLock) -> Result<RpLock> {
+        let abs_path = build_abs_path(&self.info.root(), path);
+        let token = self
+            .core
+            .lock(&abs_path, args.ttl(), args.owner(), args.token())
+            .await?;
+        Ok(RpLock::new(token))
+    }
+
+    async fn unlock(&self, path: &str, args: OpUnlock) -> Result<RpUnlock> {
+        let abs_path = build_abs_path(&self.info.root(), path);
+        self.core
+            .unlock(&abs_path, args.owner(), args.token())
+            .await?;
+        Ok(RpUnlock::default())
+    }
 }
@@ -17,11 +17,18 @@
 
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::time::Duration;
 
 use bb8::PooledConnection;
 use bb8::RunError;
 use etcd_client::Client;
+use etcd_client::Compare;
+use etcd_client::CompareOp;
 use etcd_client::ConnectOptions;
+use etcd_client::PutOptions;
+use etcd_client::Txn;
+use etcd_client::TxnOp;
+use etcd_client::TxnOpResponse;
 use tokio::sync::OnceCell;
 
 use crate::services::etcd::error::format_etcd_error;
@@ -126,10 +133,44 @@ impl EtcdCore {
         }
     }
 
-    pub async fn set(&self, key: &str, value: Buffer) -> Result<()> {
+    /// Get the value of `key` with its remaining ttl if it's attached to a lease.
+    pub async fn get_with_ttl(&self, key: &str) -> Result<Option<(Buffer, Option<Duration>)>> {
         let mut client = self.conn().await?;
+        let resp = client.get(key, None).await.map_err(format_etcd_error)?;
+        let Some(kv) = resp.kvs().first() else {
+            return Ok(None);
+        };
+
+        let value = Buffer::from(kv.value().to_vec());
+        if kv.lease() == 0 {
+            return Ok(Some((value, None)));
+        }
+        let ttl = client
+            .lease_time_to_live(kv.lease(), None)
+            .await
+            .map_err(format_etcd_error)?
+            .ttl();
+        Ok(Some((value, Some(Duration::from_secs(ttl.max(0) as u64)))))
+    }
+
+    /// Set `key` to `value`, the key is attached to a new lease if `ttl` is given.
+    pub async fn set(&self, key: &str, value: Buffer, ttl: Option<Duration>) -> Result<()> {
+        let mut client = self.conn().await?;
+        let options = match ttl {
+            Some(ttl) => {
+                // The ttl of etcd lease is in seconds.
+                let ttl = ttl.as_millis().div_ceil(1000).max(1) as i64;
+                let lease = client
+                    .lease_grant(ttl, None)
+                    .await
+                    .map_err(format_etcd_error)?
+                    .id();
+                Some(PutOptions::new().with_lease(lease))
+            }
+            None => None,
+        };
         let _ = client
-            .put(key, value.to_vec(), None)
+            .put(key, value.to_vec(), options)
             .await
             .map_err(format_etcd_error)?;
         Ok(())
@@ -140,4 +181,131 @@ impl EtcdCore {
         let _ = client.delete(key, None).await.map_err(format_etcd_error)?;
         Ok(())
     }
+
+    /// Acquire the lock at `key` for `owner`, or renew it if `token` is set.
+    ///
+    /// The key is attached to a lease of `ttl` granted while acquiring, which is
+    /// kept alive while renewing. The create revision of the key is used as the
+    /// fencing token.
+    pub async fn lock(
+        &self,
+        key: &str,
+        ttl: Duration,
+        owner: &str,
+        token: Option<u64>,
+    ) -> Result<u64> {
+        match token {
+            Some(token) => self.renew(key, owner, token).await.map(|_| token),
+            None => self.acquire(key, ttl, owner).await,
+        }
+    }
+
+    async fn acquire(&self, key: &str, ttl: Duration, owner: &str) -> Result<u64> {
+        let mut client = self.conn().await?;
+        // The ttl of etcd lease is in seconds.
+        let ttl = ttl.as_millis().div_ceil(1000).max(1) as i64;
+        let lease = client
+            .lease_grant(ttl, None)
+            .await
+            .map_err(format_etcd_error)?
+            .id();
+
+        let txn = Txn::new()
+            .when(vec![Compare::create_revision(key, CompareOp::Equal, 0)])
+            .and_then(vec![TxnOp::put(
+                key,
+                owner,
+                Some(PutOptions::new().with_lease(lease)),
+            )])
+            .or_else(vec![TxnOp::get(key, None)]);
+        let resp = client.txn(txn).await.map_err(format_etcd_error)?;
+
+        if resp.succeeded() {
+            // The key is created by this txn, so its create revision is the txn revision.
+            return resp
+                .header()
+                .map(|v| v.revision() as u64)
+                .ok_or_else(|| Error::new(ErrorKind::Unexpected, "etcd txn has no header"));
+        }
+        // Revoke is best effort, the lease will expire otherwise.
+        let _ = client.lease_revoke(lease).await;
+
+        // Acquiring the lock again for the same owner is allowed, the key keeps
+        // the lease granted while it was acquired.
+        for op in resp.op_responses() {
+            if let TxnOpResponse::Get(get) = op {
+                if let Some(kv) = get.kvs().first() {
+                    if kv.value() == owner.as_bytes() {
+                        return Ok(kv.create_revision() as u64);
+                    }
+                }
+            }
+        }
+        Err(Error::new(
+            ErrorKind::ConditionNotMatch,
+            "lock is held by another owner",
+        ))
+    }
+
+    /// Keep the lease of the lock alive if it's still held by `owner` with `token`.
+    async fn renew(&self, key: &str, owner: &str, token: u64) -> Result<()> {
+        let lost = || {
+            Error::new(
+                ErrorKind::ConditionNotMatch,
+                "lease of the lock has been lost",
+            )
+        };
+
+        let mut client = self.conn().await?;
+        let resp = client.get(key, None).await.map_err(format_etcd_error)?;
+        let lease = match resp.kvs().first() {
+            Some(kv) if kv.create_revision() as u64 == token && kv.value() == owner.as_bytes() => {
+                kv.lease()
+            }
+            _ => return Err(lost()),
+        };
+
+        // The first response is consumed while creating the keeper, send one
+        // more request to learn the ttl after renewing.
+        let (mut keeper, mut stream) = client
+            .lease_keep_alive(lease)
+            .await
+            .map_err(format_etcd_error)?;
+        keeper.keep_alive().await.map_err(format_etcd_error)?;
+        match stream.message().await.map_err(format_etcd_error)? {
+            // The lease has expired if its ttl is not positive.
+            Some(resp) if resp.ttl() > 0 => Ok(()),
+            _ => Err(lost()),
+        }
+    }
+
+    /// Release the lock at `key` if it's still held by `owner` with `token`.
+    ///
+    /// The lease of the lock is revoked after the key is deleted.
+    pub async fn unlock(&self, key: &str, owner: &str, token: u64) -> Result<()> {
+        let mut client = self.conn().await?;
+        let txn = Txn::new()
+            .when(vec![
+                Compare::create_revision(key, CompareOp::Equal, token as i64),
+                Compare::value(key, CompareOp::Equal, owner),
+            ])
+            .and_then(vec![TxnOp::get(key, None), TxnOp::delete(key, None)]);
+        let resp = client.txn(txn).await.map_err(format_etcd_error)?;
+
+        if !resp.succeeded() {
+            return Err(Error::new(
+                ErrorKind::ConditionNotMatch,
+                "lock is not held with this token",
+            ));
+        }
+        for op in resp.op_responses() {
+            if let TxnOpResponse::Get(get) = op {
+                if let Some(kv) = get.kvs().first().filter(|kv| kv.lease() != 0) {
+                    // Revoke is best effort, the lease will expire otherwise.
+                    let _ = client.lease_revoke(kv.lease()).await;
+                }
+            }
+        }
+        Ok(())
+    }
 }
@@ -18,20 +18,22 @@
 use std::sync::Arc;
 
 use super::core::EtcdCore;
-use crate::raw::oio;
+use crate::raw::*;
 use crate::*;
 
 pub struct EtcdWriter {
     core: Arc<EtcdCore>,
     path: String,
+    op: OpWrite,
     buffer: oio::QueueBuf,
 }
 
 impl EtcdWriter {
-    pub fn new(core: Arc<EtcdCore>, path: String) -> Self {
+    pub fn new(core: Arc<EtcdCore>, path: String, op: OpWrite) -> Self {
         Self {
             core,
             path,
+            op,
             buffer: oio::QueueBuf::new(),
         }
     }
@@ -46,10 +48,15 @@ impl oio::Write for EtcdWriter {
     async fn close(&mut self) -> Result<Metadata> {
         let buf = self.buffer.clone().collect();
 
-        self.core.set(&self.path, buf.clone()).await?;
+        self.core
+            .set(&self.path, buf.clone(), self.op.ttl())
+            .await?;
 
         let mut metadata = Metadata::new(EntryMode::from_path(&self.path));
         metadata.set_content_length(buf.len() as u64);
+        if let Some(ttl) = self.op.ttl() {
+            metadata.set_expires_at(expire_time_from_ttl(ttl));
+        }
 
         Ok(metadata)
     }
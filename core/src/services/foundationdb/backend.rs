@@ -21,6 +21,8 @@ use std::sync::Arc;
 
 use foundationdb::api::NetworkAutoStop;
 use foundationdb::Database;
+use foundationdb::KeySelector;
+use foundationdb::RangeOption;
 
 use crate::raw::adapters::kv;
 use crate::raw::*;
@@ -108,8 +110,55 @@ impl Debug for Adapter {
     }
 }
 
+impl kv::ScanPage for Adapter {
+    async fn next_page(
+        &self,
+        prefix: &str,
+        start_after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<String>> {
+        let transaction = self.db.create_trx().expect("Unable to create transaction");
+
+        let begin = match start_after {
+            Some(key) => KeySelector::first_greater_than(key.as_bytes().to_vec()),
+            None => KeySelector::first_greater_or_equal(prefix.as_bytes().to_vec()),
+        };
+        // Keys are valid utf-8, so that all keys with prefix are less than `prefix\xff`.
+        let mut end = prefix.as_bytes().to_vec();
+        end.push(0xff);
+
+        let mut keys = Vec::with_capacity(limit);
+        let mut opt = Some(RangeOption {
+            begin,
+            end: KeySelector::first_greater_or_equal(end),
+            limit: Some(limit),
+            ..RangeOption::default()
+        });
+        let mut iteration = 1;
+        while let Some(current) = opt {
+            let values = transaction
+                .get_range(&current, iteration, true)
+                .await
+                .map_err(|e| {
+                    Error::new(ErrorKind::Unexpected, e.to_string().as_str())
+                        .with_context("service", Scheme::Foundationdb)
+                })?;
+            for value in values.iter() {
+                let key = String::from_utf8(value.key().to_vec()).map_err(|err| {
+                    Error::new(ErrorKind::Unexpected, "store key is not valid utf-8 string")
+                        .set_source(err)
+                })?;
+                keys.push(key);
+            }
+            opt = current.next_range(&values);
+            iteration += 1;
+        }
+        Ok(keys)
+    }
+}
+
 impl kv::Adapter for Adapter {
-    type Scanner = ();
+    type Scanner = kv::PagedScanner<Self>;
 
     fn info(&self) -> kv::Info {
         kv::Info::new(
@@ -118,7 +167,10 @@ impl kv::Adapter for Adapter {
             Capability {
                 read: true,
                 write: true,
+                list: true,
                 delete: true,
+                lock: true,
+                transaction: true,
                 shared: true,
                 ..Default::default()
             },
@@ -163,6 +215,66 @@ impl kv::Adapter for Adapter {
             Err(e) => Err(parse_transaction_commit_error(e)),
         }
     }
+
+    async fn scan(&self, path: &str, opts: kv::ScanOptions) -> Result<Self::Scanner> {
+        Ok(kv::PagedScanner::new(self.clone(), path, opts))
+    }
+
+    async fn compare_and_swap(
+        &self,
+        path: &str,
+        current: Option<Buffer>,
+        value: Buffer,
+    ) -> Result<bool> {
+        let transaction = self.db.create_trx().expect("Unable to create transaction");
+
+        let actual = transaction.get(path.as_bytes(), false).await.map_err(|e| {
+            Error::new(ErrorKind::Unexpected, e.to_string().as_str())
+                .with_context("service", Scheme::Foundationdb)
+        })?;
+        if actual.as_deref() != current.map(|v| v.to_vec()).as_deref() {
+            return Ok(false);
+        }
+        transaction.set(path.as_bytes(), &value.to_vec());
+
+        match transaction.commit().await {
+            Ok(_) => Ok(true),
+            // `not_committed`: the key has been changed by another transaction.
+            Err(e) if e.code() == 1020 => Ok(false),
+            Err(e) => Err(parse_transaction_commit_error(e)),
+        }
+    }
+
+    async fn transaction(
+        &self,
+        checks: Vec<(String, Option<Buffer>)>,
+        mutations: Vec<(String, Option<Buffer>)>,
+    ) -> Result<bool> {
+        let transaction = self.db.create_trx().expect("Unable to create transaction");
+
+        for (path, expected) in checks {
+            let actual = transaction.get(path.as_bytes(), false).await.map_err(|e| {
+                Error::new(ErrorKind::Unexpected, e.to_string().as_str())
+                    .with_context("service", Scheme::Foundationdb)
+            })?;
+            if actual.as_deref() != expected.map(|v| v.to_vec()).as_deref() {
+                return Ok(false);
+            }
+        }
+        for (path, value) in mutations {
+            match value {
+                Some(value) => transaction.set(path.as_bytes(), &value.to_vec()),
+                None => transaction.clear(path.as_bytes()),
+            }
+        }
+
+        match transaction.commit().await {
+            Ok(_) => Ok(true),
+            // `not_committed`: keys read have been changed by another transaction.
+            Err(e) if e.code() == 1020 => Ok(false),
+            Err(e) => Err(parse_transaction_commit_error(e)),
+        }
+    }
 }
 
 fn parse_transaction_commit_error(e: foundationdb::TransactionCommitError) -> Error {
@@ -22,10 +22,13 @@ use log::debug;
 
 use super::core::*;
 use super::delete::FsDeleter;
+use super::direct::*;
+use super::group_commit::*;
 use super::lister::FsLister;
 use super::reader::FsReader;
 use super::writer::FsWriter;
 use super::writer::FsWriters;
+use super::xattr::*;
 use super::DEFAULT_SCHEME;
 use crate::raw::*;
 use crate::services::FsConfig;
@@ -69,6 +72,69 @@ impl FsBuilder {
 
         self
     }
+
+    /// Enable storing metadata in extended attributes of files.
+    ///
+    /// Content type, content disposition, cache control and user metadata of
+    /// writes are stored in `user.opendal.*` attributes, every write also stores
+    /// a new etag so that conditional reads and writes work.
+    ///
+    /// # Notes
+    ///
+    /// - The file system must support user extended attributes.
+    /// - Files changed without OpenDAL keep their stale metadata and etag.
+    /// - `atomic_write_dir` is required, files are written into temp files in it.
+    /// - Conditional writes are only atomic between writers in the same process.
+    pub fn enable_xattr(mut self, enabled: bool) -> Self {
+        self.config.enable_xattr = enabled;
+        self
+    }
+
+    /// Don't fsync files while closing writers.
+    ///
+    /// Written data could be lost on power failure or system crash, only use
+    /// it for data that can be rebuilt.
+    pub fn disable_sync(mut self, disabled: bool) -> Self {
+        self.config.disable_sync = disabled;
+        self
+    }
+
+    /// Fsync the parent dir after files are written, copied or renamed.
+    ///
+    /// Fsync of a file doesn't make its entry in the parent dir durable, new or
+    /// renamed files could disappear after power failure without this. It's
+    /// required for atomic writes to survive power failure.
+    pub fn sync_dir(mut self, enabled: bool) -> Self {
+        self.config.sync_dir = enabled;
+        self
+    }
+
+    /// Sync files closed concurrently together with one `syncfs`.
+    ///
+    /// # Notes
+    ///
+    /// - Only supported on linux.
+    /// - `syncfs` flushes all dirty data of the file system, it's faster than
+    ///   many `fsync` only if lots of small files are closed concurrently.
+    pub fn enable_group_commit(mut self, enabled: bool) -> Self {
+        self.config.enable_group_commit = enabled;
+        self
+    }
+
+    /// Read and write files with `O_DIRECT` to bypass page cache.
+    ///
+    /// # Notes
+    ///
+    /// - Only supported on linux, files on file systems without `O_DIRECT`
+    ///   support are read and written as usual.
+    /// - Only reads and sequential writes of new content are affected, appends
+    ///   and random access writes still go through page cache.
+    /// - Data is read and written with aligned buffers of 2 MiB, it's only
+    ///   suitable for large sequential IO.
+    pub fn enable_direct_io(mut self, enabled: bool) -> Self {
+        self.config.enable_direct_io = enabled;
+        self
+    }
 }
 
 impl Builder for FsBuilder {
@@ -98,6 +164,44 @@ impl Builder for FsBuilder {
             }
         }
 
+        if self.config.enable_xattr && !XATTR_SUPPORTED {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "extended attributes are not supported on this platform",
+            )
+            .with_operation("Builder::build"));
+        }
+        // Temp files beside targets would be visible and left behind on crash.
+        if self.config.enable_xattr && self.config.atomic_write_dir.is_none() {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "atomic_write_dir is required while xattr is enabled",
+            )
+            .with_operation("Builder::build"));
+        }
+
+        if self.config.enable_group_commit && !GROUP_COMMIT_SUPPORTED {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "group commit is not supported on this platform",
+            )
+            .with_operation("Builder::build"));
+        }
+        if self.config.enable_group_commit && self.config.disable_sync {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "group commit can't be enabled while sync is disabled",
+            )
+            .with_operation("Builder::build"));
+        }
+        if self.config.enable_direct_io && !DIRECT_IO_SUPPORTED {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "direct io is not supported on this platform",
+            )
+            .with_operation("Builder::build"));
+        }
+
         let atomic_write_dir = self.config.atomic_write_dir.map(PathBuf::from);
 
         // If atomic write dir is not exist, we must create it.
@@ -140,6 +244,7 @@ impl Builder for FsBuilder {
             })
             .unwrap_or(Ok(None))?;
 
+        let enable_xattr = self.config.enable_xattr;
         Ok(FsBackend {
             core: Arc::new(FsCore {
                 info: {
@@ -148,16 +253,36 @@ impl Builder for FsBuilder {
                         .set_root(&root.to_string_lossy())
                         .set_native_capability(Capability {
                             stat: true,
+                            stat_with_follow_symlinks: true,
+                            stat_with_if_match: enable_xattr,
+                            stat_with_if_none_match: enable_xattr,
 
                             read: true,
+                            read_with_if_match: enable_xattr,
+                            read_with_if_none_match: enable_xattr,
 
                             write: true,
                             write_can_empty: true,
                             write_can_append: true,
+                            write_can_random_access: true,
+                            write_can_set_len: true,
                             write_can_multi: true,
                             write_with_if_not_exists: true,
+                            write_with_if_match: enable_xattr,
+                            write_with_if_none_match: enable_xattr,
+                            write_with_content_type: enable_xattr,
+                            write_with_content_disposition: enable_xattr,
+                            write_with_cache_control: enable_xattr,
+                            write_with_user_metadata: enable_xattr,
+
+                            update_metadata: enable_xattr,
+                            update_metadata_with_if_match: enable_xattr,
 
                             create_dir: true,
+                            create_symlink: cfg!(any(unix, windows)),
+                            read_link: true,
+                            set_permissions: cfg!(unix),
+                            set_times: true,
                             delete: true,
 
                             list: true,
@@ -174,6 +299,12 @@ impl Builder for FsBuilder {
                 },
                 root,
                 atomic_write_dir,
+                enable_xattr,
+                commit_lock: tokio::sync::Mutex::new(()),
+                disable_sync: self.config.disable_sync,
+                sync_dir: self.config.sync_dir,
+                group_commit: self.config.enable_group_commit.then(GroupCommit::default),
+                enable_direct_io: self.config.enable_direct_io,
                 buf_pool: oio::PooledBuf::new(16).with_initial_capacity(256 * 1024),
             }),
         })
@@ -187,7 +318,7 @@ pub struct FsBackend {
 }
 
 impl Access for FsBackend {
-    type Reader = FsReader<tokio::fs::File>;
+    type Reader = TwoWays<FsReader<tokio::fs::File>, FsDirectReader>;
     type Writer = FsWriters;
     type Lister = Option<FsLister<tokio::fs::ReadDir>>;
     type Deleter = oio::OneShotDeleter<FsDeleter>;
@@ -201,8 +332,11 @@ impl Access for FsBackend {
         Ok(RpCreateDir::default())
     }
 
-    async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
-        let m = self.core.fs_stat(path).await?;
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let m = self.core.fs_stat(path, args.follow_symlinks()).await?;
+        if args.if_match().is_some() || args.if_none_match().is_some() {
+            check_conditions(m.etag(), args.if_match(), args.if_none_match())?;
+        }
         Ok(RpStat::new(m))
     }
 
@@ -217,21 +351,32 @@ impl Access for FsBackend {
     /// Benchmark could be found [here](https://gist.github.com/Xuanwo/48f9cfbc3022ea5f865388bb62e1a70f)
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
         let f = self.core.fs_read(path, &args).await?;
-        let r = FsReader::new(
-            self.core.clone(),
-            f,
-            args.range().size().unwrap_or(u64::MAX) as _,
-        );
-        Ok((RpRead::new(), r))
+        let size = args.range().size().unwrap_or(u64::MAX);
+
+        if self.core.enable_direct_io {
+            let f = f.into_std().await;
+            // Fallback to buffered read if the file system doesn't support `O_DIRECT`.
+            let r = if set_direct_flag(&f).map_err(new_std_io_error)? {
+                TwoWays::Two(FsDirectReader::new(f, args.range().offset(), size))
+            } else {
+                let f = tokio::fs::File::from_std(f);
+                TwoWays::One(FsReader::new(self.core.clone(), f, size as _))
+            };
+            return Ok((RpRead::new(), r));
+        }
+
+        let r = FsReader::new(self.core.clone(), f, size as _);
+        Ok((RpRead::new(), TwoWays::One(r)))
     }
 
     async fn write(&self, path: &str, op: OpWrite) -> Result<(RpWrite, Self::Writer)> {
-        let is_append = op.append();
+        // Direct writes must be sequential to keep offsets aligned.
+        let is_sequential = !op.random_access() && (op.append() || self.core.enable_direct_io);
         let concurrent = op.concurrent();
 
         let writer = FsWriter::create(self.core.clone(), path, op).await?;
 
-        let writer = if is_append {
+        let writer = if is_sequential {
             FsWriters::One(writer)
         } else {
             FsWriters::Two(oio::PositionWriter::new(
@@ -270,4 +415,38 @@ impl Access for FsBackend {
         self.core.fs_rename(from, to).await?;
         Ok(RpRename::default())
     }
+
+    async fn create_symlink(&self, path: &str, args: OpCreateSymlink) -> Result<RpCreateSymlink> {
+        self.core.fs_create_symlink(args.target(), path).await?;
+        Ok(RpCreateSymlink::default())
+    }
+
+    async fn read_link(&self, path: &str, _: OpReadLink) -> Result<RpReadLink> {
+        let target = self.core.fs_read_link(path).await?;
+        Ok(RpReadLink::new(&target))
+    }
+
+    async fn set_permissions(
+        &self,
+        path: &str,
+        args: OpSetPermissions,
+    ) -> Result<RpSetPermissions> {
+        self.core.fs_set_permissions(path, args.mode()).await?;
+        Ok(RpSetPermissions::default())
+    }
+
+    async fn set_times(&self, path: &str, args: OpSetTimes) -> Result<RpSetTimes> {
+        self.core.fs_set_times(path, &args).await?;
+        Ok(RpSetTimes::default())
+    }
+
+    async fn update_metadata(
+        &self,
+        path: &str,
+        args: OpUpdateMetadata,
+    ) -> Result<RpUpdateMetadata> {
+        self.core.fs_update_metadata(path, args.update()).await?;
+        let meta = self.core.fs_stat(path, true).await?;
+        Ok(RpUpdateMetadata::new(meta))
+    }
 }
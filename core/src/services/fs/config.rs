@@ -30,4 +30,19 @@ pub struct FsConfig {
 
     /// tmp dir for atomic write
     pub atomic_write_dir: Option<String>,
+
+    /// store metadata like content type and user metadata in extended attributes
+    pub enable_xattr: bool,
+
+    /// don't fsync files on close, written data could be lost on power failure
+    pub disable_sync: bool,
+
+    /// fsync the parent dir after files are written or renamed
+    pub sync_dir: bool,
+
+    /// fsync files closed concurrently together with one `syncfs`, only supported on linux
+    pub enable_group_commit: bool,
+
+    /// read and write files sequentially with `O_DIRECT` to bypass page cache, only supported on linux
+    pub enable_direct_io: bool,
 }
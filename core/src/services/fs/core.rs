@@ -23,6 +23,8 @@ use std::sync::Arc;
 use chrono::DateTime;
 
 use super::error::*;
+use super::group_commit::GroupCommit;
+use super::xattr::*;
 use crate::raw::*;
 use crate::*;
 
@@ -31,6 +33,14 @@ pub struct FsCore {
     pub info: Arc<AccessorInfo>,
     pub root: PathBuf,
     pub atomic_write_dir: Option<PathBuf>,
+    pub enable_xattr: bool,
+    /// Serializes renaming of conditional writes, see `FsWriter::commit`.
+    pub commit_lock: tokio::sync::Mutex<()>,
+    pub disable_sync: bool,
+    pub sync_dir: bool,
+    /// Files are synced with `syncfs` together if group commit is enabled.
+    pub group_commit: Option<GroupCommit>,
+    pub enable_direct_io: bool,
     pub buf_pool: oio::PooledBuf,
 }
 
@@ -71,28 +81,206 @@ impl FsCore {
         Ok(())
     }
 
-    pub async fn fs_stat(&self, path: &str) -> Result<Metadata> {
+    pub async fn fs_stat(&self, path: &str, follow_symlinks: bool) -> Result<Metadata> {
         let p = self.root.join(path.trim_end_matches('/'));
-        let meta = tokio::fs::metadata(&p).await.map_err(new_std_io_error)?;
+        let meta = if follow_symlinks {
+            tokio::fs::metadata(&p).await
+        } else {
+            tokio::fs::symlink_metadata(&p).await
+        }
+        .map_err(new_std_io_error)?;
 
         let mode = if meta.is_dir() {
             EntryMode::DIR
         } else if meta.is_file() {
             EntryMode::FILE
+        } else if meta.is_symlink() {
+            EntryMode::Symlink
         } else {
             EntryMode::Unknown
         };
-        let m = Metadata::new(mode)
+        let mut m = Metadata::new(mode)
             .with_content_length(meta.len())
             .with_last_modified(
                 meta.modified()
                     .map(DateTime::from)
                     .map_err(new_std_io_error)?,
             );
+        if mode == EntryMode::Symlink {
+            m.set_symlink_target(&self.fs_read_link(path).await?);
+        } else if self.enable_xattr {
+            m = self.fs_load_xattr(p).await?.apply(m);
+        }
+        // Not all platforms and filesystems record access or creation time.
+        if let Ok(v) = meta.accessed() {
+            m.set_accessed(DateTime::from(v));
+        }
+        if let Ok(v) = meta.created() {
+            m.set_created(DateTime::from(v));
+        }
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+
+            m.set_unix_mode(meta.mode() & 0o7777)
+                .set_uid(meta.uid())
+                .set_gid(meta.gid());
+        }
 
         Ok(m)
     }
 
+    /// Load the metadata stored in extended attributes of the file at `p`.
+    pub async fn fs_load_xattr(&self, p: PathBuf) -> Result<XattrMetadata> {
+        tokio::task::spawn_blocking(move || XattrMetadata::load(&p))
+            .await
+            .map_err(new_task_join_error)?
+    }
+
+    /// Store `meta` in extended attributes of the file at `p`.
+    pub async fn fs_store_xattr(&self, p: PathBuf, meta: XattrMetadata) -> Result<()> {
+        tokio::task::spawn_blocking(move || meta.store(&p))
+            .await
+            .map_err(new_task_join_error)?
+    }
+
+    /// Update the metadata stored in extended attributes of the file at `path`.
+    pub async fn fs_update_metadata(&self, path: &str, update: &MetadataUpdate) -> Result<()> {
+        let p = self.root.join(path.trim_end_matches('/'));
+        tokio::fs::metadata(&p).await.map_err(new_std_io_error)?;
+
+        // Hold the commit lock like conditional writes, so that the file is not
+        // replaced between checking its etag and storing the metadata.
+        let _guard = self.commit_lock.lock().await;
+        let mut meta = self.fs_load_xattr(p.clone()).await?;
+        check_conditions(meta.etag(), update.if_match(), None)?;
+        meta.update(update);
+        self.fs_store_xattr(p, meta).await
+    }
+
+    /// Make the content written to `f` durable unless sync is disabled.
+    pub async fn fs_sync_file(&self, f: std::fs::File) -> Result<()> {
+        if self.disable_sync {
+            return Ok(());
+        }
+        if let Some(group_commit) = &self.group_commit {
+            return group_commit.sync(f).await;
+        }
+
+        tokio::task::spawn_blocking(move || f.sync_all())
+            .await
+            .map_err(new_task_join_error)?
+            .map_err(new_std_io_error)
+    }
+
+    /// Make the entry of `p` in its parent dir durable if `sync_dir` is enabled.
+    pub async fn fs_sync_parent(&self, p: &Path) -> Result<()> {
+        if !self.sync_dir {
+            return Ok(());
+        }
+        let Some(parent) = p.parent().map(Path::to_path_buf) else {
+            return Ok(());
+        };
+
+        // Directories can't be opened as files on windows.
+        #[cfg(unix)]
+        tokio::task::spawn_blocking(move || std::fs::File::open(parent)?.sync_all())
+            .await
+            .map_err(new_task_join_error)?
+            .map_err(new_std_io_error)?;
+        #[cfg(not(unix))]
+        let _ = parent;
+
+        Ok(())
+    }
+
+    pub async fn fs_set_permissions(&self, path: &str, mode: u32) -> Result<()> {
+        let p = self.root.join(path.trim_end_matches('/'));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            tokio::fs::set_permissions(&p, std::fs::Permissions::from_mode(mode))
+                .await
+                .map_err(new_std_io_error)
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = (p, mode);
+            Err(Error::new(
+                ErrorKind::Unsupported,
+                "set permissions is not supported on this platform",
+            ))
+        }
+    }
+
+    pub async fn fs_set_times(&self, path: &str, args: &OpSetTimes) -> Result<()> {
+        let p = self.root.join(path.trim_end_matches('/'));
+
+        let mut times = std::fs::FileTimes::new();
+        if let Some(v) = args.accessed() {
+            times = times.set_accessed(v.into());
+        }
+        if let Some(v) = args.modified() {
+            times = times.set_modified(v.into());
+        }
+
+        tokio::task::spawn_blocking(move || {
+            // Windows requires write access to change times, but directories
+            // can't be opened for write on unix.
+            let f = std::fs::OpenOptions::new()
+                .read(!cfg!(windows))
+                .write(cfg!(windows))
+                .open(&p)?;
+            f.set_times(times)
+        })
+        .await
+        .map_err(new_task_join_error)?
+        .map_err(new_std_io_error)
+    }
+
+    pub async fn fs_create_symlink(&self, target: &str, path: &str) -> Result<()> {
+        let p = self
+            .ensure_write_abs_path(&self.root, path.trim_end_matches('/'))
+            .await?;
+
+        #[cfg(unix)]
+        {
+            tokio::fs::symlink(target, &p)
+                .await
+                .map_err(new_std_io_error)
+        }
+        #[cfg(windows)]
+        {
+            tokio::fs::symlink_file(target, &p)
+                .await
+                .map_err(new_std_io_error)
+        }
+        #[cfg(not(any(unix, windows)))]
+        {
+            let _ = (target, p);
+            Err(Error::new(
+                ErrorKind::Unsupported,
+                "symlink is not supported on this platform",
+            ))
+        }
+    }
+
+    pub async fn fs_read_link(&self, path: &str) -> Result<String> {
+        let p = self.root.join(path.trim_end_matches('/'));
+        let target = tokio::fs::read_link(&p).await.map_err(|err| {
+            // `read_link` returns `EINVAL` if path is not a symlink, it's not retryable.
+            if err.kind() == std::io::ErrorKind::InvalidInput {
+                Error::new(ErrorKind::Unexpected, "path is not a symlink").set_source(err)
+            } else {
+                new_std_io_error(err)
+            }
+        })?;
+
+        Ok(target.to_string_lossy().to_string())
+    }
+
     pub async fn fs_read(&self, path: &str, args: &OpRead) -> Result<tokio::fs::File> {
         let p = self.root.join(path.trim_end_matches('/'));
 
@@ -102,6 +290,11 @@ impl FsCore {
             .await
             .map_err(new_std_io_error)?;
 
+        if args.if_match().is_some() || args.if_none_match().is_some() {
+            let meta = self.fs_load_xattr(p).await?;
+            check_conditions(meta.etag(), args.if_match(), args.if_none_match())?;
+        }
+
         if args.range().offset() != 0 {
             use tokio::io::AsyncSeekExt;
             f.seek(SeekFrom::Start(args.range().offset()))
@@ -207,8 +400,28 @@ impl FsCore {
             .ensure_write_abs_path(&self.root, to.trim_end_matches('/'))
             .await?;
 
-        tokio::fs::copy(from, to).await.map_err(new_std_io_error)?;
-        Ok(())
+        tokio::task::spawn_blocking({
+            let (from, to) = (from.clone(), to.clone());
+            move || std_copy_file(&from, &to)
+        })
+        .await
+        .map_err(new_task_join_error)?
+        .map_err(new_std_io_error)?;
+
+        // Extended attributes are not copied, the copy gets its own etag like a new write.
+        if self.enable_xattr {
+            let meta = self.fs_load_xattr(from).await?;
+            if meta.etag().is_some() {
+                self.fs_store_xattr(to.clone(), meta.with_new_etag())
+                    .await?;
+            }
+        }
+
+        if !self.disable_sync {
+            let f = tokio::fs::File::open(&to).await.map_err(new_std_io_error)?;
+            self.fs_sync_file(f.into_std().await).await?;
+        }
+        self.fs_sync_parent(&to).await
     }
 
     pub async fn fs_rename(&self, from: &str, to: &str) -> Result<()> {
@@ -219,9 +432,14 @@ impl FsCore {
             .ensure_write_abs_path(&self.root, to.trim_end_matches('/'))
             .await?;
 
-        tokio::fs::rename(from, to)
+        tokio::fs::rename(&from, &to)
             .await
             .map_err(new_std_io_error)?;
+
+        self.fs_sync_parent(&to).await?;
+        if from.parent() != to.parent() {
+            self.fs_sync_parent(&from).await?;
+        }
         Ok(())
     }
 }
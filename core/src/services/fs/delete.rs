@@ -35,7 +35,8 @@ impl oio::OneShotDelete for FsDeleter {
     async fn delete_once(&self, path: String, _: OpDelete) -> Result<()> {
         let p = self.core.root.join(path.trim_end_matches('/'));
 
-        let meta = tokio::fs::metadata(&p).await;
+        // Use `symlink_metadata` so that we delete the symlink itself instead of its target.
+        let meta = tokio::fs::symlink_metadata(&p).await;
 
         match meta {
             Ok(meta) => {
@@ -0,0 +1,344 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fs::File;
+use std::sync::Arc;
+
+use bytes::Buf;
+use bytes::Bytes;
+
+use crate::raw::*;
+use crate::*;
+
+/// Whether direct I/O is supported on this platform.
+pub const DIRECT_IO_SUPPORTED: bool = cfg!(any(target_os = "linux", target_os = "android"));
+
+/// Offsets, lengths and buffers of direct I/O must be aligned to this value.
+///
+/// 4 KiB covers the logical block size of almost all devices.
+pub const DIRECT_IO_ALIGN: usize = 4096;
+
+/// The size of buffers used by direct I/O, must be a multiple of [`DIRECT_IO_ALIGN`].
+pub const DIRECT_IO_BUFFER_SIZE: usize = 2 * 1024 * 1024;
+
+/// AlignedBuf is a buffer whose start address is aligned to [`DIRECT_IO_ALIGN`].
+pub struct AlignedBuf {
+    buf: Vec<u8>,
+    /// The offset of the aligned start in `buf`.
+    start: usize,
+    len: usize,
+    cap: usize,
+}
+
+impl AlignedBuf {
+    pub fn new(cap: usize) -> Self {
+        let buf = vec![0; cap + DIRECT_IO_ALIGN];
+        let start = buf.as_ptr().align_offset(DIRECT_IO_ALIGN);
+        Self {
+            buf,
+            start,
+            len: 0,
+            cap,
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == self.cap
+    }
+
+    /// Copy bytes from `bs` as many as possible, returns the number of copied bytes.
+    pub fn push(&mut self, bs: &[u8]) -> usize {
+        let n = bs.len().min(self.cap - self.len);
+        let at = self.start + self.len;
+        self.buf[at..at + n].copy_from_slice(&bs[..n]);
+        self.len += n;
+        n
+    }
+
+    /// The filled part of this buffer.
+    pub fn filled(&self) -> &[u8] {
+        &self.buf[self.start..self.start + self.len]
+    }
+
+    /// The whole capacity of this buffer, used to read into.
+    pub fn spare_mut(&mut self) -> &mut [u8] {
+        &mut self.buf[self.start..self.start + self.cap]
+    }
+
+    pub fn set_len(&mut self, len: usize) {
+        assert!(len <= self.cap, "len must not exceed capacity");
+        self.len = len;
+    }
+
+    /// Convert the filled part into bytes without copying.
+    pub fn into_bytes(self) -> Bytes {
+        Bytes::from(self.buf).slice(self.start..self.start + self.len)
+    }
+}
+
+/// Round `n` down to a multiple of [`DIRECT_IO_ALIGN`].
+pub fn align_down(n: u64) -> u64 {
+    n - n % DIRECT_IO_ALIGN as u64
+}
+
+/// Turn on `O_DIRECT` of `f`, returns `false` if the file system doesn't support it.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn set_direct_flag(f: &File) -> std::io::Result<bool> {
+    use rustix::fs::OFlags;
+    use rustix::io::Errno;
+
+    let flags = rustix::fs::fcntl_getfl(f)?;
+    match rustix::fs::fcntl_setfl(f, flags | OFlags::DIRECT) {
+        Ok(()) => Ok(true),
+        Err(Errno::INVAL) => Ok(false),
+        Err(err) => Err(err.into()),
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub fn set_direct_flag(_: &File) -> std::io::Result<bool> {
+    Ok(false)
+}
+
+/// Turn off `O_DIRECT` of `f`, so that the unaligned tail of a file can be written.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn clear_direct_flag(f: &File) -> std::io::Result<()> {
+    use rustix::fs::OFlags;
+
+    let flags = rustix::fs::fcntl_getfl(f)?;
+    rustix::fs::fcntl_setfl(f, flags - OFlags::DIRECT)?;
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub fn clear_direct_flag(_: &File) -> std::io::Result<()> {
+    Ok(())
+}
+
+/// FsDirectReader reads files opened with `O_DIRECT`.
+///
+/// Every read starts at an aligned offset and fills an aligned buffer, bytes
+/// before the requested offset are dropped.
+pub struct FsDirectReader {
+    f: Arc<File>,
+    offset: u64,
+    remaining: u64,
+}
+
+impl FsDirectReader {
+    pub fn new(f: File, offset: u64, size: u64) -> Self {
+        Self {
+            f: Arc::new(f),
+            offset,
+            remaining: size,
+        }
+    }
+}
+
+impl oio::Read for FsDirectReader {
+    async fn read(&mut self) -> Result<Buffer> {
+        if self.remaining == 0 {
+            return Ok(Buffer::new());
+        }
+
+        let start = align_down(self.offset);
+        let skip = (self.offset - start) as usize;
+
+        let f = self.f.clone();
+        let buf = tokio::task::spawn_blocking(move || read_at(&f, start))
+            .await
+            .map_err(new_task_join_error)?
+            .map_err(new_std_io_error)?;
+
+        let bs = buf.into_bytes();
+        if bs.len() <= skip {
+            self.remaining = 0;
+            return Ok(Buffer::new());
+        }
+        let n = (bs.len() - skip).min(self.remaining as usize);
+        self.offset += n as u64;
+        self.remaining -= n as u64;
+        Ok(Buffer::from(bs.slice(skip..skip + n)))
+    }
+}
+
+/// DirectWriter writes files opened with `O_DIRECT` sequentially.
+///
+/// Data is collected into an aligned buffer and written once the buffer is
+/// full. The unaligned tail is written after `O_DIRECT` is turned off while
+/// closing.
+pub struct DirectWriter {
+    f: Arc<File>,
+    buf: Option<AlignedBuf>,
+}
+
+impl DirectWriter {
+    pub fn new(f: File) -> Self {
+        Self {
+            f: Arc::new(f),
+            buf: Some(AlignedBuf::new(DIRECT_IO_BUFFER_SIZE)),
+        }
+    }
+
+    pub async fn write(&mut self, mut bs: Buffer) -> Result<()> {
+        while bs.has_remaining() {
+            let buf = self.buf.as_mut().expect("buf must be valid");
+            let n = buf.push(bs.chunk());
+            bs.advance(n);
+            if buf.is_full() {
+                self.flush(false).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write all buffered data, must be called before the file is synced.
+    pub async fn close(&mut self) -> Result<()> {
+        self.flush(true).await
+    }
+
+    async fn flush(&mut self, last: bool) -> Result<()> {
+        let buf = self.buf.take().expect("buf must be valid");
+        let f = self.f.clone();
+        let (mut buf, res) = tokio::task::spawn_blocking(move || {
+            let res = write_buf(&f, &buf, last);
+            (buf, res)
+        })
+        .await
+        .map_err(new_task_join_error)?;
+        if res.is_ok() {
+            buf.set_len(0);
+        }
+        self.buf = Some(buf);
+        res.map_err(new_std_io_error)
+    }
+}
+
+/// Write the whole `buf` at the current position of `f`, the unaligned tail is
+/// only allowed if `last` is true.
+fn write_buf(mut f: &File, buf: &AlignedBuf, last: bool) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let bs = buf.filled();
+    if !last {
+        return f.write_all(bs);
+    }
+
+    let aligned = align_down(bs.len() as u64) as usize;
+    f.write_all(&bs[..aligned])?;
+    if aligned < bs.len() {
+        clear_direct_flag(f)?;
+        f.write_all(&bs[aligned..])?;
+    }
+    Ok(())
+}
+
+/// Fill a buffer from `offset` of `f` until it's full or EOF is reached.
+fn read_at(f: &File, offset: u64) -> std::io::Result<AlignedBuf> {
+    use std::os::unix::fs::FileExt;
+
+    let mut buf = AlignedBuf::new(DIRECT_IO_BUFFER_SIZE);
+    let mut filled = 0;
+    while filled < DIRECT_IO_BUFFER_SIZE {
+        let n = f.read_at(&mut buf.spare_mut()[filled..], offset + filled as u64)?;
+        if n == 0 {
+            break;
+        }
+        filled += n;
+        // Direct reads only return less than requested at EOF.
+        if filled % DIRECT_IO_ALIGN != 0 {
+            break;
+        }
+    }
+    buf.set_len(filled);
+    Ok(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_align_down() {
+        assert_eq!(align_down(0), 0);
+        assert_eq!(align_down(4095), 0);
+        assert_eq!(align_down(4096), 4096);
+        assert_eq!(align_down(8193), 8192);
+    }
+
+    #[test]
+    fn test_aligned_buf() {
+        let mut buf = AlignedBuf::new(2 * DIRECT_IO_ALIGN);
+        assert_eq!(buf.filled().as_ptr() as usize % DIRECT_IO_ALIGN, 0);
+        assert_eq!(buf.spare_mut().as_ptr() as usize % DIRECT_IO_ALIGN, 0);
+        assert_eq!(buf.spare_mut().len(), 2 * DIRECT_IO_ALIGN);
+
+        let content: Vec<u8> = (0..3 * DIRECT_IO_ALIGN).map(|v| v as u8).collect();
+        assert_eq!(buf.push(&content[..5000]), 5000);
+        assert!(!buf.is_full());
+        // Push stops at capacity.
+        assert_eq!(buf.push(&content[5000..]), 2 * DIRECT_IO_ALIGN - 5000);
+        assert!(buf.is_full());
+        assert_eq!(buf.push(&content), 0);
+
+        let bs = buf.into_bytes();
+        assert_eq!(bs.as_ptr() as usize % DIRECT_IO_ALIGN, 0);
+        assert_eq!(bs, content[..2 * DIRECT_IO_ALIGN]);
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[tokio::test]
+    async fn test_direct_round_trip() {
+        use rand::RngCore;
+
+        let path = std::env::temp_dir().join(format!("opendal-direct-{}", uuid::Uuid::new_v4()));
+        // Larger than one buffer and not aligned.
+        let mut content = vec![0; DIRECT_IO_BUFFER_SIZE + 3 * DIRECT_IO_ALIGN + 123];
+        rand::thread_rng().fill_bytes(&mut content);
+
+        let f = std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .unwrap();
+        // Files are written without `O_DIRECT` on file systems not supporting it.
+        set_direct_flag(&f).unwrap();
+        let mut w = DirectWriter::new(f);
+        for chunk in content.chunks(1000 * 1000) {
+            w.write(Buffer::from(chunk.to_vec())).await.unwrap();
+        }
+        w.close().await.unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), content);
+
+        // Read an unaligned range across the buffer boundary.
+        let (offset, size) = (5000, DIRECT_IO_BUFFER_SIZE as u64);
+        let f = std::fs::File::open(&path).unwrap();
+        set_direct_flag(&f).unwrap();
+        let mut r = FsDirectReader::new(f, offset, size);
+        let mut read = Vec::new();
+        loop {
+            let bs = oio::Read::read(&mut r).await.unwrap();
+            if bs.is_empty() {
+                break;
+            }
+            read.extend_from_slice(&bs.to_bytes());
+        }
+        assert_eq!(read, content[offset as usize..(offset + size) as usize]);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
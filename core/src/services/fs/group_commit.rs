@@ -0,0 +1,123 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fs::File;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use crate::raw::*;
+use crate::*;
+
+/// Whether group commit is supported on this platform.
+pub const GROUP_COMMIT_SUPPORTED: bool = cfg!(any(target_os = "linux", target_os = "android"));
+
+/// GroupCommit syncs files closed concurrently with one `syncfs`.
+///
+/// A file waiting for sync is durable once a `syncfs` started after it became
+/// ready has finished. The first waiter becomes the leader and calls `syncfs`,
+/// others wait for it and call another `syncfs` for all files that became ready
+/// in the meantime.
+#[derive(Debug, Default)]
+pub struct GroupCommit {
+    lock: tokio::sync::Mutex<()>,
+    /// The id of the latest started `syncfs`.
+    started: AtomicU64,
+    /// The id of the latest finished `syncfs`.
+    finished: AtomicU64,
+}
+
+impl GroupCommit {
+    /// Wait until the content written to `f` is durable.
+    pub async fn sync(&self, f: File) -> Result<()> {
+        let ready = self.started.load(Ordering::SeqCst);
+
+        let _guard = self.lock.lock().await;
+        if self.finished.load(Ordering::SeqCst) > ready {
+            return Ok(());
+        }
+
+        let id = self.started.fetch_add(1, Ordering::SeqCst) + 1;
+        tokio::task::spawn_blocking(move || syncfs(&f))
+            .await
+            .map_err(new_task_join_error)?
+            .map_err(new_std_io_error)?;
+        self.finished.store(id, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn syncfs(f: &File) -> std::io::Result<()> {
+    Ok(rustix::fs::syncfs(f)?)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn syncfs(_: &File) -> std::io::Result<()> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "syncfs is not supported on this platform",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::pin::pin;
+
+    use super::*;
+
+    fn temp_file() -> (std::path::PathBuf, File) {
+        let path =
+            std::env::temp_dir().join(format!("opendal-group-commit-{}", uuid::Uuid::new_v4()));
+        let f = File::create(&path).unwrap();
+        (path, f)
+    }
+
+    #[tokio::test]
+    async fn test_sync_after_later_syncfs() {
+        let gc = GroupCommit::default();
+        let (path, f) = temp_file();
+
+        // Hold the lock like a leader calling `syncfs`.
+        let guard = gc.lock.lock().await;
+        let mut fut = pin!(gc.sync(f));
+        assert!(futures::poll!(fut.as_mut()).is_pending());
+
+        // The leader started and finished a `syncfs` after the file became ready.
+        let id = gc.started.fetch_add(1, Ordering::SeqCst) + 1;
+        gc.finished.store(id, Ordering::SeqCst);
+        drop(guard);
+
+        fut.await.unwrap();
+        // The file is durable without another `syncfs`.
+        assert_eq!(gc.started.load(Ordering::SeqCst), 1);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[tokio::test]
+    async fn test_sync() {
+        let gc = GroupCommit::default();
+        let (path, f) = temp_file();
+
+        gc.sync(f).await.unwrap();
+        assert_eq!(gc.started.load(Ordering::SeqCst), 1);
+        assert_eq!(gc.finished.load(Ordering::SeqCst), 1);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -73,6 +73,8 @@ impl oio::List for FsLister<tokio::fs::ReadDir> {
             oio::Entry::new(&format!("{rel_path}/"), Metadata::new(EntryMode::DIR))
         } else if ft.is_file() {
             oio::Entry::new(&rel_path, Metadata::new(EntryMode::FILE))
+        } else if ft.is_symlink() {
+            oio::Entry::new(&rel_path, Metadata::new(EntryMode::Symlink))
         } else {
             oio::Entry::new(&rel_path, Metadata::new(EntryMode::Unknown))
         };
@@ -23,13 +23,19 @@ mod core;
 #[cfg(feature = "services-fs")]
 mod delete;
 #[cfg(feature = "services-fs")]
+mod direct;
+#[cfg(feature = "services-fs")]
 mod error;
 #[cfg(feature = "services-fs")]
+mod group_commit;
+#[cfg(feature = "services-fs")]
 mod lister;
 #[cfg(feature = "services-fs")]
 mod reader;
 #[cfg(feature = "services-fs")]
 mod writer;
+#[cfg(feature = "services-fs")]
+mod xattr;
 
 #[cfg(feature = "services-fs")]
 mod backend;
@@ -17,6 +17,7 @@
 
 use std::fs::File;
 use std::io::Write;
+use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -25,29 +26,53 @@ use tokio::io::AsyncWriteExt;
 
 use crate::raw::*;
 use crate::services::fs::core::FsCore;
+use crate::services::fs::direct::*;
+use crate::services::fs::xattr::*;
 use crate::*;
 
 pub type FsWriters = TwoWays<FsWriter, oio::PositionWriter<FsWriter>>;
 
 pub struct FsWriter {
+    core: Arc<FsCore>,
     target_path: PathBuf,
     /// The temp_path is used to specify whether we should move to target_path after the file has been closed.
     temp_path: Option<PathBuf>,
     f: tokio::fs::File,
+    op: OpWrite,
+    /// The metadata to store in extended attributes while closing, only set if xattr is enabled.
+    xattr: Option<XattrMetadata>,
+    /// Set if the file is written with `O_DIRECT`.
+    direct: Option<DirectWriter>,
 }
 
 impl FsWriter {
     pub async fn create(core: Arc<FsCore>, path: &str, op: OpWrite) -> Result<Self> {
         let target_path = core.ensure_write_abs_path(&core.root, path).await?;
 
+        let current = check_conditions_of(&core, &target_path, &op).await?;
+        // Appending keeps the metadata of existing file.
+        let xattr = core.enable_xattr.then(|| match current {
+            Some(v) if op.append() && v.etag().is_some() => v.with_new_etag(),
+            _ => XattrMetadata::new(&op),
+        });
+
         // Quick path while atomic_write_dir is not set.
+        //
+        // It's always set if xattr is enabled, files with xattr are written into
+        // temp files so that they never show up without their metadata, and
+        // conditions on etag can be checked again before renaming.
         if core.atomic_write_dir.is_none() {
             let target_file = core.fs_write(&target_path, &op).await?;
+            let direct = direct_writer_of(&core, &target_file, &op).await?;
 
             return Ok(Self {
+                core,
                 target_path,
                 temp_path: None,
                 f: target_file,
+                op,
+                xattr,
+                direct,
             });
         }
 
@@ -55,12 +80,6 @@ impl FsWriter {
         let is_exist = tokio::fs::try_exists(&target_path)
             .await
             .map_err(new_std_io_error)?;
-        if op.if_not_exists() && is_exist {
-            return Err(Error::new(
-                ErrorKind::ConditionNotMatch,
-                "file already exists, doesn't match the condition if_not_exists",
-            ));
-        }
 
         // The only case we allow write in place is the file
         // exists and users request for append writing.
@@ -70,13 +89,70 @@ impl FsWriter {
             let f = core.fs_write(&target_path, &op).await?;
             (f, None)
         };
+        let direct = direct_writer_of(&core, &f, &op).await?;
 
         Ok(Self {
+            core,
             target_path,
             temp_path,
             f,
+            op,
+            xattr,
+            direct,
         })
     }
+
+    /// Move the temp file to target path.
+    ///
+    /// Conditional writes check their conditions again while holding the commit
+    /// lock, so that they never overwrite each other in the same process.
+    async fn commit(&self) -> Result<()> {
+        let Some(temp_path) = &self.temp_path else {
+            return Ok(());
+        };
+
+        let conditional = self.op.if_not_exists()
+            || self.op.if_match().is_some()
+            || self.op.if_none_match().is_some();
+        let _guard = if conditional {
+            let guard = self.core.commit_lock.lock().await;
+            if let Err(err) = check_conditions_of(&self.core, &self.target_path, &self.op).await {
+                let _ = tokio::fs::remove_file(temp_path).await;
+                return Err(err);
+            }
+            Some(guard)
+        } else {
+            None
+        };
+
+        tokio::fs::rename(temp_path, &self.target_path)
+            .await
+            .map_err(new_std_io_error)
+    }
+
+    /// Make the file durable and visible at target path.
+    async fn finish(&self, f: File) -> Result<()> {
+        self.store_xattr().await?;
+        self.core.fs_sync_file(f).await?;
+        self.commit().await?;
+        self.core.fs_sync_parent(&self.target_path).await
+    }
+
+    /// Store the metadata in extended attributes before the file is moved to target path.
+    async fn store_xattr(&self) -> Result<()> {
+        let Some(xattr) = &self.xattr else {
+            return Ok(());
+        };
+        let p = self.temp_path.as_ref().unwrap_or(&self.target_path);
+        self.core.fs_store_xattr(p.clone(), xattr.clone()).await
+    }
+
+    fn apply_xattr(&self, meta: Metadata) -> Metadata {
+        match &self.xattr {
+            Some(xattr) => xattr.apply(meta),
+            None => meta,
+        }
+    }
 }
 
 /// # Safety
@@ -86,6 +162,10 @@ unsafe impl Sync for FsWriter {}
 
 impl oio::Write for FsWriter {
     async fn write(&mut self, mut bs: Buffer) -> Result<()> {
+        if let Some(direct) = &mut self.direct {
+            return direct.write(bs).await;
+        }
+
         while bs.has_remaining() {
             let n = self.f.write(bs.chunk()).await.map_err(new_std_io_error)?;
             bs.advance(n);
@@ -95,20 +175,24 @@ impl oio::Write for FsWriter {
     }
 
     async fn close(&mut self) -> Result<Metadata> {
-        self.f.flush().await.map_err(new_std_io_error)?;
-        self.f.sync_all().await.map_err(new_std_io_error)?;
-
-        if let Some(temp_path) = &self.temp_path {
-            tokio::fs::rename(temp_path, &self.target_path)
-                .await
-                .map_err(new_std_io_error)?;
+        if let Some(direct) = &mut self.direct {
+            direct.close().await?;
         }
+        self.f.flush().await.map_err(new_std_io_error)?;
+        let f = self
+            .f
+            .try_clone()
+            .await
+            .map_err(new_std_io_error)?
+            .into_std()
+            .await;
+        self.finish(f).await?;
 
         let file_meta = self.f.metadata().await.map_err(new_std_io_error)?;
         let meta = Metadata::new(EntryMode::FILE)
             .with_content_length(file_meta.len())
             .with_last_modified(file_meta.modified().map_err(new_std_io_error)?.into());
-        Ok(meta)
+        Ok(self.apply_xattr(meta))
     }
 
     async fn abort(&mut self) -> Result<()> {
@@ -153,6 +237,10 @@ impl oio::PositionWrite for FsWriter {
         .map_err(new_task_join_error)?
     }
 
+    async fn set_len(&self, len: u64) -> Result<()> {
+        self.f.set_len(len).await.map_err(new_std_io_error)
+    }
+
     async fn close(&self) -> Result<Metadata> {
         let mut f = self
             .f
@@ -163,13 +251,8 @@ impl oio::PositionWrite for FsWriter {
             .await;
 
         f.flush().map_err(new_std_io_error)?;
-        f.sync_all().map_err(new_std_io_error)?;
-
-        if let Some(temp_path) = &self.temp_path {
-            tokio::fs::rename(temp_path, &self.target_path)
-                .await
-                .map_err(new_std_io_error)?;
-        }
+        self.finish(f.try_clone().map_err(new_std_io_error)?)
+            .await?;
 
         let file_meta = f.metadata().map_err(new_std_io_error)?;
         let mode = if file_meta.is_file() {
@@ -182,7 +265,7 @@ impl oio::PositionWrite for FsWriter {
         let meta = Metadata::new(mode)
             .with_content_length(file_meta.len())
             .with_last_modified(file_meta.modified().map_err(new_std_io_error)?.into());
-        Ok(meta)
+        Ok(self.apply_xattr(meta))
     }
 
     async fn abort(&self) -> Result<()> {
@@ -199,6 +282,61 @@ impl oio::PositionWrite for FsWriter {
     }
 }
 
+/// Check the conditions of `op` against the file at `target_path`, returns the
+/// metadata stored in its extended attributes if exists.
+async fn check_conditions_of(
+    core: &FsCore,
+    target_path: &Path,
+    op: &OpWrite,
+) -> Result<Option<XattrMetadata>> {
+    if op.if_not_exists()
+        && tokio::fs::try_exists(target_path)
+            .await
+            .map_err(new_std_io_error)?
+    {
+        return Err(Error::new(
+            ErrorKind::ConditionNotMatch,
+            "file already exists, doesn't match the condition if_not_exists",
+        ));
+    }
+    if !core.enable_xattr {
+        return Ok(None);
+    }
+
+    let current = match core.fs_load_xattr(target_path.to_path_buf()).await {
+        Ok(v) => Some(v),
+        Err(err) if err.kind() == ErrorKind::NotFound => None,
+        Err(err) => return Err(err),
+    };
+    check_write_conditions(current.as_ref(), op)?;
+    Ok(current)
+}
+
+/// Write with `O_DIRECT` if enabled, only new files written sequentially are
+/// supported since writes must start at aligned offsets.
+async fn direct_writer_of(
+    core: &FsCore,
+    f: &tokio::fs::File,
+    op: &OpWrite,
+) -> Result<Option<DirectWriter>> {
+    if !core.enable_direct_io || op.append() || op.random_access() {
+        return Ok(None);
+    }
+
+    let f = f
+        .try_clone()
+        .await
+        .map_err(new_std_io_error)?
+        .into_std()
+        .await;
+    // Fallback to buffered write if the file system doesn't support `O_DIRECT`.
+    if set_direct_flag(&f).map_err(new_std_io_error)? {
+        Ok(Some(DirectWriter::new(f)))
+    } else {
+        Ok(None)
+    }
+}
+
 #[cfg(windows)]
 fn write_at(f: &File, buf: &[u8], offset: u64) -> Result<usize> {
     use std::os::windows::fs::FileExt;
@@ -0,0 +1,309 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::raw::*;
+use crate::*;
+
+/// Whether extended attributes are supported on this platform.
+pub const XATTR_SUPPORTED: bool = cfg!(any(
+    target_os = "linux",
+    target_os = "android",
+    target_vendor = "apple"
+));
+
+/// All extended attributes managed by OpenDAL start with this prefix.
+const XATTR_PREFIX: &str = "user.opendal.";
+const XATTR_ETAG: &str = "user.opendal.etag";
+const XATTR_CONTENT_TYPE: &str = "user.opendal.content_type";
+const XATTR_CONTENT_DISPOSITION: &str = "user.opendal.content_disposition";
+const XATTR_CACHE_CONTROL: &str = "user.opendal.cache_control";
+/// User metadata is stored as `user.opendal.meta.<key>`.
+const XATTR_USER_METADATA_PREFIX: &str = "user.opendal.meta.";
+
+/// XattrMetadata is the metadata of a file stored in its extended attributes.
+///
+/// Files not written by OpenDAL don't have any of them, and their etag is absent.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct XattrMetadata {
+    etag: Option<String>,
+    content_type: Option<String>,
+    content_disposition: Option<String>,
+    cache_control: Option<String>,
+    user_metadata: HashMap<String, String>,
+}
+
+impl XattrMetadata {
+    /// Create metadata for a file written with `args`.
+    ///
+    /// Every write gets a new etag, so that etags never come back even if the
+    /// same content is written again.
+    pub fn new(args: &OpWrite) -> Self {
+        Self {
+            etag: Some(new_etag()),
+            content_type: args.content_type().map(String::from),
+            content_disposition: args.content_disposition().map(String::from),
+            cache_control: args.cache_control().map(String::from),
+            user_metadata: args.user_metadata().cloned().unwrap_or_default(),
+        }
+    }
+
+    pub fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
+    /// Keep the metadata but take a new etag, used when content is appended.
+    pub fn with_new_etag(mut self) -> Self {
+        self.etag = Some(new_etag());
+        self
+    }
+
+    /// Apply `update` to the metadata, the etag is kept since the content is unchanged.
+    pub fn update(&mut self, update: &MetadataUpdate) {
+        if let Some(v) = update.content_type() {
+            self.content_type = Some(v.to_string());
+        }
+        if let Some(v) = update.content_disposition() {
+            self.content_disposition = Some(v.to_string());
+        }
+        if let Some(v) = update.cache_control() {
+            self.cache_control = Some(v.to_string());
+        }
+        if let Some(v) = update.user_metadata() {
+            self.user_metadata = v.clone();
+        }
+    }
+
+    /// Load the metadata of the file at `path`, symlinks are followed.
+    ///
+    /// This function is blocking.
+    pub fn load(path: &Path) -> Result<Self> {
+        let attrs = sys::read(path).map_err(new_std_io_error)?;
+
+        let mut meta = Self::default();
+        for (name, value) in attrs {
+            match name.as_str() {
+                XATTR_ETAG => meta.etag = Some(value),
+                XATTR_CONTENT_TYPE => meta.content_type = Some(value),
+                XATTR_CONTENT_DISPOSITION => meta.content_disposition = Some(value),
+                XATTR_CACHE_CONTROL => meta.cache_control = Some(value),
+                _ => {
+                    if let Some(key) = name.strip_prefix(XATTR_USER_METADATA_PREFIX) {
+                        meta.user_metadata.insert(key.to_string(), value);
+                    }
+                }
+            }
+        }
+        Ok(meta)
+    }
+
+    /// Store the metadata to the file at `path`, replacing all metadata stored before.
+    ///
+    /// This function is blocking.
+    pub fn store(&self, path: &Path) -> Result<()> {
+        let mut attrs = Vec::new();
+        if let Some(v) = &self.etag {
+            attrs.push((XATTR_ETAG.to_string(), v.clone()));
+        }
+        if let Some(v) = &self.content_type {
+            attrs.push((XATTR_CONTENT_TYPE.to_string(), v.clone()));
+        }
+        if let Some(v) = &self.content_disposition {
+            attrs.push((XATTR_CONTENT_DISPOSITION.to_string(), v.clone()));
+        }
+        if let Some(v) = &self.cache_control {
+            attrs.push((XATTR_CACHE_CONTROL.to_string(), v.clone()));
+        }
+        for (k, v) in &self.user_metadata {
+            attrs.push((format!("{XATTR_USER_METADATA_PREFIX}{k}"), v.clone()));
+        }
+
+        sys::write(path, &attrs).map_err(new_std_io_error)
+    }
+
+    /// Fill the metadata of a file with this metadata.
+    pub fn apply(&self, mut meta: Metadata) -> Metadata {
+        if let Some(v) = &self.etag {
+            meta.set_etag(v);
+        }
+        if let Some(v) = &self.content_type {
+            meta.set_content_type(v);
+        }
+        if let Some(v) = &self.content_disposition {
+            meta.set_content_disposition(v);
+        }
+        if let Some(v) = &self.cache_control {
+            meta.set_cache_control(v);
+        }
+        if !self.user_metadata.is_empty() {
+            meta = meta.with_user_metadata(self.user_metadata.clone());
+        }
+        meta
+    }
+}
+
+fn new_etag() -> String {
+    format!("\"{}\"", uuid::Uuid::new_v4())
+}
+
+/// Check `if_match` and `if_none_match` against the etag of an existing file.
+///
+/// Files without etag never match any etag other than `*`.
+pub fn check_conditions(
+    etag: Option<&str>,
+    if_match: Option<&str>,
+    if_none_match: Option<&str>,
+) -> Result<()> {
+    if let Some(if_match) = if_match {
+        if !etag_matches(if_match, etag) {
+            return Err(condition_not_match("etag doesn't match"));
+        }
+    }
+    if let Some(if_none_match) = if_none_match {
+        if etag_matches(if_none_match, etag) {
+            return Err(condition_not_match("etag matches"));
+        }
+    }
+    Ok(())
+}
+
+/// Check the conditions of write against the file being replaced, `current` is
+/// `None` if the file doesn't exist.
+pub fn check_write_conditions(current: Option<&XattrMetadata>, args: &OpWrite) -> Result<()> {
+    if let Some(if_match) = args.if_match() {
+        if !current.is_some_and(|v| etag_matches(if_match, v.etag())) {
+            return Err(condition_not_match("etag doesn't match"));
+        }
+    }
+    if let Some(if_none_match) = args.if_none_match() {
+        if current.is_some_and(|v| etag_matches(if_none_match, v.etag())) {
+            return Err(condition_not_match("etag matches"));
+        }
+    }
+    Ok(())
+}
+
+/// `*` matches any existing file.
+fn etag_matches(condition: &str, etag: Option<&str>) -> bool {
+    condition == "*" || etag == Some(condition)
+}
+
+fn condition_not_match(msg: &str) -> Error {
+    Error::new(ErrorKind::ConditionNotMatch, msg)
+}
+
+#[cfg(any(target_os = "linux", target_os = "android", target_vendor = "apple"))]
+mod sys {
+    use std::io;
+    use std::path::Path;
+
+    use rustix::io::Errno;
+
+    use super::XATTR_PREFIX;
+
+    /// Read all extended attributes managed by OpenDAL.
+    ///
+    /// File systems without extended attributes are taken as having no attributes.
+    pub fn read(path: &Path) -> io::Result<Vec<(String, String)>> {
+        let names = match read_value(|buf| rustix::fs::listxattr(path, buf)) {
+            Ok(v) => v,
+            Err(Errno::NOTSUP) => return Ok(Vec::new()),
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut attrs = Vec::new();
+        for name in names.split(|b| *b == 0) {
+            let Ok(name) = std::str::from_utf8(name) else {
+                continue;
+            };
+            if !name.starts_with(XATTR_PREFIX) {
+                continue;
+            }
+            let value = match read_value(|buf| rustix::fs::getxattr(path, name, buf)) {
+                Ok(v) => v,
+                // The attribute has been removed after listed.
+                Err(Errno::NODATA) => continue,
+                Err(err) => return Err(err.into()),
+            };
+            if let Ok(value) = String::from_utf8(value) {
+                attrs.push((name.to_string(), value));
+            }
+        }
+        Ok(attrs)
+    }
+
+    /// Replace all extended attributes managed by OpenDAL with `attrs`.
+    pub fn write(path: &Path, attrs: &[(String, String)]) -> io::Result<()> {
+        for (name, _) in read(path)? {
+            match rustix::fs::removexattr(path, name.as_str()) {
+                Ok(()) | Err(Errno::NODATA) => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+        for (name, value) in attrs {
+            rustix::fs::setxattr(
+                path,
+                name.as_str(),
+                value.as_bytes(),
+                rustix::fs::XattrFlags::empty(),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Read a value by asking its size first, `f` returns the size of value
+    /// if the given buffer is empty.
+    fn read_value(
+        f: impl Fn(&mut [u8]) -> rustix::io::Result<usize>,
+    ) -> rustix::io::Result<Vec<u8>> {
+        loop {
+            let size = f(&mut [])?;
+            let mut buf = vec![0; size];
+            match f(&mut buf) {
+                Ok(n) => {
+                    buf.truncate(n);
+                    return Ok(buf);
+                }
+                // The value grows after we got its size, try again.
+                Err(Errno::RANGE) => continue,
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_vendor = "apple")))]
+mod sys {
+    use std::io;
+    use std::path::Path;
+
+    pub fn read(_: &Path) -> io::Result<Vec<(String, String)>> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "extended attributes are not supported on this platform",
+        ))
+    }
+
+    pub fn write(_: &Path, _: &[(String, String)]) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "extended attributes are not supported on this platform",
+        ))
+    }
+}
@@ -17,14 +17,11 @@
 
 use std::fmt::Debug;
 use std::fmt::Formatter;
-use std::str;
-use std::str::FromStr;
 use std::sync::Arc;
 
 use http::Uri;
 use log::debug;
 use services::ftp::core::Manager;
-use suppaftp::list::File;
 use suppaftp::types::Response;
 use suppaftp::FtpError;
 use suppaftp::Status;
@@ -246,19 +243,7 @@ impl Access for FtpBackend {
     }
 
     async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
-        let file = self.ftp_stat(path).await?;
-
-        let mode = if file.is_file() {
-            EntryMode::FILE
-        } else if file.is_directory() {
-            EntryMode::DIR
-        } else {
-            EntryMode::Unknown
-        };
-
-        let mut meta = Metadata::new(mode);
-        meta.set_content_length(file.size() as u64);
-        meta.set_last_modified(file.modified().into());
+        let meta = self.ftp_stat(path).await?;
 
         Ok(RpStat::new(meta))
     }
@@ -312,34 +297,31 @@ impl Access for FtpBackend {
     }
 
     async fn list(&self, path: &str, _: OpList) -> Result<(RpList, Self::Lister)> {
-        let mut ftp_stream = self.core.ftp_connect(Operation::List).await?;
-
         let pathname = if path == "/" { None } else { Some(path) };
-        let files = ftp_stream.list(pathname).await.map_err(parse_error)?;
+        let (format, files) = self.core.ftp_list(Operation::List, pathname).await?;
 
         Ok((
             RpList::default(),
-            FtpLister::new(if path == "/" { "" } else { path }, files),
+            FtpLister::new(if path == "/" { "" } else { path }, format, files),
         ))
     }
 }
 
 impl FtpBackend {
-    pub async fn ftp_stat(&self, path: &str) -> Result<File> {
-        let mut ftp_stream = self.core.ftp_connect(Operation::Stat).await?;
-
+    pub async fn ftp_stat(&self, path: &str) -> Result<Metadata> {
         let (parent, basename) = (get_parent(path), get_basename(path));
 
         let pathname = if parent == "/" { None } else { Some(parent) };
 
-        let resp = ftp_stream.list(pathname).await.map_err(parse_error)?;
+        let (format, resp) = self.core.ftp_list(Operation::Stat, pathname).await?;
 
         // Get stat of file.
         let mut files = resp
-            .into_iter()
-            .filter_map(|file| File::from_str(file.as_str()).ok())
-            .filter(|f| f.name() == basename.trim_end_matches('/'))
-            .collect::<Vec<File>>();
+            .iter()
+            .filter_map(|file| format.parse(file).ok().flatten())
+            .filter(|(name, _)| name == basename.trim_end_matches('/'))
+            .map(|(_, meta)| meta)
+            .collect::<Vec<Metadata>>();
 
         if files.is_empty() {
             Err(Error::new(
@@ -15,13 +15,18 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::str::FromStr;
 use std::sync::Arc;
 
 use bb8::Pool;
 use bb8::PooledConnection;
 use bb8::RunError;
+use chrono::DateTime;
+use chrono::NaiveDateTime;
+use chrono::Utc;
 use futures_rustls::TlsConnector;
 use raw::Operation;
+use suppaftp::list::File;
 use suppaftp::rustls::ClientConfig;
 use suppaftp::types::FileType;
 use suppaftp::AsyncRustlsConnector;
@@ -61,6 +66,132 @@ impl FtpCore {
             }
         })
     }
+
+    /// List `path` via `MLSD`, falling back to `LIST` if the server doesn't support it.
+    ///
+    /// Only `MLSD` returns machine-readable facts like `unix.mode`, `unix.uid` and `unix.gid`.
+    pub async fn ftp_list(
+        &self,
+        op: Operation,
+        path: Option<&str>,
+    ) -> Result<(ListFormat, Vec<String>)> {
+        let mut ftp_stream = self.ftp_connect(op).await?;
+
+        match ftp_stream.mlsd(path).await {
+            Ok(lines) => Ok((ListFormat::Mlsd, lines)),
+            Err(FtpError::UnexpectedResponse(resp))
+                if matches!(
+                    resp.status,
+                    Status::BadCommand | Status::NotImplemented | Status::NotImplementedParameter
+                ) =>
+            {
+                let lines = ftp_stream.list(path).await.map_err(parse_error)?;
+                Ok((ListFormat::List, lines))
+            }
+            Err(err) => Err(parse_error(err)),
+        }
+    }
+}
+
+/// The command used to list a directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListFormat {
+    Mlsd,
+    List,
+}
+
+impl ListFormat {
+    /// Parse a line of the listing into entry name and metadata.
+    ///
+    /// Returns `None` for the `cdir` and `pdir` entries of `MLSD`.
+    pub fn parse(self, line: &str) -> Result<Option<(String, Metadata)>> {
+        match self {
+            ListFormat::Mlsd => parse_mlsx_line(line),
+            ListFormat::List => {
+                let file = File::from_str(line).map_err(|e| {
+                    Error::new(ErrorKind::Unexpected, "parse file from response").set_source(e)
+                })?;
+
+                let mode = if file.is_file() {
+                    EntryMode::FILE
+                } else if file.is_directory() {
+                    EntryMode::DIR
+                } else {
+                    EntryMode::Unknown
+                };
+                let mut meta = Metadata::new(mode);
+                meta.set_content_length(file.size() as u64);
+                meta.set_last_modified(file.modified().into());
+
+                Ok(Some((file.name().to_string(), meta)))
+            }
+        }
+    }
+}
+
+/// Parse a `MLSD` line like `type=file;size=6;modify=20240102030405;unix.mode=0644; name`.
+///
+/// Refer to [RFC 3659](https://datatracker.ietf.org/doc/html/rfc3659#section-7) for the facts.
+fn parse_mlsx_line(line: &str) -> Result<Option<(String, Metadata)>> {
+    let invalid = || {
+        Error::new(ErrorKind::Unexpected, "parse mlsd line from response")
+            .with_context("line", line)
+    };
+
+    let (facts, name) = line.split_once(' ').ok_or_else(invalid)?;
+
+    let mut meta = Metadata::new(EntryMode::Unknown);
+    for fact in facts.split(';') {
+        let Some((key, value)) = fact.split_once('=') else {
+            continue;
+        };
+        match key.to_ascii_lowercase().as_str() {
+            "type" => match value.to_ascii_lowercase().as_str() {
+                "cdir" | "pdir" => return Ok(None),
+                "file" => {
+                    meta.set_mode(EntryMode::FILE);
+                }
+                "dir" => {
+                    meta.set_mode(EntryMode::DIR);
+                }
+                _ => {}
+            },
+            "size" => {
+                meta.set_content_length(value.parse().map_err(|_| invalid())?);
+            }
+            "modify" => {
+                meta.set_last_modified(parse_mlsx_time(value).ok_or_else(invalid)?);
+            }
+            "create" => {
+                meta.set_created(parse_mlsx_time(value).ok_or_else(invalid)?);
+            }
+            "unix.mode" => {
+                let mode = u32::from_str_radix(value, 8).map_err(|_| invalid())?;
+                meta.set_unix_mode(mode & 0o7777);
+            }
+            // Some servers return user and group names instead of ids, skip them.
+            "unix.uid" | "unix.owner" => {
+                if let Ok(uid) = value.parse() {
+                    meta.set_uid(uid);
+                }
+            }
+            "unix.gid" | "unix.group" => {
+                if let Ok(gid) = value.parse() {
+                    meta.set_gid(gid);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(Some((name.to_string(), meta)))
+}
+
+/// Parse time value like `20240102030405` or `20240102030405.123` in UTC.
+fn parse_mlsx_time(v: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(v, "%Y%m%d%H%M%S%.f")
+        .ok()
+        .map(|t| t.and_utc())
 }
 
 #[derive(Clone)]
@@ -134,3 +265,38 @@ impl bb8::ManageConnection for Manager {
         true
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mlsx_line() {
+        let (name, meta) =
+            parse_mlsx_line("type=file;size=6;modify=20240102030405.123;unix.mode=0644;unix.uid=1000;unix.gid=100; hello world.txt")
+                .unwrap()
+                .unwrap();
+        assert_eq!(name, "hello world.txt");
+        assert_eq!(meta.mode(), EntryMode::FILE);
+        assert_eq!(meta.content_length(), 6);
+        assert_eq!(
+            meta.last_modified().unwrap().to_rfc3339(),
+            "2024-01-02T03:04:05.123+00:00"
+        );
+        assert_eq!(meta.unix_mode(), Some(0o644));
+        assert_eq!(meta.uid(), Some(1000));
+        assert_eq!(meta.gid(), Some(100));
+
+        let (name, meta) = parse_mlsx_line("Type=dir;Modify=20240102030405;UNIX.owner=ftp; dir")
+            .unwrap()
+            .unwrap();
+        assert_eq!(name, "dir");
+        assert_eq!(meta.mode(), EntryMode::DIR);
+        assert_eq!(meta.uid(), None);
+
+        assert!(parse_mlsx_line("type=cdir;modify=20240102030405; .")
+            .unwrap()
+            .is_none());
+        assert!(parse_mlsx_line("type=file;size=abc; x").is_err());
+    }
+}
@@ -19,6 +19,8 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
 
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
 use http::Response;
 use http::StatusCode;
 use log::debug;
@@ -280,6 +282,41 @@ impl Builder for GcsBuilder {
                 .with_disable_well_known_location();
         }
 
+        // Post policy must be signed by the private key of a service account, which is not
+        // exposed by the credential loader.
+        let credential = if let Some(cred) = &self.config.credential {
+            let cred = BASE64_STANDARD.decode(cred).map_err(|err| {
+                Error::new(ErrorKind::ConfigInvalid, "credential is not valid base64")
+                    .with_operation("Builder::build")
+                    .with_context("service", Scheme::Gcs)
+                    .set_source(err)
+            })?;
+            Some(cred)
+        } else if let Some(path) = &self.config.credential_path {
+            let cred = std::fs::read(path).map_err(|err| {
+                new_std_io_error(err)
+                    .with_operation("Builder::build")
+                    .with_context("service", Scheme::Gcs)
+                    .with_context("credential_path", path)
+            })?;
+            Some(cred)
+        } else {
+            None
+        };
+        let service_account = match credential {
+            Some(cred) => {
+                let cred: serde_json::Value = serde_json::from_slice(&cred).map_err(|err| {
+                    Error::new(ErrorKind::ConfigInvalid, "credential is not valid json")
+                        .with_operation("Builder::build")
+                        .with_context("service", Scheme::Gcs)
+                        .set_source(err)
+                })?;
+                // Other kinds of credential like external accounts can't sign post policy.
+                serde_json::from_value::<ServiceAccountKey>(cred).ok()
+            }
+            None => None,
+        };
+
         let scope = if let Some(scope) = &self.config.scope {
             scope
         } else {
@@ -345,16 +382,22 @@ impl Builder for GcsBuilder {
                             delete: true,
                             delete_max_size: Some(100),
                             copy: true,
+                            concat: true,
+
+                            update_metadata: true,
+                            update_metadata_with_if_match: true,
 
                             list: true,
                             list_with_limit: true,
                             list_with_start_after: true,
+                            list_with_continuation_token: true,
                             list_with_recursive: true,
 
                             presign: true,
                             presign_stat: true,
                             presign_read: true,
                             presign_write: true,
+                            presign_post: service_account.is_some(),
 
                             shared: true,
 
@@ -380,6 +423,7 @@ impl Builder for GcsBuilder {
                 predefined_acl: self.config.predefined_acl.clone(),
                 default_storage_class: self.config.default_storage_class.clone(),
                 allow_anonymous: self.config.allow_anonymous,
+                service_account,
             }),
         };
 
@@ -416,6 +460,39 @@ impl Access for GcsBackend {
         Ok(RpStat::new(m))
     }
 
+    async fn update_metadata(
+        &self,
+        path: &str,
+        args: OpUpdateMetadata,
+    ) -> Result<RpUpdateMetadata> {
+        let update = args.update();
+
+        // GCS merges user metadata, so we need to find out the keys to remove.
+        let mut removed_keys = vec![];
+        if let Some(user_metadata) = update.user_metadata() {
+            let meta = self.stat(path, OpStat::new()).await?.into_metadata();
+            if let Some(current) = meta.user_metadata() {
+                removed_keys = current
+                    .keys()
+                    .filter(|k| !user_metadata.contains_key(*k))
+                    .cloned()
+                    .collect();
+            }
+        }
+
+        let resp = self
+            .core
+            .gcs_patch_object_metadata(path, update, &removed_keys)
+            .await?;
+
+        if !resp.status().is_success() {
+            return Err(parse_error(resp));
+        }
+
+        let m = GcsCore::build_metadata_from_object_response(path, resp.into_body())?;
+        Ok(RpUpdateMetadata::new(m))
+    }
+
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
         let resp = self.core.gcs_get_object(path, args.range(), &args).await?;
 
@@ -457,7 +534,10 @@ impl Access for GcsBackend {
             args.start_after(),
         );
 
-        Ok((RpList::default(), oio::PageLister::new(l)))
+        Ok((
+            RpList::default(),
+            oio::PageLister::new(l).with_continuation_token(args.continuation_token()),
+        ))
     }
 
     async fn copy(&self, from: &str, to: &str, _: OpCopy) -> Result<RpCopy> {
@@ -470,6 +550,39 @@ impl Access for GcsBackend {
         }
     }
 
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        // Compose can only take a limited number of sources, so we compose the
+        // rest onto the previous result until all sources are consumed.
+        let (first, rest) = args
+            .sources()
+            .split_at(args.sources().len().min(GCS_COMPOSE_MAX_SOURCES));
+        let mut batches = vec![first.to_vec()];
+        for chunk in rest.chunks(GCS_COMPOSE_MAX_SOURCES - 1) {
+            let mut batch = Vec::with_capacity(chunk.len() + 1);
+            batch.push(path.to_string());
+            batch.extend_from_slice(chunk);
+            batches.push(batch);
+        }
+
+        for batch in batches {
+            let resp = self
+                .core
+                .gcs_compose_object(path, &batch, args.content_type())
+                .await?;
+
+            if !resp.status().is_success() {
+                return Err(parse_error(resp));
+            }
+        }
+
+        Ok(RpConcat::default())
+    }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        let post = self.core.gcs_presign_post(path, &args)?;
+        Ok(RpPresignPost::new(post))
+    }
+
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         // We will not send this request out, just for signing.
         let req = match args.operation() {
@@ -479,7 +592,7 @@ impl Access for GcsBackend {
                 self.core
                     .gcs_insert_object_xml_request(path, v, Buffer::new())
             }
-            PresignOperation::Delete(_) => Err(Error::new(
+            PresignOperation::Delete(_) | PresignOperation::UploadPart(_) => Err(Error::new(
                 ErrorKind::Unsupported,
                 "operation is not supported",
             )),
@@ -25,8 +25,12 @@ use std::time::Duration;
 
 use backon::ExponentialBuilder;
 use backon::Retryable;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
 use bytes::Buf;
 use bytes::Bytes;
+use chrono::SecondsFormat;
+use chrono::Utc;
 use constants::*;
 use http::header::CACHE_CONTROL;
 use http::header::CONTENT_DISPOSITION;
@@ -45,8 +49,15 @@ use reqsign::GoogleCredentialLoader;
 use reqsign::GoogleSigner;
 use reqsign::GoogleToken;
 use reqsign::GoogleTokenLoader;
+use rsa::pkcs1v15::SigningKey;
+use rsa::pkcs8::DecodePrivateKey;
+use rsa::sha2::Sha256;
+use rsa::signature::SignatureEncoding;
+use rsa::signature::Signer;
+use rsa::RsaPrivateKey;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::json;
 
 use super::uri::percent_encode_path;
 use crate::raw::*;
@@ -74,6 +85,17 @@ pub struct GcsCore {
     pub default_storage_class: Option<String>,
 
     pub allow_anonymous: bool,
+
+    /// Service account key loaded from `credential` or `credential_path`, used to
+    /// sign POST policies.
+    pub service_account: Option<ServiceAccountKey>,
+}
+
+/// The fields of a service account key file used by post policy signing.
+#[derive(Clone, Deserialize)]
+pub struct ServiceAccountKey {
+    pub client_email: String,
+    pub private_key: String,
 }
 
 impl Debug for GcsCore {
@@ -180,6 +202,90 @@ impl GcsCore {
     }
 }
 
+impl GcsCore {
+    /// Build a presigned POST form with V4 signing process.
+    ///
+    /// ref: <https://cloud.google.com/storage/docs/authentication/signatures#policy-document>
+    pub fn gcs_presign_post(&self, path: &str, args: &OpPresignPost) -> Result<PresignedPost> {
+        let Some(sa) = &self.service_account else {
+            return Err(Error::new(
+                ErrorKind::ConfigInvalid,
+                "presign post requires a service account key in credential or credential_path",
+            ));
+        };
+
+        let p = build_abs_path(&self.root, path);
+        let now = Utc::now();
+        let expiration = now
+            + chrono::Duration::from_std(args.expire()).map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "expire is out of range").set_source(err)
+            })?;
+        let credential = format!(
+            "{}/{}/auto/storage/goog4_request",
+            sa.client_email,
+            now.format("%Y%m%d")
+        );
+
+        let mut fields = Vec::new();
+        let mut conditions = vec![json!({ "bucket": self.bucket })];
+        if p.is_empty() || p.ends_with('/') {
+            conditions.push(json!(["starts-with", "$key", p]));
+            fields.push(("key".to_string(), format!("{p}${{filename}}")));
+        } else {
+            conditions.push(json!({ "key": p }));
+            fields.push(("key".to_string(), p));
+        }
+        if let Some((min, max)) = args.content_length_range() {
+            conditions.push(json!(["content-length-range", min, max]));
+        }
+        if let Some(prefix) = args.content_type_prefix() {
+            conditions.push(json!(["starts-with", "$Content-Type", prefix]));
+        }
+
+        fields.push((
+            "x-goog-algorithm".to_string(),
+            "GOOG4-RSA-SHA256".to_string(),
+        ));
+        fields.push(("x-goog-credential".to_string(), credential));
+        fields.push((
+            "x-goog-date".to_string(),
+            now.format("%Y%m%dT%H%M%SZ").to_string(),
+        ));
+        for (k, v) in &fields[1..] {
+            conditions.push(json!({ k: v }));
+        }
+
+        let policy = json!({
+            "expiration": expiration.to_rfc3339_opts(SecondsFormat::Secs, true),
+            "conditions": conditions,
+        });
+        let policy = BASE64_STANDARD.encode(policy.to_string());
+
+        let private_key = RsaPrivateKey::from_pkcs8_pem(&sa.private_key).map_err(|err| {
+            Error::new(
+                ErrorKind::ConfigInvalid,
+                "service account private key is invalid",
+            )
+            .set_source(err)
+        })?;
+        let signature = format_hex(
+            &SigningKey::<Sha256>::new(private_key)
+                .sign(policy.as_bytes())
+                .to_bytes(),
+        );
+
+        fields.push(("policy".to_string(), policy));
+        fields.push(("x-goog-signature".to_string(), signature));
+
+        let uri = format!("{}/{}/", self.endpoint, self.bucket)
+            .parse()
+            .map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "endpoint is not a valid uri").set_source(err)
+            })?;
+        Ok(PresignedPost::new(uri, fields))
+    }
+}
+
 impl GcsCore {
     pub fn gcs_get_object_request(
         &self,
@@ -521,6 +627,101 @@ impl GcsCore {
         self.send(req).await
     }
 
+    /// Compose `sources` into `path`.
+    ///
+    /// GCS accepts at most [`GCS_COMPOSE_MAX_SOURCES`] sources in one request.
+    pub async fn gcs_compose_object(
+        &self,
+        path: &str,
+        sources: &[String],
+        content_type: Option<&str>,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/storage/v1/b/{}/o/{}/compose",
+            self.endpoint,
+            self.bucket,
+            percent_encode_path(&p)
+        );
+
+        let request = ComposeRequest {
+            source_objects: sources
+                .iter()
+                .map(|v| ComposeRequestSourceObject {
+                    name: build_abs_path(&self.root, v),
+                })
+                .collect(),
+            destination: ComposeRequestDestination { content_type },
+        };
+
+        let mut req = Request::post(&url)
+            .header(CONTENT_TYPE, "application/json; charset=UTF-8")
+            .extension(Operation::Concat)
+            .body(Buffer::from(
+                serde_json::to_vec(&request).map_err(new_json_serialize_error)?,
+            ))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
+    /// Patch the metadata of an object.
+    ///
+    /// `removed_keys` are user metadata keys that should be deleted, GCS merges
+    /// the `metadata` map instead of replacing it.
+    pub async fn gcs_patch_object_metadata(
+        &self,
+        path: &str,
+        update: &MetadataUpdate,
+        removed_keys: &[String],
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!(
+            "{}/storage/v1/b/{}/o/{}",
+            self.endpoint,
+            self.bucket,
+            percent_encode_path(&p)
+        );
+
+        let metadata = update.user_metadata().map(|user_metadata| {
+            let mut metadata: HashMap<&str, Option<&str>> = user_metadata
+                .iter()
+                .map(|(k, v)| (k.as_str(), Some(v.as_str())))
+                .collect();
+            for k in removed_keys {
+                metadata.entry(k.as_str()).or_insert(None);
+            }
+            metadata
+        });
+
+        let request_metadata = PatchRequestMetadata {
+            content_type: update.content_type(),
+            cache_control: update.cache_control(),
+            content_disposition: update.content_disposition(),
+            metadata,
+        };
+
+        let mut req = Request::patch(&url)
+            .header(CONTENT_TYPE, "application/json; charset=UTF-8")
+            .extension(Operation::UpdateMetadata);
+
+        if let Some(if_match) = update.if_match() {
+            req = req.header(IF_MATCH, if_match);
+        }
+
+        let mut req = req
+            .body(Buffer::from(
+                serde_json::to_vec(&request_metadata).map_err(new_json_serialize_error)?,
+            ))
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     pub async fn gcs_list_objects(
         &self,
         path: &str,
@@ -790,6 +991,51 @@ impl InsertRequestMetadata<'_> {
             && self.metadata.is_none()
     }
 }
+
+/// The max sources of GCS compose API.
+///
+/// ref: <https://cloud.google.com/storage/docs/composing-objects>
+pub const GCS_COMPOSE_MAX_SOURCES: usize = 32;
+
+/// Request JSON for GCS compose object API.
+///
+/// refer to https://cloud.google.com/storage/docs/json_api/v1/objects/compose for details
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComposeRequest<'a> {
+    source_objects: Vec<ComposeRequestSourceObject>,
+    destination: ComposeRequestDestination<'a>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ComposeRequestSourceObject {
+    name: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComposeRequestDestination<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_type: Option<&'a str>,
+}
+
+/// Request JSON for GCS patch object API.
+///
+/// refer to https://cloud.google.com/storage/docs/json_api/v1/objects/patch for details
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchRequestMetadata<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_type: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cache_control: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_disposition: Option<&'a str>,
+    /// Keys with `None` value will be removed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<HashMap<&'a str, Option<&'a str>>>,
+}
+
 /// Response JSON from GCS list objects API.
 ///
 /// refer to https://cloud.google.com/storage/docs/json_api/v1/objects/list for details
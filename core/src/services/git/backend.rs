@@ -0,0 +1,421 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use super::core::*;
+use super::lister::GitLister;
+use super::DEFAULT_SCHEME;
+use crate::raw::*;
+use crate::services::GitConfig;
+use crate::*;
+
+impl Configurator for GitConfig {
+    type Builder = GitBuilder;
+    fn into_builder(self) -> Self::Builder {
+        GitBuilder { config: self }
+    }
+}
+
+/// Read-only git repository service support.
+#[doc = include_str!("docs.md")]
+#[derive(Default, Debug)]
+pub struct GitBuilder {
+    config: GitConfig,
+}
+
+impl GitBuilder {
+    /// Set the root dir inside the tree of the commit.
+    pub fn root(mut self, root: &str) -> Self {
+        self.config.root = if root.is_empty() {
+            None
+        } else {
+            Some(root.to_string())
+        };
+
+        self
+    }
+
+    /// Set the path of git repository, either a bare repository or a work tree.
+    pub fn path(mut self, path: &str) -> Self {
+        if !path.is_empty() {
+            self.config.path = Some(path.to_string());
+        }
+
+        self
+    }
+
+    /// Set the revision to expose, like a branch, tag or commit id.
+    ///
+    /// Default to `HEAD` if not set.
+    pub fn reference(mut self, reference: &str) -> Self {
+        if !reference.is_empty() {
+            self.config.reference = Some(reference.to_string());
+        }
+
+        self
+    }
+
+    /// Set the url or path of remote to fetch from while building the backend.
+    ///
+    /// Branches and tags of remote are mirrored into the repository as is, a bare
+    /// repository will be cloned into path if it doesn't exist.
+    pub fn remote(mut self, remote: &str) -> Self {
+        if !remote.is_empty() {
+            self.config.remote = Some(remote.to_string());
+        }
+
+        self
+    }
+}
+
+impl Builder for GitBuilder {
+    type Config = GitConfig;
+
+    fn build(self) -> Result<impl Access> {
+        let path = self.config.path.ok_or_else(|| {
+            Error::new(ErrorKind::ConfigInvalid, "path is not specified")
+                .with_operation("Builder::build")
+                .with_context("service", Scheme::Git)
+        })?;
+
+        if let Some(remote) = &self.config.remote {
+            fetch(&path, remote).map_err(|err| {
+                err.with_operation("Builder::build")
+                    .with_context("service", Scheme::Git)
+                    .with_context("remote", remote)
+            })?;
+        }
+
+        let repo = gix::ThreadSafeRepository::open(&path).map_err(|err| {
+            new_open_error(err)
+                .with_operation("Builder::build")
+                .with_context("service", Scheme::Git)
+                .with_context("path", &path)
+        })?;
+        let reference = self.config.reference.as_deref().unwrap_or("HEAD");
+        let snapshot = resolve(&repo.to_thread_local(), reference).map_err(|err| {
+            err.with_operation("Builder::build")
+                .with_context("service", Scheme::Git)
+        })?;
+
+        let root = normalize_root(self.config.root.as_deref().unwrap_or("/"));
+
+        let info = AccessorInfo::default();
+        info.set_scheme(DEFAULT_SCHEME)
+            .set_root(&root)
+            .set_name(&path)
+            .set_native_capability(Capability {
+                stat: true,
+                stat_with_version: true,
+                read: true,
+                read_with_version: true,
+                read_link: true,
+                list: true,
+                list_with_recursive: true,
+                list_with_start_after: true,
+                ..Default::default()
+            });
+
+        Ok(GitBackend {
+            core: Arc::new(GitCore {
+                info: info.into(),
+                repo,
+                snapshot,
+            }),
+            root,
+        })
+    }
+}
+
+/// Backend is used to serve `Accessor` support for git repositories.
+#[derive(Debug, Clone)]
+pub struct GitBackend {
+    core: Arc<GitCore>,
+    root: String,
+}
+
+impl Access for GitBackend {
+    type Reader = Buffer;
+    type Writer = ();
+    type Lister = GitLister;
+    type Deleter = ();
+
+    fn info(&self) -> Arc<AccessorInfo> {
+        self.core.info.clone()
+    }
+
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let p = build_abs_path(&self.root, path);
+        let meta = self.core.stat(&p, args.version())?;
+        Ok(RpStat::new(meta))
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let p = build_abs_path(&self.root, path);
+        let buf = self.core.read(&p, args.range(), args.version())?;
+        Ok((RpRead::new(), buf))
+    }
+
+    async fn read_link(&self, path: &str, _: OpReadLink) -> Result<RpReadLink> {
+        let p = build_abs_path(&self.root, path);
+        let target = self.core.read_link(&p)?;
+        Ok(RpReadLink::new(&target))
+    }
+
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        let p = build_abs_path(&self.root, path);
+        let start_after = args.start_after().map(|v| build_abs_path(&self.root, v));
+        let entries = self
+            .core
+            .list(&p, args.recursive(), start_after.as_deref())?;
+
+        Ok((RpList::default(), GitLister::new(&self.root, entries)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+    use gix::objs::tree::EntryKind;
+    use gix::ObjectId;
+
+    use super::*;
+
+    fn signature(time: &str) -> gix::actor::SignatureRef<'_> {
+        gix::actor::SignatureRef {
+            name: "opendal".into(),
+            email: "opendal@example.com".into(),
+            time,
+        }
+    }
+
+    fn write_tree(repo: &gix::Repository, entries: Vec<(&str, EntryKind, ObjectId)>) -> ObjectId {
+        let mut entries: Vec<_> = entries
+            .into_iter()
+            .map(|(name, kind, oid)| gix::objs::tree::Entry {
+                mode: kind.into(),
+                filename: name.into(),
+                oid,
+            })
+            .collect();
+        entries.sort();
+        repo.write_object(gix::objs::Tree { entries })
+            .unwrap()
+            .detach()
+    }
+
+    /// Build a bare repository with two commits on `main`, returns their ids.
+    fn build_repo(path: &str) -> (ObjectId, ObjectId) {
+        let repo = gix::init_bare(path).unwrap();
+        let blob = |data: &str| repo.write_blob(data).unwrap().detach();
+
+        let dir = write_tree(&repo, vec![("b.txt", EntryKind::Blob, blob("world"))]);
+        let bin = write_tree(
+            &repo,
+            vec![("run.sh", EntryKind::BlobExecutable, blob("#!/bin/sh"))],
+        );
+        let tree = write_tree(
+            &repo,
+            vec![
+                ("a.txt", EntryKind::Blob, blob("hello")),
+                ("bin", EntryKind::Tree, bin),
+                ("dir", EntryKind::Tree, dir),
+                ("dir-link", EntryKind::Link, blob("dir")),
+            ],
+        );
+        let first = repo
+            .commit_as(
+                signature("1700000000 +0000"),
+                signature("1700000000 +0000"),
+                "refs/heads/main",
+                "first",
+                tree,
+                None::<ObjectId>,
+            )
+            .unwrap()
+            .detach();
+
+        let tree = write_tree(
+            &repo,
+            vec![
+                ("a.txt", EntryKind::Blob, blob("hello, git")),
+                ("dir", EntryKind::Tree, dir),
+            ],
+        );
+        let second = repo
+            .commit_as(
+                signature("1700000100 +0800"),
+                signature("1700000100 +0800"),
+                "refs/heads/main",
+                "second",
+                tree,
+                Some(first),
+            )
+            .unwrap()
+            .detach();
+        (first, second)
+    }
+
+    fn temp_path() -> String {
+        std::env::temp_dir()
+            .join(format!("opendal-git-{}", uuid::Uuid::new_v4()))
+            .to_string_lossy()
+            .to_string()
+    }
+
+    async fn list_paths(op: &Operator, path: &str, recursive: bool) -> Vec<String> {
+        let entries: Vec<_> = op
+            .lister_with(path)
+            .recursive(recursive)
+            .await
+            .unwrap()
+            .try_collect()
+            .await
+            .unwrap();
+        entries.into_iter().map(|e| e.path().to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_git() {
+        let path = temp_path();
+        let (first, second) = build_repo(&path);
+
+        let builder = GitBuilder::default().path(&path).reference("main");
+        let op = Operator::new(builder).unwrap().finish();
+
+        assert_eq!(op.read("a.txt").await.unwrap().to_vec(), b"hello, git");
+        assert_eq!(
+            op.read_with("a.txt").range(7..).await.unwrap().to_vec(),
+            b"git"
+        );
+        let meta = op.stat("a.txt").await.unwrap();
+        assert_eq!(meta.content_length(), 10);
+        assert_eq!(meta.last_modified().unwrap().timestamp(), 1700000100);
+        assert_eq!(meta.unix_mode(), Some(0o644));
+        assert_eq!(
+            op.stat("/").await.unwrap().version(),
+            Some(second.to_string().as_str())
+        );
+        assert!(op.stat("dir/").await.unwrap().is_dir());
+        assert_eq!(
+            op.stat("a.txt/").await.unwrap_err().kind(),
+            ErrorKind::NotFound
+        );
+        assert_eq!(
+            op.stat("bin/run.sh").await.unwrap_err().kind(),
+            ErrorKind::NotFound
+        );
+
+        // Read the file as of the first commit.
+        let first = first.to_string();
+        let meta = op.stat_with("a.txt").version(&first).await.unwrap();
+        assert_eq!(meta.last_modified().unwrap().timestamp(), 1700000000);
+        assert_eq!(
+            op.read_with("a.txt")
+                .version(&first)
+                .await
+                .unwrap()
+                .to_vec(),
+            b"hello"
+        );
+        assert_eq!(
+            op.read_with("a.txt")
+                .version(meta.version().unwrap())
+                .await
+                .unwrap()
+                .to_vec(),
+            b"hello"
+        );
+        assert_eq!(
+            op.read_with("a.txt")
+                .version("not-exist")
+                .await
+                .unwrap_err()
+                .kind(),
+            ErrorKind::NotFound
+        );
+
+        assert_eq!(list_paths(&op, "/", false).await, ["/", "a.txt", "dir/"]);
+        assert_eq!(
+            list_paths(&op, "/", true).await,
+            ["/", "a.txt", "dir/", "dir/b.txt"]
+        );
+        assert!(list_paths(&op, "not-exist/", true).await.is_empty());
+
+        let builder = GitBuilder::default()
+            .path(&path)
+            .reference(&first)
+            .root("/bin");
+        let op = Operator::new(builder).unwrap().finish();
+        assert_eq!(op.read("run.sh").await.unwrap().to_vec(), b"#!/bin/sh");
+        assert_eq!(op.stat("run.sh").await.unwrap().unix_mode(), Some(0o755));
+    }
+
+    #[tokio::test]
+    async fn test_git_symlink() {
+        let path = temp_path();
+        let (first, _) = build_repo(&path);
+
+        let builder = GitBuilder::default()
+            .path(&path)
+            .reference(&first.to_string());
+        let op = Operator::new(builder).unwrap().finish();
+
+        let meta = op.stat("dir-link").await.unwrap();
+        assert_eq!(meta.mode(), EntryMode::Symlink);
+        assert_eq!(meta.symlink_target(), Some("dir"));
+        assert_eq!(op.read_link("dir-link").await.unwrap(), "dir");
+        assert_eq!(
+            op.read("dir-link").await.unwrap_err().kind(),
+            ErrorKind::Unsupported
+        );
+    }
+
+    #[tokio::test]
+    async fn test_git_fetch() {
+        let remote = temp_path();
+        build_repo(&remote);
+
+        let path = temp_path();
+        let builder = GitBuilder::default()
+            .path(&path)
+            .remote(&remote)
+            .reference("main");
+        let op = Operator::new(builder).unwrap().finish();
+        assert_eq!(op.read("a.txt").await.unwrap().to_vec(), b"hello, git");
+
+        // Fetch again into the existing repository.
+        let builder = GitBuilder::default()
+            .path(&path)
+            .remote(&format!("file://{remote}"))
+            .reference("main");
+        let op = Operator::new(builder).unwrap().finish();
+        assert_eq!(op.read("dir/b.txt").await.unwrap().to_vec(), b"world");
+    }
+
+    #[test]
+    fn test_git_invalid_reference() {
+        let path = temp_path();
+        build_repo(&path);
+
+        let builder = GitBuilder::default().path(&path).reference("not-exist");
+        let err = Operator::new(builder).err().expect("build must fail");
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+    }
+}
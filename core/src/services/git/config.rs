@@ -0,0 +1,40 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Config for git service.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct GitConfig {
+    /// root of the backend, it's a dir inside the tree of the commit.
+    pub root: Option<String>,
+    /// path of the git repository, either a bare repository or a work tree.
+    pub path: Option<String>,
+    /// revision to expose, like a branch, tag or commit id.
+    ///
+    /// Default to `HEAD` if not set.
+    pub reference: Option<String>,
+    /// url or path of the remote to fetch from while building the backend.
+    ///
+    /// A bare repository will be cloned into path if it doesn't exist.
+    pub remote: Option<String>,
+}
@@ -0,0 +1,351 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use gix::bstr::ByteSlice;
+use gix::objs::tree::EntryKind;
+use gix::ObjectId;
+
+use crate::raw::*;
+use crate::*;
+
+/// Refspecs used to fetch from remote, branches and tags are mirrored as is.
+const MIRROR_REFSPECS: [&str; 2] = ["+refs/heads/*:refs/heads/*", "+refs/tags/*:refs/tags/*"];
+
+/// Snapshot is the commit exposed by the backend.
+#[derive(Debug, Clone, Copy)]
+pub struct Snapshot {
+    pub commit: ObjectId,
+    pub tree: ObjectId,
+    pub last_modified: DateTime<Utc>,
+}
+
+pub struct GitCore {
+    pub info: Arc<AccessorInfo>,
+    pub repo: gix::ThreadSafeRepository,
+    pub snapshot: Snapshot,
+}
+
+impl Debug for GitCore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GitCore")
+            .field("path", &self.repo.path())
+            .field("snapshot", &self.snapshot)
+            .finish_non_exhaustive()
+    }
+}
+
+impl GitCore {
+    /// Build the metadata of an entry, `path` is only used in errors.
+    fn metadata(
+        &self,
+        repo: &gix::Repository,
+        path: &str,
+        kind: EntryKind,
+        id: ObjectId,
+        snapshot: &Snapshot,
+    ) -> Result<Metadata> {
+        let mut meta = match kind {
+            EntryKind::Tree => {
+                let mut meta = Metadata::new(EntryMode::DIR).with_etag(id.to_string());
+                // The root dir is versioned by commit since trees can be shared by commits.
+                if id == snapshot.tree {
+                    meta.set_version(&snapshot.commit.to_string());
+                }
+                meta
+            }
+            EntryKind::Blob | EntryKind::BlobExecutable => {
+                let header = repo.find_header(id).map_err(new_git_error)?;
+                Metadata::new(EntryMode::FILE)
+                    .with_content_length(header.size())
+                    .with_etag(id.to_string())
+                    .with_version(id.to_string())
+                    .with_unix_mode(if kind == EntryKind::Blob {
+                        0o644
+                    } else {
+                        0o755
+                    })
+            }
+            EntryKind::Link => {
+                let target = repo.find_object(id).map_err(new_git_error)?.detach().data;
+                Metadata::new(EntryMode::Symlink)
+                    .with_content_length(target.len() as u64)
+                    .with_etag(id.to_string())
+                    .with_version(id.to_string())
+                    .with_symlink_target(target.to_str_lossy().to_string())
+            }
+            EntryKind::Commit => return Err(submodule_error(path)),
+        };
+        meta.set_last_modified(snapshot.last_modified);
+        Ok(meta)
+    }
+
+    /// Find the entry of `path`, it's looked up in the commit of `version`
+    /// if given, or `version` is the id of blob itself.
+    fn lookup(
+        &self,
+        repo: &gix::Repository,
+        path: &str,
+        version: Option<&str>,
+    ) -> Result<(EntryKind, ObjectId, Snapshot)> {
+        let snapshot = match version {
+            None => self.snapshot,
+            Some(v) => {
+                let object = rev_parse(repo, v)?;
+                if object.kind == gix::objs::Kind::Blob {
+                    if path.ends_with('/') {
+                        return Err(not_found(path));
+                    }
+                    // Blobs don't have their own time, use the time of snapshot instead.
+                    return Ok((EntryKind::Blob, object.id, self.snapshot));
+                }
+                let commit = object.peel_to_commit().map_err(|err| {
+                    Error::new(ErrorKind::NotFound, "version is not a commit or blob")
+                        .with_context("version", v)
+                        .set_source(err)
+                })?;
+                new_snapshot(commit)?
+            }
+        };
+
+        let p = path.trim_end_matches('/');
+        if p.is_empty() {
+            return Ok((EntryKind::Tree, snapshot.tree, snapshot));
+        }
+
+        let tree = repo.find_tree(snapshot.tree).map_err(new_git_error)?;
+        let entry = tree
+            .lookup_entry_by_path(p)
+            .map_err(new_git_error)?
+            .ok_or_else(|| not_found(path))?;
+        let kind = entry.mode().kind();
+        if path.ends_with('/') && kind != EntryKind::Tree {
+            return Err(not_found(path));
+        }
+        Ok((kind, entry.object_id(), snapshot))
+    }
+
+    pub fn stat(&self, path: &str, version: Option<&str>) -> Result<Metadata> {
+        let repo = self.repo.to_thread_local();
+        let (kind, id, snapshot) = self.lookup(&repo, path, version)?;
+        self.metadata(&repo, path, kind, id, &snapshot)
+    }
+
+    pub fn read(&self, path: &str, range: BytesRange, version: Option<&str>) -> Result<Buffer> {
+        let repo = self.repo.to_thread_local();
+        let (kind, id, _) = self.lookup(&repo, path, version)?;
+        match kind {
+            EntryKind::Tree => {
+                return Err(Error::new(ErrorKind::IsADirectory, "entry is a dir")
+                    .with_context("path", path))
+            }
+            EntryKind::Link => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    "reading symlink is not supported, use read_link instead",
+                )
+                .with_context("path", path))
+            }
+            EntryKind::Commit => return Err(submodule_error(path)),
+            EntryKind::Blob | EntryKind::BlobExecutable => {}
+        }
+
+        // Blobs are stored compressed as a whole, they can only be read entirely.
+        let data = repo.find_object(id).map_err(new_git_error)?.detach().data;
+        let size = data.len();
+        let offset = (range.offset() as usize).min(size);
+        let len = range
+            .size()
+            .map_or(size - offset, |v| (v as usize).min(size - offset));
+        Ok(Buffer::from(data).slice(offset..offset + len))
+    }
+
+    pub fn read_link(&self, path: &str) -> Result<String> {
+        let repo = self.repo.to_thread_local();
+        let (kind, id, _) = self.lookup(&repo, path, None)?;
+        if kind != EntryKind::Link {
+            return Err(Error::new(ErrorKind::Unexpected, "path is not a symlink")
+                .with_context("path", path));
+        }
+        let target = repo.find_object(id).map_err(new_git_error)?.detach().data;
+        Ok(target.to_str_lossy().to_string())
+    }
+
+    /// List the dir `path` itself and all entries inside it in ascending order.
+    ///
+    /// Git sorts tree entries as if dirs have a trailing `/`, so walking trees
+    /// in depth-first order yields paths in ascending order too.
+    pub fn list(
+        &self,
+        path: &str,
+        recursive: bool,
+        start_after: Option<&str>,
+    ) -> Result<Vec<(String, Metadata)>> {
+        let repo = self.repo.to_thread_local();
+        let (kind, id, snapshot) = match self.lookup(&repo, path, None) {
+            Ok(v) => v,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) => return Err(err),
+        };
+        if kind != EntryKind::Tree {
+            return Ok(vec![]);
+        }
+
+        let mut entries = vec![(
+            path.to_string(),
+            self.metadata(&repo, path, kind, id, &snapshot)?,
+        )];
+        self.walk(&repo, id, path, recursive, &snapshot, &mut entries)?;
+        Ok(entries
+            .into_iter()
+            .filter(|(k, _)| start_after.is_none_or(|v| k.as_str() > v))
+            .collect())
+    }
+
+    fn walk(
+        &self,
+        repo: &gix::Repository,
+        tree: ObjectId,
+        prefix: &str,
+        recursive: bool,
+        snapshot: &Snapshot,
+        entries: &mut Vec<(String, Metadata)>,
+    ) -> Result<()> {
+        let tree = repo.find_tree(tree).map_err(new_git_error)?;
+        for entry in tree.iter() {
+            let entry = entry.map_err(new_git_error)?;
+            let name = entry.filename().to_str_lossy();
+            match entry.kind() {
+                // Submodules are not part of this repository.
+                EntryKind::Commit => continue,
+                EntryKind::Tree => {
+                    let path = format!("{prefix}{name}/");
+                    let meta =
+                        self.metadata(repo, &path, EntryKind::Tree, entry.object_id(), snapshot)?;
+                    entries.push((path.clone(), meta));
+                    if recursive {
+                        self.walk(repo, entry.object_id(), &path, true, snapshot, entries)?;
+                    }
+                }
+                kind => {
+                    let path = format!("{prefix}{name}");
+                    let meta = self.metadata(repo, &path, kind, entry.object_id(), snapshot)?;
+                    entries.push((path, meta));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Resolve `reference` into the snapshot of the commit it points to.
+pub fn resolve(repo: &gix::Repository, reference: &str) -> Result<Snapshot> {
+    let commit = rev_parse(repo, reference)?
+        .peel_to_commit()
+        .map_err(|err| {
+            Error::new(ErrorKind::ConfigInvalid, "reference is not a commit")
+                .with_context("reference", reference)
+                .set_source(err)
+        })?;
+    new_snapshot(commit)
+}
+
+/// Fetch branches and tags from `remote` into the repository at `path`.
+///
+/// A bare repository is cloned if `path` doesn't exist, its `HEAD` follows
+/// the `HEAD` of remote.
+pub fn fetch(path: &str, remote: &str) -> Result<()> {
+    let interrupt = AtomicBool::new(false);
+
+    if !Path::new(path).exists() {
+        let mut clone = gix::prepare_clone_bare(remote, path)
+            .map_err(new_fetch_error)?
+            .configure_remote(|mut remote| {
+                remote.replace_refspecs(MIRROR_REFSPECS, gix::remote::Direction::Fetch)?;
+                Ok(remote)
+            });
+        clone
+            .fetch_only(gix::progress::Discard, &interrupt)
+            .map_err(new_fetch_error)?;
+        return Ok(());
+    }
+
+    let repo = gix::open(path).map_err(new_open_error)?;
+    repo.remote_at(remote)
+        .map_err(new_fetch_error)?
+        .with_refspecs(MIRROR_REFSPECS, gix::remote::Direction::Fetch)
+        .map_err(new_fetch_error)?
+        .connect(gix::remote::Direction::Fetch)
+        .map_err(new_fetch_error)?
+        .prepare_fetch(gix::progress::Discard, Default::default())
+        .map_err(new_fetch_error)?
+        .receive(gix::progress::Discard, &interrupt)
+        .map_err(new_fetch_error)?;
+    Ok(())
+}
+
+fn rev_parse<'repo>(repo: &'repo gix::Repository, spec: &str) -> Result<gix::Object<'repo>> {
+    repo.rev_parse_single(spec)
+        .map_err(|err| {
+            Error::new(ErrorKind::NotFound, "revision not found in git repository")
+                .with_context("revision", spec)
+                .set_source(err)
+        })?
+        .object()
+        .map_err(new_git_error)
+}
+
+fn new_snapshot(commit: gix::Commit<'_>) -> Result<Snapshot> {
+    let time = commit.time().map_err(new_git_error)?;
+    let last_modified = DateTime::from_timestamp(time.seconds, 0).ok_or_else(|| {
+        Error::new(ErrorKind::Unexpected, "commit time is out of range")
+            .with_context("commit", commit.id)
+    })?;
+
+    Ok(Snapshot {
+        commit: commit.id,
+        tree: commit.tree_id().map_err(new_git_error)?.detach(),
+        last_modified,
+    })
+}
+
+fn not_found(path: &str) -> Error {
+    Error::new(ErrorKind::NotFound, "entry not found in git tree").with_context("path", path)
+}
+
+fn submodule_error(path: &str) -> Error {
+    Error::new(ErrorKind::Unsupported, "submodule is not supported").with_context("path", path)
+}
+
+pub fn new_git_error(err: impl Into<anyhow::Error>) -> Error {
+    Error::new(ErrorKind::Unexpected, "error from git").set_source(err)
+}
+
+pub fn new_open_error(err: gix::open::Error) -> Error {
+    Error::new(ErrorKind::ConfigInvalid, "failed to open git repository").set_source(err)
+}
+
+fn new_fetch_error(err: impl Into<anyhow::Error>) -> Error {
+    Error::new(ErrorKind::Unexpected, "failed to fetch from remote").set_source(err)
+}
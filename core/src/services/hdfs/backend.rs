@@ -250,6 +250,8 @@ impl Access for HdfsBackend {
         let mut m = Metadata::new(mode);
         m.set_content_length(meta.len());
         m.set_last_modified(meta.modified().into());
+        m.set_accessed(meta.accessed().into());
+        m.set_unix_mode(meta.permissions() as u32 & 0o7777);
 
         Ok(RpStat::new(m))
     }
@@ -56,7 +56,9 @@ impl oio::List for HdfsLister {
         let entry = if de.is_file() {
             let meta = Metadata::new(EntryMode::FILE)
                 .with_content_length(de.len())
-                .with_last_modified(de.modified().into());
+                .with_last_modified(de.modified().into())
+                .with_accessed(de.accessed().into())
+                .with_unix_mode(de.permissions() as u32 & 0o7777);
             oio::Entry::new(&path, meta)
         } else if de.is_dir() {
             // Make sure we are returning the correct path.
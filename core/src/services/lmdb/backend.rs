@@ -0,0 +1,348 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::ops::Bound;
+
+use heed::types::Bytes;
+
+use crate::raw::adapters::kv;
+use crate::raw::*;
+use crate::services::LmdbConfig;
+use crate::Builder;
+use crate::Error;
+use crate::ErrorKind;
+use crate::Scheme;
+use crate::*;
+
+type Database = heed::Database<Bytes, Bytes>;
+
+impl Configurator for LmdbConfig {
+    type Builder = LmdbBuilder;
+    fn into_builder(self) -> Self::Builder {
+        LmdbBuilder {
+            config: self,
+            env: None,
+        }
+    }
+}
+
+/// Lmdb service support.
+#[doc = include_str!("docs.md")]
+#[derive(Default, Debug)]
+pub struct LmdbBuilder {
+    config: LmdbConfig,
+
+    env: Option<heed::Env<heed::WithoutTls>>,
+}
+
+impl LmdbBuilder {
+    /// Set the environment for Lmdb.
+    ///
+    /// This method should be called when the environment has been opened in
+    /// this process, since heed doesn't allow opening an environment twice.
+    /// The environment must be opened with `read_txn_without_tls`.
+    ///
+    /// <div class="warning">
+    ///
+    /// `datadir` and `env` should not be set simultaneously.
+    /// If both are set, `env` will take precedence.
+    ///
+    /// </div>
+    pub fn env(mut self, env: heed::Env<heed::WithoutTls>) -> Self {
+        self.env = Some(env);
+        self
+    }
+
+    /// Set the path to the lmdb environment directory. Will create if not exists.
+    pub fn datadir(mut self, path: &str) -> Self {
+        self.config.datadir = Some(path.into());
+        self
+    }
+
+    /// Set the name of database in the environment. Will create if not exists.
+    ///
+    /// The unnamed database is used if not set.
+    pub fn database(mut self, database: &str) -> Self {
+        self.config.database = Some(database.into());
+        self
+    }
+
+    /// Set the max size of the memory map in bytes, it's the max size of the environment.
+    ///
+    /// Only used while opening environment via `datadir`, lmdb's default will be used if not set.
+    pub fn map_size(mut self, size: usize) -> Self {
+        self.config.map_size = Some(size);
+        self
+    }
+
+    /// Set the root for Lmdb.
+    pub fn root(mut self, path: &str) -> Self {
+        self.config.root = Some(path.into());
+        self
+    }
+
+    /// Store metadata like content type, etag and last modified with values.
+    ///
+    /// Values written before are still readable, they just don't have metadata.
+    pub fn enable_metadata(mut self, enabled: bool) -> Self {
+        self.config.enable_metadata = enabled;
+        self
+    }
+}
+
+impl Builder for LmdbBuilder {
+    type Config = LmdbConfig;
+
+    fn build(self) -> Result<impl Access> {
+        let (datadir, env) = if let Some(env) = self.env {
+            (env.path().to_string_lossy().to_string(), env)
+        } else {
+            let datadir = self.config.datadir.ok_or_else(|| {
+                Error::new(ErrorKind::ConfigInvalid, "datadir is required but not set")
+                    .with_context("service", Scheme::Lmdb)
+            })?;
+
+            std::fs::create_dir_all(&datadir).map_err(|e| {
+                Error::new(ErrorKind::Unexpected, "create datadir for lmdb failed")
+                    .with_context("service", Scheme::Lmdb)
+                    .with_context("datadir", &datadir)
+                    .set_source(e)
+            })?;
+
+            let mut options = heed::EnvOpenOptions::new().read_txn_without_tls();
+            if let Some(size) = self.config.map_size {
+                options.map_size(size);
+            }
+            if self.config.database.is_some() {
+                options.max_dbs(1);
+            }
+            // SAFETY: The environment is only accessed through lmdb, users must
+            // not modify the files of environment by other means.
+            let env = unsafe { options.open(&datadir) }.map_err(|e| {
+                parse_error(e)
+                    .with_context("service", Scheme::Lmdb)
+                    .with_context("datadir", &datadir)
+            })?;
+
+            (datadir, env)
+        };
+
+        let db = open_database(&env, self.config.database.as_deref())?;
+
+        let backend = LmdbBackend::new(Adapter {
+            datadir,
+            database: self.config.database,
+            env,
+            db,
+        })
+        .with_root(self.config.root.as_deref().unwrap_or_default());
+
+        Ok(if self.config.enable_metadata {
+            backend.with_metadata()
+        } else {
+            backend
+        })
+    }
+}
+
+/// Backend for Lmdb services.
+pub type LmdbBackend = kv::Backend<Adapter>;
+
+#[derive(Clone)]
+pub struct Adapter {
+    datadir: String,
+    database: Option<String>,
+    env: heed::Env<heed::WithoutTls>,
+    db: Database,
+}
+
+impl Debug for Adapter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut ds = f.debug_struct("Adapter");
+        ds.field("path", &self.datadir);
+        ds.field("database", &self.database);
+        ds.finish()
+    }
+}
+
+impl kv::ScanPage for Adapter {
+    async fn next_page(
+        &self,
+        prefix: &str,
+        start_after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<String>> {
+        let rtxn = self.env.read_txn().map_err(parse_error)?;
+
+        let start = match start_after {
+            Some(key) => Bound::Excluded(key.as_bytes()),
+            None => Bound::Included(prefix.as_bytes()),
+        };
+        let mut keys = Vec::with_capacity(limit);
+        for item in self
+            .db
+            .range(&rtxn, &(start, Bound::Unbounded))
+            .map_err(parse_error)?
+        {
+            let (key, _) = item.map_err(parse_error)?;
+            if !key.starts_with(prefix.as_bytes()) || keys.len() >= limit {
+                break;
+            }
+            // Environments shared with other components might contain keys
+            // that are not valid utf-8, they can't be paths so skip them.
+            if let Ok(key) = std::str::from_utf8(key) {
+                keys.push(key.to_string());
+            }
+        }
+        Ok(keys)
+    }
+}
+
+impl kv::Adapter for Adapter {
+    type Scanner = kv::PagedScanner<Self>;
+
+    fn info(&self) -> kv::Info {
+        kv::Info::new(
+            Scheme::Lmdb,
+            self.database.as_deref().unwrap_or(&self.datadir),
+            Capability {
+                read: true,
+                write: true,
+                list: true,
+                transaction: true,
+                shared: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    async fn get(&self, path: &str) -> Result<Option<Buffer>> {
+        let rtxn = self.env.read_txn().map_err(parse_error)?;
+
+        let value = self.db.get(&rtxn, path.as_bytes()).map_err(parse_error)?;
+        Ok(value.map(|v| Buffer::from(v.to_vec())))
+    }
+
+    async fn get_many(&self, paths: &[String]) -> Result<Vec<Option<Buffer>>> {
+        // All keys are read from the same snapshot.
+        let rtxn = self.env.read_txn().map_err(parse_error)?;
+
+        paths
+            .iter()
+            .map(|path| {
+                let value = self.db.get(&rtxn, path.as_bytes()).map_err(parse_error)?;
+                Ok(value.map(|v| Buffer::from(v.to_vec())))
+            })
+            .collect()
+    }
+
+    async fn set(&self, path: &str, value: Buffer) -> Result<()> {
+        let mut wtxn = self.env.write_txn().map_err(parse_error)?;
+
+        self.db
+            .put(&mut wtxn, path.as_bytes(), &value.to_vec())
+            .map_err(parse_error)?;
+
+        wtxn.commit().map_err(parse_error)?;
+        Ok(())
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        let mut wtxn = self.env.write_txn().map_err(parse_error)?;
+
+        self.db
+            .delete(&mut wtxn, path.as_bytes())
+            .map_err(parse_error)?;
+
+        wtxn.commit().map_err(parse_error)?;
+        Ok(())
+    }
+
+    async fn scan(&self, path: &str, opts: kv::ScanOptions) -> Result<Self::Scanner> {
+        Ok(kv::PagedScanner::new(self.clone(), path, opts))
+    }
+
+    async fn transaction(
+        &self,
+        checks: Vec<(String, Option<Buffer>)>,
+        mutations: Vec<(String, Option<Buffer>)>,
+    ) -> Result<bool> {
+        // Only one write transaction is permitted at same time, so checks
+        // within it can't be changed by others before commit.
+        let mut wtxn = self.env.write_txn().map_err(parse_error)?;
+
+        for (path, expected) in checks {
+            let actual = self
+                .db
+                .get(&wtxn, path.as_bytes())
+                .map_err(parse_error)?
+                .map(|v| v.to_vec());
+            if actual != expected.map(|v| v.to_vec()) {
+                // Dropping the transaction aborts it.
+                return Ok(false);
+            }
+        }
+        for (path, value) in mutations {
+            match value {
+                Some(value) => self
+                    .db
+                    .put(&mut wtxn, path.as_bytes(), &value.to_vec())
+                    .map_err(parse_error)?,
+                None => self
+                    .db
+                    .delete(&mut wtxn, path.as_bytes())
+                    .map(|_| ())
+                    .map_err(parse_error)?,
+            }
+        }
+
+        wtxn.commit().map_err(parse_error)?;
+        Ok(true)
+    }
+}
+
+fn parse_error(e: heed::Error) -> Error {
+    let kind = match &e {
+        heed::Error::Mdb(heed::MdbError::NotFound) => ErrorKind::NotFound,
+        _ => ErrorKind::Unexpected,
+    };
+    Error::new(kind, "error from lmdb").set_source(e)
+}
+
+/// Open the database in environment, create it if not exists.
+fn open_database(env: &heed::Env<heed::WithoutTls>, name: Option<&str>) -> Result<Database> {
+    // Only one write transaction is permitted at same time, applying new one
+    // will block until it available.
+    //
+    // So we first try opening database via read transaction.
+    {
+        let rtxn = env.read_txn().map_err(parse_error)?;
+        let db = env.open_database(&rtxn, name).map_err(parse_error)?;
+        // Committing makes the database handle available to other transactions.
+        rtxn.commit().map_err(parse_error)?;
+        if let Some(db) = db {
+            return Ok(db);
+        }
+    }
+
+    let mut wtxn = env.write_txn().map_err(parse_error)?;
+    let db = env.create_database(&mut wtxn, name).map_err(parse_error)?;
+    wtxn.commit().map_err(parse_error)?;
+    Ok(db)
+}
@@ -0,0 +1,40 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::Debug;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Config for lmdb service support.
+#[derive(Default, Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct LmdbConfig {
+    /// path to the lmdb environment directory.
+    pub datadir: Option<String>,
+    /// The root for lmdb.
+    pub root: Option<String>,
+    /// The name of database in the lmdb environment.
+    ///
+    /// The unnamed database is used if not set.
+    pub database: Option<String>,
+    /// The max size of the memory map in bytes, it's the max size of the environment.
+    pub map_size: Option<usize>,
+    /// Store metadata like content type, etag and last modified with values.
+    pub enable_metadata: bool,
+}
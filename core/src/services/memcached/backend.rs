@@ -82,6 +82,15 @@ impl MemcachedBuilder {
         self.config.default_ttl = Some(ttl);
         self
     }
+
+    /// Split values larger than `chunk_size` into chunks.
+    ///
+    /// Memcached rejects values larger than 1MiB by default, set this to store
+    /// larger values.
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.config.chunk_size = Some(chunk_size);
+        self
+    }
 }
 
 impl Builder for MemcachedBuilder {
@@ -145,14 +154,19 @@ impl Builder for MemcachedBuilder {
         );
 
         let conn = OnceCell::new();
-        Ok(MemcachedBackend::new(Adapter {
+        let backend = MemcachedBackend::new(Adapter {
             endpoint,
             username: self.config.username.clone(),
             password: self.config.password.clone(),
             conn,
             default_ttl: self.config.default_ttl,
         })
-        .with_normalized_root(root))
+        .with_normalized_root(root);
+
+        Ok(match self.config.chunk_size {
+            Some(chunk_size) => backend.with_chunk_size(chunk_size),
+            None => backend,
+        })
     }
 }
 
@@ -205,6 +219,7 @@ impl kv::Adapter for Adapter {
             Capability {
                 read: true,
                 write: true,
+                write_with_ttl: true,
                 shared: true,
 
                 ..Default::default()
@@ -225,13 +240,18 @@ impl kv::Adapter for Adapter {
             &percent_encode_path(key),
             &value.to_vec(),
             // Set expiration to 0 if ttl not set.
-            self.default_ttl
-                .map(|v| v.as_secs() as u32)
-                .unwrap_or_default(),
+            self.default_ttl.map(expiration).unwrap_or_default(),
         )
         .await
     }
 
+    async fn set_with_ttl(&self, key: &str, value: Buffer, ttl: Duration) -> Result<()> {
+        let mut conn = self.conn().await?;
+
+        conn.set(&percent_encode_path(key), &value.to_vec(), expiration(ttl))
+            .await
+    }
+
     async fn delete(&self, key: &str) -> Result<()> {
         let mut conn = self.conn().await?;
 
@@ -239,6 +259,20 @@ impl kv::Adapter for Adapter {
     }
 }
 
+/// Memcached takes expirations longer than 30 days as unix timestamps.
+const MAX_RELATIVE_EXPIRATION: u64 = 60 * 60 * 24 * 30;
+
+/// Convert `ttl` into the expiration of memcached, rounded up to seconds.
+fn expiration(ttl: Duration) -> u32 {
+    let secs = (ttl.as_secs() + u64::from(ttl.subsec_nanos() > 0)).max(1);
+    if secs <= MAX_RELATIVE_EXPIRATION {
+        return secs as u32;
+    }
+    expire_time_from_ttl(ttl)
+        .timestamp()
+        .clamp(0, u32::MAX as i64) as u32
+}
+
 /// A `bb8::ManageConnection` for `memcache_async::ascii::Protocol`.
 #[derive(Clone, Debug)]
 struct MemcacheConnectionManager {
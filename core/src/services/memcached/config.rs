@@ -40,4 +40,9 @@ pub struct MemcachedConfig {
     pub password: Option<String>,
     /// The default ttl for put operations.
     pub default_ttl: Option<Duration>,
+    /// Split values larger than this size into chunks.
+    ///
+    /// Memcached rejects values larger than 1MiB by default, set this to store
+    /// larger values.
+    pub chunk_size: Option<usize>,
 }
@@ -47,6 +47,15 @@ impl MemoryBuilder {
         self.config.root = Some(path.into());
         self
     }
+
+    /// Set if versioning is enabled for this service.
+    ///
+    /// Writes and deletes keep previous versions around, which can be accessed
+    /// by their versions like s3 does for versioned buckets.
+    pub fn enable_versioning(mut self, enabled: bool) -> Self {
+        self.config.enable_versioning = enabled;
+        self
+    }
 }
 
 impl Builder for MemoryBuilder {
@@ -55,7 +64,7 @@ impl Builder for MemoryBuilder {
     fn build(self) -> Result<impl Access> {
         let root = normalize_root(self.config.root.as_deref().unwrap_or("/"));
 
-        let core = MemoryCore::new();
+        let core = MemoryCore::new(self.config.enable_versioning);
         Ok(MemoryAccessor::new(core).with_normalized_root(root))
     }
 }
@@ -70,6 +79,7 @@ pub struct MemoryAccessor {
 
 impl MemoryAccessor {
     fn new(core: MemoryCore) -> Self {
+        let versioning = core.versions.is_some();
         let info = AccessorInfo::default();
         info.set_scheme(DEFAULT_SCHEME);
         info.set_name(&format!("{:p}", Arc::as_ptr(&core.data)));
@@ -82,10 +92,19 @@ impl MemoryAccessor {
             write_with_content_type: true,
             write_with_content_disposition: true,
             write_with_content_encoding: true,
+            write_with_ttl: true,
             delete: true,
+            delete_with_version: versioning,
             stat: true,
+            stat_with_version: versioning,
+            read_with_version: versioning,
             list: true,
             list_with_recursive: true,
+            list_with_start_after: true,
+            list_with_versions: versioning,
+            list_with_deleted: versioning,
+            lock: true,
+            transaction: true,
             shared: false,
             ..Default::default()
         });
@@ -114,13 +133,17 @@ impl Access for MemoryAccessor {
         self.info.clone()
     }
 
-    async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         let p = build_abs_path(&self.root, path);
 
         if p == build_abs_path(&self.root, "") {
             Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
         } else {
-            match self.core.get(&p)? {
+            let value = match args.version() {
+                Some(version) => self.core.get_version(&p, version)?,
+                None => self.core.get(&p)?,
+            };
+            match value {
                 Some(value) => Ok(RpStat::new(value.metadata)),
                 None => Err(Error::new(
                     ErrorKind::NotFound,
@@ -133,7 +156,11 @@ impl Access for MemoryAccessor {
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
         let p = build_abs_path(&self.root, path);
 
-        let value = match self.core.get(&p)? {
+        let value = match args.version() {
+            Some(version) => self.core.get_version(&p, version)?,
+            None => self.core.get(&p)?,
+        };
+        let value = match value {
             Some(value) => value,
             None => {
                 return Err(Error::new(
@@ -166,12 +193,60 @@ impl Access for MemoryAccessor {
 
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
         let p = build_abs_path(&self.root, path);
-        let keys = self.core.scan(&p)?;
-        let lister = MemoryLister::new(&self.root, keys);
+        let mut entries = if args.versions() || args.deleted() {
+            self.core
+                .scan_versions(&p, args.versions(), args.deleted())?
+        } else {
+            self.core
+                .scan(&p)?
+                .into_iter()
+                .map(|key| (key, Metadata::new(EntryMode::FILE)))
+                .collect()
+        };
+        if let Some(start_after) = args.start_after() {
+            let start_after = build_abs_path(&self.root, start_after);
+            entries.retain(|(key, _)| *key > start_after);
+        }
+        let lister = MemoryLister::new(&self.root, entries);
         let lister = oio::HierarchyLister::new(lister, path, args.recursive());
 
         Ok((RpList::default(), lister))
     }
+
+    async fn lock(&self, path: &str, args: OpLock) -> Result<RpLock> {
+        let p = build_abs_path(&self.root, path);
+        let record = self
+            .core
+            .update_lock(&p, |current| LockRecord::lock(current, &args))?;
+        Ok(RpLock::new(record.token()))
+    }
+
+    async fn unlock(&self, path: &str, args: OpUnlock) -> Result<RpUnlock> {
+        let p = build_abs_path(&self.root, path);
+        self.core
+            .update_lock(&p, |current| LockRecord::unlock(current, &args))?;
+        Ok(RpUnlock::default())
+    }
+
+    async fn transaction(&self, args: OpTransaction) -> Result<RpTransaction> {
+        let abs = |entries: &[(String, Option<Buffer>)]| {
+            entries
+                .iter()
+                .map(|(path, value)| (build_abs_path(&self.root, path), value.clone()))
+                .collect()
+        };
+        let checks = abs(args.checks());
+        let mutations = abs(args.mutations());
+
+        if self.core.transaction(checks, mutations)? {
+            Ok(RpTransaction::default())
+        } else {
+            Err(Error::new(
+                ErrorKind::ConditionNotMatch,
+                "transaction conflicts with changes by others",
+            ))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -186,4 +261,35 @@ mod tests {
         let b2 = MemoryBuilder::default().build().unwrap();
         assert_ne!(b1.info().name(), b2.info().name())
     }
+
+    #[test]
+    fn test_delete_not_existing_with_versioning() {
+        let core = MemoryCore::new(true);
+        core.delete("a").unwrap();
+
+        let versions = core.scan_versions("a", true, true).unwrap();
+        assert_eq!(versions.len(), 1);
+        assert!(versions[0].1.is_deleted());
+        let version = versions[0].1.version().unwrap();
+        assert!(core.get_version("a", version).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_get_expired_version() {
+        let core = MemoryCore::new(true);
+        let metadata = Metadata::new(EntryMode::FILE)
+            .with_expires_at(chrono::Utc::now() - chrono::Duration::seconds(1));
+        let metadata = core
+            .set(
+                "a",
+                MemoryValue {
+                    metadata,
+                    content: Buffer::from("hello"),
+                },
+            )
+            .unwrap();
+
+        let version = metadata.version().unwrap();
+        assert!(core.get_version("a", version).unwrap().is_none());
+    }
 }
@@ -27,4 +27,6 @@ use serde::Serialize;
 pub struct MemoryConfig {
     /// root of the backend.
     pub root: Option<String>,
+    /// Keep version histories of written keys like bucket versioning of s3.
+    pub enable_versioning: bool,
 }
@@ -19,7 +19,12 @@ use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::sync::MutexGuard;
 
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::raw::LockRecord;
 use crate::*;
 
 /// Value stored in memory containing both metadata and content
@@ -29,9 +34,24 @@ pub struct MemoryValue {
     pub content: Buffer,
 }
 
+impl MemoryValue {
+    /// Check if this value has expired, expired values are removed lazily.
+    pub fn is_expired(&self) -> bool {
+        self.metadata.expires_at().is_some_and(|v| v <= Utc::now())
+    }
+}
+
+/// Version histories of keys from oldest to latest.
+type VersionMap = BTreeMap<String, Vec<MemoryValue>>;
+
 #[derive(Clone)]
 pub struct MemoryCore {
     pub data: Arc<Mutex<BTreeMap<String, MemoryValue>>>,
+    /// Version histories of keys from oldest to latest, `None` if versioning is disabled.
+    ///
+    /// Delete markers are kept as versions with `is_deleted` set and empty content.
+    /// This lock is always acquired after `data`.
+    pub versions: Option<Arc<Mutex<VersionMap>>>,
 }
 
 impl Debug for MemoryCore {
@@ -41,28 +61,146 @@ impl Debug for MemoryCore {
 }
 
 impl MemoryCore {
-    pub fn new() -> Self {
+    pub fn new(enable_versioning: bool) -> Self {
         Self {
             data: Arc::new(Mutex::new(BTreeMap::new())),
+            versions: enable_versioning.then(|| Arc::new(Mutex::new(BTreeMap::new()))),
         }
     }
 
+    fn lock_versions(&self) -> Option<MutexGuard<'_, VersionMap>> {
+        self.versions.as_ref().map(|v| v.lock().unwrap())
+    }
+
     pub fn get(&self, key: &str) -> Result<Option<MemoryValue>> {
-        Ok(self.data.lock().unwrap().get(key).cloned())
+        let mut data = self.data.lock().unwrap();
+        Ok(live_value(&mut data, key).cloned())
     }
 
-    pub fn set(&self, key: &str, value: MemoryValue) -> Result<()> {
-        self.data.lock().unwrap().insert(key.to_string(), value);
-        Ok(())
+    /// Get the value of `key` at `version`, delete markers and expired versions are
+    /// never returned.
+    pub fn get_version(&self, key: &str, version: &str) -> Result<Option<MemoryValue>> {
+        let Some(versions) = self.lock_versions() else {
+            return Ok(None);
+        };
+        Ok(versions.get(key).and_then(|history| {
+            history
+                .iter()
+                .find(|v| v.metadata.version() == Some(version))
+                .filter(|v| !v.metadata.is_deleted() && !v.is_expired())
+                .cloned()
+        }))
+    }
+
+    /// Set the value of `key`, returns the metadata with its version filled.
+    pub fn set(&self, key: &str, value: MemoryValue) -> Result<Metadata> {
+        let mut data = self.data.lock().unwrap();
+        let mut versions = self.lock_versions();
+        Ok(put_value(&mut data, versions.as_deref_mut(), key, value))
     }
 
     pub fn delete(&self, key: &str) -> Result<()> {
-        self.data.lock().unwrap().remove(key);
+        let mut data = self.data.lock().unwrap();
+        let mut versions = self.lock_versions();
+        remove_value(&mut data, versions.as_deref_mut(), key);
         Ok(())
     }
 
+    /// Delete `version` of `key` permanently, the previous version becomes current if
+    /// `version` is the latest one.
+    ///
+    /// Deleting a version that doesn't exist is a no-op.
+    pub fn delete_version(&self, key: &str, version: &str) -> Result<()> {
+        let mut data = self.data.lock().unwrap();
+        let Some(mut versions) = self.lock_versions() else {
+            return Ok(());
+        };
+        let Some(history) = versions.get_mut(key) else {
+            return Ok(());
+        };
+        let Some(idx) = history
+            .iter()
+            .position(|v| v.metadata.version() == Some(version))
+        else {
+            return Ok(());
+        };
+
+        history.remove(idx);
+        if idx == history.len() {
+            match history.last() {
+                Some(latest) if !latest.metadata.is_deleted() => {
+                    data.insert(key.to_string(), latest.clone());
+                }
+                _ => {
+                    data.remove(key);
+                }
+            }
+        }
+        if history.is_empty() {
+            versions.remove(key);
+        }
+        Ok(())
+    }
+
+    /// Replace the lock record at `key` with the one built by `f` atomically.
+    pub fn update_lock(
+        &self,
+        key: &str,
+        f: impl FnOnce(Option<&LockRecord>) -> Result<LockRecord>,
+    ) -> Result<LockRecord> {
+        let mut data = self.data.lock().unwrap();
+
+        let current = match live_value(&mut data, key) {
+            Some(value) => Some(LockRecord::parse(&value.content.to_bytes())?),
+            None => None,
+        };
+        let record = f(current.as_ref())?;
+
+        let content = Buffer::from(record.to_bytes());
+        let metadata = Metadata::new(EntryMode::FILE).with_content_length(content.len() as u64);
+        let mut versions = self.lock_versions();
+        put_value(
+            &mut data,
+            versions.as_deref_mut(),
+            key,
+            MemoryValue { metadata, content },
+        );
+        Ok(record)
+    }
+
+    /// Apply `mutations` atomically if all `checks` hold, returns `false` otherwise.
+    pub fn transaction(
+        &self,
+        checks: Vec<(String, Option<Buffer>)>,
+        mutations: Vec<(String, Option<Buffer>)>,
+    ) -> Result<bool> {
+        let mut data = self.data.lock().unwrap();
+
+        for (key, expected) in checks {
+            let current = live_value(&mut data, &key).map(|v| v.content.to_bytes());
+            if current != expected.map(|v| v.to_bytes()) {
+                return Ok(false);
+            }
+        }
+
+        let mut versions = self.lock_versions();
+        for (key, value) in mutations {
+            match value {
+                Some(content) => {
+                    let metadata =
+                        Metadata::new(EntryMode::FILE).with_content_length(content.len() as u64);
+                    let value = MemoryValue { metadata, content };
+                    put_value(&mut data, versions.as_deref_mut(), &key, value);
+                }
+                None => remove_value(&mut data, versions.as_deref_mut(), &key),
+            }
+        }
+        Ok(true)
+    }
+
     pub fn scan(&self, prefix: &str) -> Result<Vec<String>> {
-        let data = self.data.lock().unwrap();
+        let mut data = self.data.lock().unwrap();
+        data.retain(|_, v| !v.is_expired());
 
         if prefix.is_empty() {
             return Ok(data.keys().cloned().collect());
@@ -77,4 +215,93 @@ impl MemoryCore {
         }
         Ok(keys)
     }
+
+    /// Scan the version histories of keys that start with `prefix`.
+    ///
+    /// - Only the latest version of each key is returned unless `all_versions` is set.
+    /// - Delete markers are skipped unless `deleted` is set.
+    pub fn scan_versions(
+        &self,
+        prefix: &str,
+        all_versions: bool,
+        deleted: bool,
+    ) -> Result<Vec<(String, Metadata)>> {
+        let Some(versions) = self.lock_versions() else {
+            return Ok(Vec::new());
+        };
+
+        let mut entries = Vec::new();
+        for (key, history) in versions.range(prefix.to_string()..) {
+            if !key.starts_with(prefix) {
+                break;
+            }
+            // List versions from the latest to the oldest like s3 does.
+            for (idx, value) in history.iter().rev().enumerate() {
+                if idx > 0 && !all_versions {
+                    break;
+                }
+                if value.metadata.is_deleted() && !deleted {
+                    continue;
+                }
+                let metadata = value.metadata.clone().with_is_current(Some(idx == 0));
+                entries.push((key.clone(), metadata));
+            }
+        }
+        Ok(entries)
+    }
+}
+
+/// Insert `value` as the current value of `key`, a new version is recorded if
+/// versioning is enabled.
+fn put_value(
+    data: &mut BTreeMap<String, MemoryValue>,
+    versions: Option<&mut VersionMap>,
+    key: &str,
+    mut value: MemoryValue,
+) -> Metadata {
+    if let Some(versions) = versions {
+        value.metadata.set_version(&Uuid::new_v4().to_string());
+        versions
+            .entry(key.to_string())
+            .or_default()
+            .push(value.clone());
+    }
+    let metadata = value.metadata.clone();
+    data.insert(key.to_string(), value);
+    metadata
+}
+
+/// Remove the current value of `key`, a delete marker is recorded if versioning is
+/// enabled.
+///
+/// Like s3, the delete marker is recorded even if `key` doesn't exist.
+fn remove_value(
+    data: &mut BTreeMap<String, MemoryValue>,
+    versions: Option<&mut VersionMap>,
+    key: &str,
+) {
+    data.remove(key);
+    if let Some(versions) = versions {
+        let metadata = Metadata::new(EntryMode::FILE)
+            .with_is_deleted(true)
+            .with_version(Uuid::new_v4().to_string());
+        versions
+            .entry(key.to_string())
+            .or_default()
+            .push(MemoryValue {
+                metadata,
+                content: Buffer::new(),
+            });
+    }
+}
+
+/// Get the value of `key` if it has not expired, expired value is removed.
+fn live_value<'a>(
+    data: &'a mut BTreeMap<String, MemoryValue>,
+    key: &str,
+) -> Option<&'a MemoryValue> {
+    if data.get(key).is_some_and(|v| v.is_expired()) {
+        data.remove(key);
+    }
+    data.get(key)
 }
@@ -34,9 +34,12 @@ impl MemoryDeleter {
 }
 
 impl oio::OneShotDelete for MemoryDeleter {
-    async fn delete_once(&self, path: String, _: OpDelete) -> Result<()> {
+    async fn delete_once(&self, path: String, args: OpDelete) -> Result<()> {
         let p = build_abs_path(&self.root, &path);
-        self.core.delete(&p)?;
+        match args.version() {
+            Some(version) => self.core.delete_version(&p, version)?,
+            None => self.core.delete(&p)?,
+        }
         Ok(())
     }
 }
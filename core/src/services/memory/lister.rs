@@ -23,32 +23,31 @@ use crate::*;
 
 pub struct MemoryLister {
     root: String,
-    keys: IntoIter<String>,
+    entries: IntoIter<(String, Metadata)>,
 }
 
 impl MemoryLister {
-    pub fn new(root: &str, keys: Vec<String>) -> Self {
+    pub fn new(root: &str, entries: Vec<(String, Metadata)>) -> Self {
         Self {
             root: root.to_string(),
-            keys: keys.into_iter(),
+            entries: entries.into_iter(),
         }
     }
 }
 
 impl oio::List for MemoryLister {
     async fn next(&mut self) -> Result<Option<oio::Entry>> {
-        match self.keys.next() {
-            Some(key) => {
-                let mode = if key.ends_with('/') {
-                    EntryMode::DIR
-                } else {
-                    EntryMode::FILE
-                };
+        match self.entries.next() {
+            Some((key, mut metadata)) => {
+                // Directories are stored as empty files whose keys end with `/`.
+                if key.ends_with('/') {
+                    metadata.set_mode(EntryMode::DIR);
+                }
                 let mut path = build_rel_path(&self.root, &key);
                 if path.is_empty() {
                     path = "/".to_string();
                 }
-                Ok(Some(oio::Entry::new(&path, Metadata::new(mode))))
+                Ok(Some(oio::Entry::new(&path, metadata)))
             }
             None => Ok(None),
         }
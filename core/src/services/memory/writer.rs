@@ -67,15 +67,13 @@ impl oio::Write for MemoryWriter {
         if let Some(v) = self.op.content_encoding() {
             metadata.set_content_encoding(v);
         }
+        if let Some(ttl) = self.op.ttl() {
+            metadata.set_expires_at(expire_time_from_ttl(ttl));
+        }
 
-        let value = MemoryValue {
-            metadata: metadata.clone(),
-            content,
-        };
-
-        self.core.set(&self.path, value)?;
+        let value = MemoryValue { metadata, content };
 
-        Ok(metadata)
+        self.core.set(&self.path, value)
     }
 
     async fn abort(&mut self) -> Result<()> {
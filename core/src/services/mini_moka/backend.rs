@@ -162,6 +162,7 @@ impl Access for MiniMokaBackend {
                 read: true,
                 write: true,
                 write_can_empty: true,
+                write_with_ttl: true,
                 delete: true,
                 list: true,
 
@@ -18,6 +18,7 @@
 use std::fmt::Debug;
 use std::fmt::Formatter;
 
+use chrono::Utc;
 use mini_moka::sync::Cache;
 
 use crate::*;
@@ -31,6 +32,13 @@ pub struct MiniMokaValue {
     pub content: Buffer,
 }
 
+impl MiniMokaValue {
+    /// Check if this value has expired, expired values are removed lazily.
+    pub fn is_expired(&self) -> bool {
+        self.metadata.expires_at().is_some_and(|v| v <= Utc::now())
+    }
+}
+
 #[derive(Clone)]
 pub struct MiniMokaCore {
     pub cache: Cache<String, MiniMokaValue>,
@@ -47,6 +55,13 @@ impl Debug for MiniMokaCore {
 
 impl MiniMokaCore {
     pub fn get(&self, key: &str) -> Option<MiniMokaValue> {
-        self.cache.get(&key.to_string())
+        let key = key.to_string();
+        match self.cache.get(&key) {
+            Some(value) if value.is_expired() => {
+                self.cache.invalidate(&key);
+                None
+            }
+            value => value,
+        }
     }
 }
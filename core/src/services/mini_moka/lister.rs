@@ -34,6 +34,7 @@ impl MiniMokaLister {
         let keys: Vec<String> = core
             .cache
             .iter()
+            .filter(|entry| !entry.value().is_expired())
             .map(|entry| entry.key().to_string())
             .collect();
 
@@ -66,6 +66,9 @@ impl oio::Write for MiniMokaWriter {
         if let Some(cache_control) = self.op.cache_control() {
             md.set_cache_control(cache_control);
         }
+        if let Some(ttl) = self.op.ttl() {
+            md.set_expires_at(expire_time_from_ttl(ttl));
+        }
 
         let value = MiniMokaValue {
             metadata: md.clone(),
@@ -25,6 +25,9 @@ pub use aliyun_drive::*;
 mod alluxio;
 pub use alluxio::*;
 
+mod archive;
+pub use archive::*;
+
 mod azblob;
 pub use azblob::*;
 
@@ -82,6 +85,9 @@ pub use gdrive::*;
 mod ghac;
 pub use ghac::*;
 
+mod git;
+pub use git::*;
+
 mod github;
 pub use github::*;
 
@@ -112,6 +118,9 @@ pub use koofr::*;
 mod lakefs;
 pub use lakefs::*;
 
+mod lmdb;
+pub use lmdb::*;
+
 mod memcached;
 pub use memcached::*;
 
@@ -67,6 +67,8 @@ impl MokaBuilder {
     ///
     /// Refer to [`moka::future::CacheBuilder`](https://docs.rs/moka/latest/moka/future/struct.CacheBuilder.html)
     ///
+    /// The `expire_after` of the given builder will be replaced to support writes with ttl.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -178,6 +180,8 @@ impl Builder for MokaBuilder {
         if let Some(v) = self.config.time_to_idle {
             builder = builder.time_to_idle(v);
         }
+        // Values written with ttl expire by themselves.
+        builder = builder.expire_after(MokaExpiry);
 
         debug!("backend build finished: {:?}", self.config);
 
@@ -211,6 +215,7 @@ impl MokaAccessor {
             write_with_content_type: true,
             write_with_content_disposition: true,
             write_with_content_encoding: true,
+            write_with_ttl: true,
             delete: true,
             stat: true,
             list: true,
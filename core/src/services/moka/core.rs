@@ -17,8 +17,12 @@
 
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::time::Duration;
+use std::time::Instant;
 
+use chrono::Utc;
 use moka::future::Cache;
+use moka::Expiry;
 
 use crate::*;
 
@@ -31,6 +35,34 @@ pub struct MokaValue {
     pub content: Buffer,
 }
 
+impl MokaValue {
+    /// The remaining ttl of this value if it's written with ttl.
+    fn ttl(&self) -> Option<Duration> {
+        self.metadata
+            .expires_at()
+            .map(|v| (v - Utc::now()).to_std().unwrap_or_default())
+    }
+}
+
+/// MokaExpiry expires values at the `expires_at` of their metadata.
+pub struct MokaExpiry;
+
+impl Expiry<String, MokaValue> for MokaExpiry {
+    fn expire_after_create(&self, _: &String, value: &MokaValue, _: Instant) -> Option<Duration> {
+        value.ttl()
+    }
+
+    fn expire_after_update(
+        &self,
+        _: &String,
+        value: &MokaValue,
+        _: Instant,
+        _: Option<Duration>,
+    ) -> Option<Duration> {
+        value.ttl()
+    }
+}
+
 #[derive(Clone)]
 pub struct MokaCore {
     pub cache: Cache<String, MokaValue>,
@@ -18,6 +18,7 @@
 use std::fmt::Debug;
 use std::fmt::Formatter;
 
+use futures::TryStreamExt;
 use mongodb::bson::doc;
 use mongodb::bson::Binary;
 use mongodb::bson::Document;
@@ -224,8 +225,45 @@ impl Adapter {
     }
 }
 
+impl kv::ScanPage for Adapter {
+    async fn next_page(
+        &self,
+        prefix: &str,
+        start_after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<String>> {
+        let collection = self.get_collection().await?;
+
+        // Strings are compared by bytes, all keys with prefix are less than
+        // the prefix followed by the max char.
+        let upper = format!("{prefix}{}", char::MAX);
+        let filter = match start_after {
+            Some(key) => doc! { self.key_field.as_str(): { "$gt": key, "$lt": upper } },
+            None => doc! { self.key_field.as_str(): { "$gte": prefix, "$lt": upper } },
+        };
+        let docs: Vec<Document> = collection
+            .find(filter)
+            .sort(doc! { self.key_field.as_str(): 1 })
+            .projection(doc! { self.key_field.as_str(): 1 })
+            .limit(limit as i64)
+            .await
+            .map_err(parse_mongodb_error)?
+            .try_collect()
+            .await
+            .map_err(parse_mongodb_error)?;
+
+        docs.iter()
+            .map(|doc| {
+                doc.get_str(&self.key_field)
+                    .map(String::from)
+                    .map_err(parse_bson_error)
+            })
+            .collect()
+    }
+}
+
 impl kv::Adapter for Adapter {
-    type Scanner = ();
+    type Scanner = kv::PagedScanner<Self>;
 
     fn info(&self) -> kv::Info {
         kv::Info::new(
@@ -234,6 +272,7 @@ impl kv::Adapter for Adapter {
             Capability {
                 read: true,
                 write: true,
+                list: true,
                 shared: true,
                 ..Default::default()
             },
@@ -280,6 +319,12 @@ impl kv::Adapter for Adapter {
             .map_err(parse_mongodb_error)?;
         Ok(())
     }
+
+    async fn scan(&self, path: &str, opts: kv::ScanOptions) -> Result<Self::Scanner> {
+        // Connect before cloning, so that the scanner shares the same client.
+        self.get_collection().await?;
+        Ok(kv::PagedScanner::new(self.clone(), path, opts))
+    }
 }
 
 fn parse_mongodb_error(err: mongodb::error::Error) -> Error {
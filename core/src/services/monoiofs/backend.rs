@@ -21,10 +21,8 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use chrono::DateTime;
-use monoio::fs::OpenOptions;
 
 use super::core::MonoiofsCore;
-use super::core::BUFFER_SIZE;
 use super::delete::MonoiofsDeleter;
 use super::reader::MonoiofsReader;
 use super::writer::MonoiofsWriter;
@@ -142,7 +140,8 @@ impl Access for MonoiofsBackend {
 
     async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let path = self.core.prepare_write_path(path).await?;
-        let writer = MonoiofsWriter::new(self.core.clone(), path, args.append()).await?;
+        let append = args.append() && !args.random_access();
+        let writer = MonoiofsWriter::new(self.core.clone(), path, append).await?;
         Ok((RpWrite::default(), writer))
     }
 
@@ -191,47 +190,10 @@ impl Access for MonoiofsBackend {
             .await
             .map_err(new_std_io_error)?;
         let to = self.core.prepare_write_path(to).await?;
+        // io_uring has no operation to copy files, copy them on a blocking thread instead.
         self.core
-            .dispatch({
-                let core = self.core.clone();
-                move || async move {
-                    let from = OpenOptions::new().read(true).open(from).await?;
-                    let to = OpenOptions::new()
-                        .write(true)
-                        .create(true)
-                        .truncate(true)
-                        .open(to)
-                        .await?;
-
-                    // AsyncReadRent and AsyncWriteRent is not implemented
-                    // for File, so we can't write this:
-                    // monoio::io::copy(&mut from, &mut to).await?;
-
-                    let mut pos = 0;
-                    // allocate and resize buffer
-                    let mut buf = core.buf_pool.get();
-                    // set capacity of buf to exact size to avoid excessive read
-                    buf.reserve(BUFFER_SIZE);
-                    let _ = buf.split_off(BUFFER_SIZE);
-
-                    loop {
-                        let result;
-                        (result, buf) = from.read_at(buf, pos).await;
-                        if result? == 0 {
-                            // EOF
-                            break;
-                        }
-                        let result;
-                        (result, buf) = to.write_all_at(buf, pos).await;
-                        result?;
-                        pos += buf.len() as u64;
-                        buf.clear();
-                    }
-                    core.buf_pool.put(buf);
-                    Ok(())
-                }
-            })
-            .await
+            .dispatch_blocking(move || std_copy_file(&from, &to))
+            .await?
             .map_err(new_std_io_error)?;
         Ok(RpCopy::default())
     }
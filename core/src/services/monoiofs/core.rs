@@ -78,6 +78,7 @@ impl MonoiofsCore {
 
                         write: true,
                         write_can_append: true,
+                        write_can_random_access: true,
 
                         delete: true,
                         rename: true,
@@ -161,6 +162,34 @@ impl MonoiofsCore {
         self.unwrap(rx.await)
     }
 
+    /// Run a blocking function on a dedicated thread and wait for its
+    /// result.
+    ///
+    /// Blocking functions are not sent to the thread pool, since they
+    /// would block all tasks on the monoio runtime of a worker thread.
+    /// An error is returned if the thread can't be spawned or exits
+    /// without result, like when the function panics.
+    pub async fn dispatch_blocking<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce() -> T + 'static + Send,
+        T: 'static + Send,
+    {
+        let (tx, rx) = oneshot::channel();
+        std::thread::Builder::new()
+            .name("monoiofs-blocking".to_string())
+            .spawn(move || {
+                let _ = tx.send(f());
+            })
+            .map_err(new_std_io_error)?;
+        rx.await.map_err(|err| {
+            Error::new(
+                ErrorKind::Unexpected,
+                "blocking thread exited without result",
+            )
+            .set_source(err)
+        })
+    }
+
     /// Create a TaskSpawner, send it to the thread pool and spawn the task.
     pub async fn spawn<F, Fut, T>(&self, f: F)
     where
@@ -310,4 +339,21 @@ mod tests {
         let core = new_core(4);
         dispatch_panic(core).await;
     }
+
+    #[tokio::test]
+    async fn test_monoio_dispatch_blocking() {
+        let core = new_core(1);
+        let result = core.dispatch_blocking(|| 42).await.unwrap();
+        assert_eq!(result, 42);
+    }
+
+    #[tokio::test]
+    async fn test_monoio_dispatch_blocking_panic() {
+        let core = new_core(1);
+        let err = core
+            .dispatch_blocking(|| -> u64 { panic!("BOOM") })
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Unexpected);
+    }
 }
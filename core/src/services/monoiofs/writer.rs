@@ -127,22 +127,22 @@ impl oio::Write for MonoiofsWriter {
     /// Send write request to worker thread and wait for result. Actual
     /// write happens in [`MonoiofsWriter::worker_entrypoint`] running
     /// on worker thread.
-    async fn write(&mut self, mut bs: Buffer) -> Result<()> {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        let n = bs.len();
+        self.write_at(self.pos, bs).await?;
+        self.pos += n as u64;
+        Ok(())
+    }
+
+    async fn write_at(&mut self, mut pos: u64, mut bs: Buffer) -> Result<()> {
         while bs.has_remaining() {
             let buf = bs.current();
             let n = buf.len();
             let (tx, rx) = oneshot::channel();
-            self.core.unwrap(
-                self.tx
-                    .send(WriterRequest::Write {
-                        pos: self.pos,
-                        buf,
-                        tx,
-                    })
-                    .await,
-            );
+            self.core
+                .unwrap(self.tx.send(WriterRequest::Write { pos, buf, tx }).await);
             self.core.unwrap(rx.await)?;
-            self.pos += n as u64;
+            pos += n as u64;
             bs.advance(n);
         }
         Ok(())
@@ -186,8 +186,36 @@ impl Adapter {
     }
 }
 
+impl kv::ScanPage for Adapter {
+    async fn next_page(
+        &self,
+        prefix: &str,
+        start_after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<String>> {
+        let pool = self.get_client().await?;
+
+        // Compare keys as binary, so that keys are ordered the same way as
+        // we do regardless of the collation.
+        sqlx::query_scalar(&format!(
+            r#"SELECT `{key}` FROM `{table}`
+            WHERE `{key}` LIKE ? AND (? IS NULL OR CAST(`{key}` AS BINARY) > CAST(? AS BINARY))
+            ORDER BY CAST(`{key}` AS BINARY) LIMIT ?"#,
+            key = self.key_field,
+            table = self.table,
+        ))
+        .bind(format!("{prefix}%"))
+        .bind(start_after)
+        .bind(start_after)
+        .bind(limit as u64)
+        .fetch_all(pool)
+        .await
+        .map_err(parse_mysql_error)
+    }
+}
+
 impl kv::Adapter for Adapter {
-    type Scanner = ();
+    type Scanner = kv::PagedScanner<Self>;
 
     fn info(&self) -> kv::Info {
         kv::Info::new(
@@ -196,7 +224,9 @@ impl kv::Adapter for Adapter {
             Capability {
                 read: true,
                 write: true,
+                list: true,
                 delete: true,
+                transaction: true,
                 shared: true,
                 ..Default::default()
             },
@@ -249,6 +279,93 @@ impl kv::Adapter for Adapter {
 
         Ok(())
     }
+
+    async fn scan(&self, path: &str, opts: kv::ScanOptions) -> Result<Self::Scanner> {
+        // Connect before cloning, so that the scanner shares the same pool.
+        self.get_client().await?;
+        Ok(kv::PagedScanner::new(self.clone(), path, opts))
+    }
+
+    async fn transaction(
+        &self,
+        checks: Vec<(String, Option<Buffer>)>,
+        mutations: Vec<(String, Option<Buffer>)>,
+    ) -> Result<bool> {
+        let pool = self.get_client().await?;
+        match self.apply(pool, checks, mutations).await {
+            Ok(applied) => Ok(applied),
+            Err(err) if is_serialization_failure(&err) => Ok(false),
+            Err(err) => Err(parse_mysql_error(err)),
+        }
+    }
+}
+
+impl Adapter {
+    /// Check and apply the transaction with the keys checked locked.
+    async fn apply(
+        &self,
+        pool: &MySqlPool,
+        checks: Vec<(String, Option<Buffer>)>,
+        mutations: Vec<(String, Option<Buffer>)>,
+    ) -> std::result::Result<bool, sqlx::Error> {
+        // Transaction is rolled back on drop.
+        let mut tx = pool.begin().await?;
+
+        for (path, expected) in checks {
+            // `FOR UPDATE` locks the row, or the gap if the key doesn't exist,
+            // so that it can't be changed by others before commit.
+            let actual: Option<Vec<u8>> = sqlx::query_scalar(&format!(
+                "SELECT `{}` FROM `{}` WHERE `{}` = ? LIMIT 1 FOR UPDATE",
+                self.value_field, self.table, self.key_field
+            ))
+            .bind(path)
+            .fetch_optional(&mut *tx)
+            .await?;
+            if actual != expected.map(|v| v.to_vec()) {
+                return Ok(false);
+            }
+        }
+
+        for (path, value) in mutations {
+            match value {
+                Some(value) => {
+                    sqlx::query(&format!(
+                        r#"INSERT INTO `{}` (`{}`, `{}`) VALUES (?, ?)
+                    ON DUPLICATE KEY UPDATE `{}` = VALUES({})"#,
+                        self.table,
+                        self.key_field,
+                        self.value_field,
+                        self.value_field,
+                        self.value_field
+                    ))
+                    .bind(path)
+                    .bind(value.to_vec())
+                    .execute(&mut *tx)
+                    .await?
+                }
+                None => {
+                    sqlx::query(&format!(
+                        "DELETE FROM `{}` WHERE `{}` = ?",
+                        self.table, self.key_field
+                    ))
+                    .bind(path)
+                    .execute(&mut *tx)
+                    .await?
+                }
+            };
+        }
+
+        tx.commit().await?;
+        Ok(true)
+    }
+}
+
+/// Check if the error is a deadlock, which means the transaction conflicts
+/// with others.
+fn is_serialization_failure(err: &sqlx::Error) -> bool {
+    err.as_database_error()
+        .and_then(|e| e.code())
+        .is_some_and(|code| code == "40001")
 }
 
 fn parse_mysql_error(err: sqlx::Error) -> Error {
@@ -20,6 +20,7 @@ use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
 
+use bytes::Buf;
 use http::Response;
 use http::StatusCode;
 use http::Uri;
@@ -29,6 +30,11 @@ use reqsign::HuaweicloudObsCredentialLoader;
 use reqsign::HuaweicloudObsSigner;
 
 use super::core::constants;
+use super::core::CompleteMultipartUploadRequestPart;
+use super::core::CopyObjectResult;
+use super::core::CopyPartResult;
+use super::core::InitiateMultipartUploadResult;
+use super::core::ListMultipartUploadsOutput;
 use super::core::ObsCore;
 use super::delete::ObsDeleter;
 use super::error::parse_error;
@@ -249,6 +255,7 @@ impl Builder for ObsBuilder {
                             write_can_empty: true,
                             write_can_append: true,
                             write_can_multi: true,
+                            write_can_random_access: true,
                             write_with_content_type: true,
                             write_with_cache_control: true,
                             // The min multipart size of OBS is 5 MiB.
@@ -267,10 +274,19 @@ impl Builder for ObsBuilder {
 
                             delete: true,
                             copy: true,
+                            copy_with_if_match: true,
+                            copy_with_if_none_match: true,
+
+                            update_metadata: true,
+                            update_metadata_with_if_match: true,
 
                             list: true,
                             list_with_recursive: true,
 
+                            list_uploads: true,
+
+                            abort_upload: true,
+
                             presign: true,
                             presign_stat: true,
                             presign_read: true,
@@ -305,6 +321,120 @@ pub struct ObsBackend {
     core: Arc<ObsCore>,
 }
 
+#[derive(Clone)]
+struct UploadPartCopyInput {
+    core: Arc<ObsCore>,
+    path: Arc<String>,
+    upload_id: Arc<String>,
+    part_number: usize,
+    part: oio::PartCopy,
+}
+
+impl ObsBackend {
+    /// Build the object at `path` by copying `parts` in a multipart upload.
+    async fn copy_parts(
+        &self,
+        path: &str,
+        args: &OpWrite,
+        parts: Vec<oio::PartCopy>,
+        concurrent: usize,
+    ) -> Result<()> {
+        let resp = self
+            .core
+            .obs_initiate_multipart_upload(path, args.content_type(), args.content_encoding())
+            .await?;
+        let upload_id = match resp.status() {
+            StatusCode::OK => {
+                let result: InitiateMultipartUploadResult =
+                    quick_xml::de::from_reader(resp.into_body().reader())
+                        .map_err(new_xml_deserialize_error)?;
+                result.upload_id
+            }
+            _ => return Err(parse_error(resp)),
+        };
+
+        let res = self
+            .copy_parts_inner(path, &upload_id, parts, concurrent)
+            .await;
+        if res.is_err() {
+            // Abort is best effort, the upload will be cleaned by lifecycle rules otherwise.
+            let _ = self.core.obs_abort_multipart_upload(path, &upload_id).await;
+        }
+        res
+    }
+
+    async fn copy_parts_inner(
+        &self,
+        path: &str,
+        upload_id: &str,
+        parts: Vec<oio::PartCopy>,
+        concurrent: usize,
+    ) -> Result<()> {
+        let mut tasks = ConcurrentTasks::new(
+            self.core.info.executor(),
+            concurrent.max(1),
+            concurrent.max(1),
+            |input: UploadPartCopyInput| {
+                Box::pin(async move {
+                    let fut = input.core.obs_upload_part_copy(
+                        &input.path,
+                        &input.upload_id,
+                        input.part_number,
+                        &input.part.source,
+                        input.part.range,
+                        input.part.if_match.as_deref(),
+                    );
+                    match fut.await {
+                        Ok(resp) if resp.status() == StatusCode::OK => {
+                            let result: Result<CopyPartResult> =
+                                quick_xml::de::from_reader(resp.into_body().reader())
+                                    .map_err(new_xml_deserialize_error);
+                            let part = result.map(|v| CompleteMultipartUploadRequestPart {
+                                part_number: input.part_number,
+                                etag: v.etag,
+                            });
+                            (input, part)
+                        }
+                        Ok(resp) => (input, Err(parse_error(resp))),
+                        Err(err) => (input, Err(err)),
+                    }
+                })
+            },
+        );
+
+        let path = Arc::new(path.to_string());
+        let upload_id = Arc::new(upload_id.to_string());
+        let total = parts.len();
+        for (idx, part) in parts.into_iter().enumerate() {
+            tasks
+                .execute(UploadPartCopyInput {
+                    core: self.core.clone(),
+                    path: path.clone(),
+                    upload_id: upload_id.clone(),
+                    // Part number starts from 1.
+                    part_number: idx + 1,
+                    part,
+                })
+                .await?;
+        }
+
+        let mut parts = Vec::with_capacity(total);
+        while let Some(part) = tasks.next().await {
+            parts.push(part?);
+        }
+
+        let resp = self
+            .core
+            .obs_complete_multipart_upload(&path, &upload_id, parts)
+            .await?;
+
+        match resp.status() {
+            StatusCode::OK => Ok(()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+}
+
 impl Access for ObsBackend {
     type Reader = HttpBody;
     type Writer = ObsWriters;
@@ -323,31 +453,7 @@ impl Access for ObsBackend {
 
         // The response is very similar to azblob.
         match status {
-            StatusCode::OK => {
-                let mut meta = parse_into_metadata(path, headers)?;
-                let user_meta = headers
-                    .iter()
-                    .filter_map(|(name, _)| {
-                        name.as_str()
-                            .strip_prefix(constants::X_OBS_META_PREFIX)
-                            .and_then(|stripped_key| {
-                                parse_header_to_str(headers, name)
-                                    .unwrap_or(None)
-                                    .map(|val| (stripped_key.to_string(), val.to_string()))
-                            })
-                    })
-                    .collect::<HashMap<_, _>>();
-
-                if !user_meta.is_empty() {
-                    meta = meta.with_user_metadata(user_meta);
-                }
-
-                if let Some(v) = parse_header_to_str(headers, constants::X_OBS_VERSION_ID)? {
-                    meta.set_version(v);
-                }
-
-                Ok(RpStat::new(meta))
-            }
+            StatusCode::OK => Ok(RpStat::new(parse_head_object_metadata(path, headers)?)),
             StatusCode::NOT_FOUND if path.ends_with('/') => {
                 Ok(RpStat::new(Metadata::new(EntryMode::DIR)))
             }
@@ -378,11 +484,10 @@ impl Access for ObsBackend {
         let w = if args.append() {
             ObsWriters::Two(oio::AppendWriter::new(writer))
         } else {
-            ObsWriters::One(oio::MultipartWriter::new(
-                self.core.info.clone(),
-                writer,
-                args.concurrent(),
-            ))
+            ObsWriters::One(
+                oio::MultipartWriter::new(self.core.info.clone(), writer, args.concurrent())
+                    .with_block_size(args.chunk()),
+            )
         };
 
         Ok((RpWrite::default(), w))
@@ -400,13 +505,142 @@ impl Access for ObsBackend {
         Ok((RpList::default(), oio::PageLister::new(l)))
     }
 
-    async fn copy(&self, from: &str, to: &str, _args: OpCopy) -> Result<RpCopy> {
-        let resp = self.core.obs_copy_object(from, to).await?;
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        // CopyObject can copy at most 5 GiB, larger sources must be copied in parts.
+        //
+        // ref: <https://support.huaweicloud.com/intl/en-us/api-obs/obs_04_0082.html>
+        let max_single_size = 5 * 1024 * 1024 * 1024;
+
+        // Sources over the limit are rejected with `400 Bad Request`.
+        let single_err = if args.chunk().is_none() {
+            let resp = self.core.obs_copy_object(from, to, &args).await?;
+            match resp.status() {
+                StatusCode::OK => return Ok(RpCopy::default()),
+                StatusCode::BAD_REQUEST => Some(parse_error(resp)),
+                _ => return Err(parse_error(resp)),
+            }
+        } else {
+            None
+        };
 
-        let status = resp.status();
+        let stat = oio::CopyPlan::stat_args(&args);
+        let resp = self.core.obs_head_object(from, &stat).await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp));
+        }
+        let meta = parse_head_object_metadata(from, resp.headers())?;
 
-        match status {
-            StatusCode::OK => Ok(RpCopy::default()),
+        // Parts of multipart upload must be at least 5 MiB.
+        match oio::CopyPlan::new(from, &meta, &args, max_single_size, 5 * 1024 * 1024) {
+            oio::CopyPlan::Single => {
+                if let Some(err) = single_err {
+                    return Err(err);
+                }
+                let resp = self.core.obs_copy_object(from, to, &args).await?;
+                match resp.status() {
+                    StatusCode::OK => Ok(RpCopy::default()),
+                    _ => Err(parse_error(resp)),
+                }
+            }
+            oio::CopyPlan::Multipart { args: op, parts } => {
+                self.copy_parts(to, &op, parts, args.concurrent()).await?;
+                Ok(RpCopy::default())
+            }
+        }
+    }
+
+    async fn update_metadata(
+        &self,
+        path: &str,
+        args: OpUpdateMetadata,
+    ) -> Result<RpUpdateMetadata> {
+        let resp = self.core.obs_head_object(path, &OpStat::new()).await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp));
+        }
+        let mut meta = parse_head_object_metadata(path, resp.headers())?;
+        let storage_class = parse_header_to_str(resp.headers(), constants::X_OBS_STORAGE_CLASS)?;
+
+        let update = args.update();
+        if let (Some(expected), Some(actual)) = (update.if_match(), meta.etag()) {
+            if expected != actual {
+                return Err(Error::new(
+                    ErrorKind::ConditionNotMatch,
+                    "etag of the object doesn't match",
+                ));
+            }
+        }
+        update.apply(&mut meta);
+
+        // Copy only if the object is still the one we read, otherwise we may
+        // overwrite a concurrent write with stale content.
+        let if_match = meta.etag().map(|v| v.to_string());
+        let resp = self
+            .core
+            .obs_replace_object_metadata(path, &meta, storage_class, if_match.as_deref())
+            .await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let result: CopyObjectResult =
+                    quick_xml::de::from_reader(resp.into_body().reader())
+                        .map_err(new_xml_deserialize_error)?;
+                meta.set_etag(&result.etag);
+                meta.set_last_modified(parse_datetime_from_rfc3339(&result.last_modified)?);
+                Ok(RpUpdateMetadata::new(meta))
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn list_uploads(&self, path: &str, _: OpListUploads) -> Result<RpListUploads> {
+        let mut uploads = Vec::new();
+        let mut key_marker = String::new();
+        let mut upload_id_marker = String::new();
+
+        loop {
+            let resp = self
+                .core
+                .obs_list_multipart_uploads(path, &key_marker, &upload_id_marker)
+                .await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp));
+            }
+
+            let output: ListMultipartUploadsOutput =
+                quick_xml::de::from_reader(resp.into_body().reader())
+                    .map_err(new_xml_deserialize_error)?;
+
+            for upload in output.upload {
+                let path = build_rel_path(&self.core.root, &upload.key);
+                let mut u = Upload::new(&path, &upload.upload_id);
+                if let Some(initiated) = &upload.initiated {
+                    u = u.with_initiated(parse_datetime_from_rfc3339(initiated)?);
+                }
+                uploads.push(u);
+            }
+
+            if !output.is_truncated.unwrap_or_default() {
+                break;
+            }
+            key_marker = output.next_key_marker.unwrap_or_default();
+            upload_id_marker = output.next_upload_id_marker.unwrap_or_default();
+            if key_marker.is_empty() && upload_id_marker.is_empty() {
+                break;
+            }
+        }
+
+        Ok(RpListUploads::new(uploads))
+    }
+
+    async fn abort_upload(&self, path: &str, args: OpAbortUpload) -> Result<RpAbortUpload> {
+        let resp = self
+            .core
+            .obs_abort_multipart_upload(path, args.upload_id())
+            .await?;
+
+        match resp.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(RpAbortUpload::default()),
             _ => Err(parse_error(resp)),
         }
     }
@@ -422,7 +656,7 @@ impl Access for ObsBackend {
                 self.core
                     .obs_put_object_request(path, None, v, Buffer::new())
             }
-            PresignOperation::Delete(_) => Err(Error::new(
+            PresignOperation::Delete(_) | PresignOperation::UploadPart(_) => Err(Error::new(
                 ErrorKind::Unsupported,
                 "operation is not supported",
             )),
@@ -440,3 +674,30 @@ impl Access for ObsBackend {
         )))
     }
 }
+
+/// Parse metadata from the response headers of `HeadObject`.
+fn parse_head_object_metadata(path: &str, headers: &http::HeaderMap) -> Result<Metadata> {
+    let mut meta = parse_into_metadata(path, headers)?;
+    let user_meta = headers
+        .iter()
+        .filter_map(|(name, _)| {
+            name.as_str()
+                .strip_prefix(constants::X_OBS_META_PREFIX)
+                .and_then(|stripped_key| {
+                    parse_header_to_str(headers, name)
+                        .unwrap_or(None)
+                        .map(|val| (stripped_key.to_string(), val.to_string()))
+                })
+        })
+        .collect::<HashMap<_, _>>();
+
+    if !user_meta.is_empty() {
+        meta = meta.with_user_metadata(user_meta);
+    }
+
+    if let Some(v) = parse_header_to_str(headers, constants::X_OBS_VERSION_ID)? {
+        meta.set_version(v);
+    }
+
+    Ok(meta)
+}
@@ -23,6 +23,7 @@ use std::time::Duration;
 use bytes::Bytes;
 use http::header::CACHE_CONTROL;
 use http::header::CONTENT_DISPOSITION;
+use http::header::CONTENT_ENCODING;
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_TYPE;
 use http::header::IF_MATCH;
@@ -41,6 +42,12 @@ use crate::*;
 pub mod constants {
     pub const X_OBS_META_PREFIX: &str = "x-obs-meta-";
     pub const X_OBS_VERSION_ID: &str = "x-obs-version-id";
+    pub const X_OBS_COPY_SOURCE: &str = "x-obs-copy-source";
+    pub const X_OBS_COPY_SOURCE_IF_MATCH: &str = "x-obs-copy-source-if-match";
+    pub const X_OBS_COPY_SOURCE_IF_NONE_MATCH: &str = "x-obs-copy-source-if-none-match";
+    pub const X_OBS_COPY_SOURCE_RANGE: &str = "x-obs-copy-source-range";
+    pub const X_OBS_METADATA_DIRECTIVE: &str = "x-obs-metadata-directive";
+    pub const X_OBS_STORAGE_CLASS: &str = "x-obs-storage-class";
 }
 
 pub struct ObsCore {
@@ -282,7 +289,12 @@ impl ObsCore {
         Ok(req)
     }
 
-    pub async fn obs_copy_object(&self, from: &str, to: &str) -> Result<Response<Buffer>> {
+    pub async fn obs_copy_object(
+        &self,
+        from: &str,
+        to: &str,
+        args: &OpCopy,
+    ) -> Result<Response<Buffer>> {
         let source = build_abs_path(&self.root, from);
         let target = build_abs_path(&self.root, to);
 
@@ -291,7 +303,67 @@ impl ObsCore {
 
         let mut req = Request::put(&url)
             .extension(Operation::Copy)
-            .header("x-obs-copy-source", &source)
+            .header(constants::X_OBS_COPY_SOURCE, &source);
+        if let Some(v) = args.if_match() {
+            req = req.header(constants::X_OBS_COPY_SOURCE_IF_MATCH, v);
+        }
+        if let Some(v) = args.if_none_match() {
+            req = req.header(constants::X_OBS_COPY_SOURCE_IF_NONE_MATCH, v);
+        }
+
+        let mut req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
+    /// Copy the object onto itself with `REPLACE` metadata directive.
+    ///
+    /// OBS replaces all metadata of the object in this case, so `meta` MUST
+    /// contain all metadata that should be kept.
+    pub async fn obs_replace_object_metadata(
+        &self,
+        path: &str,
+        meta: &Metadata,
+        storage_class: Option<&str>,
+        if_match: Option<&str>,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let source = format!("/{}/{}", self.bucket, percent_encode_path(&p));
+        let url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
+
+        let mut req = Request::put(&url);
+
+        if let Some(v) = meta.content_type() {
+            req = req.header(CONTENT_TYPE, v);
+        }
+        if let Some(v) = meta.content_disposition() {
+            req = req.header(CONTENT_DISPOSITION, v);
+        }
+        if let Some(v) = meta.content_encoding() {
+            req = req.header(CONTENT_ENCODING, v);
+        }
+        if let Some(v) = meta.cache_control() {
+            req = req.header(CACHE_CONTROL, v);
+        }
+        if let Some(user_metadata) = meta.user_metadata() {
+            for (key, value) in user_metadata {
+                req = req.header(format!("{}{}", constants::X_OBS_META_PREFIX, key), value)
+            }
+        }
+        if let Some(v) = storage_class {
+            req = req.header(constants::X_OBS_STORAGE_CLASS, v);
+        }
+        if let Some(v) = if_match {
+            req = req.header(constants::X_OBS_COPY_SOURCE_IF_MATCH, v);
+        }
+
+        let mut req = req
+            .extension(Operation::UpdateMetadata)
+            .header(constants::X_OBS_COPY_SOURCE, &source)
+            .header(constants::X_OBS_METADATA_DIRECTIVE, "REPLACE")
             .body(Buffer::new())
             .map_err(new_request_build_error)?;
 
@@ -336,6 +408,7 @@ impl ObsCore {
         &self,
         path: &str,
         content_type: Option<&str>,
+        content_encoding: Option<&str>,
     ) -> Result<Response<Buffer>> {
         let p = build_abs_path(&self.root, path);
 
@@ -346,6 +419,10 @@ impl ObsCore {
             req = req.header(CONTENT_TYPE, mime)
         }
 
+        if let Some(encoding) = content_encoding {
+            req = req.header(CONTENT_ENCODING, encoding)
+        }
+
         let mut req = req
             .extension(Operation::Write)
             .body(Buffer::new())
@@ -390,6 +467,48 @@ impl ObsCore {
         self.send(req).await
     }
 
+    /// Upload a part by copying the `range` of `source` object.
+    ///
+    /// ref: <https://support.huaweicloud.com/intl/en-us/api-obs/obs_04_0101.html>
+    pub async fn obs_upload_part_copy(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        source: &str,
+        range: Option<BytesRange>,
+        if_match: Option<&str>,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let source = build_abs_path(&self.root, source);
+
+        let url = format!(
+            "{}/{}?partNumber={}&uploadId={}",
+            self.endpoint,
+            percent_encode_path(&p),
+            part_number,
+            percent_encode_path(upload_id)
+        );
+        let source = format!("/{}/{}", self.bucket, percent_encode_path(&source));
+
+        let mut req = Request::put(&url).header(constants::X_OBS_COPY_SOURCE, &source);
+        if let Some(range) = range {
+            req = req.header(constants::X_OBS_COPY_SOURCE_RANGE, range.to_header());
+        }
+        if let Some(v) = if_match {
+            req = req.header(constants::X_OBS_COPY_SOURCE_IF_MATCH, v);
+        }
+
+        let mut req = req
+            .extension(Operation::Copy)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
     pub async fn obs_complete_multipart_upload(
         &self,
         path: &str,
@@ -424,6 +543,39 @@ impl ObsCore {
         self.send(req).await
     }
 
+    /// List in-progress multipart uploads.
+    ///
+    /// ref: <https://support.huaweicloud.com/intl/en-us/api-obs/obs_04_0097.html>
+    pub async fn obs_list_multipart_uploads(
+        &self,
+        prefix: &str,
+        key_marker: &str,
+        upload_id_marker: &str,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, prefix);
+
+        let mut url = QueryPairsWriter::new(&self.endpoint);
+        url = url.push("uploads", "");
+
+        if !p.is_empty() {
+            url = url.push("prefix", &percent_encode_path(&p));
+        }
+        if !key_marker.is_empty() {
+            url = url.push("key-marker", &percent_encode_path(key_marker));
+        }
+        if !upload_id_marker.is_empty() {
+            url = url.push("upload-id-marker", &percent_encode_path(upload_id_marker));
+        }
+
+        let mut req = Request::get(url.finish())
+            .extension(Operation::ListUploads)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     /// Abort an on-going multipart upload.
     pub async fn obs_abort_multipart_upload(
         &self,
@@ -500,6 +652,23 @@ pub struct CompleteMultipartUploadRequestPart {
     pub etag: String,
 }
 
+/// Output of `UploadPartCopy` operation
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct CopyPartResult {
+    #[serde(rename = "ETag")]
+    pub etag: String,
+}
+
+/// Output of `CopyObject` operation
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct CopyObjectResult {
+    #[serde(rename = "ETag")]
+    pub etag: String,
+    pub last_modified: String,
+}
+
 /// Output of `CompleteMultipartUpload` operation
 #[derive(Debug, Default, Deserialize)]
 #[serde[default, rename_all = "PascalCase"]]
@@ -535,6 +704,24 @@ pub struct ListObjectsOutputContent {
     pub size: u64,
 }
 
+/// Output of ListMultipartUploads
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListMultipartUploadsOutput {
+    pub is_truncated: Option<bool>,
+    pub next_key_marker: Option<String>,
+    pub next_upload_id_marker: Option<String>,
+    pub upload: Vec<ListMultipartUploadsOutputUpload>,
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListMultipartUploadsOutputUpload {
+    pub key: String,
+    pub upload_id: String,
+    pub initiated: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use bytes::Buf;
@@ -85,7 +85,11 @@ impl oio::MultipartWrite for ObsWriter {
     async fn initiate_part(&self) -> Result<String> {
         let resp = self
             .core
-            .obs_initiate_multipart_upload(&self.path, self.op.content_type())
+            .obs_initiate_multipart_upload(
+                &self.path,
+                self.op.content_type(),
+                self.op.content_encoding(),
+            )
             .await?;
 
         let status = resp.status();
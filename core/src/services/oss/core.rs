@@ -20,10 +20,17 @@ use std::fmt::Formatter;
 use std::sync::Arc;
 use std::time::Duration;
 
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
 use bytes::Bytes;
+use chrono::SecondsFormat;
+use chrono::Utc;
 use constants::X_OSS_META_PREFIX;
+use hmac::Hmac;
+use hmac::Mac;
 use http::header::CACHE_CONTROL;
 use http::header::CONTENT_DISPOSITION;
+use http::header::CONTENT_ENCODING;
 use http::header::CONTENT_LENGTH;
 use http::header::CONTENT_TYPE;
 use http::header::IF_MATCH;
@@ -41,6 +48,8 @@ use reqsign::AliyunLoader;
 use reqsign::AliyunOssSigner;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::json;
+use sha1::Sha1;
 
 use crate::raw::*;
 use crate::services::oss::core::constants::X_OSS_FORBID_OVERWRITE;
@@ -60,6 +69,16 @@ pub mod constants {
     pub const OSS_QUERY_VERSION_ID: &str = "versionId";
 
     pub const X_OSS_META_PREFIX: &str = "x-oss-meta-";
+
+    pub const X_OSS_COPY_SOURCE: &str = "x-oss-copy-source";
+
+    pub const X_OSS_COPY_SOURCE_IF_MATCH: &str = "x-oss-copy-source-if-match";
+    pub const X_OSS_COPY_SOURCE_IF_NONE_MATCH: &str = "x-oss-copy-source-if-none-match";
+    pub const X_OSS_COPY_SOURCE_RANGE: &str = "x-oss-copy-source-range";
+
+    pub const X_OSS_METADATA_DIRECTIVE: &str = "x-oss-metadata-directive";
+
+    pub const X_OSS_STORAGE_CLASS: &str = "x-oss-storage-class";
 }
 
 pub struct OssCore {
@@ -495,7 +514,12 @@ impl OssCore {
         self.send(req).await
     }
 
-    pub async fn oss_copy_object(&self, from: &str, to: &str) -> Result<Response<Buffer>> {
+    pub async fn oss_copy_object(
+        &self,
+        from: &str,
+        to: &str,
+        args: &OpCopy,
+    ) -> Result<Response<Buffer>> {
         let source = build_abs_path(&self.root, from);
         let target = build_abs_path(&self.root, to);
 
@@ -510,7 +534,14 @@ impl OssCore {
 
         req = self.insert_sse_headers(req);
 
-        req = req.header("x-oss-copy-source", source);
+        req = req.header(constants::X_OSS_COPY_SOURCE, source);
+
+        if let Some(v) = args.if_match() {
+            req = req.header(constants::X_OSS_COPY_SOURCE_IF_MATCH, v);
+        }
+        if let Some(v) = args.if_none_match() {
+            req = req.header(constants::X_OSS_COPY_SOURCE_IF_NONE_MATCH, v);
+        }
 
         let req = req.extension(Operation::Copy);
 
@@ -520,6 +551,68 @@ impl OssCore {
         self.send(req).await
     }
 
+    /// Copy the object onto itself with `REPLACE` metadata directive.
+    ///
+    /// OSS replaces all metadata of the object in this case, so `meta` MUST
+    /// contain all metadata that should be kept.
+    pub async fn oss_replace_object_metadata(
+        &self,
+        path: &str,
+        meta: &Metadata,
+        storage_class: Option<&str>,
+        if_match: Option<&str>,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+
+        let url = format!("{}/{}", self.get_endpoint(false), percent_encode_path(&p));
+        let source = format!("/{}/{}", self.bucket, percent_encode_path(&p));
+
+        let mut req = Request::put(&url);
+
+        req = self.insert_sse_headers(req);
+
+        if let Some(v) = meta.content_type() {
+            req = req.header(CONTENT_TYPE, v);
+        }
+        if let Some(v) = meta.content_disposition() {
+            req = req.header(CONTENT_DISPOSITION, v);
+        }
+        if let Some(v) = meta.content_encoding() {
+            req = req.header(CONTENT_ENCODING, v);
+        }
+        if let Some(v) = meta.cache_control() {
+            req = req.header(CACHE_CONTROL, v);
+        }
+        if let Some(user_metadata) = meta.user_metadata() {
+            for (key, value) in user_metadata {
+                if !self.check_user_metadata_key(key) {
+                    return Err(Error::new(
+                        ErrorKind::Unsupported,
+                        "the format of the user metadata key is invalid, please refer the document",
+                    ));
+                }
+                req = req.header(format!("{X_OSS_META_PREFIX}{key}"), value)
+            }
+        }
+        if let Some(v) = storage_class {
+            req = req.header(constants::X_OSS_STORAGE_CLASS, v);
+        }
+        if let Some(v) = if_match {
+            req = req.header(constants::X_OSS_COPY_SOURCE_IF_MATCH, v);
+        }
+
+        req = req
+            .header(constants::X_OSS_COPY_SOURCE, source)
+            .header(constants::X_OSS_METADATA_DIRECTIVE, "REPLACE");
+
+        let req = req.extension(Operation::UpdateMetadata);
+
+        let mut req = req.body(Buffer::new()).map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     pub async fn oss_list_object(
         &self,
         path: &str,
@@ -631,6 +724,7 @@ impl OssCore {
         content_type: Option<&str>,
         content_disposition: Option<&str>,
         cache_control: Option<&str>,
+        content_encoding: Option<&str>,
         is_presign: bool,
     ) -> Result<Response<Buffer>> {
         let path = build_abs_path(&self.root, path);
@@ -646,6 +740,9 @@ impl OssCore {
         if let Some(cache_control) = cache_control {
             req = req.header(CACHE_CONTROL, cache_control);
         }
+        if let Some(encoding) = content_encoding {
+            req = req.header(CONTENT_ENCODING, encoding);
+        }
         req = self.insert_sse_headers(req);
 
         let req = req.extension(Operation::Write);
@@ -686,6 +783,49 @@ impl OssCore {
         self.send(req).await
     }
 
+    /// Upload a part by copying the whole `source` object.
+    ///
+    /// ref: <https://www.alibabacloud.com/help/en/oss/developer-reference/uploadpartcopy>
+    #[allow(clippy::too_many_arguments)]
+    pub async fn oss_upload_part_copy(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        source: &str,
+        range: Option<BytesRange>,
+        if_match: Option<&str>,
+        op: Operation,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let source = build_abs_path(&self.root, source);
+
+        let url = format!(
+            "{}/{}?partNumber={}&uploadId={}",
+            self.endpoint,
+            percent_encode_path(&p),
+            part_number,
+            percent_encode_path(upload_id)
+        );
+        let source = format!("/{}/{}", self.bucket, percent_encode_path(&source));
+
+        let mut req = Request::put(&url).header(constants::X_OSS_COPY_SOURCE, source);
+        if let Some(range) = range {
+            req = req.header(constants::X_OSS_COPY_SOURCE_RANGE, range.to_header());
+        }
+        if let Some(v) = if_match {
+            req = req.header(constants::X_OSS_COPY_SOURCE_IF_MATCH, v);
+        }
+
+        let mut req = req
+            .extension(op)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     pub async fn oss_complete_multipart_upload_request(
         &self,
         path: &str,
@@ -723,6 +863,103 @@ impl OssCore {
         self.send(req).await
     }
 
+    /// Build a presigned PostObject form.
+    ///
+    /// ref: <https://www.alibabacloud.com/help/en/oss/developer-reference/postobject>
+    pub async fn oss_presign_post(
+        &self,
+        path: &str,
+        args: &OpPresignPost,
+    ) -> Result<PresignedPost> {
+        let Some(cred) = self.load_credential().await? else {
+            return Err(Error::new(
+                ErrorKind::PermissionDenied,
+                "presign post requires credential but anonymous access is used",
+            ));
+        };
+
+        let p = build_abs_path(&self.root, path);
+        let expiration = Utc::now()
+            + chrono::Duration::from_std(args.expire()).map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "expire is out of range").set_source(err)
+            })?;
+
+        let mut fields = Vec::new();
+        let mut conditions = vec![json!({ "bucket": self.bucket })];
+        if p.is_empty() || p.ends_with('/') {
+            conditions.push(json!(["starts-with", "$key", p]));
+            fields.push(("key".to_string(), format!("{p}${{filename}}")));
+        } else {
+            conditions.push(json!({ "key": p }));
+            fields.push(("key".to_string(), p));
+        }
+        if let Some((min, max)) = args.content_length_range() {
+            conditions.push(json!(["content-length-range", min, max]));
+        }
+        if let Some(prefix) = args.content_type_prefix() {
+            conditions.push(json!(["starts-with", "$Content-Type", prefix]));
+        }
+        if let Some(token) = &cred.security_token {
+            conditions.push(json!({ "x-oss-security-token": token }));
+            fields.push(("x-oss-security-token".to_string(), token.clone()));
+        }
+
+        let policy = json!({
+            "expiration": expiration.to_rfc3339_opts(SecondsFormat::Millis, true),
+            "conditions": conditions,
+        });
+        let policy = BASE64_STANDARD.encode(policy.to_string());
+
+        let mut h = Hmac::<Sha1>::new_from_slice(cred.access_key_secret.as_bytes())
+            .expect("hmac accepts key of any size");
+        h.update(policy.as_bytes());
+        let signature = BASE64_STANDARD.encode(h.finalize().into_bytes());
+
+        fields.push(("OSSAccessKeyId".to_string(), cred.access_key_id));
+        fields.push(("policy".to_string(), policy));
+        fields.push(("Signature".to_string(), signature));
+
+        let uri = format!("{}/", self.get_endpoint(true))
+            .parse()
+            .map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "endpoint is not a valid uri").set_source(err)
+            })?;
+        Ok(PresignedPost::new(uri, fields))
+    }
+
+    /// List in-progress multipart uploads.
+    ///
+    /// ref: <https://www.alibabacloud.com/help/en/oss/developer-reference/listmultipartuploads>
+    pub async fn oss_list_multipart_uploads(
+        &self,
+        prefix: &str,
+        key_marker: &str,
+        upload_id_marker: &str,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, prefix);
+
+        let mut url = QueryPairsWriter::new(&self.endpoint);
+        url = url.push("uploads", "");
+
+        if !p.is_empty() {
+            url = url.push("prefix", &percent_encode_path(&p));
+        }
+        if !key_marker.is_empty() {
+            url = url.push("key-marker", &percent_encode_path(key_marker));
+        }
+        if !upload_id_marker.is_empty() {
+            url = url.push("upload-id-marker", &percent_encode_path(upload_id_marker));
+        }
+
+        let mut req = Request::get(url.finish())
+            .extension(Operation::ListUploads)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+        self.send(req).await
+    }
+
     /// Abort an ongoing multipart upload.
     /// reference docs https://www.alibabacloud.com/help/zh/oss/developer-reference/abortmultipartupload
     pub async fn oss_abort_multipart_upload(
@@ -748,6 +985,23 @@ impl OssCore {
     }
 }
 
+/// Output of `UploadPartCopy` operation
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct CopyPartResult {
+    #[serde(rename = "ETag")]
+    pub etag: String,
+}
+
+/// Output of `CopyObject` operation
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct CopyObjectResult {
+    #[serde(rename = "ETag")]
+    pub etag: String,
+    pub last_modified: String,
+}
+
 /// Request of DeleteObjects.
 #[derive(Default, Debug, Serialize)]
 #[serde(default, rename = "Delete", rename_all = "PascalCase")]
@@ -879,6 +1133,24 @@ pub struct ListObjectVersionsOutputDeleteMarker {
     pub last_modified: String,
 }
 
+/// Output of ListMultipartUploads
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListMultipartUploadsOutput {
+    pub is_truncated: Option<bool>,
+    pub next_key_marker: Option<String>,
+    pub next_upload_id_marker: Option<String>,
+    pub upload: Vec<ListMultipartUploadsOutputUpload>,
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListMultipartUploadsOutputUpload {
+    pub key: String,
+    pub upload_id: String,
+    pub initiated: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use bytes::Buf;
@@ -17,6 +17,7 @@
 
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::ops::Bound;
 use std::str;
 
 use persy;
@@ -149,8 +150,35 @@ impl Debug for Adapter {
     }
 }
 
+impl kv::ScanPage for Adapter {
+    async fn next_page(
+        &self,
+        prefix: &str,
+        start_after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<String>> {
+        let start = match start_after {
+            Some(key) => Bound::Excluded(key.to_string()),
+            None => Bound::Included(prefix.to_string()),
+        };
+        let it = self
+            .persy
+            .range::<String, persy::PersyId, _>(&self.index, (start, Bound::Unbounded))
+            .map_err(parse_error)?;
+
+        let mut keys = Vec::with_capacity(limit);
+        for (key, _) in it {
+            if !key.starts_with(prefix) || keys.len() >= limit {
+                break;
+            }
+            keys.push(key);
+        }
+        Ok(keys)
+    }
+}
+
 impl kv::Adapter for Adapter {
-    type Scanner = ();
+    type Scanner = kv::PagedScanner<Self>;
 
     fn info(&self) -> kv::Info {
         kv::Info::new(
@@ -159,6 +187,7 @@ impl kv::Adapter for Adapter {
             Capability {
                 read: true,
                 write: true,
+                list: true,
                 delete: true,
                 shared: false,
                 ..Default::default()
@@ -213,6 +242,10 @@ impl kv::Adapter for Adapter {
 
         Ok(())
     }
+
+    async fn scan(&self, path: &str, opts: kv::ScanOptions) -> Result<Self::Scanner> {
+        Ok(kv::PagedScanner::new(self.clone(), path, opts))
+    }
 }
 
 fn parse_error<T: Into<persy::PersyError>>(err: persy::PE<T>) -> Error {
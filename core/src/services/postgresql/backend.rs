@@ -185,8 +185,36 @@ impl Adapter {
     }
 }
 
+impl kv::ScanPage for Adapter {
+    async fn next_page(
+        &self,
+        prefix: &str,
+        start_after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<String>> {
+        let pool = self.get_client().await?;
+
+        // Compare keys with "C" collation, so that keys are ordered by bytes the
+        // same way as we do.
+        let table = &self.table;
+        let key_field = &self.key_field;
+        sqlx::query_scalar(&format!(
+            r#"SELECT "{key_field}" FROM "{table}"
+                WHERE "{key_field}" LIKE $1
+                    AND ($2::text IS NULL OR "{key_field}" COLLATE "C" > $2)
+                ORDER BY "{key_field}" COLLATE "C" LIMIT $3"#,
+        ))
+        .bind(format!("{prefix}%"))
+        .bind(start_after)
+        .bind(limit as i64)
+        .fetch_all(pool)
+        .await
+        .map_err(parse_postgres_error)
+    }
+}
+
 impl kv::Adapter for Adapter {
-    type Scanner = ();
+    type Scanner = kv::PagedScanner<Self>;
 
     fn info(&self) -> kv::Info {
         kv::Info::new(
@@ -195,6 +223,8 @@ impl kv::Adapter for Adapter {
             Capability {
                 read: true,
                 write: true,
+                list: true,
+                transaction: true,
                 shared: true,
                 ..Default::default()
             },
@@ -251,6 +281,95 @@ impl kv::Adapter for Adapter {
 
         Ok(())
     }
+
+    async fn scan(&self, path: &str, opts: kv::ScanOptions) -> Result<Self::Scanner> {
+        // Connect before cloning, so that the scanner shares the same pool.
+        self.get_client().await?;
+        Ok(kv::PagedScanner::new(self.clone(), path, opts))
+    }
+
+    async fn transaction(
+        &self,
+        checks: Vec<(String, Option<Buffer>)>,
+        mutations: Vec<(String, Option<Buffer>)>,
+    ) -> Result<bool> {
+        let pool = self.get_client().await?;
+        match self.apply(pool, checks, mutations).await {
+            Ok(applied) => Ok(applied),
+            Err(err) if is_serialization_failure(&err) => Ok(false),
+            Err(err) => Err(parse_postgres_error(err)),
+        }
+    }
+}
+
+impl Adapter {
+    /// Check and apply the transaction in a serializable transaction.
+    async fn apply(
+        &self,
+        pool: &PgPool,
+        checks: Vec<(String, Option<Buffer>)>,
+        mutations: Vec<(String, Option<Buffer>)>,
+    ) -> std::result::Result<bool, sqlx::Error> {
+        let table = &self.table;
+        let key_field = &self.key_field;
+        let value_field = &self.value_field;
+
+        // Transaction is rolled back on drop.
+        let mut tx = pool.begin().await?;
+        // Serializable isolation makes the commit fail if keys checked, including
+        // the not existing ones, are changed by others.
+        sqlx::query("SET TRANSACTION ISOLATION LEVEL SERIALIZABLE")
+            .execute(&mut *tx)
+            .await?;
+
+        for (path, expected) in checks {
+            let actual: Option<Vec<u8>> = sqlx::query_scalar(&format!(
+                r#"SELECT "{value_field}" FROM "{table}" WHERE "{key_field}" = $1 LIMIT 1"#,
+            ))
+            .bind(path)
+            .fetch_optional(&mut *tx)
+            .await?;
+            if actual != expected.map(|v| v.to_vec()) {
+                return Ok(false);
+            }
+        }
+
+        for (path, value) in mutations {
+            match value {
+                Some(value) => {
+                    sqlx::query(&format!(
+                        r#"INSERT INTO "{table}" ("{key_field}", "{value_field}")
+                        VALUES ($1, $2)
+                        ON CONFLICT ("{key_field}")
+                            DO UPDATE SET "{value_field}" = EXCLUDED."{value_field}""#,
+                    ))
+                    .bind(path)
+                    .bind(value.to_vec())
+                    .execute(&mut *tx)
+                    .await?
+                }
+                None => {
+                    sqlx::query(&format!(
+                        r#"DELETE FROM "{table}" WHERE "{key_field}" = $1"#,
+                    ))
+                    .bind(path)
+                    .execute(&mut *tx)
+                    .await?
+                }
+            };
+        }
+
+        tx.commit().await?;
+        Ok(true)
+    }
+}
+
+/// Check if the error is `serialization_failure`, which means the transaction
+/// conflicts with others.
+fn is_serialization_failure(err: &sqlx::Error) -> bool {
+    err.as_database_error()
+        .and_then(|e| e.code())
+        .is_some_and(|code| code == "40001")
 }
 
 fn parse_postgres_error(err: sqlx::Error) -> Error {
@@ -17,8 +17,11 @@
 
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::ops::Bound;
 use std::sync::Arc;
 
+use redb::ReadableTable;
+
 use crate::raw::adapters::kv;
 use crate::raw::*;
 use crate::services::RedbConfig;
@@ -94,6 +97,14 @@ impl RedbBuilder {
         self.config.root = Some(path.into());
         self
     }
+
+    /// Store metadata like content type, etag and last modified with values.
+    ///
+    /// Values written before are still readable, they just don't have metadata.
+    pub fn enable_metadata(mut self, enabled: bool) -> Self {
+        self.config.enable_metadata = enabled;
+        self
+    }
 }
 
 impl Builder for RedbBuilder {
@@ -122,12 +133,18 @@ impl Builder for RedbBuilder {
 
         create_table(&db, &table_name)?;
 
-        Ok(RedbBackend::new(Adapter {
+        let backend = RedbBackend::new(Adapter {
             datadir,
             table: table_name,
             db,
         })
-        .with_root(self.config.root.as_deref().unwrap_or_default()))
+        .with_root(self.config.root.as_deref().unwrap_or_default());
+
+        Ok(if self.config.enable_metadata {
+            backend.with_metadata()
+        } else {
+            backend
+        })
     }
 }
 
@@ -149,8 +166,44 @@ impl Debug for Adapter {
     }
 }
 
+impl kv::ScanPage for Adapter {
+    async fn next_page(
+        &self,
+        prefix: &str,
+        start_after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<String>> {
+        let read_txn = self.db.begin_read().map_err(parse_transaction_error)?;
+
+        let table_define: redb::TableDefinition<&str, &[u8]> =
+            redb::TableDefinition::new(&self.table);
+
+        let table = read_txn
+            .open_table(table_define)
+            .map_err(parse_table_error)?;
+
+        let start = match start_after {
+            Some(key) => Bound::Excluded(key),
+            None => Bound::Included(prefix),
+        };
+        let mut keys = Vec::with_capacity(limit);
+        for item in table
+            .range::<&str>((start, Bound::Unbounded))
+            .map_err(parse_storage_error)?
+        {
+            let (key, _) = item.map_err(parse_storage_error)?;
+            let key = key.value();
+            if !key.starts_with(prefix) || keys.len() >= limit {
+                break;
+            }
+            keys.push(key.to_string());
+        }
+        Ok(keys)
+    }
+}
+
 impl kv::Adapter for Adapter {
-    type Scanner = ();
+    type Scanner = kv::PagedScanner<Self>;
 
     fn info(&self) -> kv::Info {
         kv::Info::new(
@@ -159,6 +212,8 @@ impl kv::Adapter for Adapter {
             Capability {
                 read: true,
                 write: true,
+                list: true,
+                transaction: true,
                 shared: false,
                 ..Default::default()
             },
@@ -220,6 +275,55 @@ impl kv::Adapter for Adapter {
         write_txn.commit().map_err(parse_commit_error)?;
         Ok(())
     }
+
+    async fn scan(&self, path: &str, opts: kv::ScanOptions) -> Result<Self::Scanner> {
+        Ok(kv::PagedScanner::new(self.clone(), path, opts))
+    }
+
+    async fn transaction(
+        &self,
+        checks: Vec<(String, Option<Buffer>)>,
+        mutations: Vec<(String, Option<Buffer>)>,
+    ) -> Result<bool> {
+        // Only one `WriteTransaction` is permitted at same time, so checks
+        // within it can't be changed by others before commit.
+        let write_txn = self.db.begin_write().map_err(parse_transaction_error)?;
+
+        let table_define: redb::TableDefinition<&str, &[u8]> =
+            redb::TableDefinition::new(&self.table);
+
+        {
+            let mut table = write_txn
+                .open_table(table_define)
+                .map_err(parse_table_error)?;
+
+            for (path, expected) in checks {
+                let actual = table
+                    .get(path.as_str())
+                    .map_err(parse_storage_error)?
+                    .map(|v| v.value().to_vec());
+                if actual != expected.map(|v| v.to_vec()) {
+                    // Dropping the transaction aborts it.
+                    return Ok(false);
+                }
+            }
+            for (path, value) in mutations {
+                match value {
+                    Some(value) => table
+                        .insert(path.as_str(), &*value.to_vec())
+                        .map(|_| ())
+                        .map_err(parse_storage_error)?,
+                    None => table
+                        .remove(path.as_str())
+                        .map(|_| ())
+                        .map_err(parse_storage_error)?,
+                }
+            }
+        }
+
+        write_txn.commit().map_err(parse_commit_error)?;
+        Ok(true)
+    }
 }
 
 fn parse_transaction_error(e: redb::TransactionError) -> Error {
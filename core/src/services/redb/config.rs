@@ -31,4 +31,6 @@ pub struct RedbConfig {
     pub root: Option<String>,
     /// The table name for redb.
     pub table: Option<String>,
+    /// Store metadata like content type, etag and last modified with values.
+    pub enable_metadata: bool,
 }
@@ -33,6 +33,7 @@ use super::core::*;
 use super::delete::RedisDeleter;
 use super::writer::RedisWriter;
 use super::DEFAULT_SCHEME;
+use crate::raw::adapters::kv;
 use crate::raw::oio;
 use crate::raw::*;
 use crate::services::RedisConfig;
@@ -123,7 +124,7 @@ impl RedisBuilder {
 
     /// Set the default ttl for redis services.
     ///
-    /// If set, we will specify `EX` for write operations.
+    /// If set, we will specify `PX` for write operations without their own ttl.
     pub fn default_ttl(mut self, ttl: Duration) -> Self {
         self.config.default_ttl = Some(ttl);
         self
@@ -141,6 +142,14 @@ impl RedisBuilder {
 
         self
     }
+
+    /// Store metadata like content type, etag and last modified with values.
+    ///
+    /// Values written before are still readable, they just don't have metadata.
+    pub fn enable_metadata(mut self, enabled: bool) -> Self {
+        self.config.enable_metadata = enabled;
+        self
+    }
 }
 
 impl Builder for RedisBuilder {
@@ -155,7 +164,7 @@ impl Builder for RedisBuilder {
                 .as_str(),
         );
 
-        if let Some(endpoints) = self.config.cluster_endpoints.clone() {
+        let accessor = if let Some(endpoints) = self.config.cluster_endpoints.clone() {
             let mut cluster_endpoints: Vec<ConnectionInfo> = Vec::default();
             for endpoint in endpoints.split(',') {
                 cluster_endpoints.push(self.get_connection_info(endpoint.to_string())?);
@@ -171,14 +180,14 @@ impl Builder for RedisBuilder {
 
             let conn = OnceCell::new();
 
-            Ok(RedisAccessor::new(RedisCore {
+            RedisAccessor::new(RedisCore {
                 addr: endpoints,
                 client: None,
                 cluster_client: Some(client),
                 conn,
                 default_ttl: self.config.default_ttl,
             })
-            .with_normalized_root(root))
+            .with_normalized_root(root)
         } else {
             let endpoint = self
                 .config
@@ -196,15 +205,21 @@ impl Builder for RedisBuilder {
                 })?;
 
             let conn = OnceCell::new();
-            Ok(RedisAccessor::new(RedisCore {
+            RedisAccessor::new(RedisCore {
                 addr: endpoint,
                 client: Some(client),
                 cluster_client: None,
                 conn,
                 default_ttl: self.config.default_ttl,
             })
-            .with_normalized_root(root))
-        }
+            .with_normalized_root(root)
+        };
+
+        Ok(if self.config.enable_metadata {
+            accessor.with_metadata()
+        } else {
+            accessor
+        })
     }
 }
 
@@ -272,6 +287,7 @@ pub struct RedisAccessor {
     core: std::sync::Arc<RedisCore>,
     root: String,
     info: std::sync::Arc<AccessorInfo>,
+    metadata: bool,
 }
 
 impl RedisAccessor {
@@ -286,6 +302,8 @@ impl RedisAccessor {
             delete: true,
             stat: true,
             write_can_empty: true,
+            write_with_ttl: true,
+            lock: true,
             shared: true,
             ..Default::default()
         });
@@ -294,6 +312,7 @@ impl RedisAccessor {
             core: std::sync::Arc::new(core),
             root: "/".to_string(),
             info: std::sync::Arc::new(info),
+            metadata: false,
         }
     }
 
@@ -302,6 +321,32 @@ impl RedisAccessor {
         self.root = root;
         self
     }
+
+    /// Store metadata in front of values, see [`kv::Backend::with_metadata`].
+    fn with_metadata(mut self) -> Self {
+        let mut cap = self.info.native_capability();
+        cap.write_with_content_type = true;
+        cap.write_with_content_disposition = true;
+        cap.write_with_content_encoding = true;
+        cap.write_with_cache_control = true;
+        cap.write_with_user_metadata = true;
+        cap.write_with_if_match = true;
+        cap.write_with_if_none_match = true;
+        cap.write_with_if_not_exists = true;
+
+        cap.stat_with_if_match = true;
+        cap.stat_with_if_none_match = true;
+        cap.stat_with_if_modified_since = true;
+        cap.stat_with_if_unmodified_since = true;
+        cap.read_with_if_match = true;
+        cap.read_with_if_none_match = true;
+        cap.read_with_if_modified_since = true;
+        cap.read_with_if_unmodified_since = true;
+        self.info.set_native_capability(cap);
+
+        self.metadata = true;
+        self
+    }
 }
 
 impl Access for RedisAccessor {
@@ -314,7 +359,7 @@ impl Access for RedisAccessor {
         self.info.clone()
     }
 
-    async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         let p = build_abs_path(&self.root, path);
 
         if p == build_abs_path(&self.root, "") {
@@ -322,9 +367,30 @@ impl Access for RedisAccessor {
         } else {
             let bs = self.core.get(&p).await?;
             match bs {
-                Some(bs) => Ok(RpStat::new(
-                    Metadata::new(EntryMode::FILE).with_content_length(bs.len() as u64),
-                )),
+                Some(bs) if self.metadata => {
+                    let (value_meta, bs) = kv::ValueMetadata::split(bs)?;
+                    kv::check_conditions(
+                        value_meta.as_ref(),
+                        args.if_match(),
+                        args.if_none_match(),
+                        args.if_modified_since(),
+                        args.if_unmodified_since(),
+                    )?;
+
+                    let meta = Metadata::new(EntryMode::FILE).with_content_length(bs.len() as u64);
+                    Ok(RpStat::new(match value_meta {
+                        Some(value_meta) => value_meta.apply(meta),
+                        None => meta,
+                    }))
+                }
+                Some(bs) => {
+                    let mut meta =
+                        Metadata::new(EntryMode::FILE).with_content_length(bs.len() as u64);
+                    if let Some(ttl) = self.core.ttl(&p).await? {
+                        meta.set_expires_at(expire_time_from_ttl(ttl));
+                    }
+                    Ok(RpStat::new(meta))
+                }
                 None => Err(Error::new(ErrorKind::NotFound, "key not found in redis")),
             }
         }
@@ -334,7 +400,22 @@ impl Access for RedisAccessor {
         let p = build_abs_path(&self.root, path);
 
         let range = args.range();
-        let buffer = if range.is_full() {
+        let buffer = if self.metadata {
+            // The length of metadata is unknown, read the whole value instead.
+            let bs = match self.core.get(&p).await? {
+                Some(bs) => bs,
+                None => return Err(Error::new(ErrorKind::NotFound, "key not found in redis")),
+            };
+            let (value_meta, bs) = kv::ValueMetadata::split(bs)?;
+            kv::check_conditions(
+                value_meta.as_ref(),
+                args.if_match(),
+                args.if_none_match(),
+                args.if_modified_since(),
+                args.if_unmodified_since(),
+            )?;
+            bs.slice(range.to_range_as_usize())
+        } else if range.is_full() {
             // Full read - use GET
             match self.core.get(&p).await? {
                 Some(bs) => bs,
@@ -357,9 +438,12 @@ impl Access for RedisAccessor {
         Ok((RpRead::new(), buffer))
     }
 
-    async fn write(&self, path: &str, _: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
         let p = build_abs_path(&self.root, path);
-        Ok((RpWrite::new(), RedisWriter::new(self.core.clone(), p)))
+        Ok((
+            RpWrite::new(),
+            RedisWriter::new(self.core.clone(), p, args, self.metadata),
+        ))
     }
 
     async fn delete(&self) -> Result<(RpDelete, Self::Deleter)> {
@@ -374,6 +458,21 @@ impl Access for RedisAccessor {
         // Redis doesn't support listing keys, return empty list
         Ok((RpList::default(), ()))
     }
+
+    async fn lock(&self, path: &str, args: OpLock) -> Result<RpLock> {
+        let p = build_abs_path(&self.root, path);
+        let token = self
+            .core
+            .lock(&p, args.ttl(), args.owner(), args.token())
+            .await?;
+        Ok(RpLock::new(token))
+    }
+
+    async fn unlock(&self, path: &str, args: OpUnlock) -> Result<RpUnlock> {
+        let p = build_abs_path(&self.root, path);
+        self.core.unlock(&p, args.owner(), args.token()).await?;
+        Ok(RpUnlock::default())
+    }
 }
 
 #[cfg(test)]
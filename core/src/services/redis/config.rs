@@ -53,6 +53,8 @@ pub struct RedisConfig {
     pub db: i64,
     /// The default ttl for put operations.
     pub default_ttl: Option<Duration>,
+    /// Store metadata like content type, etag and last modified with values.
+    pub enable_metadata: bool,
 }
 
 impl Debug for RedisConfig {
@@ -61,6 +63,7 @@ impl Debug for RedisConfig {
 
         d.field("db", &self.db.to_string());
         d.field("root", &self.root);
+        d.field("enable_metadata", &self.enable_metadata);
         if let Some(endpoint) = self.endpoint.clone() {
             d.field("endpoint", &endpoint);
         }
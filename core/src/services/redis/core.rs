@@ -181,12 +181,13 @@ impl RedisCore {
         Ok(result.map(Buffer::from))
     }
 
-    pub async fn set(&self, key: &str, value: Buffer) -> Result<()> {
+    /// Set `key` to `value`, expires after `ttl` or the default ttl if given.
+    pub async fn set(&self, key: &str, value: Buffer, ttl: Option<Duration>) -> Result<()> {
         let mut conn = self.conn().await?;
         let value = value.to_vec();
-        if let Some(dur) = self.default_ttl {
+        if let Some(dur) = ttl.or(self.default_ttl) {
             let _: () = conn
-                .set_ex(key, value, dur.as_secs())
+                .pset_ex(key, value, dur.as_millis().max(1) as u64)
                 .await
                 .map_err(format_redis_error)?;
         } else {
@@ -195,13 +196,148 @@ impl RedisCore {
         Ok(())
     }
 
+    /// Get the remaining ttl of `key`, returns `None` if it doesn't expire or exist.
+    pub async fn ttl(&self, key: &str) -> Result<Option<Duration>> {
+        let mut conn = self.conn().await?;
+        let ttl: i64 = conn.pttl(key).await.map_err(format_redis_error)?;
+        Ok((ttl >= 0).then(|| Duration::from_millis(ttl as u64)))
+    }
+
     pub async fn delete(&self, key: &str) -> Result<()> {
         let mut conn = self.conn().await?;
         let _: () = conn.del(key).await.map_err(format_redis_error)?;
         Ok(())
     }
+
+    /// Set `key` to `value` only if its current value is still `current`.
+    ///
+    /// `current` is `None` means the key must not exist, returns `false` if the
+    /// current value doesn't match. The new value expires like [`RedisCore::set`].
+    pub async fn compare_and_swap(
+        &self,
+        key: &str,
+        current: Option<Buffer>,
+        value: Buffer,
+        ttl: Option<Duration>,
+    ) -> Result<bool> {
+        let mut conn = self.conn().await?;
+        let ttl = ttl
+            .or(self.default_ttl)
+            .map(|v| v.as_millis().max(1) as u64)
+            .unwrap_or(0);
+        let swapped: u64 = redis::Script::new(CAS_SCRIPT)
+            .key(key)
+            .arg(current.is_some() as u8)
+            .arg(current.map(|v| v.to_vec()).unwrap_or_default())
+            .arg(value.to_vec())
+            .arg(ttl)
+            .invoke_async(&mut *conn)
+            .await
+            .map_err(format_redis_error)?;
+
+        Ok(swapped == 1)
+    }
+
+    /// Acquire or renew the lock at `key` with `SET NX PX`, returns the fencing token.
+    ///
+    /// Tokens are allocated from a counter stored next to the lock key, the counter
+    /// uses `key` as hash tag so that both keys live in the same cluster slot.
+    pub async fn lock(
+        &self,
+        key: &str,
+        ttl: Duration,
+        owner: &str,
+        token: Option<u64>,
+    ) -> Result<u64> {
+        let mut conn = self.conn().await?;
+        let token: u64 = redis::Script::new(LOCK_SCRIPT)
+            .key(key)
+            .key(format!("{{{key}}}:fence"))
+            .arg(ttl.as_millis().max(1) as u64)
+            .arg(owner)
+            .arg(token.unwrap_or_default())
+            .invoke_async(&mut *conn)
+            .await
+            .map_err(format_redis_error)?;
+
+        match token {
+            0 => Err(Error::new(
+                ErrorKind::ConditionNotMatch,
+                "lock is held by another owner",
+            )),
+            token => Ok(token),
+        }
+    }
+
+    /// Release the lock at `key` if it's still held with `token`.
+    pub async fn unlock(&self, key: &str, owner: &str, token: u64) -> Result<()> {
+        let mut conn = self.conn().await?;
+        let deleted: u64 = redis::Script::new(UNLOCK_SCRIPT)
+            .key(key)
+            .arg(format!("{token}:{owner}"))
+            .invoke_async(&mut *conn)
+            .await
+            .map_err(format_redis_error)?;
+
+        if deleted == 0 {
+            return Err(Error::new(
+                ErrorKind::ConditionNotMatch,
+                "lock is not held with this token",
+            ));
+        }
+        Ok(())
+    }
 }
 
+/// The lock value is `{token}:{owner}`, ARGV is `ttl_ms, owner, token`.
+///
+/// Returns the token on success, or 0 if the lock is held by others or the
+/// lease to renew (token is not 0) has been lost.
+const LOCK_SCRIPT: &str = r"
+local v = redis.call('GET', KEYS[1])
+if v then
+  local t, o = string.match(v, '^(%d+):(.*)$')
+  if o == ARGV[2] and (ARGV[3] == '0' or t == ARGV[3]) then
+    redis.call('PEXPIRE', KEYS[1], ARGV[1])
+    return tonumber(t)
+  end
+  return 0
+end
+if ARGV[3] ~= '0' then
+  return 0
+end
+local t = redis.call('INCR', KEYS[2])
+redis.call('SET', KEYS[1], t .. ':' .. ARGV[2], 'NX', 'PX', ARGV[1])
+return t
+";
+
+/// Set the value to ARGV[3] only if the current value is ARGV[2], or doesn't
+/// exist if ARGV[1] is `0`. ARGV[4] is the ttl in milliseconds, `0` means no ttl.
+const CAS_SCRIPT: &str = r"
+local v = redis.call('GET', KEYS[1])
+if ARGV[1] == '1' then
+  if v ~= ARGV[2] then
+    return 0
+  end
+elseif v then
+  return 0
+end
+if ARGV[4] ~= '0' then
+  redis.call('SET', KEYS[1], ARGV[3], 'PX', ARGV[4])
+else
+  redis.call('SET', KEYS[1], ARGV[3])
+end
+return 1
+";
+
+/// Delete the lock only if its value is still ARGV[1].
+const UNLOCK_SCRIPT: &str = r"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+  return redis.call('DEL', KEYS[1])
+end
+return 0
+";
+
 pub fn format_redis_error(e: RedisError) -> Error {
     Error::new(ErrorKind::Unexpected, e.category())
         .set_source(e)
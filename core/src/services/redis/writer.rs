@@ -16,20 +16,30 @@
 // under the License.
 
 use super::core::RedisCore;
-use crate::raw::oio;
+use crate::raw::adapters::kv;
+use crate::raw::*;
 use crate::*;
 
 pub struct RedisWriter {
     core: std::sync::Arc<RedisCore>,
     path: String,
+    args: OpWrite,
+    metadata: bool,
     buffer: oio::QueueBuf,
 }
 
 impl RedisWriter {
-    pub fn new(core: std::sync::Arc<RedisCore>, path: String) -> Self {
+    pub fn new(
+        core: std::sync::Arc<RedisCore>,
+        path: String,
+        args: OpWrite,
+        metadata: bool,
+    ) -> Self {
         Self {
             core,
             path,
+            args,
+            metadata,
             buffer: oio::QueueBuf::new(),
         }
     }
@@ -44,9 +54,43 @@ impl oio::Write for RedisWriter {
     async fn close(&mut self) -> Result<Metadata> {
         let buf = self.buffer.clone().collect();
         let length = buf.len() as u64;
-        self.core.set(&self.path, buf).await?;
+        let mut meta = Metadata::new(EntryMode::from_path(&self.path)).with_content_length(length);
+        if !self.metadata {
+            self.core.set(&self.path, buf, self.args.ttl()).await?;
+            if let Some(ttl) = self.args.ttl() {
+                meta.set_expires_at(expire_time_from_ttl(ttl));
+            }
+            return Ok(meta);
+        }
+
+        let value_meta = kv::ValueMetadata::new(&self.args);
+        meta = value_meta.apply(meta);
+        let value = value_meta.join(buf)?;
 
-        let meta = Metadata::new(EntryMode::from_path(&self.path)).with_content_length(length);
+        if self.args.if_match().is_none()
+            && self.args.if_none_match().is_none()
+            && !self.args.if_not_exists()
+        {
+            self.core.set(&self.path, value, self.args.ttl()).await?;
+            return Ok(meta);
+        }
+
+        let current = self.core.get(&self.path).await?;
+        let current_meta = match &current {
+            Some(bs) => Some(kv::ValueMetadata::split(bs.clone())?.0),
+            None => None,
+        };
+        kv::check_write_conditions(current_meta.as_ref().map(|v| v.as_ref()), &self.args)?;
+        if !self
+            .core
+            .compare_and_swap(&self.path, current, value, self.args.ttl())
+            .await?
+        {
+            return Err(Error::new(
+                ErrorKind::ConditionNotMatch,
+                "value has been changed by others",
+            ));
+        }
         Ok(meta)
     }
 
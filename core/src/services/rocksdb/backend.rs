@@ -18,7 +18,11 @@
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
+use std::sync::RwLock;
 
+use rocksdb::Direction;
+use rocksdb::IteratorMode;
+use rocksdb::WriteBatch;
 use rocksdb::DB;
 
 use crate::raw::adapters::kv;
@@ -60,6 +64,14 @@ impl RocksdbBuilder {
 
         self
     }
+
+    /// Store metadata like content type, etag and last modified with values.
+    ///
+    /// Values written before are still readable, they just don't have metadata.
+    pub fn enable_metadata(mut self, enabled: bool) -> Self {
+        self.config.enable_metadata = enabled;
+        self
+    }
 }
 
 impl Builder for RocksdbBuilder {
@@ -85,7 +97,17 @@ impl Builder for RocksdbBuilder {
                 .as_str(),
         );
 
-        Ok(RocksdbBackend::new(Adapter { db: Arc::new(db) }).with_normalized_root(root))
+        let backend = RocksdbBackend::new(Adapter {
+            db: Arc::new(db),
+            txn_lock: Arc::default(),
+        })
+        .with_normalized_root(root);
+
+        Ok(if self.config.enable_metadata {
+            backend.with_metadata()
+        } else {
+            backend
+        })
     }
 }
 
@@ -95,6 +117,12 @@ pub type RocksdbBackend = kv::Backend<Adapter>;
 #[derive(Clone)]
 pub struct Adapter {
     db: Arc<DB>,
+    /// Writes hold the read lock, transactions hold the write lock so that the
+    /// keys they check can't be changed before the batch is written.
+    ///
+    /// A rocksdb database can only be opened by one process at a time, so this
+    /// lock covers all writers.
+    txn_lock: Arc<RwLock<()>>,
 }
 
 impl Debug for Adapter {
@@ -105,8 +133,36 @@ impl Debug for Adapter {
     }
 }
 
+impl kv::ScanPage for Adapter {
+    async fn next_page(
+        &self,
+        prefix: &str,
+        start_after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<String>> {
+        let from = start_after.unwrap_or(prefix);
+        let it = self
+            .db
+            .iterator(IteratorMode::From(from.as_bytes(), Direction::Forward));
+
+        let mut keys = Vec::with_capacity(limit);
+        for item in it {
+            let (key, _) = item.map_err(parse_rocksdb_error)?;
+            let key = String::from_utf8_lossy(&key);
+            if Some(key.as_ref()) == start_after {
+                continue;
+            }
+            if !key.starts_with(prefix) || keys.len() >= limit {
+                break;
+            }
+            keys.push(key.to_string());
+        }
+        Ok(keys)
+    }
+}
+
 impl kv::Adapter for Adapter {
-    type Scanner = kv::Scanner;
+    type Scanner = kv::PagedScanner<Self>;
 
     fn info(&self) -> kv::Info {
         kv::Info::new(
@@ -116,6 +172,7 @@ impl kv::Adapter for Adapter {
                 read: true,
                 write: true,
                 list: true,
+                transaction: true,
                 shared: false,
                 ..Default::default()
             },
@@ -128,29 +185,44 @@ impl kv::Adapter for Adapter {
     }
 
     async fn set(&self, path: &str, value: Buffer) -> Result<()> {
+        let _guard = self.txn_lock.read().unwrap();
         self.db
             .put(path, value.to_vec())
             .map_err(parse_rocksdb_error)
     }
 
     async fn delete(&self, path: &str) -> Result<()> {
+        let _guard = self.txn_lock.read().unwrap();
         self.db.delete(path).map_err(parse_rocksdb_error)
     }
 
-    async fn scan(&self, path: &str) -> Result<Self::Scanner> {
-        let it = self.db.prefix_iterator(path).map(|r| r.map(|(k, _)| k));
-        let mut res = Vec::default();
+    async fn transaction(
+        &self,
+        checks: Vec<(String, Option<Buffer>)>,
+        mutations: Vec<(String, Option<Buffer>)>,
+    ) -> Result<bool> {
+        let _guard = self.txn_lock.write().unwrap();
+
+        for (path, expected) in checks {
+            let actual = self.db.get(&path).map_err(parse_rocksdb_error)?;
+            if actual != expected.map(|v| v.to_vec()) {
+                return Ok(false);
+            }
+        }
 
-        for key in it {
-            let key = key.map_err(parse_rocksdb_error)?;
-            let key = String::from_utf8_lossy(&key);
-            if !key.starts_with(path) {
-                break;
+        let mut batch = WriteBatch::default();
+        for (path, value) in mutations {
+            match value {
+                Some(value) => batch.put(&path, value.to_vec()),
+                None => batch.delete(&path),
             }
-            res.push(key.to_string());
         }
+        self.db.write(batch).map_err(parse_rocksdb_error)?;
+        Ok(true)
+    }
 
-        Ok(Box::new(kv::ScanStdIter::new(res.into_iter().map(Ok))))
+    async fn scan(&self, path: &str, opts: kv::ScanOptions) -> Result<Self::Scanner> {
+        Ok(kv::PagedScanner::new(self.clone(), path, opts))
     }
 }
 
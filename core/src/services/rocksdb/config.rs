@@ -31,4 +31,6 @@ pub struct RocksdbConfig {
     ///
     /// default is "/"
     pub root: Option<String>,
+    /// Store metadata like content type, etag and last modified with values.
+    pub enable_metadata: bool,
 }
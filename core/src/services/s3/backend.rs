@@ -26,7 +26,9 @@ use std::sync::LazyLock;
 
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
+use bytes::Buf;
 use constants::X_AMZ_META_PREFIX;
+use constants::X_AMZ_STORAGE_CLASS;
 use constants::X_AMZ_VERSION_ID;
 use http::Response;
 use http::StatusCode;
@@ -43,7 +45,9 @@ use reqwest::Url;
 
 use super::core::*;
 use super::delete::S3Deleter;
+use super::error::from_s3_error;
 use super::error::parse_error;
+use super::error::S3Error;
 use super::lister::S3ListerV1;
 use super::lister::S3ListerV2;
 use super::lister::S3Listers;
@@ -937,6 +941,7 @@ impl Builder for S3Builder {
                             write: true,
                             write_can_empty: true,
                             write_can_multi: true,
+                            write_can_random_access: true,
                             write_can_append: self.config.enable_write_with_append,
 
                             write_with_cache_control: true,
@@ -964,18 +969,42 @@ impl Builder for S3Builder {
                             delete_with_version: self.config.enable_versioning,
 
                             copy: true,
+                            copy_with_if_match: true,
+                            copy_with_if_none_match: true,
+                            concat: true,
+                            // Every part except the last one of multipart upload must
+                            // be at least 5 MiB and at most 5 GiB, and there are at
+                            // most 10000 parts.
+                            //
+                            // ref: <https://docs.aws.amazon.com/AmazonS3/latest/userguide/qfacts.html>
+                            concat_min_source_size: Some(5 * 1024 * 1024),
+                            concat_max_source_size: if cfg!(target_pointer_width = "64") {
+                                Some(5 * 1024 * 1024 * 1024)
+                            } else {
+                                Some(usize::MAX)
+                            },
+                            concat_max_sources: Some(10000),
+
+                            update_metadata: true,
+                            update_metadata_with_if_match: true,
 
                             list: true,
                             list_with_limit: true,
                             list_with_start_after: true,
+                            list_with_continuation_token: true,
                             list_with_recursive: true,
                             list_with_versions: self.config.enable_versioning,
                             list_with_deleted: self.config.enable_versioning,
 
+                            list_uploads: true,
+                            abort_upload: true,
+
                             presign: true,
                             presign_stat: true,
                             presign_read: true,
                             presign_write: true,
+                            presign_multipart: true,
+                            presign_post: true,
 
                             shared: true,
 
@@ -992,6 +1021,7 @@ impl Builder for S3Builder {
                 },
                 bucket: bucket.to_string(),
                 endpoint,
+                region,
                 root,
                 server_side_encryption,
                 server_side_encryption_aws_kms_key_id,
@@ -1017,6 +1047,142 @@ pub struct S3Backend {
     core: Arc<S3Core>,
 }
 
+#[derive(Clone)]
+struct UploadPartCopyInput {
+    core: Arc<S3Core>,
+    path: Arc<String>,
+    upload_id: Arc<String>,
+    part_number: usize,
+    part: oio::PartCopy,
+    op: Operation,
+}
+
+impl S3Backend {
+    /// Build the object at `path` by copying `parts` in a multipart upload.
+    async fn copy_parts(
+        &self,
+        path: &str,
+        args: &OpWrite,
+        parts: Vec<oio::PartCopy>,
+        concurrent: usize,
+        op: Operation,
+    ) -> Result<()> {
+        let resp = self.core.s3_initiate_multipart_upload(path, args).await?;
+        let upload_id = match resp.status() {
+            StatusCode::OK => {
+                let result: InitiateMultipartUploadResult =
+                    quick_xml::de::from_reader(resp.into_body().reader())
+                        .map_err(new_xml_deserialize_error)?;
+                result.upload_id
+            }
+            _ => return Err(parse_error(resp)),
+        };
+
+        let res = self
+            .copy_parts_inner(path, &upload_id, parts, concurrent, op)
+            .await;
+        if res.is_err() {
+            // Abort is best effort, the upload will be cleaned by lifecycle rules otherwise.
+            let _ = self.core.s3_abort_multipart_upload(path, &upload_id).await;
+        }
+        res
+    }
+
+    async fn copy_parts_inner(
+        &self,
+        path: &str,
+        upload_id: &str,
+        parts: Vec<oio::PartCopy>,
+        concurrent: usize,
+        op: Operation,
+    ) -> Result<()> {
+        let mut tasks = ConcurrentTasks::new(
+            self.core.info.executor(),
+            concurrent.max(1),
+            concurrent.max(1),
+            |input: UploadPartCopyInput| {
+                Box::pin(async move {
+                    let fut = input.core.s3_upload_part_copy(
+                        &input.path,
+                        &input.upload_id,
+                        input.part_number,
+                        &input.part.source,
+                        input.part.range,
+                        input.part.if_match.as_deref(),
+                        input.op,
+                    );
+                    match fut.await {
+                        Ok(resp) if resp.status() == StatusCode::OK => {
+                            let result: Result<CopyPartResult> =
+                                quick_xml::de::from_reader(resp.into_body().reader())
+                                    .map_err(new_xml_deserialize_error);
+                            let part = result.map(|v| CompleteMultipartUploadRequestPart {
+                                part_number: input.part_number,
+                                etag: v.etag,
+                                checksum_crc32c: v.checksum_crc32c,
+                            });
+                            (input, part)
+                        }
+                        Ok(resp) => (input, Err(parse_error(resp))),
+                        Err(err) => (input, Err(err)),
+                    }
+                })
+            },
+        );
+
+        let path = Arc::new(path.to_string());
+        let upload_id = Arc::new(upload_id.to_string());
+        let total = parts.len();
+        for (idx, part) in parts.into_iter().enumerate() {
+            tasks
+                .execute(UploadPartCopyInput {
+                    core: self.core.clone(),
+                    path: path.clone(),
+                    upload_id: upload_id.clone(),
+                    // Part number starts from 1.
+                    part_number: idx + 1,
+                    part,
+                    op,
+                })
+                .await?;
+        }
+
+        let mut parts = Vec::with_capacity(total);
+        while let Some(part) = tasks.next().await {
+            parts.push(part?);
+        }
+
+        let resp = self
+            .core
+            .s3_complete_multipart_upload(&path, &upload_id, parts)
+            .await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                // S3 might return error for status code 200, check the body as writer does.
+                let (parts, body) = resp.into_parts();
+
+                let ret: CompleteMultipartUploadResult =
+                    quick_xml::de::from_reader(body.reader()).map_err(new_xml_deserialize_error)?;
+                if !ret.code.is_empty() {
+                    return Err(from_s3_error(
+                        S3Error {
+                            code: ret.code,
+                            message: ret.message,
+                            resource: "".to_string(),
+                            request_id: ret.request_id,
+                        },
+                        parts,
+                    ));
+                }
+
+                Ok(())
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+}
+
 impl Access for S3Backend {
     type Reader = HttpBody;
     type Writer = S3Writers;
@@ -1033,21 +1199,10 @@ impl Access for S3Backend {
         let status = resp.status();
 
         match status {
-            StatusCode::OK => {
-                let headers = resp.headers();
-                let mut meta = parse_into_metadata(path, headers)?;
-
-                let user_meta = parse_prefixed_headers(headers, X_AMZ_META_PREFIX);
-                if !user_meta.is_empty() {
-                    meta = meta.with_user_metadata(user_meta);
-                }
-
-                if let Some(v) = parse_header_to_str(headers, X_AMZ_VERSION_ID)? {
-                    meta.set_version(v);
-                }
-
-                Ok(RpStat::new(meta))
-            }
+            StatusCode::OK => Ok(RpStat::new(parse_head_object_metadata(
+                path,
+                resp.headers(),
+            )?)),
             _ => Err(parse_error(resp)),
         }
     }
@@ -1074,11 +1229,10 @@ impl Access for S3Backend {
         let w = if args.append() {
             S3Writers::Two(oio::AppendWriter::new(writer))
         } else {
-            S3Writers::One(oio::MultipartWriter::new(
-                self.core.info.clone(),
-                writer,
-                args.concurrent(),
-            ))
+            S3Writers::One(
+                oio::MultipartWriter::new(self.core.info.clone(), writer, args.concurrent())
+                    .with_block_size(args.chunk()),
+            )
         };
 
         Ok((RpWrite::default(), w))
@@ -1092,40 +1246,263 @@ impl Access for S3Backend {
     }
 
     async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        let token = args.continuation_token().map(|v| v.to_string());
         let l = if args.versions() || args.deleted() {
-            ThreeWays::Three(PageLister::new(S3ObjectVersionsLister::new(
-                self.core.clone(),
-                path,
-                args,
-            )))
+            ThreeWays::Three(
+                PageLister::new(S3ObjectVersionsLister::new(self.core.clone(), path, args))
+                    .with_continuation_token(token.as_deref()),
+            )
         } else if self.core.disable_list_objects_v2 {
-            ThreeWays::One(PageLister::new(S3ListerV1::new(
-                self.core.clone(),
-                path,
-                args,
-            )))
+            ThreeWays::One(
+                PageLister::new(S3ListerV1::new(self.core.clone(), path, args))
+                    .with_continuation_token(token.as_deref()),
+            )
         } else {
-            ThreeWays::Two(PageLister::new(S3ListerV2::new(
-                self.core.clone(),
-                path,
-                args,
-            )))
+            ThreeWays::Two(
+                PageLister::new(S3ListerV2::new(self.core.clone(), path, args))
+                    .with_continuation_token(token.as_deref()),
+            )
         };
 
         Ok((RpList::default(), l))
     }
 
-    async fn copy(&self, from: &str, to: &str, _args: OpCopy) -> Result<RpCopy> {
-        let resp = self.core.s3_copy_object(from, to).await?;
+    async fn copy(&self, from: &str, to: &str, args: OpCopy) -> Result<RpCopy> {
+        // CopyObject can copy at most 5 GiB, larger sources must be copied in parts.
+        //
+        // ref: <https://docs.aws.amazon.com/AmazonS3/latest/API/API_CopyObject.html>
+        let max_single_size = 5 * 1024 * 1024 * 1024;
+
+        // Sources over the limit are rejected with `400 Bad Request`.
+        let single_err = if args.chunk().is_none() {
+            let resp = self.core.s3_copy_object(from, to, &args).await?;
+            match resp.status() {
+                StatusCode::OK => return Ok(RpCopy::default()),
+                StatusCode::BAD_REQUEST => Some(parse_error(resp)),
+                _ => return Err(parse_error(resp)),
+            }
+        } else {
+            None
+        };
 
-        let status = resp.status();
+        let stat = oio::CopyPlan::stat_args(&args);
+        let resp = self.core.s3_head_object(from, stat).await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp));
+        }
+        let meta = parse_head_object_metadata(from, resp.headers())?;
 
-        match status {
-            StatusCode::OK => Ok(RpCopy::default()),
+        // Parts of multipart upload must be at least 5 MiB.
+        match oio::CopyPlan::new(from, &meta, &args, max_single_size, 5 * 1024 * 1024) {
+            oio::CopyPlan::Single => {
+                if let Some(err) = single_err {
+                    return Err(err);
+                }
+                let resp = self.core.s3_copy_object(from, to, &args).await?;
+                match resp.status() {
+                    StatusCode::OK => Ok(RpCopy::default()),
+                    _ => Err(parse_error(resp)),
+                }
+            }
+            oio::CopyPlan::Multipart { args: op, parts } => {
+                self.copy_parts(to, &op, parts, args.concurrent(), Operation::Copy)
+                    .await?;
+                Ok(RpCopy::default())
+            }
+        }
+    }
+
+    async fn update_metadata(
+        &self,
+        path: &str,
+        args: OpUpdateMetadata,
+    ) -> Result<RpUpdateMetadata> {
+        let resp = self.core.s3_head_object(path, OpStat::new()).await?;
+        if resp.status() != StatusCode::OK {
+            return Err(parse_error(resp));
+        }
+        let mut meta = parse_head_object_metadata(path, resp.headers())?;
+        let storage_class = parse_header_to_str(resp.headers(), X_AMZ_STORAGE_CLASS)?;
+
+        let update = args.update();
+        if let (Some(expected), Some(actual)) = (update.if_match(), meta.etag()) {
+            if expected != actual {
+                return Err(Error::new(
+                    ErrorKind::ConditionNotMatch,
+                    "etag of the object doesn't match",
+                ));
+            }
+        }
+        update.apply(&mut meta);
+
+        // Copy only if the object is still the one we read, otherwise we may
+        // overwrite a concurrent write with stale content.
+        let if_match = meta.etag().map(|v| v.to_string());
+        let resp = self
+            .core
+            .s3_replace_object_metadata(path, &meta, storage_class, if_match.as_deref())
+            .await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let result: CopyObjectResult =
+                    quick_xml::de::from_reader(resp.into_body().reader())
+                        .map_err(new_xml_deserialize_error)?;
+                meta.set_etag(&result.etag);
+                meta.set_last_modified(parse_datetime_from_rfc3339(&result.last_modified)?);
+                Ok(RpUpdateMetadata::new(meta))
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn concat(&self, path: &str, args: OpConcat) -> Result<RpConcat> {
+        let mut op = OpWrite::new();
+        if let Some(v) = args.content_type() {
+            op = op.with_content_type(v);
+        }
+
+        let parts = args
+            .sources()
+            .iter()
+            .map(|source| oio::PartCopy {
+                source: source.clone(),
+                range: None,
+                if_match: None,
+            })
+            .collect();
+        self.copy_parts(path, &op, parts, args.concurrent(), Operation::Concat)
+            .await?;
+
+        Ok(RpConcat::default())
+    }
+
+    async fn list_uploads(&self, path: &str, _: OpListUploads) -> Result<RpListUploads> {
+        let mut uploads = Vec::new();
+        let mut key_marker = String::new();
+        let mut upload_id_marker = String::new();
+
+        loop {
+            let resp = self
+                .core
+                .s3_list_multipart_uploads(path, &key_marker, &upload_id_marker)
+                .await?;
+            if resp.status() != StatusCode::OK {
+                return Err(parse_error(resp));
+            }
+
+            let output: ListMultipartUploadsOutput =
+                quick_xml::de::from_reader(resp.into_body().reader())
+                    .map_err(new_xml_deserialize_error)?;
+
+            for upload in output.upload {
+                let path = build_rel_path(&self.core.root, &upload.key);
+                let mut u = Upload::new(&path, &upload.upload_id);
+                if let Some(initiated) = &upload.initiated {
+                    u = u.with_initiated(parse_datetime_from_rfc3339(initiated)?);
+                }
+                uploads.push(u);
+            }
+
+            if !output.is_truncated.unwrap_or_default() {
+                break;
+            }
+            key_marker = output.next_key_marker.unwrap_or_default();
+            upload_id_marker = output.next_upload_id_marker.unwrap_or_default();
+            if key_marker.is_empty() && upload_id_marker.is_empty() {
+                break;
+            }
+        }
+
+        Ok(RpListUploads::new(uploads))
+    }
+
+    async fn abort_upload(&self, path: &str, args: OpAbortUpload) -> Result<RpAbortUpload> {
+        let resp = self
+            .core
+            .s3_abort_multipart_upload(path, args.upload_id())
+            .await?;
+
+        match resp.status() {
+            StatusCode::NO_CONTENT | StatusCode::OK => Ok(RpAbortUpload::default()),
             _ => Err(parse_error(resp)),
         }
     }
 
+    async fn initiate_upload(
+        &self,
+        path: &str,
+        args: OpInitiateUpload,
+    ) -> Result<RpInitiateUpload> {
+        let resp = self
+            .core
+            .s3_initiate_multipart_upload(path, args.write())
+            .await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                let result: InitiateMultipartUploadResult =
+                    quick_xml::de::from_reader(resp.into_body().reader())
+                        .map_err(new_xml_deserialize_error)?;
+
+                Ok(RpInitiateUpload::new(&result.upload_id))
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn complete_upload(
+        &self,
+        path: &str,
+        args: OpCompleteUpload,
+    ) -> Result<RpCompleteUpload> {
+        // Parts are uploaded by clients via presigned requests, so we don't have
+        // their checksums here.
+        let parts = args
+            .parts()
+            .iter()
+            .map(|p| CompleteMultipartUploadRequestPart {
+                part_number: p.part_number(),
+                etag: p.etag().to_string(),
+                ..Default::default()
+            })
+            .collect();
+
+        let resp = self
+            .core
+            .s3_complete_multipart_upload(path, args.upload_id(), parts)
+            .await?;
+
+        match resp.status() {
+            StatusCode::OK => {
+                // S3 might return error for status code 200, check the body as writer does.
+                let (parts, body) = resp.into_parts();
+
+                let ret: CompleteMultipartUploadResult =
+                    quick_xml::de::from_reader(body.reader()).map_err(new_xml_deserialize_error)?;
+                if !ret.code.is_empty() {
+                    return Err(from_s3_error(
+                        S3Error {
+                            code: ret.code,
+                            message: ret.message,
+                            resource: "".to_string(),
+                            request_id: ret.request_id,
+                        },
+                        parts,
+                    ));
+                }
+
+                Ok(RpCompleteUpload::new())
+            }
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn presign_post(&self, path: &str, args: OpPresignPost) -> Result<RpPresignPost> {
+        let post = self.core.s3_presign_post(path, &args).await?;
+        Ok(RpPresignPost::new(post))
+    }
+
     async fn presign(&self, path: &str, args: OpPresign) -> Result<RpPresign> {
         let (expire, op) = args.into_parts();
         // We will not send this request out, just for signing.
@@ -1139,6 +1516,14 @@ impl Access for S3Backend {
                 self.core
                     .s3_put_object_request(path, None, &OpWrite::default(), Buffer::new())
             }
+            PresignOperation::UploadPart(v) => self.core.s3_upload_part_request(
+                path,
+                v.upload_id(),
+                v.part_number(),
+                None,
+                Buffer::new(),
+                None,
+            ),
             PresignOperation::Delete(_) => Err(Error::new(
                 ErrorKind::Unsupported,
                 "operation is not supported",
@@ -1159,6 +1544,22 @@ impl Access for S3Backend {
     }
 }
 
+/// Parse metadata from the response headers of `HeadObject`.
+fn parse_head_object_metadata(path: &str, headers: &http::HeaderMap) -> Result<Metadata> {
+    let mut meta = parse_into_metadata(path, headers)?;
+
+    let user_meta = parse_prefixed_headers(headers, X_AMZ_META_PREFIX);
+    if !user_meta.is_empty() {
+        meta = meta.with_user_metadata(user_meta);
+    }
+
+    if let Some(v) = parse_header_to_str(headers, X_AMZ_VERSION_ID)? {
+        meta.set_version(v);
+    }
+
+    Ok(meta)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
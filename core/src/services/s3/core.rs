@@ -28,7 +28,11 @@ use std::time::Duration;
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
 use bytes::Bytes;
+use chrono::SecondsFormat;
+use chrono::Utc;
 use constants::X_AMZ_META_PREFIX;
+use hmac::Hmac;
+use hmac::Mac;
 use http::header::HeaderName;
 use http::header::CACHE_CONTROL;
 use http::header::CONTENT_DISPOSITION;
@@ -48,12 +52,18 @@ use reqsign::AwsCredentialLoad;
 use reqsign::AwsV4Signer;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::json;
+use sha2::Sha256;
 
 use crate::raw::*;
 use crate::*;
 
 pub mod constants {
     pub const X_AMZ_COPY_SOURCE: &str = "x-amz-copy-source";
+    pub const X_AMZ_COPY_SOURCE_IF_MATCH: &str = "x-amz-copy-source-if-match";
+    pub const X_AMZ_COPY_SOURCE_IF_NONE_MATCH: &str = "x-amz-copy-source-if-none-match";
+    pub const X_AMZ_COPY_SOURCE_RANGE: &str = "x-amz-copy-source-range";
+    pub const X_AMZ_METADATA_DIRECTIVE: &str = "x-amz-metadata-directive";
 
     pub const X_AMZ_SERVER_SIDE_ENCRYPTION: &str = "x-amz-server-side-encryption";
     pub const X_AMZ_SERVER_REQUEST_PAYER: (&str, &str) = ("x-amz-request-payer", "requester");
@@ -93,6 +103,7 @@ pub struct S3Core {
 
     pub bucket: String,
     pub endpoint: String,
+    pub region: String,
     pub root: String,
     pub server_side_encryption: Option<HeaderValue>,
     pub server_side_encryption_aws_kms_key_id: Option<HeaderValue>,
@@ -358,6 +369,51 @@ impl S3Core {
         }
         req
     }
+
+    /// Insert SSE-C headers for the copy source, the source is encrypted with
+    /// the same customer key as the target.
+    pub fn insert_copy_source_sse_headers(
+        &self,
+        mut req: http::request::Builder,
+    ) -> http::request::Builder {
+        if let Some(v) = &self.server_side_encryption_customer_algorithm {
+            let mut v = v.clone();
+            v.set_sensitive(true);
+
+            req = req.header(
+                HeaderName::from_static(
+                    constants::X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM,
+                ),
+                v,
+            )
+        }
+
+        if let Some(v) = &self.server_side_encryption_customer_key {
+            let mut v = v.clone();
+            v.set_sensitive(true);
+
+            req = req.header(
+                HeaderName::from_static(
+                    constants::X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY,
+                ),
+                v,
+            )
+        }
+
+        if let Some(v) = &self.server_side_encryption_customer_key_md5 {
+            let mut v = v.clone();
+            v.set_sensitive(true);
+
+            req = req.header(
+                HeaderName::from_static(
+                    constants::X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5,
+                ),
+                v,
+            )
+        }
+
+        req
+    }
 }
 
 impl S3Core {
@@ -652,7 +708,12 @@ impl S3Core {
         self.send(req).await
     }
 
-    pub async fn s3_copy_object(&self, from: &str, to: &str) -> Result<Response<Buffer>> {
+    pub async fn s3_copy_object(
+        &self,
+        from: &str,
+        to: &str,
+        args: &OpCopy,
+    ) -> Result<Response<Buffer>> {
         let from = build_abs_path(&self.root, from);
         let to = build_abs_path(&self.root, to);
 
@@ -664,49 +725,81 @@ impl S3Core {
         // Set SSE headers.
         req = self.insert_sse_headers(req, true);
 
-        if let Some(v) = &self.server_side_encryption_customer_algorithm {
-            let mut v = v.clone();
-            v.set_sensitive(true);
+        req = self.insert_copy_source_sse_headers(req);
 
-            req = req.header(
-                HeaderName::from_static(
-                    constants::X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM,
-                ),
-                v,
-            )
+        // Set request payer header if enabled.
+        req = self.insert_request_payer_header(req);
+
+        if let Some(v) = args.if_match() {
+            req = req.header(constants::X_AMZ_COPY_SOURCE_IF_MATCH, v);
+        }
+        if let Some(v) = args.if_none_match() {
+            req = req.header(constants::X_AMZ_COPY_SOURCE_IF_NONE_MATCH, v);
         }
 
-        if let Some(v) = &self.server_side_encryption_customer_key {
-            let mut v = v.clone();
-            v.set_sensitive(true);
+        let mut req = req
+            // Inject operation to the request.
+            .extension(Operation::Copy)
+            .header(constants::X_AMZ_COPY_SOURCE, &source)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
 
-            req = req.header(
-                HeaderName::from_static(
-                    constants::X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY,
-                ),
-                v,
-            )
-        }
+        self.sign(&mut req).await?;
 
-        if let Some(v) = &self.server_side_encryption_customer_key_md5 {
-            let mut v = v.clone();
-            v.set_sensitive(true);
+        self.send(req).await
+    }
 
-            req = req.header(
-                HeaderName::from_static(
-                    constants::X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5,
-                ),
-                v,
-            )
-        }
+    /// Copy the object onto itself with `REPLACE` metadata directive.
+    ///
+    /// S3 replaces all metadata of the object in this case, so `meta` MUST
+    /// contain all metadata that should be kept.
+    pub async fn s3_replace_object_metadata(
+        &self,
+        path: &str,
+        meta: &Metadata,
+        storage_class: Option<&str>,
+        if_match: Option<&str>,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
 
-        // Set request payer header if enabled.
+        let source = format!("{}/{}", self.bucket, percent_encode_path(&p));
+        let url = format!("{}/{}", self.endpoint, percent_encode_path(&p));
+
+        let mut req = Request::put(&url);
+
+        req = self.insert_sse_headers(req, true);
+        req = self.insert_copy_source_sse_headers(req);
         req = self.insert_request_payer_header(req);
 
+        if let Some(v) = meta.content_type() {
+            req = req.header(CONTENT_TYPE, v)
+        }
+        if let Some(v) = meta.content_disposition() {
+            req = req.header(CONTENT_DISPOSITION, v)
+        }
+        if let Some(v) = meta.content_encoding() {
+            req = req.header(CONTENT_ENCODING, v)
+        }
+        if let Some(v) = meta.cache_control() {
+            req = req.header(CACHE_CONTROL, v)
+        }
+        if let Some(user_metadata) = meta.user_metadata() {
+            for (key, value) in user_metadata {
+                req = req.header(format!("{X_AMZ_META_PREFIX}{key}"), value)
+            }
+        }
+        // Storage class will be reset to `STANDARD` if not specified.
+        if let Some(v) = storage_class {
+            req = req.header(HeaderName::from_static(constants::X_AMZ_STORAGE_CLASS), v);
+        }
+        if let Some(v) = if_match {
+            req = req.header(constants::X_AMZ_COPY_SOURCE_IF_MATCH, v);
+        }
+
         let mut req = req
-            // Inject operation to the request.
-            .extension(Operation::Copy)
+            .extension(Operation::UpdateMetadata)
             .header(constants::X_AMZ_COPY_SOURCE, &source)
+            .header(constants::X_AMZ_METADATA_DIRECTIVE, "REPLACE")
             .body(Buffer::new())
             .map_err(new_request_build_error)?;
 
@@ -830,6 +923,10 @@ impl S3Core {
             req = req.header(CACHE_CONTROL, cache_control)
         }
 
+        if let Some(encoding) = args.content_encoding() {
+            req = req.header(CONTENT_ENCODING, encoding);
+        }
+
         // Set storage class header
         if let Some(v) = &self.default_storage_class {
             req = req.header(HeaderName::from_static(constants::X_AMZ_STORAGE_CLASS), v);
@@ -866,7 +963,7 @@ impl S3Core {
         path: &str,
         upload_id: &str,
         part_number: usize,
-        size: u64,
+        size: Option<u64>,
         body: Buffer,
         checksum: Option<String>,
     ) -> Result<Request<Buffer>> {
@@ -882,7 +979,9 @@ impl S3Core {
 
         let mut req = Request::put(&url);
 
-        req = req.header(CONTENT_LENGTH, size);
+        if let Some(size) = size {
+            req = req.header(CONTENT_LENGTH, size);
+        }
 
         // Set request payer header if enabled.
         req = self.insert_request_payer_header(req);
@@ -904,6 +1003,59 @@ impl S3Core {
         Ok(req)
     }
 
+    /// Upload a part by copying `range` of the `source` object, or the whole
+    /// object if `range` is not given.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn s3_upload_part_copy(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        source: &str,
+        range: Option<BytesRange>,
+        if_match: Option<&str>,
+        op: Operation,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let source = build_abs_path(&self.root, source);
+
+        let url = format!(
+            "{}/{}?partNumber={}&uploadId={}",
+            self.endpoint,
+            percent_encode_path(&p),
+            part_number,
+            percent_encode_path(upload_id)
+        );
+        let source = format!("{}/{}", self.bucket, percent_encode_path(&source));
+
+        let mut req = Request::put(&url);
+
+        // Set SSE headers.
+        req = self.insert_sse_headers(req, true);
+        req = self.insert_copy_source_sse_headers(req);
+
+        // Set request payer header if enabled.
+        req = self.insert_request_payer_header(req);
+
+        if let Some(range) = range {
+            req = req.header(constants::X_AMZ_COPY_SOURCE_RANGE, range.to_header());
+        }
+        if let Some(v) = if_match {
+            req = req.header(constants::X_AMZ_COPY_SOURCE_IF_MATCH, v);
+        }
+
+        let mut req = req
+            // Inject operation to the request.
+            .extension(op)
+            .header(constants::X_AMZ_COPY_SOURCE, &source)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
     pub async fn s3_complete_multipart_upload(
         &self,
         path: &str,
@@ -976,6 +1128,117 @@ impl S3Core {
         self.send(req).await
     }
 
+    pub async fn s3_list_multipart_uploads(
+        &self,
+        prefix: &str,
+        key_marker: &str,
+        upload_id_marker: &str,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, prefix);
+
+        let mut url = QueryPairsWriter::new(&self.endpoint);
+        url = url.push("uploads", "");
+
+        if !p.is_empty() {
+            url = url.push("prefix", &percent_encode_path(&p));
+        }
+        if !key_marker.is_empty() {
+            url = url.push("key-marker", &percent_encode_path(key_marker));
+        }
+        if !upload_id_marker.is_empty() {
+            url = url.push("upload-id-marker", &percent_encode_path(upload_id_marker));
+        }
+
+        let mut req = Request::get(url.finish());
+
+        // Set request payer header if enabled.
+        req = self.insert_request_payer_header(req);
+
+        let mut req = req
+            // Inject operation to the request.
+            .extension(Operation::ListUploads)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.sign(&mut req).await?;
+
+        self.send(req).await
+    }
+
+    /// Build a presigned POST form with AWS Signature Version 4.
+    ///
+    /// Reference: <https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-HTTPPOSTConstructPolicy.html>
+    pub async fn s3_presign_post(&self, path: &str, args: &OpPresignPost) -> Result<PresignedPost> {
+        let Some(cred) = self.load_credential().await? else {
+            return Err(Error::new(
+                ErrorKind::PermissionDenied,
+                "presign post requires credential but anonymous access is used",
+            ));
+        };
+
+        let p = build_abs_path(&self.root, path);
+        let now = Utc::now();
+        let expiration = now
+            + chrono::Duration::from_std(args.expire()).map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "expire is out of range").set_source(err)
+            })?;
+        let date = now.format("%Y%m%d").to_string();
+        let datetime = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let credential = format!(
+            "{}/{}/{}/s3/aws4_request",
+            cred.access_key_id, date, self.region
+        );
+
+        let mut fields = Vec::new();
+        let mut conditions = vec![json!({ "bucket": self.bucket })];
+        if p.is_empty() || p.ends_with('/') {
+            conditions.push(json!(["starts-with", "$key", p]));
+            fields.push(("key".to_string(), format!("{p}${{filename}}")));
+        } else {
+            conditions.push(json!({ "key": p }));
+            fields.push(("key".to_string(), p));
+        }
+        if let Some((min, max)) = args.content_length_range() {
+            conditions.push(json!(["content-length-range", min, max]));
+        }
+        if let Some(prefix) = args.content_type_prefix() {
+            conditions.push(json!(["starts-with", "$Content-Type", prefix]));
+        }
+
+        fields.push((
+            "x-amz-algorithm".to_string(),
+            "AWS4-HMAC-SHA256".to_string(),
+        ));
+        fields.push(("x-amz-credential".to_string(), credential));
+        fields.push(("x-amz-date".to_string(), datetime));
+        if let Some(token) = &cred.session_token {
+            fields.push(("x-amz-security-token".to_string(), token.clone()));
+        }
+        for (k, v) in &fields[1..] {
+            conditions.push(json!({ k: v }));
+        }
+
+        let policy = json!({
+            "expiration": expiration.to_rfc3339_opts(SecondsFormat::Millis, true),
+            "conditions": conditions,
+        });
+        let policy = BASE64_STANDARD.encode(policy.to_string());
+
+        let mut key = format!("AWS4{}", cred.secret_access_key).into_bytes();
+        for v in [date.as_str(), self.region.as_str(), "s3", "aws4_request"] {
+            key = hmac_sha256(&key, v.as_bytes());
+        }
+        let signature = format_hex(&hmac_sha256(&key, policy.as_bytes()));
+
+        fields.push(("policy".to_string(), policy));
+        fields.push(("x-amz-signature".to_string(), signature));
+
+        let uri = format!("{}/", self.endpoint).parse().map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "endpoint is not a valid uri").set_source(err)
+        })?;
+        Ok(PresignedPost::new(uri, fields))
+    }
+
     pub async fn s3_delete_objects(
         &self,
         paths: Vec<(String, OpDelete)>,
@@ -1069,6 +1332,12 @@ impl S3Core {
     }
 }
 
+fn hmac_sha256(key: &[u8], content: &[u8]) -> Vec<u8> {
+    let mut h = Hmac::<Sha256>::new_from_slice(key).expect("hmac accepts key of any size");
+    h.update(content);
+    h.finalize().into_bytes().to_vec()
+}
+
 /// Result of CreateMultipartUpload
 #[derive(Default, Debug, Deserialize)]
 #[serde(default, rename_all = "PascalCase")]
@@ -1136,6 +1405,25 @@ pub struct CompleteMultipartUploadResult {
     pub request_id: String,
 }
 
+/// Output of `UploadPartCopy` operation
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct CopyPartResult {
+    #[serde(rename = "ETag")]
+    pub etag: String,
+    #[serde(rename = "ChecksumCRC32C")]
+    pub checksum_crc32c: Option<String>,
+}
+
+/// Output of `CopyObject` operation
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct CopyObjectResult {
+    #[serde(rename = "ETag")]
+    pub etag: String,
+    pub last_modified: String,
+}
+
 /// Request of DeleteObjects.
 #[derive(Default, Debug, Serialize)]
 #[serde(default, rename = "Delete", rename_all = "PascalCase")]
@@ -1228,6 +1516,24 @@ pub struct OutputCommonPrefix {
     pub prefix: String,
 }
 
+/// Output of ListMultipartUploads
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "PascalCase")]
+pub struct ListMultipartUploadsOutput {
+    pub is_truncated: Option<bool>,
+    pub next_key_marker: Option<String>,
+    pub next_upload_id_marker: Option<String>,
+    pub upload: Vec<ListMultipartUploadsOutputUpload>,
+}
+
+#[derive(Default, Debug, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListMultipartUploadsOutputUpload {
+    pub key: String,
+    pub upload_id: String,
+    pub initiated: Option<String>,
+}
+
 /// Output of ListObjectVersions
 #[derive(Default, Debug, Deserialize)]
 #[serde(default, rename_all = "PascalCase")]
@@ -1311,6 +1617,61 @@ mod tests {
         )
     }
 
+    /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_ListMultipartUploads.html#API_ListMultipartUploads_Examples
+    #[test]
+    fn test_deserialize_list_multipart_uploads_output() {
+        let bs = Bytes::from(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <ListMultipartUploadsResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+              <Bucket>bucket</Bucket>
+              <KeyMarker></KeyMarker>
+              <UploadIdMarker></UploadIdMarker>
+              <NextKeyMarker>my-movie.m2ts</NextKeyMarker>
+              <NextUploadIdMarker>YW55IGlkZWEgd2h5IGVsdmluZydzIHVwbG9hZCBmYWlsZWQ</NextUploadIdMarker>
+              <MaxUploads>3</MaxUploads>
+              <IsTruncated>true</IsTruncated>
+              <Upload>
+                <Key>my-divisor</Key>
+                <UploadId>XMgbGlrZSBlbHZpbmcncyBub3QgaGF2aW5nIG11Y2ggbHVjaw</UploadId>
+                <StorageClass>REDUCED_REDUNDANCY</StorageClass>
+                <Initiated>2010-11-10T20:48:33.000Z</Initiated>
+              </Upload>
+              <Upload>
+                <Key>my-movie.m2ts</Key>
+                <UploadId>VXBsb2FkIElEIGZvciBlbHZpbmcncyBteS1tb3ZpZS5tMnRzIHVwbG9hZA</UploadId>
+                <StorageClass>STANDARD</StorageClass>
+                <Initiated>2010-11-10T20:48:33.000Z</Initiated>
+              </Upload>
+            </ListMultipartUploadsResult>"#,
+        );
+
+        let out: ListMultipartUploadsOutput =
+            quick_xml::de::from_reader(bs.reader()).expect("must success");
+
+        assert_eq!(out.is_truncated, Some(true));
+        assert_eq!(out.next_key_marker.as_deref(), Some("my-movie.m2ts"));
+        assert_eq!(
+            out.next_upload_id_marker.as_deref(),
+            Some("YW55IGlkZWEgd2h5IGVsdmluZydzIHVwbG9hZCBmYWlsZWQ")
+        );
+        assert_eq!(
+            out.upload,
+            vec![
+                ListMultipartUploadsOutputUpload {
+                    key: "my-divisor".to_string(),
+                    upload_id: "XMgbGlrZSBlbHZpbmcncyBub3QgaGF2aW5nIG11Y2ggbHVjaw".to_string(),
+                    initiated: Some("2010-11-10T20:48:33.000Z".to_string()),
+                },
+                ListMultipartUploadsOutputUpload {
+                    key: "my-movie.m2ts".to_string(),
+                    upload_id: "VXBsb2FkIElEIGZvciBlbHZpbmcncyBteS1tb3ZpZS5tMnRzIHVwbG9hZA"
+                        .to_string(),
+                    initiated: Some("2010-11-10T20:48:33.000Z".to_string()),
+                },
+            ]
+        );
+    }
+
     /// This example is from https://docs.aws.amazon.com/AmazonS3/latest/API/API_CompleteMultipartUpload.html#API_CompleteMultipartUpload_Examples
     #[test]
     fn test_serialize_complete_multipart_upload_request() {
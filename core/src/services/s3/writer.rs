@@ -121,7 +121,7 @@ impl oio::MultipartWrite for S3Writer {
             &self.path,
             upload_id,
             part_number,
-            size,
+            Some(size),
             body,
             checksum.clone(),
         )?;
@@ -22,8 +22,13 @@ use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use log::debug;
 use openssh::KnownHosts;
+use openssh_sftp_client::metadata::MetaDataBuilder;
+use openssh_sftp_client::metadata::Permissions;
+use openssh_sftp_client::UnixTimeStamp;
 use tokio::io::AsyncSeekExt;
 use tokio::sync::OnceCell;
 
@@ -183,13 +188,20 @@ impl Builder for SftpBuilder {
             .set_scheme(DEFAULT_SCHEME)
             .set_native_capability(Capability {
                 stat: true,
+                stat_with_follow_symlinks: true,
 
                 read: true,
 
                 write: true,
                 write_can_multi: true,
+                write_can_random_access: true,
+                write_can_set_len: true,
 
                 create_dir: true,
+                create_symlink: true,
+                read_link: true,
+                set_permissions: true,
+                set_times: true,
                 delete: true,
 
                 list: true,
@@ -267,12 +279,23 @@ impl Access for SftpBackend {
         Ok(RpCreateDir::default())
     }
 
-    async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
         let client = self.core.connect().await?;
         let mut fs = client.fs();
         fs.set_cwd(&self.core.root);
 
-        let meta: Metadata = fs.metadata(path).await.map_err(parse_sftp_error)?.into();
+        let mut meta: Metadata = if args.follow_symlinks() {
+            fs.metadata(path).await
+        } else {
+            fs.symlink_metadata(path).await
+        }
+        .map_err(parse_sftp_error)?
+        .into();
+
+        if meta.is_symlink() {
+            let target = fs.read_link(path).await.map_err(parse_sftp_error)?;
+            meta.set_symlink_target(&target.to_string_lossy());
+        }
 
         Ok(RpStat::new(meta))
     }
@@ -315,7 +338,7 @@ impl Access for SftpBackend {
 
         let mut option = client.options();
         option.create(true);
-        if op.append() {
+        if op.append() && !op.random_access() {
             option.append(true);
         } else {
             option.write(true).truncate(true);
@@ -394,4 +417,71 @@ impl Access for SftpBackend {
 
         Ok(RpRename::default())
     }
+
+    async fn create_symlink(&self, path: &str, args: OpCreateSymlink) -> Result<RpCreateSymlink> {
+        if let Some((dir, _)) = path.rsplit_once('/') {
+            self.create_dir(dir, OpCreateDir::default()).await?;
+        }
+
+        let client = self.core.connect().await?;
+        // Don't set cwd here, otherwise a relative target will be joined with it.
+        let mut fs = client.fs();
+
+        let link = Path::new(&self.core.root).join(path);
+        fs.symlink(args.target(), link)
+            .await
+            .map_err(parse_sftp_error)?;
+
+        Ok(RpCreateSymlink::default())
+    }
+
+    async fn read_link(&self, path: &str, _: OpReadLink) -> Result<RpReadLink> {
+        let client = self.core.connect().await?;
+        let mut fs = client.fs();
+        fs.set_cwd(&self.core.root);
+
+        let target = fs.read_link(path).await.map_err(parse_sftp_error)?;
+
+        Ok(RpReadLink::new(&target.to_string_lossy()))
+    }
+
+    async fn set_permissions(
+        &self,
+        path: &str,
+        args: OpSetPermissions,
+    ) -> Result<RpSetPermissions> {
+        let client = self.core.connect().await?;
+        let mut fs = client.fs();
+        fs.set_cwd(&self.core.root);
+
+        fs.set_permissions(path, Permissions::from(args.mode() as u16))
+            .await
+            .map_err(parse_sftp_error)?;
+
+        Ok(RpSetPermissions::default())
+    }
+
+    async fn set_times(&self, path: &str, args: OpSetTimes) -> Result<RpSetTimes> {
+        let client = self.core.connect().await?;
+        let mut fs = client.fs();
+        fs.set_cwd(&self.core.root);
+
+        // sftp always sets both times, so fill the missing one from current metadata.
+        let current = fs.metadata(path).await.map_err(parse_sftp_error)?;
+        let to_timestamp = |v: Option<DateTime<Utc>>, current: Option<UnixTimeStamp>| match v {
+            Some(v) => UnixTimeStamp::new(v.into()).map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "time is out of sftp range").set_source(err)
+            }),
+            None => Ok(current.unwrap_or_else(UnixTimeStamp::unix_epoch)),
+        };
+        let accessed = to_timestamp(args.accessed(), current.accessed())?;
+        let modified = to_timestamp(args.modified(), current.modified())?;
+
+        let meta = MetaDataBuilder::new().time(accessed, modified).create();
+        fs.set_metadata(path, meta)
+            .await
+            .map_err(parse_sftp_error)?;
+
+        Ok(RpSetTimes::default())
+    }
 }
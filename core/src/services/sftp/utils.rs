@@ -16,6 +16,7 @@
 // under the License.
 
 use openssh_sftp_client::metadata::MetaData as SftpMeta;
+use openssh_sftp_client::metadata::Permissions;
 
 use crate::EntryMode;
 use crate::Metadata;
@@ -30,6 +31,8 @@ impl From<SftpMeta> for Metadata {
                     EntryMode::FILE
                 } else if filetype.is_dir() {
                     EntryMode::DIR
+                } else if filetype.is_symlink() {
+                    EntryMode::Symlink
                 } else {
                     EntryMode::Unknown
                 }
@@ -46,6 +49,45 @@ impl From<SftpMeta> for Metadata {
             metadata.set_last_modified(modified.as_system_time().into());
         }
 
+        if let Some(accessed) = meta.accessed() {
+            metadata.set_accessed(accessed.as_system_time().into());
+        }
+
+        if let Some(perm) = meta.permissions() {
+            metadata.set_unix_mode(permissions_to_mode(perm));
+        }
+
+        if let Some(uid) = meta.uid() {
+            metadata.set_uid(uid);
+        }
+
+        if let Some(gid) = meta.gid() {
+            metadata.set_gid(gid);
+        }
+
         metadata
     }
 }
+
+/// Convert sftp permissions back into numeric mode bits.
+///
+/// This is the reverse of `From<u16> for Permissions`.
+fn permissions_to_mode(perm: Permissions) -> u32 {
+    [
+        (perm.suid(), 0o4000),
+        (perm.sgid(), 0o2000),
+        (perm.svtx(), 0o1000),
+        (perm.read_by_owner(), 0o400),
+        (perm.write_by_owner(), 0o200),
+        (perm.execute_by_owner(), 0o100),
+        (perm.read_by_group(), 0o40),
+        (perm.write_by_group(), 0o20),
+        (perm.execute_by_group(), 0o10),
+        (perm.read_by_other(), 0o4),
+        (perm.write_by_other(), 0o2),
+        (perm.execute_by_other(), 0o1),
+    ]
+    .into_iter()
+    .filter(|(set, _)| *set)
+    .fold(0, |mode, (_, bit)| mode | bit)
+}
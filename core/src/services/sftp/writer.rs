@@ -15,13 +15,16 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::io::SeekFrom;
 use std::pin::Pin;
 
 use bytes::Buf;
 use openssh_sftp_client::file::File;
 use openssh_sftp_client::file::TokioCompatFile;
+use tokio::io::AsyncSeekExt;
 use tokio::io::AsyncWriteExt;
 
+use super::error::parse_sftp_error;
 use crate::raw::*;
 use crate::*;
 
@@ -52,6 +55,43 @@ impl oio::Write for SftpWriter {
         Ok(())
     }
 
+    async fn write_at(&mut self, offset: u64, mut bs: Buffer) -> Result<()> {
+        // Sftp sends offset along with every write request, so we need to
+        // flush pending writes before moving the offset.
+        self.file.flush().await.map_err(new_std_io_error)?;
+        let pos = self.file.offset();
+
+        self.file
+            .seek(SeekFrom::Start(offset))
+            .await
+            .map_err(new_std_io_error)?;
+        while bs.has_remaining() {
+            let n = self
+                .file
+                .write(bs.chunk())
+                .await
+                .map_err(new_std_io_error)?;
+            bs.advance(n);
+        }
+        self.file.flush().await.map_err(new_std_io_error)?;
+
+        self.file
+            .seek(SeekFrom::Start(pos))
+            .await
+            .map_err(new_std_io_error)?;
+        Ok(())
+    }
+
+    async fn set_len(&mut self, len: u64) -> Result<()> {
+        self.file.flush().await.map_err(new_std_io_error)?;
+        self.file
+            .as_mut()
+            .as_mut_file()
+            .set_len(len)
+            .await
+            .map_err(parse_sftp_error)
+    }
+
     async fn close(&mut self) -> Result<Metadata> {
         self.file.shutdown().await.map_err(new_std_io_error)?;
 
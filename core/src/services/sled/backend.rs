@@ -17,8 +17,12 @@
 
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::ops::Bound;
 use std::str;
 
+use sled::transaction::ConflictableTransactionError;
+use sled::transaction::TransactionError;
+
 use crate::raw::adapters::kv;
 use crate::raw::*;
 use crate::services::SledConfig;
@@ -71,6 +75,14 @@ impl SledBuilder {
         self
     }
 
+    /// Store metadata like content type, etag and last modified with values.
+    ///
+    /// Values written before are still readable, they just don't have metadata.
+    pub fn enable_metadata(mut self, enabled: bool) -> Self {
+        self.config.enable_metadata = enabled;
+        self
+    }
+
     /// Set the tree for sled.
     pub fn tree(mut self, tree: &str) -> Self {
         self.config.tree = Some(tree.into());
@@ -108,11 +120,17 @@ impl Builder for SledBuilder {
                 .set_source(e)
         })?;
 
-        Ok(SledBackend::new(Adapter {
+        let backend = SledBackend::new(Adapter {
             datadir: datadir_path,
             tree,
         })
-        .with_root(self.config.root.as_deref().unwrap_or("/")))
+        .with_root(self.config.root.as_deref().unwrap_or("/"));
+
+        Ok(if self.config.enable_metadata {
+            backend.with_metadata()
+        } else {
+            backend
+        })
     }
 }
 
@@ -133,8 +151,40 @@ impl Debug for Adapter {
     }
 }
 
+impl kv::ScanPage for Adapter {
+    async fn next_page(
+        &self,
+        prefix: &str,
+        start_after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<String>> {
+        let start = match start_after {
+            Some(key) => Bound::Excluded(key.as_bytes()),
+            None => Bound::Included(prefix.as_bytes()),
+        };
+
+        let mut keys = Vec::with_capacity(limit);
+        for key in self
+            .tree
+            .range::<&[u8], _>((start, Bound::Unbounded))
+            .keys()
+        {
+            let bs = key.map_err(parse_error)?.to_vec();
+            if !bs.starts_with(prefix.as_bytes()) || keys.len() >= limit {
+                break;
+            }
+            let key = String::from_utf8(bs).map_err(|err| {
+                Error::new(ErrorKind::Unexpected, "store key is not valid utf-8 string")
+                    .set_source(err)
+            })?;
+            keys.push(key);
+        }
+        Ok(keys)
+    }
+}
+
 impl kv::Adapter for Adapter {
-    type Scanner = kv::Scanner;
+    type Scanner = kv::PagedScanner<Self>;
 
     fn info(&self) -> kv::Info {
         kv::Info::new(
@@ -144,6 +194,7 @@ impl kv::Adapter for Adapter {
                 read: true,
                 write: true,
                 list: true,
+                transaction: true,
                 shared: false,
                 ..Default::default()
             },
@@ -171,21 +222,45 @@ impl kv::Adapter for Adapter {
         Ok(())
     }
 
-    async fn scan(&self, path: &str) -> Result<Self::Scanner> {
-        let it = self.tree.scan_prefix(path).keys();
-        let mut res = Vec::default();
+    async fn transaction(
+        &self,
+        checks: Vec<(String, Option<Buffer>)>,
+        mutations: Vec<(String, Option<Buffer>)>,
+    ) -> Result<bool> {
+        let checks: Vec<_> = checks
+            .into_iter()
+            .map(|(k, v)| (k, v.map(|v| v.to_vec())))
+            .collect();
+        let mutations: Vec<_> = mutations
+            .into_iter()
+            .map(|(k, v)| (k, v.map(|v| v.to_vec())))
+            .collect();
 
-        for i in it {
-            let bs = i.map_err(parse_error)?.to_vec();
-            let v = String::from_utf8(bs).map_err(|err| {
-                Error::new(ErrorKind::Unexpected, "store key is not valid utf-8 string")
-                    .set_source(err)
-            })?;
+        // The closure might be called multiple times on conflicts.
+        let res = self.tree.transaction(|tx| {
+            for (path, expected) in &checks {
+                if tx.get(path)?.as_deref() != expected.as_deref() {
+                    return Err(ConflictableTransactionError::Abort(()));
+                }
+            }
+            for (path, value) in &mutations {
+                match value {
+                    Some(value) => tx.insert(path.as_str(), value.as_slice())?,
+                    None => tx.remove(path.as_str())?,
+                };
+            }
+            Ok(())
+        });
 
-            res.push(v);
+        match res {
+            Ok(()) => Ok(true),
+            Err(TransactionError::Abort(())) => Ok(false),
+            Err(TransactionError::Storage(err)) => Err(parse_error(err)),
         }
+    }
 
-        Ok(Box::new(kv::ScanStdIter::new(res.into_iter().map(Ok))))
+    async fn scan(&self, path: &str, opts: kv::ScanOptions) -> Result<Self::Scanner> {
+        Ok(kv::PagedScanner::new(self.clone(), path, opts))
     }
 }
 
@@ -32,6 +32,8 @@ pub struct SledConfig {
     pub root: Option<String>,
     /// The tree for sled.
     pub tree: Option<String>,
+    /// Store metadata like content type, etag and last modified with values.
+    pub enable_metadata: bool,
 }
 
 impl Debug for SledConfig {
@@ -40,6 +42,7 @@ impl Debug for SledConfig {
             .field("datadir", &self.datadir)
             .field("root", &self.root)
             .field("tree", &self.tree)
+            .field("enable_metadata", &self.enable_metadata)
             .finish()
     }
 }
@@ -17,16 +17,10 @@
 
 use std::fmt::Debug;
 use std::fmt::Formatter;
-use std::pin::Pin;
 use std::str::FromStr;
-use std::task::Context;
-use std::task::Poll;
 
-use futures::stream::BoxStream;
-use futures::Stream;
-use futures::StreamExt;
-use ouroboros::self_referencing;
 use sqlx::sqlite::SqliteConnectOptions;
+use sqlx::SqliteConnection;
 use sqlx::SqlitePool;
 use tokio::sync::OnceCell;
 
@@ -91,6 +85,14 @@ impl SqliteBuilder {
         self
     }
 
+    /// Store metadata like content type, etag and last modified with values.
+    ///
+    /// Values written before are still readable, they just don't have metadata.
+    pub fn enable_metadata(mut self, enabled: bool) -> Self {
+        self.config.enable_metadata = enabled;
+        self
+    }
+
     /// Set the table name of the sqlite service to read/write.
     pub fn table(mut self, table: &str) -> Self {
         if !table.is_empty() {
@@ -158,14 +160,20 @@ impl Builder for SqliteBuilder {
 
         let root = normalize_root(self.config.root.as_deref().unwrap_or("/"));
 
-        Ok(SqliteBackend::new(Adapter {
+        let backend = SqliteBackend::new(Adapter {
             pool: OnceCell::new(),
             config,
             table,
             key_field,
             value_field,
         })
-        .with_normalized_root(root))
+        .with_normalized_root(root);
+
+        Ok(if self.config.enable_metadata {
+            backend.with_metadata()
+        } else {
+            backend
+        })
     }
 }
 
@@ -194,34 +202,77 @@ impl Adapter {
     }
 }
 
-#[self_referencing]
-pub struct SqliteScanner {
-    pool: SqlitePool,
-    query: String,
-
-    #[borrows(pool, query)]
-    #[covariant]
-    stream: BoxStream<'this, Result<String>>,
-}
-
-impl Stream for SqliteScanner {
-    type Item = Result<String>;
+impl Adapter {
+    /// Check and apply the transaction within the transaction opened on `conn`.
+    async fn apply(
+        &self,
+        conn: &mut SqliteConnection,
+        checks: Vec<(String, Option<Buffer>)>,
+        mutations: Vec<(String, Option<Buffer>)>,
+    ) -> Result<bool> {
+        for (path, expected) in checks {
+            let actual: Option<Vec<u8>> = sqlx::query_scalar(&format!(
+                "SELECT `{}` FROM `{}` WHERE `{}` = $1 LIMIT 1",
+                self.value_field, self.table, self.key_field
+            ))
+            .bind(path)
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(parse_sqlite_error)?;
+            if actual != expected.map(|v| v.to_vec()) {
+                return Ok(false);
+            }
+        }
 
-    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        self.with_stream_mut(|s| s.poll_next_unpin(cx))
+        for (path, value) in mutations {
+            match value {
+                Some(value) => sqlx::query(&format!(
+                    "INSERT OR REPLACE INTO `{}` (`{}`, `{}`) VALUES ($1, $2)",
+                    self.table, self.key_field, self.value_field,
+                ))
+                .bind(path)
+                .bind(value.to_vec())
+                .execute(&mut *conn)
+                .await
+                .map_err(parse_sqlite_error)?,
+                None => sqlx::query(&format!(
+                    "DELETE FROM `{}` WHERE `{}` = $1",
+                    self.table, self.key_field
+                ))
+                .bind(path)
+                .execute(&mut *conn)
+                .await
+                .map_err(parse_sqlite_error)?,
+            };
+        }
+        Ok(true)
     }
 }
 
-unsafe impl Sync for SqliteScanner {}
+impl kv::ScanPage for Adapter {
+    async fn next_page(
+        &self,
+        prefix: &str,
+        start_after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<String>> {
+        let pool = self.get_client().await?;
 
-impl kv::Scan for SqliteScanner {
-    async fn next(&mut self) -> Result<Option<String>> {
-        <Self as StreamExt>::next(self).await.transpose()
+        sqlx::query_scalar(&format!(
+            "SELECT `{}` FROM `{}` WHERE `{}` LIKE $1 AND ($2 IS NULL OR `{}` > $2) ORDER BY `{}` LIMIT $3",
+            self.key_field, self.table, self.key_field, self.key_field, self.key_field
+        ))
+        .bind(format!("{prefix}%"))
+        .bind(start_after)
+        .bind(limit as i64)
+        .fetch_all(pool)
+        .await
+        .map_err(parse_sqlite_error)
     }
 }
 
 impl kv::Adapter for Adapter {
-    type Scanner = SqliteScanner;
+    type Scanner = kv::PagedScanner<Self>;
 
     fn info(&self) -> kv::Info {
         kv::Info::new(
@@ -232,6 +283,7 @@ impl kv::Adapter for Adapter {
                 write: true,
                 delete: true,
                 list: true,
+                transaction: true,
                 shared: false,
                 ..Default::default()
             },
@@ -284,25 +336,40 @@ impl kv::Adapter for Adapter {
         Ok(())
     }
 
-    async fn scan(&self, path: &str) -> Result<Self::Scanner> {
+    async fn transaction(
+        &self,
+        checks: Vec<(String, Option<Buffer>)>,
+        mutations: Vec<(String, Option<Buffer>)>,
+    ) -> Result<bool> {
         let pool = self.get_client().await?;
-        let stream = SqliteScannerBuilder {
-            pool: pool.clone(),
-            query: format!(
-                "SELECT `{}` FROM `{}` WHERE `{}` LIKE $1",
-                self.key_field, self.table, self.key_field
-            ),
-            stream_builder: |pool, query| {
-                sqlx::query_scalar(query)
-                    .bind(format!("{path}%"))
-                    .fetch(pool)
-                    .map(|v| v.map_err(parse_sqlite_error))
-                    .boxed()
-            },
-        }
-        .build();
+        let mut conn = pool.acquire().await.map_err(parse_sqlite_error)?;
+
+        // Take the write lock at begin, so that keys checked can't be changed by
+        // others before commit.
+        sqlx::query("BEGIN IMMEDIATE")
+            .execute(&mut *conn)
+            .await
+            .map_err(parse_sqlite_error)?;
+        let res = self.apply(&mut conn, checks, mutations).await;
+        let end = if matches!(res, Ok(true)) {
+            "COMMIT"
+        } else {
+            "ROLLBACK"
+        };
+        let ended = sqlx::query(end)
+            .execute(&mut *conn)
+            .await
+            .map_err(parse_sqlite_error);
+
+        let applied = res?;
+        ended?;
+        Ok(applied)
+    }
 
-        Ok(stream)
+    async fn scan(&self, path: &str, opts: kv::ScanOptions) -> Result<Self::Scanner> {
+        // Connect before cloning, so that the scanner shares the same pool.
+        self.get_client().await?;
+        Ok(kv::PagedScanner::new(self.clone(), path, opts))
     }
 }
 
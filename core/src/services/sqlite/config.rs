@@ -53,6 +53,8 @@ pub struct SqliteConfig {
     ///
     /// default: "/"
     pub root: Option<String>,
+    /// Store metadata like content type, etag and last modified with values.
+    pub enable_metadata: bool,
 }
 
 impl Debug for SqliteConfig {
@@ -63,7 +65,8 @@ impl Debug for SqliteConfig {
             .field("table", &self.table)
             .field("key_field", &self.key_field)
             .field("value_field", &self.value_field)
-            .field("root", &self.root);
+            .field("root", &self.root)
+            .field("enable_metadata", &self.enable_metadata);
 
         d.finish_non_exhaustive()
     }
@@ -281,8 +281,46 @@ impl Adapter {
     }
 }
 
+impl kv::ScanPage for Adapter {
+    async fn next_page(
+        &self,
+        prefix: &str,
+        start_after: Option<&str>,
+        limit: usize,
+    ) -> crate::Result<Vec<String>> {
+        let key = if self.key_field == "id" {
+            "meta::id(id)".to_string()
+        } else {
+            self.key_field.clone()
+        };
+        let (op, start) = match start_after {
+            Some(v) => (">", v),
+            None => (">=", prefix),
+        };
+        let query = format!(
+            "SELECT VALUE {key} FROM type::table($table) \
+            WHERE {key} {op} $start AND {key} < $end \
+            ORDER BY {key} LIMIT $limit"
+        );
+
+        let mut result = self
+            .get_connection()
+            .await?
+            .query(query)
+            .bind(("table", self.table.to_string()))
+            .bind(("start", start.to_string()))
+            .bind(("end", format!("{prefix}{}", char::MAX)))
+            .bind(("limit", limit))
+            .await
+            .map_err(parse_surrealdb_error)?;
+
+        let keys: Vec<String> = result.take(0).map_err(parse_surrealdb_error)?;
+        Ok(keys)
+    }
+}
+
 impl kv::Adapter for Adapter {
-    type Scanner = ();
+    type Scanner = kv::PagedScanner<Self>;
 
     fn info(&self) -> kv::Info {
         kv::Info::new(
@@ -291,6 +329,7 @@ impl kv::Adapter for Adapter {
             Capability {
                 read: true,
                 write: true,
+                list: true,
                 shared: true,
                 ..Default::default()
             },
@@ -358,6 +397,10 @@ impl kv::Adapter for Adapter {
             .map_err(parse_surrealdb_error)?;
         Ok(())
     }
+
+    async fn scan(&self, path: &str, opts: kv::ScanOptions) -> crate::Result<Self::Scanner> {
+        Ok(kv::PagedScanner::new(self.clone(), path, opts))
+    }
 }
 
 fn parse_surrealdb_error(err: surrealdb::Error) -> Error {
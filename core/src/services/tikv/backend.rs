@@ -17,6 +17,7 @@
 
 use std::fmt::Debug;
 use std::fmt::Formatter;
+use std::ops::Bound;
 
 use tikv_client::Config;
 use tikv_client::RawClient;
@@ -183,8 +184,41 @@ impl Adapter {
     }
 }
 
+impl kv::ScanPage for Adapter {
+    async fn next_page(
+        &self,
+        prefix: &str,
+        start_after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<String>> {
+        let start = match start_after {
+            Some(key) => Bound::Excluded(key.as_bytes().to_vec()),
+            None => Bound::Included(prefix.as_bytes().to_vec()),
+        };
+        // Keys are valid utf-8 which never contains `0xff`.
+        let mut end = prefix.as_bytes().to_vec();
+        end.push(0xff);
+
+        let keys = self
+            .get_connection()
+            .await?
+            .scan_keys((start, Bound::Excluded(end)), limit as u32)
+            .await
+            .map_err(parse_tikv_error)?;
+
+        keys.into_iter()
+            .map(|key| {
+                String::from_utf8(key.into()).map_err(|err| {
+                    Error::new(ErrorKind::Unexpected, "store key is not valid utf-8")
+                        .set_source(err)
+                })
+            })
+            .collect()
+    }
+}
+
 impl kv::Adapter for Adapter {
-    type Scanner = ();
+    type Scanner = kv::PagedScanner<Self>;
 
     fn info(&self) -> kv::Info {
         kv::Info::new(
@@ -193,6 +227,8 @@ impl kv::Adapter for Adapter {
             Capability {
                 read: true,
                 write: true,
+                list: true,
+                lock: true,
                 shared: true,
                 ..Default::default()
             },
@@ -224,6 +260,26 @@ impl kv::Adapter for Adapter {
             .await
             .map_err(parse_tikv_error)
     }
+
+    async fn scan(&self, path: &str, opts: kv::ScanOptions) -> Result<Self::Scanner> {
+        Ok(kv::PagedScanner::new(self.clone(), path, opts))
+    }
+
+    async fn compare_and_swap(
+        &self,
+        path: &str,
+        current: Option<Buffer>,
+        value: Buffer,
+    ) -> Result<bool> {
+        let (_, swapped) = self
+            .get_connection()
+            .await?
+            .with_atomic_for_cas()
+            .compare_and_swap(path.to_owned(), current.map(|v| v.to_vec()), value.to_vec())
+            .await
+            .map_err(parse_tikv_error)?;
+        Ok(swapped)
+    }
 }
 
 fn parse_tikv_error(e: tikv_client::Error) -> Error {
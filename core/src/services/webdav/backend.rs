@@ -120,6 +120,14 @@ impl WebdavBuilder {
         self
     }
 
+    /// Enable symlink support via redirect reference resources.
+    ///
+    /// It requires the server supports [RFC4437](https://datatracker.ietf.org/doc/html/rfc4437).
+    pub fn enable_redirect_ref(mut self, enabled: bool) -> Self {
+        self.config.enable_redirect_ref = enabled;
+        self
+    }
+
     /// Specify the http client that used by this service.
     ///
     /// # Notes
@@ -179,6 +187,7 @@ impl Builder for WebdavBuilder {
                     .set_root(&root)
                     .set_native_capability(Capability {
                         stat: true,
+                        stat_with_follow_symlinks: self.config.enable_redirect_ref,
 
                         read: true,
 
@@ -186,6 +195,8 @@ impl Builder for WebdavBuilder {
                         write_can_empty: true,
 
                         create_dir: true,
+                        create_symlink: self.config.enable_redirect_ref,
+                        read_link: self.config.enable_redirect_ref,
                         delete: true,
 
                         copy: !self.config.disable_copy,
@@ -247,11 +258,32 @@ impl Access for WebdavBackend {
         Ok(RpCreateDir::default())
     }
 
-    async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
-        let metadata = self.core.webdav_stat(path).await?;
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let metadata = if args.follow_symlinks() {
+            self.core.webdav_stat(path).await?
+        } else {
+            self.core.webdav_stat_redirect_ref(path).await?
+        };
         Ok(RpStat::new(metadata))
     }
 
+    async fn create_symlink(&self, path: &str, args: OpCreateSymlink) -> Result<RpCreateSymlink> {
+        // Ensure parent path exists
+        self.core.webdav_mkcol(get_parent(path)).await?;
+
+        self.core.webdav_mkredirectref(path, args.target()).await?;
+        Ok(RpCreateSymlink::default())
+    }
+
+    async fn read_link(&self, path: &str, _: OpReadLink) -> Result<RpReadLink> {
+        let metadata = self.core.webdav_stat_redirect_ref(path).await?;
+        match metadata.symlink_target() {
+            Some(target) => Ok(RpReadLink::new(target)),
+            None => Err(Error::new(ErrorKind::Unexpected, "path is not a symlink")
+                .with_context("path", path)),
+        }
+    }
+
     async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
         let resp = self.core.webdav_get(path, args.range(), &args).await?;
 
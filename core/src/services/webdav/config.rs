@@ -38,6 +38,10 @@ pub struct WebdavConfig {
     pub root: Option<String>,
     /// WebDAV Service doesn't support copy.
     pub disable_copy: bool,
+    /// Enable symlink support via redirect reference resources.
+    ///
+    /// It requires the server supports [RFC4437](https://datatracker.ietf.org/doc/html/rfc4437).
+    pub enable_redirect_ref: bool,
 }
 
 impl Debug for WebdavConfig {
@@ -36,6 +36,10 @@ use crate::*;
 ///
 /// rfc4918 9.1: retrieve all properties define in specification
 static PROPFIND_REQUEST: &str = r#"<?xml version="1.0" encoding="utf-8" ?><D:propfind xmlns:D="DAV:"><D:allprop/></D:propfind>"#;
+/// The request to query all properties of a redirect reference resource.
+///
+/// rfc4437 doesn't require `DAV:reftarget` to be returned by `allprop`, so we include it explicitly.
+static PROPFIND_REDIRECT_REF_REQUEST: &str = r#"<?xml version="1.0" encoding="utf-8" ?><D:propfind xmlns:D="DAV:"><D:allprop/><D:include><D:reftarget/></D:include></D:propfind>"#;
 
 /// The header to specify the depth of the query.
 ///
@@ -68,6 +72,13 @@ static HEADER_DESTINATION: &str = "Destination";
 ///
 /// reference: [RFC4918: 10.6.  Overwrite Header](https://datatracker.ietf.org/doc/html/rfc4918#section-10.6)
 static HEADER_OVERWRITE: &str = "Overwrite";
+/// The header to specify whether the method should be applied to the redirect reference
+/// resource itself instead of its target.
+///
+/// Valid values are `T` and `F`.
+///
+/// reference: [RFC4437: 12.1. Apply-To-Redirect-Ref Request Header](https://datatracker.ietf.org/doc/html/rfc4437#section-12.1)
+static HEADER_APPLY_TO_REDIRECT_REF: &str = "Apply-To-Redirect-Ref";
 
 pub struct WebdavCore {
     pub info: Arc<AccessorInfo>,
@@ -89,16 +100,33 @@ impl Debug for WebdavCore {
 impl WebdavCore {
     pub async fn webdav_stat(&self, path: &str) -> Result<Metadata> {
         let path = build_rooted_abs_path(&self.root, path);
-        self.webdav_stat_rooted_abs_path(&path).await
+        self.webdav_stat_rooted_abs_path(&path, false).await
+    }
+
+    /// Stat the redirect reference resource itself instead of its target.
+    pub async fn webdav_stat_redirect_ref(&self, path: &str) -> Result<Metadata> {
+        let path = build_rooted_abs_path(&self.root, path);
+        self.webdav_stat_rooted_abs_path(&path, true).await
     }
 
     /// Input path must be `rooted_abs_path`.
-    async fn webdav_stat_rooted_abs_path(&self, rooted_abs_path: &str) -> Result<Metadata> {
+    async fn webdav_stat_rooted_abs_path(
+        &self,
+        rooted_abs_path: &str,
+        apply_to_redirect_ref: bool,
+    ) -> Result<Metadata> {
         let url = format!("{}{}", self.endpoint, percent_encode_path(rooted_abs_path));
         let mut req = Request::builder().method("PROPFIND").uri(url);
 
+        let body = if apply_to_redirect_ref {
+            req = req.header(HEADER_APPLY_TO_REDIRECT_REF, "T");
+            PROPFIND_REDIRECT_REF_REQUEST
+        } else {
+            PROPFIND_REQUEST
+        };
+
         req = req.header(header::CONTENT_TYPE, "application/xml");
-        req = req.header(header::CONTENT_LENGTH, PROPFIND_REQUEST.len());
+        req = req.header(header::CONTENT_LENGTH, body.len());
         if let Some(auth) = &self.authorization {
             req = req.header(header::AUTHORIZATION, auth);
         }
@@ -108,7 +136,7 @@ impl WebdavCore {
 
         let req = req
             .extension(Operation::Stat)
-            .body(Buffer::from(Bytes::from(PROPFIND_REQUEST)))
+            .body(Buffer::from(Bytes::from(body)))
             .map_err(new_request_build_error)?;
 
         let resp = self.info.http_client().send(req).await?;
@@ -269,6 +297,42 @@ impl WebdavCore {
         self.info.http_client().send(req).await
     }
 
+    /// Create a redirect reference resource at path which points to target.
+    ///
+    /// reference: [RFC4437: 6. MKREDIRECTREF Method](https://datatracker.ietf.org/doc/html/rfc4437#section-6)
+    pub async fn webdav_mkredirectref(&self, path: &str, target: &str) -> Result<()> {
+        let path = build_rooted_abs_path(&self.root, path);
+        let url = format!("{}{}", self.endpoint, percent_encode_path(&path));
+
+        let body = format!(
+            r#"<?xml version="1.0" encoding="utf-8" ?><D:mkredirectref xmlns:D="DAV:"><D:reftarget><D:href>{}</D:href></D:reftarget></D:mkredirectref>"#,
+            quick_xml::escape::escape(target)
+        );
+
+        let mut req = Request::builder().method("MKREDIRECTREF").uri(&url);
+
+        req = req.header(header::CONTENT_TYPE, "application/xml");
+        req = req.header(header::CONTENT_LENGTH, body.len());
+        if let Some(auth) = &self.authorization {
+            req = req.header(header::AUTHORIZATION, auth);
+        }
+
+        let req = req
+            .extension(Operation::CreateSymlink)
+            .body(Buffer::from(Bytes::from(body)))
+            .map_err(new_request_build_error)?;
+
+        let resp = self.info.http_client().send(req).await?;
+        match resp.status() {
+            StatusCode::CREATED | StatusCode::OK => Ok(()),
+            StatusCode::METHOD_NOT_ALLOWED => Err(Error::new(
+                ErrorKind::AlreadyExists,
+                "the path already exists or the server doesn't support redirect reference",
+            )),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
     pub async fn webdav_list(&self, path: &str, args: &OpList) -> Result<Response<Buffer>> {
         let path = build_rooted_abs_path(&self.root, path);
         let url = format!("{}{}", self.endpoint, percent_encode_path(&path));
@@ -307,7 +371,7 @@ impl WebdavCore {
         let mut dirs = VecDeque::default();
 
         loop {
-            match self.webdav_stat_rooted_abs_path(path).await {
+            match self.webdav_stat_rooted_abs_path(path, false).await {
                 // Dir exists, break the loop.
                 Ok(_) => {
                     break;
@@ -392,6 +456,7 @@ pub fn parse_propstat(propstat: &Propstat) -> Result<Metadata> {
                 getcontenttype,
                 getetag,
                 resourcetype,
+                reftarget,
                 ..
             },
         status,
@@ -408,13 +473,17 @@ pub fn parse_propstat(propstat: &Propstat) -> Result<Metadata> {
         }
     }
 
-    let mode: EntryMode = if resourcetype.value == Some(ResourceType::Collection) {
-        EntryMode::DIR
-    } else {
-        EntryMode::FILE
+    let mode: EntryMode = match resourcetype.value {
+        Some(ResourceType::Collection) => EntryMode::DIR,
+        Some(ResourceType::Redirectref) => EntryMode::Symlink,
+        None => EntryMode::FILE,
     };
     let mut m = Metadata::new(mode);
 
+    if let Some(v) = reftarget {
+        m.set_symlink_target(&v.href);
+    }
+
     if let Some(v) = getcontentlength {
         m.set_content_length(v.parse::<u64>().unwrap());
     }
@@ -459,6 +528,12 @@ pub struct Prop {
     pub getcontentlength: Option<String>,
     pub getcontenttype: Option<String>,
     pub resourcetype: ResourceTypeContainer,
+    pub reftarget: Option<RefTarget>,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq, Clone)]
+pub struct RefTarget {
+    pub href: String,
 }
 
 #[derive(Deserialize, Debug, PartialEq, Eq, Clone)]
@@ -471,6 +546,7 @@ pub struct ResourceTypeContainer {
 #[serde(rename_all = "lowercase")]
 pub enum ResourceType {
     Collection,
+    Redirectref,
 }
 
 #[cfg(test)]
@@ -582,6 +658,32 @@ mod tests {
         assert_eq!(response.propstat.status, "HTTP/1.1 200 OK");
     }
 
+    #[test]
+    fn test_response_redirect_ref() {
+        let xml = r#"<D:response>
+        <D:href>/test_link</D:href>
+        <D:propstat>
+          <D:prop>
+            <D:displayname>test_link</D:displayname>
+            <D:getlastmodified>Sat, 07 May 2022 05:52:22 GMT</D:getlastmodified>
+            <D:resourcetype><D:redirectref/></D:resourcetype>
+            <D:reftarget><D:href>/test_file</D:href></D:reftarget>
+          </D:prop>
+          <D:status>HTTP/1.1 200 OK</D:status>
+        </D:propstat>
+      </D:response>"#;
+
+        let response = from_str::<PropfindResponse>(xml).unwrap();
+        assert_eq!(
+            response.propstat.prop.resourcetype.value,
+            Some(ResourceType::Redirectref)
+        );
+
+        let meta = parse_propstat(&response.propstat).unwrap();
+        assert_eq!(meta.mode(), EntryMode::Symlink);
+        assert_eq!(meta.symlink_target(), Some("/test_file"));
+    }
+
     #[test]
     fn test_with_multiple_items_simple() {
         let xml = r#"<D:multistatus xmlns:D="DAV:">
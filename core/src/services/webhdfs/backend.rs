@@ -20,6 +20,8 @@ use std::fmt::Formatter;
 use std::sync::Arc;
 
 use bytes::Buf;
+use chrono::DateTime;
+use chrono::Utc;
 use http::Response;
 use http::StatusCode;
 use log::debug;
@@ -194,6 +196,9 @@ impl Builder for WebhdfsBuilder {
                 create_dir: true,
                 delete: true,
 
+                set_permissions: true,
+                set_times: true,
+
                 list: true,
 
                 shared: true,
@@ -306,7 +311,7 @@ impl Access for WebhdfsBackend {
                     .map_err(new_json_deserialize_error)?
                     .file_status;
 
-                let meta = match file_status.ty {
+                let mut meta = match file_status.ty {
                     FileStatusType::Directory => Metadata::new(EntryMode::DIR),
                     FileStatusType::File => Metadata::new(EntryMode::FILE)
                         .with_content_length(file_status.length)
@@ -314,6 +319,7 @@ impl Access for WebhdfsBackend {
                             file_status.modification_time,
                         )?),
                 };
+                file_status.fill_posix_attrs(&mut meta)?;
 
                 Ok(RpStat::new(meta))
             }
@@ -373,4 +379,31 @@ impl Access for WebhdfsBackend {
         let l = WebhdfsLister::new(self.core.clone(), path);
         Ok((RpList::default(), oio::PageLister::new(l)))
     }
+
+    async fn set_permissions(
+        &self,
+        path: &str,
+        args: OpSetPermissions,
+    ) -> Result<RpSetPermissions> {
+        let resp = self.core.webhdfs_set_permission(path, args.mode()).await?;
+
+        match resp.status() {
+            StatusCode::OK => Ok(RpSetPermissions::default()),
+            _ => Err(parse_error(resp)),
+        }
+    }
+
+    async fn set_times(&self, path: &str, args: OpSetTimes) -> Result<RpSetTimes> {
+        let to_millis = |v: Option<DateTime<Utc>>| v.map(|v| v.timestamp_millis()).unwrap_or(-1);
+
+        let resp = self
+            .core
+            .webhdfs_set_times(path, to_millis(args.accessed()), to_millis(args.modified()))
+            .await?;
+
+        match resp.status() {
+            StatusCode::OK => Ok(RpSetTimes::default()),
+            _ => Err(parse_error(resp)),
+        }
+    }
 }
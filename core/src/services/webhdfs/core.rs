@@ -368,6 +368,57 @@ impl WebhdfsCore {
         self.info.http_client().send(req).await
     }
 
+    pub async fn webhdfs_set_permission(&self, path: &str, mode: u32) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let mut url = format!(
+            "{}/webhdfs/v1/{}?op=SETPERMISSION&permission={:o}",
+            self.endpoint,
+            percent_encode_path(&p),
+            mode,
+        );
+        if let Some(user) = &self.user_name {
+            url += format!("&user.name={user}").as_str();
+        }
+        if let Some(auth) = &self.auth {
+            url += format!("&{auth}").as_str();
+        }
+
+        let req = Request::put(&url)
+            .extension(Operation::SetPermissions)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.info.http_client().send(req).await
+    }
+
+    /// Times are in milliseconds, `-1` means unchanged.
+    pub async fn webhdfs_set_times(
+        &self,
+        path: &str,
+        access_time: i64,
+        modification_time: i64,
+    ) -> Result<Response<Buffer>> {
+        let p = build_abs_path(&self.root, path);
+        let mut url = format!(
+            "{}/webhdfs/v1/{}?op=SETTIMES&accesstime={access_time}&modificationtime={modification_time}",
+            self.endpoint,
+            percent_encode_path(&p),
+        );
+        if let Some(user) = &self.user_name {
+            url += format!("&user.name={user}").as_str();
+        }
+        if let Some(auth) = &self.auth {
+            url += format!("&{auth}").as_str();
+        }
+
+        let req = Request::put(&url)
+            .extension(Operation::SetTimes)
+            .body(Buffer::new())
+            .map_err(new_request_build_error)?;
+
+        self.info.http_client().send(req).await
+    }
+
     pub async fn webhdfs_delete(&self, path: &str) -> Result<Response<Buffer>> {
         let p = build_abs_path(&self.root, path);
         let mut url = format!(
@@ -106,7 +106,7 @@ impl oio::PageList for WebhdfsLister {
                 format!("{}/{}", self.path, status.path_suffix)
             };
 
-            let meta = match status.ty {
+            let mut meta = match status.ty {
                 FileStatusType::Directory => Metadata::new(EntryMode::DIR),
                 FileStatusType::File => Metadata::new(EntryMode::FILE)
                     .with_content_length(status.length)
@@ -114,6 +114,7 @@ impl oio::PageList for WebhdfsLister {
                         status.modification_time,
                     )?),
             };
+            status.fill_posix_attrs(&mut meta)?;
 
             if meta.mode().is_file() {
                 path = path.trim_end_matches('/').to_string();
@@ -19,6 +19,9 @@
 
 use serde::Deserialize;
 
+use crate::raw::*;
+use crate::*;
+
 #[derive(Debug, Deserialize)]
 pub(super) struct BooleanResp {
     pub boolean: bool,
@@ -66,6 +69,11 @@ pub(super) struct FileStatuses {
 pub struct FileStatus {
     pub length: u64,
     pub modification_time: i64,
+    #[serde(default)]
+    pub access_time: i64,
+    /// Permission in octal string like `755`.
+    #[serde(default)]
+    pub permission: String,
 
     pub path_suffix: String,
     #[serde(rename = "type")]
@@ -80,6 +88,22 @@ pub enum FileStatusType {
     File,
 }
 
+impl FileStatus {
+    /// Fill access time and permission bits into `meta`.
+    ///
+    /// Owner and group are names in webhdfs, so uid and gid are not available.
+    pub fn fill_posix_attrs(&self, meta: &mut Metadata) -> Result<()> {
+        // HDFS doesn't track access time of directories, it's returned as `0`.
+        if self.access_time > 0 {
+            meta.set_accessed(parse_datetime_from_from_timestamp_millis(self.access_time)?);
+        }
+        if let Ok(mode) = u32::from_str_radix(&self.permission, 8) {
+            meta.set_unix_mode(mode & 0o7777);
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -108,6 +132,15 @@ mod test {
         assert_eq!(status.file_status.modification_time, 1320173277227);
         assert_eq!(status.file_status.path_suffix, "");
         assert_eq!(status.file_status.ty, FileStatusType::Directory);
+        assert_eq!(status.file_status.permission, "777");
+
+        let mut meta = Metadata::new(EntryMode::DIR);
+        status
+            .file_status
+            .fill_posix_attrs(&mut meta)
+            .expect("must success");
+        assert_eq!(meta.unix_mode(), Some(0o777));
+        assert_eq!(meta.accessed(), None);
     }
 
     #[tokio::test]
@@ -82,6 +82,8 @@ pub struct Capability {
     pub stat_with_override_content_type: bool,
     /// Indicates if versions stat operations are supported.
     pub stat_with_version: bool,
+    /// Indicates if stat operations can be told not to follow symlinks.
+    pub stat_with_follow_symlinks: bool,
 
     /// Indicates if the operator supports read operations.
     pub read: bool,
@@ -110,6 +112,13 @@ pub struct Capability {
     pub write_can_empty: bool,
     /// Indicates if append operations are supported.
     pub write_can_append: bool,
+    /// Indicates if writers can write at arbitrary offsets via `write_at`.
+    ///
+    /// Services that emulate it on top of blocks require every offset to be
+    /// aligned to the writer's `chunk`.
+    pub write_can_random_access: bool,
+    /// Indicates if writers can truncate or extend the file via `set_len`.
+    pub write_can_set_len: bool,
     /// Indicates if Content-Type can be specified during write operations.
     pub write_with_content_type: bool,
     /// Indicates if Content-Disposition can be specified during write operations.
@@ -126,6 +135,8 @@ pub struct Capability {
     pub write_with_if_not_exists: bool,
     /// Indicates if custom user metadata can be attached during write operations.
     pub write_with_user_metadata: bool,
+    /// Indicates if a ttl can be specified during write operations.
+    pub write_with_ttl: bool,
     /// Maximum size supported for multipart uploads.
     /// For example, AWS S3 supports up to 5GiB per part in multipart uploads.
     pub write_multi_max_size: Option<usize>,
@@ -139,6 +150,21 @@ pub struct Capability {
     /// Indicates if directory creation is supported.
     pub create_dir: bool,
 
+    /// Indicates if symlink creation is supported.
+    pub create_symlink: bool,
+    /// Indicates if reading the target of a symlink is supported.
+    pub read_link: bool,
+
+    /// Indicates if changing unix permission bits is supported.
+    pub set_permissions: bool,
+    /// Indicates if changing access and modification times is supported.
+    pub set_times: bool,
+
+    /// Indicates if updating metadata of an existing object is supported.
+    pub update_metadata: bool,
+    /// Indicates if conditional metadata updates using If-Match are supported.
+    pub update_metadata_with_if_match: bool,
+
     /// Indicates if delete operations are supported.
     pub delete: bool,
     /// Indicates if versions delete operations are supported.
@@ -150,6 +176,21 @@ pub struct Capability {
     pub copy: bool,
     /// Indicates if conditional copy operations with if-not-exists are supported.
     pub copy_with_if_not_exists: bool,
+    /// Indicates if copy operations can be conditional on the source's ETag matching.
+    pub copy_with_if_match: bool,
+    /// Indicates if copy operations can be conditional on the source's ETag not matching.
+    pub copy_with_if_none_match: bool,
+
+    /// Indicates if concatenating objects into a new object is supported.
+    pub concat: bool,
+    /// Minimum size of every source except the last one for native concat.
+    /// For example, S3 `UploadPartCopy` requires at least 5MiB per part.
+    pub concat_min_source_size: Option<usize>,
+    /// Maximum size of every source for native concat.
+    /// For example, S3 `UploadPartCopy` supports up to 5GiB per part.
+    pub concat_max_source_size: Option<usize>,
+    /// Maximum number of sources supported by a single native concat.
+    pub concat_max_sources: Option<usize>,
 
     /// Indicates if rename operations are supported.
     pub rename: bool,
@@ -160,6 +201,9 @@ pub struct Capability {
     pub list_with_limit: bool,
     /// Indicates if list operations support continuation from a specific point.
     pub list_with_start_after: bool,
+    /// Indicates if list operations support resuming from a continuation token
+    /// returned by a previous page.
+    pub list_with_continuation_token: bool,
     /// Indicates if recursive listing is supported.
     pub list_with_recursive: bool,
     /// Indicates if versions listing is supported.
@@ -170,6 +214,11 @@ pub struct Capability {
     /// Indicates if listing with deleted files included is supported.
     pub list_with_deleted: bool,
 
+    /// Indicates if listing in-progress multipart uploads is supported.
+    pub list_uploads: bool,
+    /// Indicates if aborting an in-progress multipart upload is supported.
+    pub abort_upload: bool,
+
     /// Indicates if presigned URL generation is supported.
     pub presign: bool,
     /// Indicates if presigned URLs for read operations are supported.
@@ -180,6 +229,16 @@ pub struct Capability {
     pub presign_write: bool,
     /// Indicates if presigned URLs for delete operations are supported.
     pub presign_delete: bool,
+    /// Indicates if presigned multipart uploads are supported.
+    pub presign_multipart: bool,
+    /// Indicates if presigned POST forms with post policy are supported.
+    pub presign_post: bool,
+
+    /// Indicates if locks with leases and fencing tokens are supported.
+    pub lock: bool,
+
+    /// Indicates if atomic transactions across multiple paths are supported.
+    pub transaction: bool,
 
     /// Indicate if the operator supports shared access.
     pub shared: bool,
@@ -183,6 +183,30 @@ impl WriteGenerator<oio::Writer> {
         Ok(n)
     }
 
+    /// Write the entire buffer into writer at given offset.
+    ///
+    /// Buffered data of sequential writes will be flushed first so that it's not
+    /// reordered after this write.
+    pub async fn write_at(&mut self, offset: u64, bs: Buffer) -> Result<()> {
+        self.flush().await?;
+        self.w.write_at_dyn(offset, bs).await
+    }
+
+    /// Truncate or extend the file to given length.
+    pub async fn set_len(&mut self, len: u64) -> Result<()> {
+        self.flush().await?;
+        self.w.set_len_dyn(len).await
+    }
+
+    /// Flush all buffered data into underlying writer.
+    async fn flush(&mut self) -> Result<()> {
+        if !self.buffer.is_empty() {
+            let buf = self.buffer.take().collect();
+            self.w.write_dyn(buf).await?;
+        }
+        Ok(())
+    }
+
     /// Finish the write process.
     pub async fn close(&mut self) -> Result<Metadata> {
         loop {
@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::fmt::Display;
+use std::fmt::Formatter;
 use std::pin::Pin;
 use std::task::ready;
 use std::task::Context;
@@ -54,6 +56,51 @@ impl Lister {
             errored: false,
         })
     }
+
+    /// Fetch the next page of entries along with the [`ContinuationToken`] of the page after it.
+    ///
+    /// The returned token can be passed to [`ListOptions::continuation_token`] in a later
+    /// list call to resume listing exactly where this page ended. `None` means there is
+    /// no more page to fetch.
+    ///
+    /// The returned page could be empty even if the token is `Some`, users should keep
+    /// calling `next_page` until the token is `None`.
+    ///
+    /// Please don't mix `next_page` with polling this lister as a `Stream`.
+    ///
+    /// # Notes
+    ///
+    /// This function requires [`Capability::list_with_continuation_token`].
+    pub async fn next_page(&mut self) -> Result<(Vec<Entry>, Option<ContinuationToken>)> {
+        if self.fut.is_some() {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                "lister is being polled as stream, next_page is not allowed",
+            ));
+        }
+        if self.errored {
+            return Ok((vec![], None));
+        }
+        let Some(lister) = self.lister.as_mut() else {
+            return Ok((vec![], None));
+        };
+
+        match lister.next_page_dyn().await {
+            Ok((entries, token)) => {
+                if token.is_none() {
+                    self.lister = None;
+                }
+                Ok((
+                    entries.into_iter().map(|e| e.into_entry()).collect(),
+                    token.map(ContinuationToken::new),
+                ))
+            }
+            Err(err) => {
+                self.errored = true;
+                Err(err)
+            }
+        }
+    }
 }
 
 impl Stream for Lister {
@@ -95,6 +142,48 @@ impl Stream for Lister {
     }
 }
 
+/// ContinuationToken is an opaque token returned by [`Lister::next_page`].
+///
+/// Users can store it as a string (for example, in a web API response) and pass it back
+/// via [`ListOptions::continuation_token`] to resume listing from the next page.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ContinuationToken(String);
+
+impl ContinuationToken {
+    /// Create a new continuation token from the string returned before.
+    pub fn new(token: impl Into<String>) -> Self {
+        Self(token.into())
+    }
+
+    /// Get the token as `&str`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Consume the token and return the inner string.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl From<String> for ContinuationToken {
+    fn from(token: String) -> Self {
+        Self(token)
+    }
+}
+
+impl From<&str> for ContinuationToken {
+    fn from(token: &str) -> Self {
+        Self(token.to_string())
+    }
+}
+
+impl Display for ContinuationToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "services-azblob")]
 mod tests {
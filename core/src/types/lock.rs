@@ -0,0 +1,260 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::sync::Weak;
+use std::time::Duration;
+
+use backon::DefaultSleeper;
+use backon::Sleeper;
+
+use crate::raw::*;
+use crate::*;
+
+/// The key of a lease, `(service, absolute path, owner)`.
+type LeaseKey = (String, String, String);
+
+/// Leases held in this process, so that guards of the same owner share one lease.
+static LEASES: LazyLock<Mutex<HashMap<LeaseKey, Weak<Lease>>>> = LazyLock::new(Mutex::default);
+
+/// Lock is a lock held on a path, returned by [`Operator::lock`].
+///
+/// The lease of the lock is renewed in the background while the lock is held,
+/// and the lock will be released on drop. Call [`Lock::release`] to release it
+/// and wait for the result instead.
+///
+/// Acquiring the lock again with the same owner in this process returns another
+/// guard of the same lease, which is only released after all its guards are
+/// released or dropped.
+///
+/// # Fencing
+///
+/// Every holder of the lock gets a [`Lock::token`] greater than all previous
+/// holders. A holder might lose the lock without knowing it, for example after
+/// a long pause, so writes guarded by the lock should carry the token and be
+/// rejected by the receiver if it has seen a greater one.
+pub struct Lock {
+    lease: Arc<Lease>,
+}
+
+/// Lease is the lease of a lock shared by all guards of the same owner.
+struct Lease {
+    acc: Accessor,
+    path: String,
+    owner: String,
+    token: u64,
+
+    lost: Arc<AtomicBool>,
+    /// The background task that renews the lease, dropped to stop renewing.
+    renew: Option<Task<()>>,
+}
+
+impl Debug for Lock {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Lock")
+            .field("path", &self.lease.path)
+            .field("owner", &self.lease.owner)
+            .field("token", &self.lease.token)
+            .field("lost", &self.is_lost())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Lock {
+    /// Acquire the lock on `path`, retry until it's acquired or `timeout` reached.
+    pub(crate) async fn acquire(
+        acc: Accessor,
+        path: String,
+        opts: options::LockOptions,
+    ) -> Result<Self> {
+        let owner = opts
+            .owner
+            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+        let ttl = opts.ttl;
+
+        let info = acc.info();
+        let key = (
+            format!("{}:{}", info.scheme(), info.name()),
+            build_abs_path(&info.root(), &path),
+            owner.clone(),
+        );
+        let held = held_lease(&mut lock_leases(), &key);
+        if let Some(lease) = held {
+            return Ok(Self { lease });
+        }
+
+        let interval = (ttl / 10).clamp(Duration::from_millis(50), Duration::from_secs(1));
+        let mut waited = Duration::ZERO;
+        let token = loop {
+            match acc.lock(&path, OpLock::new(ttl, &owner)).await {
+                Ok(rp) => break rp.token(),
+                Err(err) if err.kind() == ErrorKind::ConditionNotMatch => {
+                    if opts.timeout.is_some_and(|v| waited >= v) {
+                        return Err(err);
+                    }
+                    DefaultSleeper::default().sleep(interval).await;
+                    waited += interval;
+                }
+                Err(err) => return Err(err),
+            }
+        };
+
+        let lost = Arc::new(AtomicBool::new(false));
+        let renew = acc.info().executor().execute(renew_lease(
+            acc.clone(),
+            path.clone(),
+            OpLock::new(ttl, &owner).with_token(token),
+            lost.clone(),
+        ));
+        let mut lease = Lease {
+            acc,
+            path,
+            owner,
+            token,
+            lost,
+            renew: Some(renew),
+        };
+
+        let mut leases = lock_leases();
+        // The same owner acquired the lock concurrently, the service keeps one
+        // lease for both of them so share it instead of releasing it twice.
+        if let Some(held) = held_lease(&mut leases, &key) {
+            lease.renew.take();
+            return Ok(Self { lease: held });
+        }
+        let lease = Arc::new(lease);
+        leases.insert(key, Arc::downgrade(&lease));
+        Ok(Self { lease })
+    }
+
+    /// Path of this lock.
+    pub fn path(&self) -> &str {
+        &self.lease.path
+    }
+
+    /// Owner of this lock.
+    pub fn owner(&self) -> &str {
+        &self.lease.owner
+    }
+
+    /// Fencing token of this lock.
+    pub fn token(&self) -> u64 {
+        self.lease.token
+    }
+
+    /// Check if the lease of this lock has been lost.
+    ///
+    /// The lease is lost if it's taken by others or can't be renewed before it
+    /// expires. The lock is not held anymore in this case.
+    pub fn is_lost(&self) -> bool {
+        self.lease.lost.load(Ordering::Acquire)
+    }
+
+    /// Release this lock.
+    ///
+    /// The lease is released by the last guard only, releasing other guards of
+    /// the same owner keeps it held.
+    ///
+    /// Returns an error with [`ErrorKind::ConditionNotMatch`] if the lease has been lost.
+    pub async fn release(self) -> Result<()> {
+        let lease = {
+            // Hold the leases so that no guard is added while checking.
+            let _leases = lock_leases();
+            Arc::try_unwrap(self.lease)
+        };
+        let mut lease = match lease {
+            Ok(lease) => lease,
+            Err(lease) if lease.lost.load(Ordering::Acquire) => {
+                return Err(Error::new(
+                    ErrorKind::ConditionNotMatch,
+                    "lease of the lock has been lost",
+                ))
+            }
+            // Other guards still hold the lease.
+            Err(_) => return Ok(()),
+        };
+
+        // Stop renewing before releasing.
+        lease.renew.take();
+
+        let args = OpUnlock::new(&lease.owner, lease.token);
+        lease.acc.unlock(&lease.path, args).await?;
+        Ok(())
+    }
+}
+
+impl Drop for Lease {
+    fn drop(&mut self) {
+        // Released already.
+        let Some(renew) = self.renew.take() else {
+            return;
+        };
+        drop(renew);
+
+        let acc = self.acc.clone();
+        let path = std::mem::take(&mut self.path);
+        let args = OpUnlock::new(&self.owner, self.token);
+        self.acc
+            .info()
+            .executor()
+            .into_inner()
+            .execute(Box::pin(async move {
+                // Release is best effort, the lease will expire otherwise.
+                let _ = acc.unlock(&path, args).await;
+            }));
+    }
+}
+
+fn lock_leases() -> std::sync::MutexGuard<'static, HashMap<LeaseKey, Weak<Lease>>> {
+    // Leases are always valid even if a holder panicked.
+    LEASES.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+/// Get the lease of `key` that is still held in this process.
+fn held_lease(leases: &mut HashMap<LeaseKey, Weak<Lease>>, key: &LeaseKey) -> Option<Arc<Lease>> {
+    leases.retain(|_, v| v.strong_count() > 0);
+    leases
+        .get(key)
+        .and_then(Weak::upgrade)
+        .filter(|v| !v.lost.load(Ordering::Acquire))
+}
+
+/// Renew the lease every third of ttl until it's lost.
+async fn renew_lease(acc: Accessor, path: String, args: OpLock, lost: Arc<AtomicBool>) {
+    let interval = args.ttl() / 3;
+    let mut since_renewed = Duration::ZERO;
+    loop {
+        DefaultSleeper::default().sleep(interval).await;
+        since_renewed += interval;
+
+        match acc.lock(&path, args.clone()).await {
+            Ok(_) => since_renewed = Duration::ZERO,
+            Err(err) if err.kind() == ErrorKind::ConditionNotMatch => break,
+            // Keep trying while the lease is still valid.
+            Err(_) if since_renewed + interval < args.ttl() => continue,
+            Err(_) => break,
+        }
+    }
+    lost.store(true, Ordering::Release);
+}
@@ -64,6 +64,14 @@ pub struct Metadata {
     etag: Option<String>,
     last_modified: Option<DateTime<Utc>>,
     version: Option<String>,
+    symlink_target: Option<String>,
+
+    unix_mode: Option<u32>,
+    uid: Option<u32>,
+    gid: Option<u32>,
+    accessed: Option<DateTime<Utc>>,
+    created: Option<DateTime<Utc>>,
+    expires_at: Option<DateTime<Utc>>,
 
     user_metadata: Option<HashMap<String, String>>,
 }
@@ -87,6 +95,13 @@ impl Metadata {
             etag: None,
             content_disposition: None,
             version: None,
+            symlink_target: None,
+            unix_mode: None,
+            uid: None,
+            gid: None,
+            accessed: None,
+            created: None,
+            expires_at: None,
             user_metadata: None,
         }
     }
@@ -118,6 +133,11 @@ impl Metadata {
         matches!(self.mode, EntryMode::DIR)
     }
 
+    /// Returns `true` if this metadata is for a symlink.
+    pub fn is_symlink(&self) -> bool {
+        matches!(self.mode, EntryMode::Symlink)
+    }
+
     /// Checks whether the metadata corresponds to the most recent version of the file.
     ///
     /// This function is particularly useful when working with versioned objects,
@@ -420,6 +440,141 @@ impl Metadata {
         self
     }
 
+    /// Target of the symlink.
+    ///
+    /// `symlink_target` is only available when the mode is [`EntryMode::Symlink`] and the
+    /// service returns it along with metadata. The target is returned as is, it could be
+    /// a relative or absolute path in the service's own format.
+    pub fn symlink_target(&self) -> Option<&str> {
+        self.symlink_target.as_deref()
+    }
+
+    /// Set the target of the symlink.
+    pub fn set_symlink_target(&mut self, v: &str) -> &mut Self {
+        self.symlink_target = Some(v.to_string());
+        self
+    }
+
+    /// With the target of the symlink.
+    pub fn with_symlink_target(mut self, v: String) -> Self {
+        self.symlink_target = Some(v);
+        self
+    }
+
+    /// Unix permission bits of this entry, for example `0o644`.
+    ///
+    /// Only the permission bits (including setuid, setgid and sticky) are
+    /// returned, the file type bits are represented by [`Metadata::mode`].
+    ///
+    /// `unix_mode` is only available on services that expose POSIX attributes
+    /// like `fs`, `sftp` and `hdfs`.
+    pub fn unix_mode(&self) -> Option<u32> {
+        self.unix_mode
+    }
+
+    /// Set the unix permission bits of this entry.
+    pub fn set_unix_mode(&mut self, v: u32) -> &mut Self {
+        self.unix_mode = Some(v);
+        self
+    }
+
+    /// With the unix permission bits of this entry.
+    pub fn with_unix_mode(mut self, v: u32) -> Self {
+        self.unix_mode = Some(v);
+        self
+    }
+
+    /// Numeric user id of the owner of this entry.
+    pub fn uid(&self) -> Option<u32> {
+        self.uid
+    }
+
+    /// Set the numeric user id of the owner of this entry.
+    pub fn set_uid(&mut self, v: u32) -> &mut Self {
+        self.uid = Some(v);
+        self
+    }
+
+    /// With the numeric user id of the owner of this entry.
+    pub fn with_uid(mut self, v: u32) -> Self {
+        self.uid = Some(v);
+        self
+    }
+
+    /// Numeric group id of the owner of this entry.
+    pub fn gid(&self) -> Option<u32> {
+        self.gid
+    }
+
+    /// Set the numeric group id of the owner of this entry.
+    pub fn set_gid(&mut self, v: u32) -> &mut Self {
+        self.gid = Some(v);
+        self
+    }
+
+    /// With the numeric group id of the owner of this entry.
+    pub fn with_gid(mut self, v: u32) -> Self {
+        self.gid = Some(v);
+        self
+    }
+
+    /// Last access time of this entry.
+    ///
+    /// Services may not update access time on every read (for example `fs`
+    /// mounted with `relatime`), so this value is only a hint.
+    pub fn accessed(&self) -> Option<DateTime<Utc>> {
+        self.accessed
+    }
+
+    /// Set the last access time of this entry.
+    pub fn set_accessed(&mut self, v: DateTime<Utc>) -> &mut Self {
+        self.accessed = Some(v);
+        self
+    }
+
+    /// With the last access time of this entry.
+    pub fn with_accessed(mut self, v: DateTime<Utc>) -> Self {
+        self.accessed = Some(v);
+        self
+    }
+
+    /// Creation time of this entry.
+    pub fn created(&self) -> Option<DateTime<Utc>> {
+        self.created
+    }
+
+    /// Set the creation time of this entry.
+    pub fn set_created(&mut self, v: DateTime<Utc>) -> &mut Self {
+        self.created = Some(v);
+        self
+    }
+
+    /// With the creation time of this entry.
+    pub fn with_created(mut self, v: DateTime<Utc>) -> Self {
+        self.created = Some(v);
+        self
+    }
+
+    /// Expiration time of this entry.
+    ///
+    /// Entries written with [`crate::options::WriteOptions::ttl`] will be removed by
+    /// the service after this time.
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.expires_at
+    }
+
+    /// Set the expiration time of this entry.
+    pub fn set_expires_at(&mut self, v: DateTime<Utc>) -> &mut Self {
+        self.expires_at = Some(v);
+        self
+    }
+
+    /// With the expiration time of this entry.
+    pub fn with_expires_at(mut self, v: DateTime<Utc>) -> Self {
+        self.expires_at = Some(v);
+        self
+    }
+
     /// User defined metadata of this entry
     ///
     /// The prefix of the user defined metadata key(for example: in oss, it's x-oss-meta-)
@@ -0,0 +1,127 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+
+use crate::Metadata;
+
+/// MetadataUpdate describes the changes applied by [`Operator::update_metadata`].
+///
+/// Fields that are not set are left unchanged. `user_metadata` replaces the whole
+/// user metadata of the object instead of merging into it.
+///
+/// [`Operator::update_metadata`]: crate::Operator::update_metadata
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MetadataUpdate {
+    content_type: Option<String>,
+    cache_control: Option<String>,
+    content_disposition: Option<String>,
+    user_metadata: Option<HashMap<String, String>>,
+    if_match: Option<String>,
+}
+
+impl MetadataUpdate {
+    /// Create a new empty metadata update.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the `Content-Type` of the object.
+    pub fn with_content_type(mut self, v: &str) -> Self {
+        self.content_type = Some(v.to_string());
+        self
+    }
+
+    /// Set the `Cache-Control` of the object.
+    pub fn with_cache_control(mut self, v: &str) -> Self {
+        self.cache_control = Some(v.to_string());
+        self
+    }
+
+    /// Set the `Content-Disposition` of the object.
+    pub fn with_content_disposition(mut self, v: &str) -> Self {
+        self.content_disposition = Some(v.to_string());
+        self
+    }
+
+    /// Replace the user metadata of the object.
+    pub fn with_user_metadata(mut self, v: impl IntoIterator<Item = (String, String)>) -> Self {
+        self.user_metadata = Some(v.into_iter().collect());
+        self
+    }
+
+    /// Only update the object if its current etag matches the given one.
+    ///
+    /// Returns [`ErrorKind::ConditionNotMatch`](crate::ErrorKind::ConditionNotMatch)
+    /// if the object has been changed since the etag was read.
+    pub fn with_if_match(mut self, v: &str) -> Self {
+        self.if_match = Some(v.to_string());
+        self
+    }
+
+    /// Get the `Content-Type` to set.
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// Get the `Cache-Control` to set.
+    pub fn cache_control(&self) -> Option<&str> {
+        self.cache_control.as_deref()
+    }
+
+    /// Get the `Content-Disposition` to set.
+    pub fn content_disposition(&self) -> Option<&str> {
+        self.content_disposition.as_deref()
+    }
+
+    /// Get the user metadata to set.
+    pub fn user_metadata(&self) -> Option<&HashMap<String, String>> {
+        self.user_metadata.as_ref()
+    }
+
+    /// Get the etag the object must match.
+    pub fn if_match(&self) -> Option<&str> {
+        self.if_match.as_deref()
+    }
+
+    /// Check if this update changes nothing.
+    pub fn is_empty(&self) -> bool {
+        self.content_type.is_none()
+            && self.cache_control.is_none()
+            && self.content_disposition.is_none()
+            && self.user_metadata.is_none()
+    }
+
+    /// Apply this update to `meta`.
+    ///
+    /// Services that can only replace all metadata at once use this to build the
+    /// full metadata from the current one.
+    pub fn apply(&self, meta: &mut Metadata) {
+        if let Some(v) = &self.content_type {
+            meta.set_content_type(v);
+        }
+        if let Some(v) = &self.cache_control {
+            meta.set_cache_control(v);
+        }
+        if let Some(v) = &self.content_disposition {
+            meta.set_content_disposition(v);
+        }
+        if let Some(v) = &self.user_metadata {
+            *meta = std::mem::take(meta).with_user_metadata(v.clone());
+        }
+    }
+}
@@ -27,13 +27,31 @@ pub use entry::Entry;
 mod metadata;
 pub use metadata::Metadata;
 
+mod metadata_update;
+pub use metadata_update::MetadataUpdate;
+
+mod post_policy;
+pub use post_policy::PostPolicy;
+
+mod upload;
+pub use upload::PresignedMultipart;
+pub use upload::Upload;
+pub use upload::UploadPart;
+
 mod read;
 pub use read::*;
 
 mod write;
 pub use write::*;
 
+mod lock;
+pub use lock::Lock;
+
+mod transaction;
+pub use transaction::Transaction;
+
 mod list;
+pub use list::ContinuationToken;
 pub use list::Lister;
 
 mod delete;
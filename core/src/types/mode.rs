@@ -25,6 +25,8 @@ pub enum EntryMode {
     FILE,
     /// DIR means the path can be listed.
     DIR,
+    /// Symlink means the path is a symbolic link, its target can be read via `read_link`.
+    Symlink,
     /// Unknown means we don't know what we can do on this path.
     Unknown,
 }
@@ -40,6 +42,11 @@ impl EntryMode {
         self == EntryMode::DIR
     }
 
+    /// Check if this mode is Symlink.
+    pub fn is_symlink(self) -> bool {
+        self == EntryMode::Symlink
+    }
+
     /// Create entry mode from given path.
     #[allow(dead_code)]
     pub(crate) fn from_path(path: &str) -> Self {
@@ -62,6 +69,7 @@ impl Display for EntryMode {
         match self {
             EntryMode::FILE => write!(f, "file"),
             EntryMode::DIR => write!(f, "dir"),
+            EntryMode::Symlink => write!(f, "symlink"),
             EntryMode::Unknown => write!(f, "unknown"),
         }
     }
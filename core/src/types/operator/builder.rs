@@ -180,6 +180,8 @@ impl Operator {
             Scheme::YandexDisk => Self::from_iter::<services::YandexDisk>(iter)?.finish(),
             #[cfg(feature = "services-pcloud")]
             Scheme::Pcloud => Self::from_iter::<services::Pcloud>(iter)?.finish(),
+            #[cfg(feature = "services-archive")]
+            Scheme::Archive => Self::from_iter::<services::Archive>(iter)?.finish(),
             #[cfg(feature = "services-azblob")]
             Scheme::Azblob => Self::from_iter::<services::Azblob>(iter)?.finish(),
             #[cfg(feature = "services-azdls")]
@@ -212,6 +214,8 @@ impl Operator {
             Scheme::Ghac => Self::from_iter::<services::Ghac>(iter)?.finish(),
             #[cfg(feature = "services-gridfs")]
             Scheme::Gridfs => Self::from_iter::<services::Gridfs>(iter)?.finish(),
+            #[cfg(feature = "services-git")]
+            Scheme::Git => Self::from_iter::<services::Git>(iter)?.finish(),
             #[cfg(feature = "services-github")]
             Scheme::Github => Self::from_iter::<services::Github>(iter)?.finish(),
             #[cfg(feature = "services-hdfs")]
@@ -276,6 +280,8 @@ impl Operator {
             Scheme::Webhdfs => Self::from_iter::<services::Webhdfs>(iter)?.finish(),
             #[cfg(feature = "services-redb")]
             Scheme::Redb => Self::from_iter::<services::Redb>(iter)?.finish(),
+            #[cfg(feature = "services-lmdb")]
+            Scheme::Lmdb => Self::from_iter::<services::Lmdb>(iter)?.finish(),
             #[cfg(feature = "services-mongodb")]
             Scheme::Mongodb => Self::from_iter::<services::Mongodb>(iter)?.finish(),
             #[cfg(feature = "services-hdfs-native")]
@@ -18,6 +18,12 @@
 use std::future::Future;
 use std::time::Duration;
 
+use backon::BackoffBuilder;
+use backon::DefaultSleeper;
+use backon::ExponentialBuilder;
+use backon::Sleeper;
+use chrono::DateTime;
+use chrono::Utc;
 use futures::Stream;
 use futures::StreamExt;
 use futures::TryStreamExt;
@@ -1186,12 +1192,373 @@ impl Operator {
             );
         }
 
-        let mut op = OpCopy::new();
-        if opts.if_not_exists {
-            op = op.with_if_not_exists(true);
+        acc.copy(&from, &to, OpCopy::from(opts)).await.map(|_| ())
+    }
+
+    /// Concatenate `sources` into a new file at `path`.
+    ///
+    /// # Notes
+    ///
+    /// - `path` and every source must be a file.
+    /// - `path` will be overwritten if it exists, sources are left unchanged.
+    /// - If `path` is one of the sources, an `IsSameFile` error will occur.
+    /// - Services like gcs and s3 concat sources without downloading them, others
+    ///   (or sources that don't fit [`Capability::concat_min_source_size`],
+    ///   [`Capability::concat_max_source_size`] and [`Capability::concat_max_sources`])
+    ///   are concatenated by streaming.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.concat("path/to/file", &["path/to/part-1", "path/to/part-2"])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn concat(&self, path: &str, sources: &[&str]) -> Result<()> {
+        self.concat_options(path, sources, options::ConcatOptions::default())
+            .await
+    }
+
+    /// Concatenate `sources` into a new file at `path` with additional options.
+    ///
+    /// # Options
+    ///
+    /// Check [`options::ConcatOptions`] for all available options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # use opendal::options::ConcatOptions;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let opts = ConcatOptions {
+    ///     content_type: Some("text/plain".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// op.concat_options("path/to/file", &["path/to/part-1", "path/to/part-2"], opts)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn concat_options(
+        &self,
+        path: &str,
+        sources: &[&str],
+        opts: impl Into<options::ConcatOptions>,
+    ) -> Result<()> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "concat path is a directory")
+                    .with_operation("Operator::concat")
+                    .with_context("service", self.info().scheme())
+                    .with_context("path", &path),
+            );
+        }
+
+        if sources.is_empty() {
+            return Err(
+                Error::new(ErrorKind::Unexpected, "concat sources are empty")
+                    .with_operation("Operator::concat")
+                    .with_context("service", self.info().scheme())
+                    .with_context("path", &path),
+            );
+        }
+
+        let mut normalized = Vec::with_capacity(sources.len());
+        for source in sources {
+            let source = normalize_path(source);
+
+            if !validate_path(&source, EntryMode::FILE) {
+                return Err(
+                    Error::new(ErrorKind::IsADirectory, "concat source is a directory")
+                        .with_operation("Operator::concat")
+                        .with_context("service", self.info().scheme())
+                        .with_context("source", source),
+                );
+            }
+
+            if source == path {
+                return Err(
+                    Error::new(ErrorKind::IsSameFile, "concat source is the target path")
+                        .with_operation("Operator::concat")
+                        .with_context("service", self.info().scheme())
+                        .with_context("path", &path),
+                );
+            }
+
+            normalized.push(source);
+        }
+
+        let op = OpConcat::from((normalized, opts.into()));
+        self.inner().concat(&path, op).await?;
+
+        Ok(())
+    }
+
+    /// Acquire a lock on `path`, waiting until it's released by others.
+    ///
+    /// The returned [`Lock`] renews its lease in the background and releases the
+    /// lock on drop. Writers guarded by the lock should pass [`Lock::token`] to
+    /// the systems they write to, so that writes from a holder that lost its
+    /// lease can be rejected.
+    ///
+    /// # Notes
+    ///
+    /// - Services like etcd and redis lock with their native primitives, others
+    ///   (that support conditional writes) store the lock state in the file at `path`.
+    /// - Leases are renewed by the executor of this operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let lock = op.lock("path/to/lock").await?;
+    /// op.write("path/to/file", "hello").await?;
+    /// lock.release().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn lock(&self, path: &str) -> Result<Lock> {
+        self.lock_options(path, options::LockOptions::default())
+            .await
+    }
+
+    /// Acquire a lock on `path` with additional options.
+    ///
+    /// # Options
+    ///
+    /// Check [`options::LockOptions`] for all available options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # use opendal::options::LockOptions;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let opts = LockOptions {
+    ///     ttl: Duration::from_secs(10),
+    ///     owner: Some("writer-1".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let lock = op.lock_options("path/to/lock", opts).await?;
+    /// println!("fencing token: {}", lock.token());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn lock_options(
+        &self,
+        path: &str,
+        opts: impl Into<options::LockOptions>,
+    ) -> Result<Lock> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "lock path is a directory")
+                    .with_operation("Operator::lock")
+                    .with_context("service", self.info().scheme())
+                    .with_context("path", &path),
+            );
+        }
+
+        Lock::acquire(self.inner().clone(), path, opts.into()).await
+    }
+
+    /// Start a transaction that commits writes and deletes on multiple paths atomically.
+    ///
+    /// Writes and deletes are buffered in the returned [`Transaction`] until
+    /// [`Transaction::commit`]. Paths read in the transaction are checked again
+    /// at commit, so the commit fails with [`ErrorKind::ConditionNotMatch`] if
+    /// they have been changed by others.
+    ///
+    /// # Notes
+    ///
+    /// - Requires [`Capability::transaction`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut txn = op.transaction()?;
+    /// let manifest = txn.read("manifest").await?;
+    /// txn.write("segments/2", "segment")?;
+    /// txn.write("manifest", "segments/1\nsegments/2")?;
+    /// txn.commit().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn transaction(&self) -> Result<Transaction> {
+        if !self.info().full_capability().transaction {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "service doesn't support transaction",
+            )
+            .with_operation("Operator::transaction")
+            .with_context("service", self.info().scheme()));
         }
 
-        acc.copy(&from, &to, op).await.map(|_| ())
+        Ok(Transaction::new(self.clone()))
+    }
+
+    /// Update the content of `path` with `f` by optimistic concurrency control.
+    ///
+    /// `f` is called with the current content (`None` if the file doesn't exist)
+    /// and returns the new content to write, or `None` to leave the file as is.
+    /// The new content is written only if the file hasn't been changed since
+    /// read, otherwise the whole read-modify-write is retried with backoff, so
+    /// `f` might be called more than once.
+    ///
+    /// Returns the content of the file after update.
+    ///
+    /// # Notes
+    ///
+    /// - Requires [`Capability::write_with_if_match`] and [`Capability::write_with_if_not_exists`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # use opendal::Buffer;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.update("path/to/counter", |current| {
+    ///     let count = match current {
+    ///         Some(bs) => String::from_utf8_lossy(&bs.to_vec()).parse::<u64>().unwrap_or_default(),
+    ///         None => 0,
+    ///     };
+    ///     Ok(Some(Buffer::from((count + 1).to_string())))
+    /// })
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update<F>(&self, path: &str, f: F) -> Result<Option<Buffer>>
+    where
+        F: FnMut(Option<Buffer>) -> Result<Option<Buffer>>,
+    {
+        self.update_options(path, options::UpdateOptions::default(), f)
+            .await
+    }
+
+    /// Update the content of `path` with `f` and additional options.
+    ///
+    /// # Options
+    ///
+    /// Check [`options::UpdateOptions`] for all available options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// # use opendal::options::UpdateOptions;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let opts = UpdateOptions { max_retries: 3 };
+    /// op.update_options("path/to/file", opts, |current| {
+    ///     Ok(current.or_else(|| Some("init".into())))
+    /// })
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_options<F>(
+        &self,
+        path: &str,
+        opts: impl Into<options::UpdateOptions>,
+        mut f: F,
+    ) -> Result<Option<Buffer>>
+    where
+        F: FnMut(Option<Buffer>) -> Result<Option<Buffer>>,
+    {
+        let path = normalize_path(path);
+        let opts = opts.into();
+
+        let cap = self.info().full_capability();
+        if !cap.write_with_if_match || !cap.write_with_if_not_exists {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "service doesn't support conditional writes",
+            )
+            .with_operation("Operator::update")
+            .with_context("service", self.info().scheme())
+            .with_context("path", &path));
+        }
+
+        let mut backoff = ExponentialBuilder::default()
+            .with_jitter()
+            .with_max_times(opts.max_retries)
+            .build();
+        loop {
+            let err = match self.try_update(&path, cap.read_with_if_match, &mut f).await {
+                Ok(content) => return Ok(content),
+                Err(err) if err.kind() == ErrorKind::ConditionNotMatch => err,
+                Err(err) => return Err(err),
+            };
+            match backoff.next() {
+                Some(dur) => DefaultSleeper::default().sleep(dur).await,
+                None => return Err(err.with_operation("Operator::update")),
+            }
+        }
+    }
+
+    /// Read `path` with its etag, apply `f` and write back if the etag still matches.
+    async fn try_update<F>(
+        &self,
+        path: &str,
+        read_with_if_match: bool,
+        f: &mut F,
+    ) -> Result<Option<Buffer>>
+    where
+        F: FnMut(Option<Buffer>) -> Result<Option<Buffer>>,
+    {
+        let (current, etag) = match self.stat(path).await {
+            Ok(meta) => {
+                let etag = meta.etag().map(|v| v.to_string()).ok_or_else(|| {
+                    Error::new(ErrorKind::Unsupported, "service doesn't return etag")
+                        .with_operation("Operator::update")
+                        .with_context("service", self.info().scheme())
+                        .with_context("path", path)
+                })?;
+                let opts = options::ReadOptions {
+                    if_match: read_with_if_match.then(|| etag.clone()),
+                    ..Default::default()
+                };
+                (Some(self.read_options(path, opts).await?), Some(etag))
+            }
+            Err(err) if err.kind() == ErrorKind::NotFound => (None, None),
+            Err(err) => return Err(err),
+        };
+
+        let Some(content) = f(current.clone())? else {
+            return Ok(current);
+        };
+        let opts = options::WriteOptions {
+            if_not_exists: etag.is_none(),
+            if_match: etag,
+            ..Default::default()
+        };
+        self.write_options(path, content.clone(), opts).await?;
+        Ok(Some(content))
     }
 
     /// Rename a file from `from` to `to`.
@@ -1251,6 +1618,221 @@ impl Operator {
         Ok(())
     }
 
+    /// Create a symlink at `path` pointing to `target`.
+    ///
+    /// # Notes
+    ///
+    /// - `path` must be a file path.
+    /// - `target` is stored as is, it's not normalized nor joined with root. A relative
+    ///   target is resolved by the service relative to the parent of `path`.
+    /// - Returns `AlreadyExists` if `path` already exists.
+    ///
+    /// # Capability
+    ///
+    /// This function requires [`Capability::create_symlink`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.create_symlink("file", "path/to/link").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_symlink(&self, target: &str, path: &str) -> Result<()> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::Symlink) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "symlink path is a directory")
+                    .with_operation("Operator::create_symlink")
+                    .with_context("service", self.info().scheme())
+                    .with_context("path", &path),
+            );
+        }
+
+        if target.is_empty() {
+            return Err(Error::new(ErrorKind::Unexpected, "symlink target is empty")
+                .with_operation("Operator::create_symlink")
+                .with_context("service", self.info().scheme())
+                .with_context("path", &path));
+        }
+
+        self.inner()
+            .create_symlink(&path, OpCreateSymlink::new(target))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Read the target of the symlink at `path`.
+    ///
+    /// The target is returned as is, the same as it was created.
+    ///
+    /// # Capability
+    ///
+    /// This function requires [`Capability::read_link`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let target = op.read_link("path/to/link").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_link(&self, path: &str) -> Result<String> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::Symlink) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "symlink path is a directory")
+                    .with_operation("Operator::read_link")
+                    .with_context("service", self.info().scheme())
+                    .with_context("path", &path),
+            );
+        }
+
+        let rp = self.inner().read_link(&path, OpReadLink::new()).await?;
+
+        Ok(rp.into_target())
+    }
+
+    /// Set the unix permission bits of `path` to `mode`, for example `0o644`.
+    ///
+    /// # Notes
+    ///
+    /// - Only the permission bits of `mode` are applied, file type bits are ignored.
+    /// - Symlinks are followed.
+    ///
+    /// # Capability
+    ///
+    /// This function requires [`Capability::set_permissions`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.set_permissions("path/to/file", 0o600).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_permissions(&self, path: &str, mode: u32) -> Result<()> {
+        let path = normalize_path(path);
+
+        self.inner()
+            .set_permissions(&path, OpSetPermissions::new(mode & 0o7777))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Set the access time and modification time of `path`.
+    ///
+    /// Times given as `None` are left unchanged.
+    ///
+    /// # Capability
+    ///
+    /// This function requires [`Capability::set_times`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// use chrono::Utc;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// op.set_times("path/to/file", None, Some(Utc::now())).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_times(
+        &self,
+        path: &str,
+        accessed: Option<DateTime<Utc>>,
+        modified: Option<DateTime<Utc>>,
+    ) -> Result<()> {
+        let path = normalize_path(path);
+
+        let mut args = OpSetTimes::new();
+        if let Some(v) = accessed {
+            args = args.with_accessed(v);
+        }
+        if let Some(v) = modified {
+            args = args.with_modified(v);
+        }
+        if args.accessed().is_none() && args.modified().is_none() {
+            return Ok(());
+        }
+
+        self.inner().set_times(&path, args).await?;
+
+        Ok(())
+    }
+
+    /// Update metadata of an existing object without re-uploading it.
+    ///
+    /// Returns the metadata of the object after update.
+    ///
+    /// # Notes
+    ///
+    /// - Fields that are not set in `update` are left unchanged.
+    /// - The etag of the object may change after update, use the returned metadata
+    ///   for later conditional operations.
+    /// - Services like s3 implement this by copying the object onto itself.
+    ///
+    /// # Capability
+    ///
+    /// This function requires [`Capability::update_metadata`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    /// use opendal::MetadataUpdate;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let meta = op
+    ///     .update_metadata(
+    ///         "path/to/file",
+    ///         MetadataUpdate::new().with_content_type("application/json"),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_metadata(&self, path: &str, update: MetadataUpdate) -> Result<Metadata> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(Error::new(
+                ErrorKind::IsADirectory,
+                "update metadata path is a directory",
+            )
+            .with_operation("Operator::update_metadata")
+            .with_context("service", self.info().scheme())
+            .with_context("path", &path));
+        }
+
+        let rp = self
+            .inner()
+            .update_metadata(&path, OpUpdateMetadata::new(update))
+            .await?;
+
+        Ok(rp.into_metadata())
+    }
+
     /// Delete the given path.
     ///
     /// # Notes
@@ -1500,7 +2082,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir {}", entry.path())
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1542,7 +2124,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir like start a new list via meta.path()")
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1597,7 +2179,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir like start a new list via meta.path()")
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1646,7 +2228,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir like start a new list via meta.path()")
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1681,7 +2263,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir {}", entry.path())
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -1725,7 +2307,7 @@ impl Operator {
     ///         EntryMode::DIR => {
     ///             println!("Handling dir {}", entry.path())
     ///         }
-    ///         EntryMode::Unknown => continue,
+    ///         EntryMode::Symlink | EntryMode::Unknown => continue,
     ///     }
     /// }
     /// # Ok(())
@@ -2233,4 +2815,342 @@ impl Operator {
         let rp = acc.presign(&path, op).await?;
         Ok(rp.into_presigned_request())
     }
+
+    /// Presign a POST form to upload content with a post policy.
+    ///
+    /// Unlike [`Operator::presign_write`], the policy is enforced by the service, so it can be
+    /// used to limit the size or content type of files uploaded by HTML forms.
+    ///
+    /// # Notes
+    ///
+    /// - If `path` ends with `/`, it's used as key prefix and clients can upload to any key
+    ///   under it. The returned `key` field will be `<prefix>${filename}` which will be
+    ///   replaced by the uploaded file name, clients can override it with their own key.
+    /// - If [`PostPolicy::with_content_type_prefix`] is set, clients MUST add a `Content-Type`
+    ///   field to the form.
+    /// - Require [`Capability::presign_post`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use anyhow::Result;
+    /// use opendal::Operator;
+    /// use opendal::PostPolicy;
+    ///
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let policy = PostPolicy::new(Duration::from_secs(3600))
+    ///         .with_content_length_range(0, 10 * 1024 * 1024)
+    ///         .with_content_type_prefix("image/");
+    ///     let post = op.presign_post("uploads/", policy).await?;
+    ///
+    ///     println!("form action: {}", post.uri());
+    ///     for (name, value) in post.fields() {
+    ///         println!("<input type=\"hidden\" name=\"{name}\" value=\"{value}\">");
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn presign_post(&self, path: &str, policy: PostPolicy) -> Result<PresignedPost> {
+        let path = normalize_path(path);
+
+        let rp = self.inner().presign_post(&path, policy.into()).await?;
+        Ok(rp.into_presigned_post())
+    }
+
+    /// List in-progress multipart uploads under the given path.
+    ///
+    /// Uploads are created by [`Writer`] while writing large files. A `Writer` dropped without
+    /// calling `close` or `abort` will leave its uploaded parts on the service, which can be
+    /// found and cleaned up by this API.
+    ///
+    /// # Notes
+    ///
+    /// - `path` is used as a prefix, all uploads whose path starts with it will be returned.
+    /// - Require [`Capability::list_uploads`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let uploads = op.list_uploads("path/to/dir/").await?;
+    /// for upload in uploads {
+    ///     println!("{} {}", upload.path(), upload.upload_id());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_uploads(&self, path: &str) -> Result<Vec<Upload>> {
+        let path = normalize_path(path);
+
+        let rp = self
+            .inner()
+            .list_uploads(&path, OpListUploads::new())
+            .await?;
+        Ok(rp.into_uploads())
+    }
+
+    /// Abort an in-progress multipart upload of the given path.
+    ///
+    /// All uploaded parts of this upload will be removed by the service.
+    ///
+    /// # Notes
+    ///
+    /// - Require [`Capability::abort_upload`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// for upload in op.list_uploads("path/to/dir/").await? {
+    ///     op.abort_upload(upload.path(), upload.upload_id()).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn abort_upload(&self, path: &str, upload_id: &str) -> Result<()> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "abort upload path is a directory")
+                    .with_operation("Operator::abort_upload")
+                    .with_context("service", self.info().scheme())
+                    .with_context("path", &path),
+            );
+        }
+
+        self.inner()
+            .abort_upload(&path, OpAbortUpload::new(upload_id))
+            .await?;
+        Ok(())
+    }
+
+    /// Abort all in-progress multipart uploads under the given path that were initiated
+    /// more than `age` ago.
+    ///
+    /// Returns the number of aborted uploads.
+    ///
+    /// # Notes
+    ///
+    /// - Uploads without an initiated time will be skipped.
+    /// - Uploads that have been completed or aborted concurrently will be ignored.
+    /// - Require [`Capability::list_uploads`] and [`Capability::abort_upload`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// // Clean up uploads that have been left for more than one day.
+    /// let aborted = op
+    ///     .abort_uploads_older_than("path/to/dir/", Duration::from_secs(24 * 60 * 60))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn abort_uploads_older_than(&self, path: &str, age: Duration) -> Result<usize> {
+        let age = chrono::Duration::from_std(age).map_err(|err| {
+            Error::new(ErrorKind::Unexpected, "age is out of range")
+                .with_operation("Operator::abort_uploads_older_than")
+                .set_source(err)
+        })?;
+        let now = chrono::Utc::now();
+
+        let mut aborted = 0;
+        for upload in self.list_uploads(path).await? {
+            let Some(initiated) = upload.initiated() else {
+                continue;
+            };
+            if now - initiated < age {
+                continue;
+            }
+
+            match self.abort_upload(upload.path(), upload.upload_id()).await {
+                Ok(()) => aborted += 1,
+                Err(err) if err.kind() == ErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(aborted)
+    }
+
+    /// Initiate a multipart upload of the given path and presign requests for all its parts.
+    ///
+    /// This allows clients like browsers to upload large files directly to the service:
+    ///
+    /// - Server calls `presign_multipart` and hands the presigned part requests to the client.
+    /// - Client sends every part with its presigned request and reports the returned etags.
+    /// - Server calls [`Operator::complete_upload`] with the reported etags to finish the
+    ///   upload, or [`Operator::abort_upload`] to discard it.
+    ///
+    /// # Notes
+    ///
+    /// - Part numbers start from 1, the part at index `i` of [`PresignedMultipart::parts`]
+    ///   has part number `i + 1`.
+    /// - Services might have limits on part size, for example, AWS S3 requires every part
+    ///   except the last one to be at least 5MiB.
+    /// - Require [`Capability::presign_multipart`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let upload = op
+    ///     .presign_multipart("path/to/file", 4, Duration::from_secs(3600))
+    ///     .await?;
+    /// for (idx, part) in upload.parts().iter().enumerate() {
+    ///     println!("part {}: {} {}", idx + 1, part.method(), part.uri());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn presign_multipart(
+        &self,
+        path: &str,
+        part_count: usize,
+        expire: Duration,
+    ) -> Result<PresignedMultipart> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(Error::new(
+                ErrorKind::IsADirectory,
+                "presign multipart path is a directory",
+            )
+            .with_operation("Operator::presign_multipart")
+            .with_context("service", self.info().scheme())
+            .with_context("path", &path));
+        }
+
+        if part_count == 0 {
+            return Err(
+                Error::new(ErrorKind::Unexpected, "part count must be greater than 0")
+                    .with_operation("Operator::presign_multipart")
+                    .with_context("service", self.info().scheme())
+                    .with_context("path", &path),
+            );
+        }
+
+        let rp = self
+            .inner()
+            .initiate_upload(&path, OpInitiateUpload::new())
+            .await?;
+        let upload_id = rp.into_upload_id();
+
+        let mut parts = Vec::with_capacity(part_count);
+        for part_number in 1..=part_count {
+            let op = OpPresign::new(OpUploadPart::new(&upload_id, part_number), expire);
+            match self.inner().presign(&path, op).await {
+                Ok(rp) => parts.push(rp.into_presigned_request()),
+                Err(err) => {
+                    // Abort the upload nobody knows about, it keeps billing storage
+                    // otherwise. Abort is best effort, the presign error is returned.
+                    let _ = self
+                        .inner()
+                        .abort_upload(&path, OpAbortUpload::new(&upload_id))
+                        .await;
+                    return Err(err);
+                }
+            }
+        }
+
+        Ok(PresignedMultipart::new(&path, &upload_id, parts))
+    }
+
+    /// Presign a request to upload the given part of an initiated multipart upload.
+    ///
+    /// This is useful to refresh the presigned request of a part that has expired.
+    ///
+    /// # Notes
+    ///
+    /// - Part number starts from 1.
+    /// - Require [`Capability::presign_multipart`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// # async fn test(op: Operator, upload_id: &str) -> Result<()> {
+    /// let req = op
+    ///     .presign_upload_part("path/to/file", upload_id, 1, Duration::from_secs(3600))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn presign_upload_part(
+        &self,
+        path: &str,
+        upload_id: &str,
+        part_number: usize,
+        expire: Duration,
+    ) -> Result<PresignedRequest> {
+        let path = normalize_path(path);
+
+        let op = OpPresign::new(OpUploadPart::new(upload_id, part_number), expire);
+        let rp = self.inner().presign(&path, op).await?;
+        Ok(rp.into_presigned_request())
+    }
+
+    /// Complete a multipart upload of the given path with the uploaded parts.
+    ///
+    /// # Notes
+    ///
+    /// - Parts don't need to be sorted, they will be sorted by part number before completing.
+    /// - Require [`Capability::presign_multipart`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use anyhow::Result;
+    /// # use opendal::Operator;
+    /// use opendal::UploadPart;
+    ///
+    /// # async fn test(op: Operator, upload_id: &str) -> Result<()> {
+    /// let parts = vec![
+    ///     UploadPart::new(1, "\"etag-of-part-1\""),
+    ///     UploadPart::new(2, "\"etag-of-part-2\""),
+    /// ];
+    /// op.complete_upload("path/to/file", upload_id, parts).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn complete_upload(
+        &self,
+        path: &str,
+        upload_id: &str,
+        mut parts: Vec<UploadPart>,
+    ) -> Result<()> {
+        let path = normalize_path(path);
+
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(Error::new(
+                ErrorKind::IsADirectory,
+                "complete upload path is a directory",
+            )
+            .with_operation("Operator::complete_upload")
+            .with_context("service", self.info().scheme())
+            .with_context("path", &path));
+        }
+
+        parts.sort_by_key(|p| p.part_number());
+
+        self.inner()
+            .complete_upload(&path, OpCompleteUpload::new(upload_id, parts))
+            .await?;
+        Ok(())
+    }
 }
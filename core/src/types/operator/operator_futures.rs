@@ -127,6 +127,14 @@ impl<F: Future<Output = Result<Metadata>>> FutureStat<F> {
         self.args.version = Some(v.to_string());
         self
     }
+
+    /// Set whether to follow symlinks for this operation.
+    ///
+    /// Refer to [`options::StatOptions::follow_symlinks`] for more details.
+    pub fn follow_symlinks(mut self, v: bool) -> Self {
+        self.args.follow_symlinks = v;
+        self
+    }
 }
 
 /// Future that generated by [`Operator::presign_stat_with`].
@@ -918,6 +926,30 @@ impl<F: Future<Output = Result<Metadata>>> FutureWrite<F> {
         self.args.0.user_metadata = Some(HashMap::from_iter(data));
         self
     }
+
+    /// Sets the time to live of the written object.
+    ///
+    /// Refer to [`options::WriteOptions::ttl`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let _ = op
+    ///     .write_with("session/abc", vec![0; 4096])
+    ///     .ttl(Duration::from_secs(60))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.args.0.ttl = Some(ttl);
+        self
+    }
 }
 
 /// Future that generated by [`Operator::writer_with`].
@@ -952,6 +984,29 @@ impl<F: Future<Output = Result<Writer>>> FutureWriter<F> {
         self
     }
 
+    /// Enables random access for this writer.
+    ///
+    /// Refer to [`options::WriteOptions::random_access`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut w = op.writer_with("path/to/file").random_access(true).await?;
+    /// w.write_at(4096, vec![1; 4096]).await?;
+    /// w.write_at(0, vec![0; 4096]).await?;
+    /// w.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn random_access(mut self, v: bool) -> Self {
+        self.args.random_access = v;
+        self
+    }
+
     /// Sets chunk size for buffered writes.
     ///
     /// Refer to [`options::WriteOptions::chunk`] for more details.
@@ -1257,6 +1312,32 @@ impl<F: Future<Output = Result<Writer>>> FutureWriter<F> {
         self.args.user_metadata = Some(HashMap::from_iter(data));
         self
     }
+
+    /// Sets the time to live of the written object.
+    ///
+    /// Refer to [`options::WriteOptions::ttl`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let mut w = op
+    ///     .writer_with("session/abc")
+    ///     .ttl(Duration::from_secs(60))
+    ///     .await?;
+    /// w.write(vec![0; 4096]).await?;
+    /// w.close().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.args.ttl = Some(ttl);
+        self
+    }
 }
 
 /// Future that generated by [`Operator::delete_with`].
@@ -1299,6 +1380,13 @@ impl<F: Future<Output = Result<Vec<Entry>>>> FutureList<F> {
         self
     }
 
+    /// The continuation token returned by [`Lister::next_page`] to resume listing
+    /// from the page after it.
+    pub fn continuation_token(mut self, v: ContinuationToken) -> Self {
+        self.args.continuation_token = Some(v);
+        self
+    }
+
     /// The recursive is used to control whether the list operation is recursive.
     ///
     /// - If `false`, list operation will only list the entries under the given path.
@@ -1360,6 +1448,13 @@ impl<F: Future<Output = Result<Lister>>> FutureLister<F> {
         self
     }
 
+    /// The continuation token returned by [`Lister::next_page`] to resume listing
+    /// from the page after it.
+    pub fn continuation_token(mut self, v: ContinuationToken) -> Self {
+        self.args.continuation_token = Some(v);
+        self
+    }
+
     /// The recursive is used to control whether the list operation is recursive.
     ///
     /// - If `false`, list operation will only list the entries under the given path.
@@ -1427,4 +1522,67 @@ impl<F: Future<Output = Result<()>>> FutureCopy<F> {
         self.args.0.if_not_exists = v;
         self
     }
+
+    /// Sets the condition that copy operation will succeed only if the source's `ETag` matches.
+    ///
+    /// Refer to [`options::CopyOptions::if_match`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator, etag: &str) -> Result<()> {
+    /// let _ = op
+    ///     .copy_with("source/path", "target/path")
+    ///     .if_match(etag)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn if_match(mut self, v: &str) -> Self {
+        self.args.0.if_match = Some(v.to_string());
+        self
+    }
+
+    /// Sets the condition that copy operation will succeed only if the source's `ETag` doesn't match.
+    ///
+    /// Refer to [`options::CopyOptions::if_none_match`] for more details.
+    pub fn if_none_match(mut self, v: &str) -> Self {
+        self.args.0.if_none_match = Some(v.to_string());
+        self
+    }
+
+    /// Sets the concurrent requests used while copying large objects in parts.
+    ///
+    /// Refer to [`options::CopyOptions::concurrent`] for more details.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// # use opendal::Result;
+    /// # use opendal::Operator;
+    ///
+    /// # async fn test(op: Operator) -> Result<()> {
+    /// let _ = op
+    ///     .copy_with("source/path", "target/path")
+    ///     .concurrent(8)
+    ///     .chunk(256 * 1024 * 1024)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn concurrent(mut self, v: usize) -> Self {
+        self.args.0.concurrent = v;
+        self
+    }
+
+    /// Sets the part size used while copying large objects in parts.
+    ///
+    /// Refer to [`options::CopyOptions::chunk`] for more details.
+    pub fn chunk(mut self, v: usize) -> Self {
+        self.args.0.chunk = Some(v);
+        self
+    }
 }
@@ -18,6 +18,7 @@
 //! Options module provides options definitions for operations.
 
 use std::collections::HashMap;
+use std::time::Duration;
 
 use chrono::DateTime;
 use chrono::Utc;
@@ -43,6 +44,12 @@ pub struct ListOptions {
     /// The start_after passes to underlying service to specify the specified key
     /// to start listing from.
     pub start_after: Option<String>,
+    /// The continuation token returned by [`Lister::next_page`](crate::Lister::next_page)
+    /// to resume listing from the page after it.
+    ///
+    /// The token is opaque and only valid for the same service, path and options
+    /// that produced it.
+    pub continuation_token: Option<crate::ContinuationToken>,
     /// The recursive is used to control whether the list operation is recursive.
     ///
     /// - If `false`, list operation will only list the entries under the given path.
@@ -248,7 +255,7 @@ pub struct ReaderOptions {
 }
 
 /// Options for stat operations.
-#[derive(Debug, Clone, Default, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct StatOptions {
     /// Set `version` for this operation.
     ///
@@ -298,6 +305,35 @@ pub struct StatOptions {
     ///
     /// This option is only meaningful when used along with presign.
     pub override_content_disposition: Option<String>,
+
+    /// Set `follow_symlinks` for this operation.
+    ///
+    /// - If `true`, stat on a symlink returns the metadata of its target.
+    /// - If `false`, stat on a symlink returns the metadata of the symlink itself with
+    ///   [`EntryMode::Symlink`] and [`Metadata::symlink_target`] set.
+    ///
+    /// Default to `true`.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::stat_with_follow_symlinks`] before setting this option to `false`.
+    pub follow_symlinks: bool,
+}
+
+impl Default for StatOptions {
+    fn default() -> Self {
+        Self {
+            version: None,
+            if_match: None,
+            if_none_match: None,
+            if_modified_since: None,
+            if_unmodified_since: None,
+            override_content_type: None,
+            override_cache_control: None,
+            override_content_disposition: None,
+            follow_symlinks: true,
+        }
+    }
 }
 
 /// Options for write operations.
@@ -473,6 +509,22 @@ pub struct WriteOptions {
     /// This operation provides a way to ensure write operations only create new resources
     /// without overwriting existing ones, useful for implementing "create if not exists" logic.
     pub if_not_exists: bool,
+    /// Sets the time to live of the written object.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_with_ttl`] before using this feature.
+    ///
+    /// ### Behavior
+    ///
+    /// - If supported, the object will be removed by the service once the ttl elapsed
+    /// - The expiration time will be returned as [`Metadata::expires_at`] if available
+    /// - Services may round the ttl up to their own granularity, for example seconds
+    /// - If not supported, an error with [`ErrorKind::Unsupported`] will be returned
+    ///
+    /// This is useful for caches and sessions that should expire by themselves
+    /// without an extra delete.
+    pub ttl: Option<Duration>,
 
     /// Sets concurrent write operations for this writer.
     ///
@@ -525,6 +577,24 @@ pub struct WriteOptions {
     /// - Lower operation costs
     /// - Better utilize network bandwidth
     pub chunk: Option<usize>,
+    /// Enables random access for the writer.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_can_random_access`] before using this option.
+    ///
+    /// ### Behavior
+    ///
+    /// - By default, writers only accept sequential writes
+    /// - When random access is set to true:
+    ///   - [`Writer::write_at`] can write data at any offset
+    ///   - [`Writer::set_len`] can truncate or extend the file if [`Capability::write_can_set_len`] is set
+    ///   - Sequential `write` keeps its own position and is not affected by `write_at`
+    /// - Services emulating random access on top of blocks (like s3) require:
+    ///   - `chunk` to be set, it will be used as the block size
+    ///   - Every offset to be aligned to `chunk`
+    ///   - Every write to be exactly `chunk` long except the last one
+    pub random_access: bool,
 }
 
 /// Options for copy operations.
@@ -545,4 +615,128 @@ pub struct CopyOptions {
     /// This operation provides a way to ensure copy operations only create new resources
     /// without overwriting existing ones, useful for implementing "copy if not exists" logic.
     pub if_not_exists: bool,
+    /// Sets the condition that copy operation will succeed only if the source's `ETag` matches.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::copy_with_if_match`] before using this feature.
+    ///
+    /// ### Behavior
+    ///
+    /// - If the source's etag doesn't match, an error with kind [`ErrorKind::ConditionNotMatch`]
+    ///   will be returned.
+    ///
+    /// This is useful to make sure the source hasn't changed since it was listed.
+    pub if_match: Option<String>,
+    /// Sets the condition that copy operation will succeed only if the source's `ETag` doesn't match.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::copy_with_if_none_match`] before using this feature.
+    ///
+    /// ### Behavior
+    ///
+    /// - If the source's etag matches, an error with kind [`ErrorKind::ConditionNotMatch`]
+    ///   will be returned.
+    pub if_none_match: Option<String>,
+    /// Sets the concurrent requests used while copying large objects in parts.
+    ///
+    /// ### Behavior
+    ///
+    /// - Services like s3 copy parts of large objects concurrently
+    /// - Default to 1, which copies parts one by one
+    pub concurrent: usize,
+    /// Sets the part size used while copying large objects in parts.
+    ///
+    /// ### Behavior
+    ///
+    /// - Services like s3 copy sources larger than `chunk` in parts, otherwise in one request
+    /// - If not set, services try to copy in one request first, and only copy in parts if the
+    ///   source exceeds their single copy limit
+    /// - If set, services stat the source first to decide how to copy it
+    /// - Services may enlarge the chunk to fit their part size and part count limits
+    pub chunk: Option<usize>,
+}
+
+/// Options for concat operations.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ConcatOptions {
+    /// Sets Content-Type header of the concatenated object.
+    ///
+    /// ### Capability
+    ///
+    /// Check [`Capability::write_with_content_type`] before using this feature.
+    pub content_type: Option<String>,
+    /// Sets the chunk size used while concatenating by streaming.
+    ///
+    /// ### Behavior
+    ///
+    /// - Only takes effect if the service can't concat these sources natively
+    /// - Works the same as [`WriteOptions::chunk`]
+    pub chunk: Option<usize>,
+    /// Sets the concurrent requests used while concatenating.
+    ///
+    /// ### Behavior
+    ///
+    /// - Services like s3 copy this many sources at the same time
+    /// - Works the same as [`WriteOptions::concurrent`] while concatenating by streaming
+    pub concurrent: usize,
+}
+
+/// Options for lock operations.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LockOptions {
+    /// Sets the time to live of the lease.
+    ///
+    /// ### Behavior
+    ///
+    /// - The lease is renewed in the background every third of `ttl` while the lock is held
+    /// - Other owners can acquire the lock once the lease expires
+    /// - Default to 30 seconds
+    pub ttl: Duration,
+    /// Sets the owner of the lock.
+    ///
+    /// ### Behavior
+    ///
+    /// - A random owner will be generated if not set
+    /// - Acquiring a lock that is already held by the same owner succeeds with the same token
+    /// - Guards of the same owner in one process share the lease, it's released by the last guard
+    pub owner: Option<String>,
+    /// Sets the max duration to wait while the lock is held by others.
+    ///
+    /// ### Behavior
+    ///
+    /// - If not set, `lock` waits until the lock is acquired
+    /// - Returns error with [`ErrorKind::ConditionNotMatch`] if the lock is still held after `timeout`
+    pub timeout: Option<Duration>,
+}
+
+impl Default for LockOptions {
+    fn default() -> Self {
+        Self {
+            ttl: Duration::from_secs(30),
+            owner: None,
+            timeout: None,
+        }
+    }
+}
+
+/// Options for update operations.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct UpdateOptions {
+    /// Sets the max times to retry while the object is changed by others.
+    ///
+    /// ### Behavior
+    ///
+    /// - Retries are delayed with exponential backoff and jitter
+    /// - Returns error with [`ErrorKind::ConditionNotMatch`] if the object is still
+    ///   changed by others after all retries
+    /// - Default to 10
+    pub max_retries: usize,
+}
+
+impl Default for UpdateOptions {
+    fn default() -> Self {
+        Self { max_retries: 10 }
+    }
 }
@@ -0,0 +1,73 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::time::Duration;
+
+/// PostPolicy describes the constraints of a presigned POST form upload created by
+/// [`Operator::presign_post`].
+///
+/// Unlike a presigned PUT request, the policy is enforced by the service while uploading,
+/// which makes it possible to limit what clients like browsers can upload.
+///
+/// [`Operator::presign_post`]: crate::Operator::presign_post
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PostPolicy {
+    expire: Duration,
+    content_length_range: Option<(u64, u64)>,
+    content_type_prefix: Option<String>,
+}
+
+impl PostPolicy {
+    /// Create a new post policy which expires after given duration.
+    pub fn new(expire: Duration) -> Self {
+        Self {
+            expire,
+            content_length_range: None,
+            content_type_prefix: None,
+        }
+    }
+
+    /// Limit the size of uploaded content to `[min, max]` bytes, both inclusive.
+    pub fn with_content_length_range(mut self, min: u64, max: u64) -> Self {
+        self.content_length_range = Some((min, max));
+        self
+    }
+
+    /// Require the `Content-Type` of uploaded content to start with given prefix,
+    /// for example, `image/`.
+    ///
+    /// Clients MUST send a `Content-Type` form field which matches this prefix.
+    pub fn with_content_type_prefix(mut self, prefix: &str) -> Self {
+        self.content_type_prefix = Some(prefix.to_string());
+        self
+    }
+
+    /// Get the expire duration of this policy.
+    pub fn expire(&self) -> Duration {
+        self.expire
+    }
+
+    /// Get the content length range of this policy.
+    pub fn content_length_range(&self) -> Option<(u64, u64)> {
+        self.content_length_range
+    }
+
+    /// Get the content type prefix of this policy.
+    pub fn content_type_prefix(&self) -> Option<&str> {
+        self.content_type_prefix.as_deref()
+    }
+}
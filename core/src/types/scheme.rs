@@ -34,6 +34,8 @@ use crate::Error;
 pub enum Scheme {
     /// [aliyun_drive][crate::services::AliyunDrive]: Aliyun Drive services.
     AliyunDrive,
+    /// [archive][crate::services::Archive]: Read zip and tar archives stored in other services.
+    Archive,
     /// [atomicserver][crate::services::Atomicserver]: Atomicserver services.
     Atomicserver,
     /// [azblob][crate::services::Azblob]: Azure Storage Blob services.
@@ -143,6 +145,8 @@ pub enum Scheme {
     Webhdfs,
     /// [redb][crate::services::Redb]: Redb Services
     Redb,
+    /// [lmdb][crate::services::Lmdb]: Lmdb Services
+    Lmdb,
     /// [tikv][crate::services::Tikv]: Tikv Services
     Tikv,
     /// [azfile][crate::services::Azfile]: Azfile Services
@@ -151,6 +155,8 @@ pub enum Scheme {
     Mongodb,
     /// [gridfs](crate::services::Gridfs): MongoDB Gridfs Services
     Gridfs,
+    /// [git][crate::services::Git]: Read-only git repository services.
+    Git,
     /// [Github Contents][crate::services::Github]: Github contents support.
     Github,
     /// [Native HDFS](crate::services::HdfsNative): Hdfs Native service, using rust hdfs-native client for hdfs
@@ -197,6 +203,8 @@ impl Scheme {
             Scheme::AliyunDrive,
             #[cfg(feature = "services-alluxio")]
             Scheme::Alluxio,
+            #[cfg(feature = "services-archive")]
+            Scheme::Archive,
             #[cfg(feature = "services-azblob")]
             Scheme::Azblob,
             #[cfg(feature = "services-azdls")]
@@ -257,6 +265,8 @@ impl Scheme {
             Scheme::Postgresql,
             #[cfg(feature = "services-gdrive")]
             Scheme::Gdrive,
+            #[cfg(feature = "services-git")]
+            Scheme::Git,
             #[cfg(feature = "services-oss")]
             Scheme::Oss,
             #[cfg(feature = "services-persy")]
@@ -295,6 +305,8 @@ impl Scheme {
             Scheme::Webhdfs,
             #[cfg(feature = "services-redb")]
             Scheme::Redb,
+            #[cfg(feature = "services-lmdb")]
+            Scheme::Lmdb,
             #[cfg(feature = "services-mongodb")]
             Scheme::Mongodb,
             #[cfg(feature = "services-hdfs-native")]
@@ -328,6 +340,7 @@ impl FromStr for Scheme {
             "aliyun_drive" => Ok(Scheme::AliyunDrive),
             "azblob" => Ok(Scheme::Azblob),
             "alluxio" => Ok(Scheme::Alluxio),
+            "archive" => Ok(Scheme::Archive),
             // Notes:
             //
             // OpenDAL used to call `azdls` as `azdfs`, we keep it for backward compatibility.
@@ -348,6 +361,7 @@ impl FromStr for Scheme {
             "gdrive" => Ok(Scheme::Gdrive),
             "ghac" => Ok(Scheme::Ghac),
             "gridfs" => Ok(Scheme::Gridfs),
+            "git" => Ok(Scheme::Git),
             "github" => Ok(Scheme::Github),
             "hdfs" => Ok(Scheme::Hdfs),
             "http" | "https" => Ok(Scheme::Http),
@@ -368,6 +382,7 @@ impl FromStr for Scheme {
             "persy" => Ok(Scheme::Persy),
             "postgresql" => Ok(Scheme::Postgresql),
             "redb" => Ok(Scheme::Redb),
+            "lmdb" => Ok(Scheme::Lmdb),
             "redis" => Ok(Scheme::Redis),
             "rocksdb" => Ok(Scheme::Rocksdb),
             "s3" => Ok(Scheme::S3),
@@ -434,6 +449,7 @@ impl From<Scheme> for &'static str {
             Scheme::Postgresql => "postgresql",
             Scheme::Mysql => "mysql",
             Scheme::Gdrive => "gdrive",
+            Scheme::Git => "git",
             Scheme::Github => "github",
             Scheme::Dropbox => "dropbox",
             Scheme::Redis => "redis",
@@ -449,11 +465,13 @@ impl From<Scheme> for &'static str {
             Scheme::Webdav => "webdav",
             Scheme::Webhdfs => "webhdfs",
             Scheme::Redb => "redb",
+            Scheme::Lmdb => "lmdb",
             Scheme::Tikv => "tikv",
             Scheme::Azfile => "azfile",
             Scheme::Sqlite => "sqlite",
             Scheme::Mongodb => "mongodb",
             Scheme::Alluxio => "alluxio",
+            Scheme::Archive => "archive",
             Scheme::Upyun => "upyun",
             Scheme::YandexDisk => "yandex_disk",
             Scheme::Pcloud => "pcloud",
@@ -0,0 +1,135 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use crate::raw::*;
+use crate::*;
+
+/// Transaction buffers writes and deletes and commits them atomically, returned
+/// by [`Operator::transaction`].
+///
+/// # Isolation
+///
+/// Reads see the writes buffered in this transaction, and read the same value
+/// for the same path. Values read from the service are checked again at commit,
+/// and the commit fails with [`ErrorKind::ConditionNotMatch`] if any of them has
+/// been changed by others. Retry the whole transaction in this case.
+pub struct Transaction {
+    op: Operator,
+
+    /// Values read from the service, `None` means the path doesn't exist.
+    reads: BTreeMap<String, Option<Buffer>>,
+    /// Values to commit, `None` means the path will be deleted.
+    writes: BTreeMap<String, Option<Buffer>>,
+}
+
+impl Debug for Transaction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Transaction")
+            .field("reads", &self.reads.keys())
+            .field("writes", &self.writes.keys())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Transaction {
+    pub(crate) fn new(op: Operator) -> Self {
+        Self {
+            op,
+            reads: BTreeMap::new(),
+            writes: BTreeMap::new(),
+        }
+    }
+
+    /// Read the whole content of `path` in this transaction.
+    ///
+    /// Returns an error with [`ErrorKind::NotFound`] if the path doesn't exist
+    /// or has been deleted in this transaction.
+    pub async fn read(&mut self, path: &str) -> Result<Buffer> {
+        let path = self.file_path(path, Operation::Read)?;
+
+        let value = match self.writes.get(&path) {
+            Some(value) => value.clone(),
+            None => match self.reads.get(&path) {
+                Some(value) => value.clone(),
+                None => {
+                    let value = match self.op.read(&path).await {
+                        Ok(bs) => Some(bs),
+                        Err(err) if err.kind() == ErrorKind::NotFound => None,
+                        Err(err) => return Err(err),
+                    };
+                    self.reads.insert(path.clone(), value.clone());
+                    value
+                }
+            },
+        };
+
+        value.ok_or_else(|| {
+            Error::new(ErrorKind::NotFound, "path doesn't exist in transaction")
+                .with_operation("Transaction::read")
+                .with_context("service", self.op.info().scheme())
+                .with_context("path", &path)
+        })
+    }
+
+    /// Write `bs` to `path` at commit.
+    pub fn write(&mut self, path: &str, bs: impl Into<Buffer>) -> Result<()> {
+        let path = self.file_path(path, Operation::Write)?;
+        self.writes.insert(path, Some(bs.into()));
+        Ok(())
+    }
+
+    /// Delete `path` at commit.
+    pub fn delete(&mut self, path: &str) -> Result<()> {
+        let path = self.file_path(path, Operation::Delete)?;
+        self.writes.insert(path, None);
+        Ok(())
+    }
+
+    /// Commit all writes and deletes in this transaction atomically.
+    ///
+    /// Returns an error with [`ErrorKind::ConditionNotMatch`] if any path read in
+    /// this transaction has been changed by others, nothing is written in this case.
+    pub async fn commit(self) -> Result<()> {
+        if self.reads.is_empty() && self.writes.is_empty() {
+            return Ok(());
+        }
+
+        let args = OpTransaction::new(
+            self.reads.into_iter().collect(),
+            self.writes.into_iter().collect(),
+        );
+        self.op.inner().transaction(args).await?;
+        Ok(())
+    }
+
+    fn file_path(&self, path: &str, op: Operation) -> Result<String> {
+        let path = normalize_path(path);
+        if !validate_path(&path, EntryMode::FILE) {
+            return Err(
+                Error::new(ErrorKind::IsADirectory, "transaction path is a directory")
+                    .with_operation(op)
+                    .with_context("service", self.op.info().scheme())
+                    .with_context("path", &path),
+            );
+        }
+        Ok(path)
+    }
+}
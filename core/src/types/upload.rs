@@ -0,0 +1,140 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::raw::PresignedRequest;
+
+/// Upload represents an in-progress multipart upload returned by [`Operator::list_uploads`].
+///
+/// [`Operator::list_uploads`]: crate::Operator::list_uploads
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Upload {
+    path: String,
+    upload_id: String,
+    initiated: Option<DateTime<Utc>>,
+}
+
+impl Upload {
+    /// Create a new upload with given path and upload id.
+    pub fn new(path: &str, upload_id: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            upload_id: upload_id.to_string(),
+            initiated: None,
+        }
+    }
+
+    /// Path of this upload. Path is relative to operator's root.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Upload id assigned by the service while initiating this upload.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
+    /// The time this upload was initiated, `None` if the service doesn't return it.
+    pub fn initiated(&self) -> Option<DateTime<Utc>> {
+        self.initiated
+    }
+
+    /// Set the initiated time of this upload.
+    pub fn with_initiated(mut self, v: DateTime<Utc>) -> Self {
+        self.initiated = Some(v);
+        self
+    }
+}
+
+/// UploadPart is a part uploaded to a multipart upload.
+///
+/// Clients report the part number and the etag returned by the service after uploading
+/// a part, which are required by [`Operator::complete_upload`].
+///
+/// [`Operator::complete_upload`]: crate::Operator::complete_upload
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct UploadPart {
+    part_number: usize,
+    etag: String,
+}
+
+impl UploadPart {
+    /// Create a new upload part with given part number and etag.
+    ///
+    /// Part number starts from 1.
+    pub fn new(part_number: usize, etag: &str) -> Self {
+        Self {
+            part_number,
+            etag: etag.to_string(),
+        }
+    }
+
+    /// Part number of this part, starting from 1.
+    pub fn part_number(&self) -> usize {
+        self.part_number
+    }
+
+    /// ETag returned by the service after this part was uploaded.
+    pub fn etag(&self) -> &str {
+        &self.etag
+    }
+}
+
+/// PresignedMultipart is a multipart upload initiated by [`Operator::presign_multipart`].
+///
+/// It carries a presigned request for every part, which can be handed to clients like
+/// browsers to upload parts directly. After all parts are uploaded, call
+/// [`Operator::complete_upload`] with the etags reported by clients, or
+/// [`Operator::abort_upload`] to discard the upload.
+///
+/// [`Operator::presign_multipart`]: crate::Operator::presign_multipart
+/// [`Operator::complete_upload`]: crate::Operator::complete_upload
+/// [`Operator::abort_upload`]: crate::Operator::abort_upload
+#[derive(Clone, Debug)]
+pub struct PresignedMultipart {
+    path: String,
+    upload_id: String,
+    parts: Vec<PresignedRequest>,
+}
+
+impl PresignedMultipart {
+    /// Create a new presigned multipart upload.
+    pub fn new(path: &str, upload_id: &str, parts: Vec<PresignedRequest>) -> Self {
+        Self {
+            path: path.to_string(),
+            upload_id: upload_id.to_string(),
+            parts,
+        }
+    }
+
+    /// Path of this upload.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Upload id assigned by the service while initiating this upload.
+    pub fn upload_id(&self) -> &str {
+        &self.upload_id
+    }
+
+    /// Presigned requests of all parts, the part at index `i` has part number `i + 1`.
+    pub fn parts(&self) -> &[PresignedRequest] {
+        &self.parts
+    }
+}
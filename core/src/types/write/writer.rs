@@ -159,6 +159,42 @@ impl Writer {
         self.write(bs).await
     }
 
+    /// Write [`Buffer`] into writer at the given offset.
+    ///
+    /// The writer must be created with `random_access` enabled. Data written by
+    /// `write` before is flushed first, and the position of following `write`
+    /// calls is not changed.
+    ///
+    /// Services emulating random access on top of blocks require `offset` to be
+    /// aligned to the writer's `chunk`, and every buffer except the last one to
+    /// be exactly `chunk` long.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use opendal::Operator;
+    /// use opendal::Result;
+    ///
+    /// async fn test(op: Operator) -> Result<()> {
+    ///     let mut w = op.writer_with("hello.txt").random_access(true).await?;
+    ///     w.write_at(6, "world").await?;
+    ///     w.write_at(0, "hello,").await?;
+    ///     w.close().await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn write_at(&mut self, offset: u64, bs: impl Into<Buffer>) -> Result<()> {
+        self.inner.write_at(offset, bs.into()).await
+    }
+
+    /// Truncate or extend the file to given length.
+    ///
+    /// The writer must be created with `random_access` enabled. Extended range
+    /// will be filled with zeros.
+    pub async fn set_len(&mut self, len: u64) -> Result<()> {
+        self.inner.set_len(len).await
+    }
+
     /// Abort the writer and clean up all written data.
     ///
     /// ## Notes
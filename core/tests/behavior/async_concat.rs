@@ -0,0 +1,146 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use anyhow::Result;
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::*;
+
+pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
+    let cap = op.info().full_capability();
+
+    if cap.read && cap.write && cap.concat {
+        tests.extend(async_trials!(
+            op,
+            test_concat_files,
+            test_concat_min_size_sources,
+            test_concat_overwrite,
+            test_concat_non_existing_source,
+            test_concat_to_source
+        ))
+    }
+}
+
+/// Concat files and test contents.
+pub async fn test_concat_files(op: Operator) -> Result<()> {
+    let mut sources = vec![];
+    let mut expected = vec![];
+    for _ in 0..3 {
+        let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+        op.write(&path, content.clone()).await?;
+        expected.extend_from_slice(&content);
+        sources.push(path);
+    }
+
+    let target_path = TEST_FIXTURE.new_file_path();
+    let sources: Vec<&str> = sources.iter().map(|v| v.as_str()).collect();
+    op.concat(&target_path, &sources).await?;
+
+    let target_content = op
+        .read(&target_path)
+        .await
+        .expect("read must succeed")
+        .to_bytes();
+    assert_eq!(target_content.len(), expected.len());
+    assert_eq!(
+        format!("{:x}", Sha256::digest(target_content)),
+        format!("{:x}", Sha256::digest(&expected)),
+    );
+
+    // Sources must be left unchanged.
+    for source in sources {
+        op.stat(source).await.expect("source must exist");
+    }
+    Ok(())
+}
+
+/// Concat sources that fit the min size of native concat.
+pub async fn test_concat_min_size_sources(op: Operator) -> Result<()> {
+    let Some(min) = op.info().full_capability().concat_min_source_size else {
+        return Ok(());
+    };
+
+    let first = TEST_FIXTURE.new_file_path();
+    let first_content = gen_fixed_bytes(min);
+    op.write(&first, first_content.clone()).await?;
+    let (second, second_content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&second, second_content.clone()).await?;
+
+    let target_path = TEST_FIXTURE.new_file_path();
+    op.concat(&target_path, &[&first, &second]).await?;
+
+    let target_content = op
+        .read(&target_path)
+        .await
+        .expect("read must succeed")
+        .to_bytes();
+    assert_eq!(
+        format!("{:x}", Sha256::digest(target_content)),
+        format!(
+            "{:x}",
+            Sha256::digest([first_content, second_content].concat())
+        ),
+    );
+    Ok(())
+}
+
+/// Concat into an existing file must overwrite it.
+pub async fn test_concat_overwrite(op: Operator) -> Result<()> {
+    let (source_path, source_content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&source_path, source_content.clone()).await?;
+
+    let (target_path, target_content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&target_path, target_content).await?;
+
+    op.concat(&target_path, &[&source_path]).await?;
+
+    let content = op.read(&target_path).await?.to_bytes();
+    assert_eq!(
+        format!("{:x}", Sha256::digest(content)),
+        format!("{:x}", Sha256::digest(&source_content)),
+    );
+    Ok(())
+}
+
+/// Concat with a non existing source should return an error.
+pub async fn test_concat_non_existing_source(op: Operator) -> Result<()> {
+    let (source_path, source_content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&source_path, source_content).await?;
+    let missing_path = uuid::Uuid::new_v4().to_string();
+    let target_path = TEST_FIXTURE.new_file_path();
+
+    let err = op
+        .concat(&target_path, &[&source_path, &missing_path])
+        .await
+        .expect_err("concat must fail");
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+    Ok(())
+}
+
+/// Concat into one of the sources should return an error.
+pub async fn test_concat_to_source(op: Operator) -> Result<()> {
+    let (source_path, source_content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&source_path, source_content).await?;
+
+    let err = op
+        .concat(&source_path, &[&source_path])
+        .await
+        .expect_err("concat must fail");
+    assert_eq!(err.kind(), ErrorKind::IsSameFile);
+    Ok(())
+}
@@ -34,10 +34,19 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_copy_target_dir,
             test_copy_self,
             test_copy_nested,
-            test_copy_overwrite
+            test_copy_overwrite,
+            test_copy_with_chunk
         ))
     }
 
+    if cap.read && cap.write && cap.copy && cap.copy_with_if_match {
+        tests.extend(async_trials!(op, test_copy_with_if_match))
+    }
+
+    if cap.read && cap.write && cap.copy && cap.copy_with_if_none_match {
+        tests.extend(async_trials!(op, test_copy_with_if_none_match))
+    }
+
     if cap.read && cap.write && cap.copy && cap.copy_with_if_not_exists {
         tests.extend(async_trials!(
             op,
@@ -312,3 +321,103 @@ pub async fn test_copy_with_if_not_exists_to_existing_file(op: Operator) -> Resu
     op.delete(&target_path).await.expect("delete must succeed");
     Ok(())
 }
+
+/// Copy in parts should keep the content.
+pub async fn test_copy_with_chunk(op: Operator) -> Result<()> {
+    let source_path = uuid::Uuid::new_v4().to_string();
+    let source_content = gen_fixed_bytes(6 * 1024 * 1024);
+
+    op.write(&source_path, source_content.clone()).await?;
+
+    let target_path = uuid::Uuid::new_v4().to_string();
+
+    op.copy_with(&source_path, &target_path)
+        .chunk(5 * 1024 * 1024)
+        .concurrent(2)
+        .await?;
+
+    let target_content = op
+        .read(&target_path)
+        .await
+        .expect("read must succeed")
+        .to_bytes();
+    assert_eq!(
+        format!("{:x}", Sha256::digest(target_content)),
+        format!("{:x}", Sha256::digest(&source_content)),
+    );
+
+    op.delete(&source_path).await.expect("delete must succeed");
+    op.delete(&target_path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Copy with if_match should succeed only if the source etag matches.
+pub async fn test_copy_with_if_match(op: Operator) -> Result<()> {
+    let source_path = uuid::Uuid::new_v4().to_string();
+    let (source_content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&source_path, source_content.clone()).await?;
+    let meta = op.stat(&source_path).await?;
+    let Some(etag) = meta.etag() else {
+        return Ok(());
+    };
+
+    let target_path = uuid::Uuid::new_v4().to_string();
+
+    let err = op
+        .copy_with(&source_path, &target_path)
+        .if_match("\"invalid_etag\"")
+        .await
+        .expect_err("copy must fail");
+    assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
+    assert!(!op.exists(&target_path).await?);
+
+    op.copy_with(&source_path, &target_path)
+        .if_match(etag)
+        .await?;
+
+    let target_content = op
+        .read(&target_path)
+        .await
+        .expect("read must succeed")
+        .to_bytes();
+    assert_eq!(
+        format!("{:x}", Sha256::digest(target_content)),
+        format!("{:x}", Sha256::digest(&source_content)),
+    );
+
+    op.delete(&source_path).await.expect("delete must succeed");
+    op.delete(&target_path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Copy with if_none_match should fail if the source etag matches.
+pub async fn test_copy_with_if_none_match(op: Operator) -> Result<()> {
+    let source_path = uuid::Uuid::new_v4().to_string();
+    let (source_content, _) = gen_bytes(op.info().full_capability());
+
+    op.write(&source_path, source_content.clone()).await?;
+    let meta = op.stat(&source_path).await?;
+    let Some(etag) = meta.etag() else {
+        return Ok(());
+    };
+
+    let target_path = uuid::Uuid::new_v4().to_string();
+
+    let err = op
+        .copy_with(&source_path, &target_path)
+        .if_none_match(etag)
+        .await
+        .expect_err("copy must fail");
+    assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
+    assert!(!op.exists(&target_path).await?);
+
+    op.copy_with(&source_path, &target_path)
+        .if_none_match("\"invalid_etag\"")
+        .await?;
+    assert!(op.exists(&target_path).await?);
+
+    op.delete(&source_path).await.expect("delete must succeed");
+    op.delete(&target_path).await.expect("delete must succeed");
+    Ok(())
+}
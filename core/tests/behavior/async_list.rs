@@ -42,6 +42,7 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_list_nested_dir,
             test_list_dir_with_file_path,
             test_list_with_start_after,
+            test_list_with_continuation_token,
             test_list_non_exist_dir_with_recursive,
             test_list_dir_with_recursive,
             test_list_dir_with_recursive_no_trailing_slash,
@@ -403,6 +404,63 @@ pub async fn test_list_with_start_after(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// List with continuation token should resume listing from the page after it.
+pub async fn test_list_with_continuation_token(op: Operator) -> Result<()> {
+    if !op.info().full_capability().list_with_continuation_token {
+        return Ok(());
+    }
+
+    let dir = &format!("{}/", uuid::Uuid::new_v4());
+    op.create_dir(dir).await?;
+
+    let mut given: Vec<String> = ["file-0", "file-1", "file-2", "file-3", "file-4"]
+        .iter()
+        .map(|name| format!("{dir}{name}-{}", uuid::Uuid::new_v4()))
+        .collect();
+
+    given
+        .iter()
+        .map(|name| async {
+            op.write(name, "content")
+                .await
+                .expect("create must succeed");
+        })
+        .collect::<FuturesUnordered<_>>()
+        .collect::<Vec<_>>()
+        .await;
+
+    let mut actual = vec![];
+    let mut token = None;
+    loop {
+        // Create a new lister for every page to make sure the token can resume listing.
+        let mut fut = op.lister_with(dir).limit(2);
+        if let Some(token) = token.take() {
+            fut = fut.continuation_token(token);
+        }
+        let mut lister = fut.await?;
+
+        let (entries, next) = lister.next_page().await?;
+        actual.extend(
+            entries
+                .into_iter()
+                .map(|e| e.path().to_string())
+                .filter(|path| path != dir),
+        );
+        match next {
+            Some(next) => token = Some(ContinuationToken::new(next.to_string())),
+            None => break,
+        }
+    }
+
+    given.sort();
+    actual.sort();
+    assert_eq!(given, actual);
+
+    op.remove_all(dir).await?;
+
+    Ok(())
+}
+
 pub async fn test_list_non_exist_dir_with_recursive(op: Operator) -> Result<()> {
     let dir = format!("{}/", uuid::Uuid::new_v4());
 
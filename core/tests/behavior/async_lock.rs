@@ -0,0 +1,159 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::time::Duration;
+
+use anyhow::Result;
+use tokio::time::sleep;
+
+use crate::*;
+
+pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
+    let cap = op.info().full_capability();
+
+    if cap.lock {
+        tests.extend(async_trials!(
+            op,
+            test_lock_and_release,
+            test_lock_held_by_others,
+            test_lock_same_owner,
+            test_lock_token_increases,
+            test_lock_renew
+        ))
+    }
+}
+
+fn lock_options(owner: &str, ttl: Duration, timeout: Duration) -> options::LockOptions {
+    options::LockOptions {
+        ttl,
+        owner: Some(owner.to_string()),
+        timeout: Some(timeout),
+    }
+}
+
+/// Acquire a lock and release it.
+pub async fn test_lock_and_release(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+
+    let lock = op.lock(&path).await?;
+    assert_eq!(lock.path(), path);
+    assert!(lock.token() > 0);
+    assert!(!lock.is_lost());
+    lock.release().await?;
+
+    // The lock can be acquired again after release.
+    let lock = op
+        .lock_options(
+            &path,
+            lock_options("other", Duration::from_secs(10), Duration::from_secs(1)),
+        )
+        .await?;
+    lock.release().await?;
+    Ok(())
+}
+
+/// Acquire a lock held by another owner must time out.
+pub async fn test_lock_held_by_others(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+    let ttl = Duration::from_secs(10);
+
+    let lock = op
+        .lock_options(&path, lock_options("a", ttl, Duration::from_secs(1)))
+        .await?;
+
+    let err = op
+        .lock_options(&path, lock_options("b", ttl, Duration::from_millis(300)))
+        .await
+        .expect_err("lock held by others must fail");
+    assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
+
+    lock.release().await?;
+    Ok(())
+}
+
+/// Acquire a lock again with the same owner returns the same token.
+pub async fn test_lock_same_owner(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+    let ttl = Duration::from_secs(10);
+
+    let lock = op
+        .lock_options(&path, lock_options("a", ttl, Duration::from_secs(1)))
+        .await?;
+    let again = op
+        .lock_options(&path, lock_options("a", ttl, Duration::from_secs(1)))
+        .await?;
+    assert_eq!(lock.token(), again.token());
+
+    // The lease is still held by `again` after releasing `lock`.
+    lock.release().await?;
+    let err = op
+        .lock_options(&path, lock_options("b", ttl, Duration::from_millis(300)))
+        .await
+        .expect_err("lock held by the other guard must fail");
+    assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
+
+    // The last guard releases the lease.
+    again.release().await?;
+    let other = op
+        .lock_options(&path, lock_options("b", ttl, Duration::from_secs(1)))
+        .await?;
+    other.release().await?;
+    Ok(())
+}
+
+/// Fencing tokens must increase across holders.
+pub async fn test_lock_token_increases(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+    let ttl = Duration::from_secs(10);
+
+    let first = op
+        .lock_options(&path, lock_options("a", ttl, Duration::from_secs(1)))
+        .await?;
+    let first_token = first.token();
+    first.release().await?;
+
+    let second = op
+        .lock_options(&path, lock_options("b", ttl, Duration::from_secs(1)))
+        .await?;
+    assert!(
+        second.token() > first_token,
+        "token must increase after release"
+    );
+    second.release().await?;
+    Ok(())
+}
+
+/// The lease must be renewed while the lock is held.
+pub async fn test_lock_renew(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+    let ttl = Duration::from_secs(1);
+
+    let lock = op
+        .lock_options(&path, lock_options("a", ttl, Duration::from_secs(1)))
+        .await?;
+    sleep(Duration::from_millis(2500)).await;
+
+    assert!(!lock.is_lost(), "lease must be renewed");
+    let err = op
+        .lock_options(&path, lock_options("b", ttl, Duration::from_millis(300)))
+        .await
+        .expect_err("lock must still be held after ttl");
+    assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
+
+    lock.release().await?;
+    Ok(())
+}
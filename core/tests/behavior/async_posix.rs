@@ -0,0 +1,91 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use anyhow::Result;
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::*;
+
+pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
+    let cap = op.info().full_capability();
+
+    if cap.stat && cap.write && cap.set_permissions {
+        tests.extend(async_trials!(op, test_set_permissions))
+    }
+
+    if cap.stat && cap.write && cap.set_times {
+        tests.extend(async_trials!(
+            op,
+            test_set_times,
+            test_set_times_keeps_unset_time
+        ))
+    }
+}
+
+/// Set permissions of a file and stat it back.
+pub async fn test_set_permissions(op: Operator) -> Result<()> {
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&path, content).await?;
+
+    op.set_permissions(&path, 0o600).await?;
+    let meta = op.stat(&path).await?;
+    assert_eq!(meta.unix_mode(), Some(0o600));
+
+    op.set_permissions(&path, 0o644).await?;
+    let meta = op.stat(&path).await?;
+    assert_eq!(meta.unix_mode(), Some(0o644));
+
+    Ok(())
+}
+
+/// Set modification time of a file and stat it back.
+pub async fn test_set_times(op: Operator) -> Result<()> {
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&path, content).await?;
+
+    let mtime = DateTime::<Utc>::from_timestamp(1_600_000_000, 0).unwrap();
+    op.set_times(&path, Some(mtime), Some(mtime)).await?;
+
+    let meta = op.stat(&path).await?;
+    assert_eq!(meta.last_modified(), Some(mtime));
+    if let Some(atime) = meta.accessed() {
+        assert_eq!(atime, mtime);
+    }
+
+    Ok(())
+}
+
+/// Times that are not given must be left unchanged.
+pub async fn test_set_times_keeps_unset_time(op: Operator) -> Result<()> {
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&path, content).await?;
+
+    let time = DateTime::<Utc>::from_timestamp(1_600_000_000, 0).unwrap();
+    op.set_times(&path, Some(time), Some(time)).await?;
+
+    let mtime = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+    op.set_times(&path, None, Some(mtime)).await?;
+
+    let meta = op.stat(&path).await?;
+    assert_eq!(meta.last_modified(), Some(mtime));
+    if let Some(atime) = meta.accessed() {
+        assert_eq!(atime, time);
+    }
+
+    Ok(())
+}
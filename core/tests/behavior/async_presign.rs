@@ -40,6 +40,18 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_presign_delete
         ))
     }
+
+    if cap.read && cap.write && cap.presign_multipart {
+        tests.extend(async_trials!(op, test_presign_multipart))
+    }
+
+    if cap.read && cap.write && cap.presign_post {
+        tests.extend(async_trials!(
+            op,
+            test_presign_post,
+            test_presign_post_with_content_length_range
+        ))
+    }
 }
 
 /// Presign write should succeed.
@@ -173,3 +185,132 @@ pub async fn test_presign_delete(op: Operator) -> Result<()> {
     assert!(!op.exists(&path).await.expect("delete must succeed"));
     Ok(())
 }
+
+/// Presigned multipart upload should be completed with client reported etags.
+pub async fn test_presign_multipart(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    // Services like s3 requires every part except the last one to be at least 5MiB.
+    let first = vec![1; 5 * 1024 * 1024];
+    let second = vec![2; 1024];
+
+    let upload = op
+        .presign_multipart(&path, 2, Duration::from_secs(3600))
+        .await?;
+    assert_eq!(upload.parts().len(), 2);
+
+    let client = reqwest::Client::new();
+    let mut parts = Vec::new();
+    for (idx, (signed_req, content)) in upload
+        .parts()
+        .iter()
+        .zip([first.clone(), second.clone()])
+        .enumerate()
+    {
+        let mut req = client.request(
+            signed_req.method().clone(),
+            Url::from_str(&signed_req.uri().to_string()).expect("must be valid url"),
+        );
+        for (k, v) in signed_req.header() {
+            req = req.header(k, v);
+        }
+        req = req.header(header::CONTENT_LENGTH, content.len());
+        req = req.body(reqwest::Body::from(content));
+
+        let resp = req.send().await.expect("send request must succeed");
+        assert_eq!(resp.status(), http::StatusCode::OK, "upload part");
+        let etag = resp
+            .headers()
+            .get(header::ETAG)
+            .expect("etag must be present")
+            .to_str()
+            .expect("etag must be valid");
+        parts.push(UploadPart::new(idx + 1, etag));
+    }
+
+    // Complete should work with parts in any order.
+    parts.reverse();
+    op.complete_upload(&path, upload.upload_id(), parts).await?;
+
+    let bs = op.read(&path).await?.to_bytes();
+    assert_eq!(bs.len(), first.len() + second.len(), "read size");
+    assert_eq!(&bs[..first.len()], &first[..], "first part");
+    assert_eq!(&bs[first.len()..], &second[..], "second part");
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Send a `multipart/form-data` POST request for the presigned post.
+async fn send_presigned_post(
+    post: &raw::PresignedPost,
+    content_type: &str,
+    content: Vec<u8>,
+) -> reqwest::Response {
+    let boundary = uuid::Uuid::new_v4().to_string();
+    let mut body = Vec::new();
+    let fields = post
+        .fields()
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .chain([("Content-Type", content_type)]);
+    for (k, v) in fields {
+        body.extend_from_slice(
+            format!("--{boundary}\r\nContent-Disposition: form-data; name=\"{k}\"\r\n\r\n{v}\r\n")
+                .as_bytes(),
+        );
+    }
+    body.extend_from_slice(
+        format!("--{boundary}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"file\"\r\nContent-Type: {content_type}\r\n\r\n")
+            .as_bytes(),
+    );
+    body.extend_from_slice(&content);
+    body.extend_from_slice(format!("\r\n--{boundary}--\r\n").as_bytes());
+
+    reqwest::Client::new()
+        .post(Url::from_str(&post.uri().to_string()).expect("must be valid url"))
+        .header(
+            header::CONTENT_TYPE,
+            format!("multipart/form-data; boundary={boundary}"),
+        )
+        .body(body)
+        .send()
+        .await
+        .expect("send request must succeed")
+}
+
+/// Presign post should upload content via form.
+pub async fn test_presign_post(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+    let (content, size) = gen_bytes(op.info().full_capability());
+
+    let policy = PostPolicy::new(Duration::from_secs(3600)).with_content_type_prefix("text/");
+    let post = op.presign_post(&path, policy).await?;
+    debug!("Generated post: {post:?}");
+
+    let resp = send_presigned_post(&post, "text/plain", content).await;
+    assert!(resp.status().is_success(), "post must succeed");
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.content_length(), size as u64);
+
+    op.delete(&path).await.expect("delete must succeed");
+    Ok(())
+}
+
+/// Presign post should reject content out of the content length range.
+pub async fn test_presign_post_with_content_length_range(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+    debug!("Generate a random file: {}", &path);
+
+    let policy = PostPolicy::new(Duration::from_secs(3600)).with_content_length_range(0, 16);
+    let post = op.presign_post(&path, policy).await?;
+
+    let resp = send_presigned_post(&post, "text/plain", vec![0; 32]).await;
+    assert!(resp.status().is_client_error(), "post must be rejected");
+
+    let res = op.stat(&path).await;
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::NotFound);
+    Ok(())
+}
@@ -0,0 +1,142 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use anyhow::Result;
+
+use crate::*;
+
+pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
+    let cap = op.info().full_capability();
+
+    if cap.read && cap.write && cap.create_symlink && cap.read_link {
+        tests.extend(async_trials!(
+            op,
+            test_create_symlink_and_read_link,
+            test_create_symlink_existing_path,
+            test_read_symlink,
+            test_stat_symlink_without_follow,
+            test_list_symlink,
+            test_delete_symlink
+        ))
+    }
+}
+
+/// Create a symlink and read its target back.
+pub async fn test_create_symlink_and_read_link(op: Operator) -> Result<()> {
+    let dir = format!("{}/", uuid::Uuid::new_v4());
+    let target = uuid::Uuid::new_v4().to_string();
+    let link = format!("{dir}{}", uuid::Uuid::new_v4());
+
+    op.create_symlink(&target, &link).await?;
+
+    let actual = op.read_link(&link).await?;
+    assert_eq!(actual, target, "read_link must return the target as is");
+
+    op.remove_all(&dir).await?;
+    Ok(())
+}
+
+/// Create a symlink on an existing path should fail.
+pub async fn test_create_symlink_existing_path(op: Operator) -> Result<()> {
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&path, content).await?;
+
+    let res = op.create_symlink("target", &path).await;
+    assert!(res.is_err(), "create symlink on existing path must fail");
+
+    Ok(())
+}
+
+/// Read a symlink should read the content of its target.
+pub async fn test_read_symlink(op: Operator) -> Result<()> {
+    let dir = format!("{}/", uuid::Uuid::new_v4());
+    let name = uuid::Uuid::new_v4().to_string();
+    let (content, size) = gen_bytes(op.info().full_capability());
+    op.write(&format!("{dir}{name}"), content.clone()).await?;
+
+    let link = format!("{dir}{}", uuid::Uuid::new_v4());
+    op.create_symlink(&name, &link).await?;
+
+    let meta = op.stat(&link).await?;
+    assert_eq!(meta.mode(), EntryMode::FILE, "stat must follow symlink");
+    assert_eq!(meta.content_length(), size as u64);
+
+    let bs = op.read(&link).await?.to_bytes();
+    assert_eq!(bs, content, "read must follow symlink");
+
+    op.remove_all(&dir).await?;
+    Ok(())
+}
+
+/// Stat a symlink without following it should return the symlink itself.
+pub async fn test_stat_symlink_without_follow(op: Operator) -> Result<()> {
+    if !op.info().full_capability().stat_with_follow_symlinks {
+        return Ok(());
+    }
+
+    let dir = format!("{}/", uuid::Uuid::new_v4());
+    let link = format!("{dir}{}", uuid::Uuid::new_v4());
+    op.create_symlink("not_exist", &link).await?;
+
+    let meta = op.stat_with(&link).follow_symlinks(false).await?;
+    assert_eq!(meta.mode(), EntryMode::Symlink);
+    assert_eq!(meta.symlink_target(), Some("not_exist"));
+
+    op.remove_all(&dir).await?;
+    Ok(())
+}
+
+/// List should return symlink as is.
+pub async fn test_list_symlink(op: Operator) -> Result<()> {
+    let dir = format!("{}/", uuid::Uuid::new_v4());
+    let link = format!("{dir}{}", uuid::Uuid::new_v4());
+    op.create_symlink("not_exist", &link).await?;
+
+    let entries = op.list(&dir).await?;
+    let entry = entries
+        .iter()
+        .find(|e| e.path() == link)
+        .expect("symlink must be listed");
+    assert_eq!(entry.metadata().mode(), EntryMode::Symlink);
+
+    op.remove_all(&dir).await?;
+    Ok(())
+}
+
+/// Delete a symlink should not delete its target.
+pub async fn test_delete_symlink(op: Operator) -> Result<()> {
+    let dir = format!("{}/", uuid::Uuid::new_v4());
+    let name = uuid::Uuid::new_v4().to_string();
+    let (content, _) = gen_bytes(op.info().full_capability());
+    op.write(&format!("{dir}{name}"), content).await?;
+
+    let link = format!("{dir}{}", uuid::Uuid::new_v4());
+    op.create_symlink(&name, &link).await?;
+
+    op.delete(&link).await?;
+    assert!(
+        !op.exists(&link).await?,
+        "symlink must be removed after delete"
+    );
+    assert!(
+        op.exists(&format!("{dir}{name}")).await?,
+        "target must not be removed"
+    );
+
+    op.remove_all(&dir).await?;
+    Ok(())
+}
@@ -0,0 +1,122 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use anyhow::Result;
+
+use crate::*;
+
+pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
+    let cap = op.info().full_capability();
+
+    if cap.read && cap.write && cap.transaction {
+        tests.extend(async_trials!(
+            op,
+            test_transaction_commit,
+            test_transaction_read_own_writes,
+            test_transaction_conflict,
+            test_transaction_conflict_on_not_existing,
+            test_transaction_drop_without_commit
+        ))
+    }
+}
+
+/// Writes and deletes in a transaction should be visible after commit.
+pub async fn test_transaction_commit(op: Operator) -> Result<()> {
+    let (first, content, _) = TEST_FIXTURE.new_file(op.clone());
+    let (second, _, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&second, "to be deleted").await?;
+
+    let mut txn = op.transaction()?;
+    txn.write(&first, content.clone())?;
+    txn.delete(&second)?;
+
+    // Nothing is visible before commit.
+    assert!(!op.exists(&first).await?);
+    assert!(op.exists(&second).await?);
+
+    txn.commit().await?;
+    assert_eq!(op.read(&first).await?.to_bytes(), content);
+    assert!(!op.exists(&second).await?);
+    Ok(())
+}
+
+/// Reads in a transaction should see its own writes.
+pub async fn test_transaction_read_own_writes(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+    op.write(&path, "old").await?;
+
+    let mut txn = op.transaction()?;
+    assert_eq!(txn.read(&path).await?.to_vec(), b"old");
+    txn.write(&path, "new")?;
+    assert_eq!(txn.read(&path).await?.to_vec(), b"new");
+    txn.delete(&path)?;
+    let err = txn.read(&path).await.expect_err("read deleted must fail");
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+    txn.commit().await?;
+
+    assert!(!op.exists(&path).await?);
+    Ok(())
+}
+
+/// Commit should fail if paths read in the transaction are changed by others.
+pub async fn test_transaction_conflict(op: Operator) -> Result<()> {
+    let manifest = TEST_FIXTURE.new_file_path();
+    let segment = TEST_FIXTURE.new_file_path();
+    op.write(&manifest, "v1").await?;
+
+    let mut txn = op.transaction()?;
+    txn.read(&manifest).await?;
+    txn.write(&segment, "segment")?;
+    txn.write(&manifest, "v2")?;
+
+    op.write(&manifest, "changed").await?;
+
+    let err = txn.commit().await.expect_err("commit must fail");
+    assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
+    assert_eq!(op.read(&manifest).await?.to_vec(), b"changed");
+    assert!(!op.exists(&segment).await?);
+    Ok(())
+}
+
+/// Commit should fail if a path that didn't exist in the transaction is created by others.
+pub async fn test_transaction_conflict_on_not_existing(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+
+    let mut txn = op.transaction()?;
+    let err = txn.read(&path).await.expect_err("read must fail");
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+    txn.write(&path, "mine")?;
+
+    op.write(&path, "others").await?;
+
+    let err = txn.commit().await.expect_err("commit must fail");
+    assert_eq!(err.kind(), ErrorKind::ConditionNotMatch);
+    assert_eq!(op.read(&path).await?.to_vec(), b"others");
+    Ok(())
+}
+
+/// Dropping a transaction without commit should write nothing.
+pub async fn test_transaction_drop_without_commit(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+
+    let mut txn = op.transaction()?;
+    txn.write(&path, "content")?;
+    drop(txn);
+
+    assert!(!op.exists(&path).await?);
+    Ok(())
+}
@@ -0,0 +1,111 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use anyhow::Result;
+use futures::future::try_join_all;
+
+use crate::*;
+
+pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
+    let cap = op.info().full_capability();
+
+    if cap.read && cap.write && cap.write_with_if_match && cap.write_with_if_not_exists {
+        tests.extend(async_trials!(
+            op,
+            test_update_not_existing,
+            test_update_existing,
+            test_update_unchanged,
+            test_update_error,
+            test_update_concurrent
+        ))
+    }
+}
+
+fn increase(current: Option<Buffer>) -> opendal::Result<Option<Buffer>> {
+    let count = match current {
+        Some(bs) => String::from_utf8(bs.to_vec())
+            .expect("content must be utf-8")
+            .parse::<u64>()
+            .expect("content must be number"),
+        None => 0,
+    };
+    Ok(Some(Buffer::from((count + 1).to_string())))
+}
+
+/// Update a not existing file should create it.
+pub async fn test_update_not_existing(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+
+    let content = op.update(&path, increase).await?;
+    assert_eq!(content.map(|v| v.to_vec()), Some(b"1".to_vec()));
+    assert_eq!(op.read(&path).await?.to_vec(), b"1");
+    Ok(())
+}
+
+/// Update an existing file should be based on its content.
+pub async fn test_update_existing(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+    op.write(&path, "41").await?;
+
+    let content = op.update(&path, increase).await?;
+    assert_eq!(content.map(|v| v.to_vec()), Some(b"42".to_vec()));
+    assert_eq!(op.read(&path).await?.to_vec(), b"42");
+    Ok(())
+}
+
+/// Returning `None` from the closure should leave the file as is.
+pub async fn test_update_unchanged(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+
+    let content = op.update(&path, |_| Ok(None)).await?;
+    assert!(content.is_none());
+    assert!(!op.exists(&path).await?);
+
+    op.write(&path, "hello").await?;
+    let content = op.update(&path, |_| Ok(None)).await?;
+    assert_eq!(content.map(|v| v.to_vec()), Some(b"hello".to_vec()));
+    Ok(())
+}
+
+/// Errors returned by the closure should be returned without retry.
+pub async fn test_update_error(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+    op.write(&path, "hello").await?;
+
+    let mut calls = 0;
+    let err = op
+        .update(&path, |_| {
+            calls += 1;
+            Err(Error::new(ErrorKind::Unexpected, "closure failed"))
+        })
+        .await
+        .expect_err("update must fail");
+    assert_eq!(err.kind(), ErrorKind::Unexpected);
+    assert_eq!(calls, 1);
+    assert_eq!(op.read(&path).await?.to_vec(), b"hello");
+    Ok(())
+}
+
+/// Concurrent updates should not lose any changes.
+pub async fn test_update_concurrent(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+    let opts = options::UpdateOptions { max_retries: 100 };
+
+    try_join_all((0..5).map(|_| op.update_options(&path, opts.clone(), increase))).await?;
+    assert_eq!(op.read(&path).await?.to_vec(), b"5");
+    Ok(())
+}
@@ -0,0 +1,136 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use crate::*;
+
+pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
+    let cap = op.info().full_capability();
+
+    if cap.stat && cap.write && cap.update_metadata {
+        tests.extend(async_trials!(
+            op,
+            test_update_metadata,
+            test_update_metadata_keeps_unset_fields,
+            test_update_metadata_keeps_content,
+            test_update_metadata_not_exist
+        ))
+    }
+
+    if cap.stat && cap.write && cap.update_metadata_with_if_match {
+        tests.extend(async_trials!(op, test_update_metadata_with_if_match))
+    }
+}
+
+/// Update metadata of a file and stat it back.
+pub async fn test_update_metadata(op: Operator) -> Result<()> {
+    let (path, content, size) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&path, content).await?;
+
+    let update = MetadataUpdate::new()
+        .with_content_type("text/plain")
+        .with_user_metadata([("location".to_string(), "everywhere".to_string())]);
+    let meta = op.update_metadata(&path, update).await?;
+    assert_eq!(meta.content_type(), Some("text/plain"));
+
+    let meta = op.stat(&path).await?;
+    assert_eq!(meta.content_length(), size as u64);
+    assert_eq!(meta.content_type(), Some("text/plain"));
+    if op.info().full_capability().write_with_user_metadata {
+        assert_eq!(
+            meta.user_metadata(),
+            Some(&HashMap::from([(
+                "location".to_string(),
+                "everywhere".to_string()
+            )]))
+        );
+    }
+
+    Ok(())
+}
+
+/// Fields that are not given must be left unchanged.
+pub async fn test_update_metadata_keeps_unset_fields(op: Operator) -> Result<()> {
+    if !op.info().full_capability().write_with_content_type {
+        return Ok(());
+    }
+
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write_with(&path, content)
+        .content_type("text/plain")
+        .await?;
+
+    let update = MetadataUpdate::new().with_cache_control("no-cache");
+    op.update_metadata(&path, update).await?;
+
+    let meta = op.stat(&path).await?;
+    assert_eq!(meta.content_type(), Some("text/plain"));
+    assert_eq!(meta.cache_control(), Some("no-cache"));
+
+    Ok(())
+}
+
+/// Update metadata must not change the content of file.
+pub async fn test_update_metadata_keeps_content(op: Operator) -> Result<()> {
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&path, content.clone()).await?;
+
+    let update = MetadataUpdate::new().with_content_type("text/plain");
+    op.update_metadata(&path, update).await?;
+
+    let bs = op.read(&path).await?.to_bytes();
+    assert_eq!(bs, content, "content must be unchanged");
+
+    Ok(())
+}
+
+/// Update metadata of a not existing file should return NotFound.
+pub async fn test_update_metadata_not_exist(op: Operator) -> Result<()> {
+    let path = uuid::Uuid::new_v4().to_string();
+
+    let update = MetadataUpdate::new().with_content_type("text/plain");
+    let res = op.update_metadata(&path, update).await;
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::NotFound);
+
+    Ok(())
+}
+
+/// Update metadata with a mismatched etag should fail.
+pub async fn test_update_metadata_with_if_match(op: Operator) -> Result<()> {
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write(&path, content).await?;
+
+    let meta = op.stat(&path).await?;
+
+    let update = MetadataUpdate::new()
+        .with_content_type("text/plain")
+        .with_if_match("\"invalid_etag\"");
+    let res = op.update_metadata(&path, update).await;
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::ConditionNotMatch);
+
+    let update = MetadataUpdate::new()
+        .with_content_type("text/plain")
+        .with_if_match(meta.etag().expect("etag must exist"));
+    op.update_metadata(&path, update).await?;
+
+    Ok(())
+}
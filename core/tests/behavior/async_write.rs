@@ -16,9 +16,11 @@
 // under the License.
 
 use std::collections::HashMap;
+use std::time::Duration;
 
 use anyhow::Result;
 use bytes::Bytes;
+use chrono::Utc;
 use futures::io::BufReader;
 use futures::io::Cursor;
 use futures::stream;
@@ -28,6 +30,7 @@ use futures::StreamExt;
 use log::warn;
 use sha2::Digest;
 use sha2::Sha256;
+use tokio::time::sleep;
 
 use crate::*;
 
@@ -63,6 +66,10 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
         ))
     }
 
+    if cap.write && cap.write_can_multi && cap.list_uploads && cap.abort_upload {
+        tests.extend(async_trials!(op, test_writer_list_and_abort_uploads))
+    }
+
     if cap.read && cap.write && cap.write_can_append && cap.stat {
         tests.extend(async_trials!(
             op,
@@ -71,6 +78,22 @@ pub fn tests(op: &Operator, tests: &mut Vec<Trial>) {
             test_writer_with_append
         ))
     }
+
+    if cap.read && cap.write && cap.write_can_random_access && cap.stat {
+        tests.extend(async_trials!(op, test_writer_write_at))
+    }
+
+    if cap.read && cap.write && cap.write_can_set_len && cap.stat {
+        tests.extend(async_trials!(op, test_writer_set_len))
+    }
+
+    if cap.read && cap.write && cap.write_with_ttl && cap.stat {
+        tests.extend(async_trials!(
+            op,
+            test_write_with_ttl,
+            test_write_with_ttl_expired
+        ))
+    }
 }
 
 /// Write a single file and test with stat.
@@ -255,6 +278,59 @@ pub async fn test_write_with_user_metadata(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Write a file with ttl, it should be readable before expired.
+pub async fn test_write_with_ttl(op: Operator) -> Result<()> {
+    let (path, content, size) = TEST_FIXTURE.new_file(op.clone());
+
+    let start = Utc::now();
+    let meta = op
+        .write_with(&path, content.clone())
+        .ttl(Duration::from_secs(300))
+        .await?;
+    if let Some(expires_at) = meta.expires_at() {
+        assert!(expires_at > start, "expires_at must be in the future");
+    }
+
+    let meta = op.stat(&path).await.expect("stat must succeed");
+    assert_eq!(meta.content_length(), size as u64);
+    if let Some(expires_at) = meta.expires_at() {
+        assert!(expires_at > start, "expires_at must be in the future");
+        assert!(
+            expires_at <= Utc::now() + Duration::from_secs(301),
+            "expires_at must not be later than ttl"
+        );
+    }
+
+    let bs = op.read(&path).await?.to_bytes();
+    assert_eq!(bs, content);
+
+    Ok(())
+}
+
+/// Write a file with a short ttl, it should be removed after expired.
+pub async fn test_write_with_ttl_expired(op: Operator) -> Result<()> {
+    // Cloudflare KV requires the ttl to be at least 60 seconds.
+    if op.info().scheme() == Scheme::CloudflareKv {
+        return Ok(());
+    }
+
+    let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
+    op.write_with(&path, content)
+        .ttl(Duration::from_secs(1))
+        .await?;
+
+    // Services expire keys at their own granularity, wait for a while.
+    for _ in 0..20 {
+        sleep(Duration::from_millis(500)).await;
+        match op.stat(&path).await {
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err.into()),
+            Ok(_) => continue,
+        }
+    }
+    panic!("file written with ttl must be removed after expired")
+}
+
 pub async fn test_write_returns_metadata(op: Operator) -> Result<()> {
     let (path, content, _) = TEST_FIXTURE.new_file(op.clone());
 
@@ -320,6 +396,36 @@ pub async fn test_writer_abort_with_concurrent(op: Operator) -> Result<()> {
     Ok(())
 }
 
+/// Writer dropped without close should leave an upload that can be listed and aborted.
+pub async fn test_writer_list_and_abort_uploads(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+    let size = 5 * 1024 * 1024; // write file with 5 MiB
+    let content_a = gen_fixed_bytes(size);
+    let content_b = gen_fixed_bytes(size);
+
+    let mut w = op.writer_with(&path).chunk(size).await?;
+    w.write(content_a).await?;
+    w.write(content_b).await?;
+    // Drop the writer without close or abort to leave the upload behind.
+    drop(w);
+
+    let uploads = op.list_uploads(&path).await?;
+    let upload = uploads
+        .iter()
+        .find(|v| v.path() == path)
+        .expect("upload of dropped writer must be listed");
+
+    op.abort_upload(&path, upload.upload_id()).await?;
+
+    let uploads = op.list_uploads(&path).await?;
+    assert!(
+        !uploads.iter().any(|v| v.path() == path),
+        "aborted upload must not be listed"
+    );
+    assert!(!op.exists(&path).await?);
+    Ok(())
+}
+
 /// Append data into writer
 pub async fn test_writer_write(op: Operator) -> Result<()> {
     if !(op.info().full_capability().write_can_multi) {
@@ -817,3 +923,64 @@ pub async fn test_write_with_if_match(op: Operator) -> Result<()> {
 
     Ok(())
 }
+
+/// Write blocks out of order with `write_at`.
+pub async fn test_writer_write_at(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+    // Services emulating random access require offsets aligned to chunk.
+    let size = op
+        .info()
+        .full_capability()
+        .write_multi_min_size
+        .unwrap_or(4096);
+    let content_a = gen_fixed_bytes(size);
+    let content_b = gen_fixed_bytes(size);
+    let content_c = gen_fixed_bytes(size / 2);
+
+    let mut w = op
+        .writer_with(&path)
+        .random_access(true)
+        .chunk(size)
+        .await?;
+    w.write_at((size * 2) as u64, content_c.clone()).await?;
+    w.write_at(0, content_a.clone()).await?;
+    w.write_at(size as u64, content_b.clone()).await?;
+    w.close().await?;
+
+    let bs = op.read(&path).await?.to_bytes();
+    assert_eq!(bs.len(), size * 2 + size / 2, "read size");
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs[..size])),
+        format!("{:x}", Sha256::digest(content_a)),
+        "read content a"
+    );
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs[size..size * 2])),
+        format!("{:x}", Sha256::digest(content_b)),
+        "read content b"
+    );
+    assert_eq!(
+        format!("{:x}", Sha256::digest(&bs[size * 2..])),
+        format!("{:x}", Sha256::digest(content_c)),
+        "read content c"
+    );
+
+    Ok(())
+}
+
+/// Truncate and extend file with `set_len`.
+pub async fn test_writer_set_len(op: Operator) -> Result<()> {
+    let path = TEST_FIXTURE.new_file_path();
+
+    let mut w = op.writer_with(&path).random_access(true).await?;
+    w.write("hello, world").await?;
+    w.set_len(5).await?;
+    w.set_len(8).await?;
+    w.write_at(5, "!").await?;
+    w.close().await?;
+
+    let bs = op.read(&path).await?.to_bytes();
+    assert_eq!(bs.as_ref(), b"hello!\0\0");
+
+    Ok(())
+}
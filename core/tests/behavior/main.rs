@@ -21,14 +21,21 @@ mod utils;
 
 pub use utils::*;
 
+mod async_concat;
 mod async_copy;
 mod async_create_dir;
 mod async_delete;
 mod async_list;
+mod async_lock;
+mod async_posix;
 mod async_presign;
 mod async_read;
 mod async_rename;
 mod async_stat;
+mod async_symlink;
+mod async_transaction;
+mod async_update;
+mod async_update_metadata;
 mod async_write;
 
 // External dependencies
@@ -51,14 +58,21 @@ fn main() -> anyhow::Result<()> {
 
     let mut tests = Vec::new();
 
+    async_concat::tests(&op, &mut tests);
     async_copy::tests(&op, &mut tests);
     async_create_dir::tests(&op, &mut tests);
     async_delete::tests(&op, &mut tests);
     async_list::tests(&op, &mut tests);
+    async_lock::tests(&op, &mut tests);
+    async_posix::tests(&op, &mut tests);
     async_presign::tests(&op, &mut tests);
     async_read::tests(&op, &mut tests);
     async_rename::tests(&op, &mut tests);
     async_stat::tests(&op, &mut tests);
+    async_symlink::tests(&op, &mut tests);
+    async_transaction::tests(&op, &mut tests);
+    async_update::tests(&op, &mut tests);
+    async_update_metadata::tests(&op, &mut tests);
     async_write::tests(&op, &mut tests);
 
     // Don't init logging while building operator which may break cargo
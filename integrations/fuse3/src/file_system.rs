@@ -21,11 +21,13 @@ use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 use bytes::Bytes;
 use fuse3::path::prelude::*;
 use fuse3::Errno;
 use fuse3::Result;
+use fuse3::Timestamp;
 use futures_util::stream;
 use futures_util::stream::BoxStream;
 use futures_util::StreamExt;
@@ -101,6 +103,11 @@ impl Filesystem {
         }
     }
 
+    /// Whether the service allows writing at arbitrary offsets.
+    fn random_access(&self) -> bool {
+        self.op.info().full_capability().write_can_random_access
+    }
+
     fn check_flags(&self, flags: u32) -> Result<(bool, bool, bool)> {
         let is_trunc = flags & libc::O_TRUNC as u32 != 0 || flags & libc::O_CREAT as u32 != 0;
         let is_append = flags & libc::O_APPEND as u32 != 0;
@@ -231,6 +238,47 @@ impl PathFilesystem for Filesystem {
     ) -> Result<ReplyAttr> {
         log::debug!("setattr(path={path:?}, fh={fh:?}, set_attr={set_attr:?})");
 
+        // Attributes not supported by the service are ignored, so that tools
+        // like `touch` and `cp -p` keep working.
+        let capability = self.op.info().full_capability();
+        if let Some(file_path) = path.map(|p| p.to_string_lossy()) {
+            if let (Some(mode), true) = (set_attr.mode, capability.set_permissions) {
+                // `mode_t` is `u16` on macOS.
+                #[allow(clippy::unnecessary_cast)]
+                let mode = mode as u32;
+                self.op
+                    .set_permissions(&file_path, mode)
+                    .await
+                    .map_err(opendal_error2errno)?;
+            }
+            if (set_attr.atime.is_some() || set_attr.mtime.is_some()) && capability.set_times {
+                self.op
+                    .set_times(
+                        &file_path,
+                        set_attr.atime.map(|t| timestamp2system_time(t).into()),
+                        set_attr.mtime.map(|t| timestamp2system_time(t).into()),
+                    )
+                    .await
+                    .map_err(opendal_error2errno)?;
+            }
+        }
+
+        // Truncating an opened file, like `ftruncate` does.
+        if let (Some(size), Some(fh), true) = (set_attr.size, fh, capability.write_can_set_len) {
+            let inner_writer = self
+                .get_opened_file(FileKey::try_from(fh)?, path)?
+                .inner_writer
+                .clone();
+            if let Some(inner_writer) = inner_writer {
+                let mut inner = inner_writer.lock().await;
+                inner
+                    .writer
+                    .set_len(size)
+                    .await
+                    .map_err(opendal_error2errno)?;
+            }
+        }
+
         self.getattr(_req, path, fh, 0).await
     }
 
@@ -363,6 +411,7 @@ impl PathFilesystem for Filesystem {
                 .op
                 .writer_with(&path.to_string_lossy())
                 .append(is_append)
+                .random_access(self.random_access() && !is_append)
                 .await
                 .map_err(opendal_error2errno)?;
             let written = if is_append {
@@ -452,18 +501,24 @@ impl PathFilesystem for Filesystem {
         };
 
         let mut inner = inner_writer.lock().await;
-        // OpenDAL doesn't support random write
-        if offset != inner.written {
+        if offset == inner.written {
+            inner
+                .writer
+                .write_from(data)
+                .await
+                .map_err(opendal_error2errno)?;
+            inner.written += data.len() as u64;
+        } else if self.random_access() {
+            inner
+                .writer
+                .write_at(offset, Bytes::copy_from_slice(data))
+                .await
+                .map_err(opendal_error2errno)?;
+        } else {
+            // Services without random access only support sequential writes.
             Err(Errno::from(libc::EINVAL))?;
         }
 
-        inner
-            .writer
-            .write_from(data)
-            .await
-            .map_err(opendal_error2errno)?;
-        inner.written += data.len() as u64;
-
         Ok(ReplyWrite {
             written: data.len() as _,
         })
@@ -606,6 +661,7 @@ impl PathFilesystem for Filesystem {
                 .writer_with(&path.to_string_lossy())
                 .chunk(4 * 1024 * 1024)
                 .append(is_append)
+                .random_access(self.random_access() && !is_append)
                 .await
                 .map_err(opendal_error2errno)?;
             Some(Arc::new(Mutex::new(InnerWriter { writer, written: 0 })))
@@ -771,19 +827,33 @@ impl PathFilesystem for Filesystem {
 const fn entry_mode2file_type(mode: EntryMode) -> FileType {
     match mode {
         EntryMode::DIR => FileType::Directory,
+        EntryMode::Symlink => FileType::Symlink,
         _ => FileType::RegularFile,
     }
 }
 
+/// Build file attr from metadata, the given `atime`, `uid` and `gid` are
+/// used as fallback if the service doesn't return POSIX attributes.
 fn metadata2file_attr(metadata: &Metadata, atime: SystemTime, uid: u32, gid: u32) -> FileAttr {
     let last_modified = metadata.last_modified().map(|t| t.into()).unwrap_or(atime);
     let kind = entry_mode2file_type(metadata.mode());
-    FileAttr {
+    let mut attr = FileAttr {
         size: metadata.content_length(),
+        atime: metadata.accessed().map(|t| t.into()).unwrap_or(atime),
         mtime: last_modified,
         ctime: last_modified,
+        uid: metadata.uid().unwrap_or(uid),
+        gid: metadata.gid().unwrap_or(gid),
         ..dummy_file_attr(kind, atime, uid, gid)
+    };
+    if let Some(mode) = metadata.unix_mode() {
+        attr.perm = fuse3::perm_from_mode_and_kind(kind, mode);
+    }
+    #[cfg(target_os = "macos")]
+    if let Some(created) = metadata.created() {
+        attr.crtime = created.into();
     }
+    attr
 }
 
 const fn dummy_file_attr(kind: FileType, now: SystemTime, uid: u32, gid: u32) -> FileAttr {
@@ -807,6 +877,15 @@ const fn dummy_file_attr(kind: FileType, now: SystemTime, uid: u32, gid: u32) ->
     }
 }
 
+fn timestamp2system_time(ts: Timestamp) -> SystemTime {
+    if ts.sec >= 0 {
+        UNIX_EPOCH + Duration::new(ts.sec as u64, ts.nsec)
+    } else {
+        UNIX_EPOCH - Duration::from_secs(ts.sec.unsigned_abs())
+            + Duration::from_nanos(ts.nsec as u64)
+    }
+}
+
 fn opendal_error2errno(err: opendal::Error) -> fuse3::Errno {
     log::trace!("opendal_error2errno: {err:?}");
     match err.kind() {
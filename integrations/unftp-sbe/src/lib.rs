@@ -96,7 +96,7 @@ impl storage::Metadata for OpendalMetadata {
     }
 
     fn is_symlink(&self) -> bool {
-        false
+        self.0.is_symlink()
     }
 
     fn modified(&self) -> storage::Result<std::time::SystemTime> {
@@ -106,11 +106,20 @@ impl storage::Metadata for OpendalMetadata {
     }
 
     fn gid(&self) -> u32 {
-        0
+        self.0.gid().unwrap_or(0)
     }
 
     fn uid(&self) -> u32 {
-        0
+        self.0.uid().unwrap_or(0)
+    }
+
+    fn permissions(&self) -> storage::Permissions {
+        // Fallback to the same default as `storage::Metadata`.
+        storage::Permissions(self.0.unix_mode().unwrap_or(0o7755))
+    }
+
+    fn readlink(&self) -> Option<&Path> {
+        self.0.symlink_target().map(Path::new)
     }
 }
 